@@ -331,6 +331,80 @@ fn memcached_ttl_benchmarks(c: &mut Criterion) {
     group.finish();
 }
 
+// ============================================================================
+// Group 5: Memcached Counter Operations
+// ============================================================================
+
+fn memcached_counter_benchmarks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("memcached_counters");
+    group.sample_size(50);
+
+    // Create tokio runtime for async operations
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+
+    let backend = rt.block_on(async { setup_memcached().await });
+
+    // Clear any existing data
+    rt.block_on(async { backend.clear_all().await })
+        .expect("Failed to clear Memcached");
+
+    // INCR (cache hit)
+    group.bench_function("incr_hit", |b| {
+        rt.block_on(async {
+            backend
+                .set("memcached_bench_counter", b"0".to_vec(), None)
+                .await
+                .expect("Failed to seed counter");
+        });
+
+        b.to_async(&rt).iter(|| async {
+            backend
+                .incr(black_box("memcached_bench_counter"), black_box(1), 0, None)
+                .await
+                .expect("Failed to incr")
+        });
+    });
+
+    // DECR (cache hit)
+    group.bench_function("decr_hit", |b| {
+        rt.block_on(async {
+            backend
+                .set("memcached_bench_decr_counter", b"1000000".to_vec(), None)
+                .await
+                .expect("Failed to seed counter");
+        });
+
+        b.to_async(&rt).iter(|| async {
+            backend
+                .decr(black_box("memcached_bench_decr_counter"), black_box(1), 0, None)
+                .await
+                .expect("Failed to decr")
+        });
+    });
+
+    // INCR (cache miss, so every iteration pays the ADD-initialization path)
+    group.bench_function("incr_miss", |b| {
+        let counter = std::sync::atomic::AtomicU64::new(0);
+
+        b.to_async(&rt).iter(|| async {
+            let key = format!(
+                "memcached_bench_incr_miss_{}",
+                counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+            );
+            backend
+                .incr(black_box(&key), black_box(1), 0, None)
+                .await
+                .expect("Failed to incr")
+        });
+    });
+
+    // Cleanup
+    rt.block_on(async { backend.clear_all().await })
+        .expect("Failed to clear Memcached");
+
+    group.finish();
+}
+
 // ============================================================================
 // Benchmark Registration
 // ============================================================================
@@ -340,6 +414,7 @@ criterion_group!(
     memcached_basic_benchmarks,
     memcached_batch_benchmarks,
     memcached_protocol_benchmarks,
-    memcached_ttl_benchmarks
+    memcached_ttl_benchmarks,
+    memcached_counter_benchmarks
 );
 criterion_main!(benches);
@@ -0,0 +1,156 @@
+//! `#[derive(CacheEntity)]` for `cache_kit::CacheEntity`.
+//!
+//! Hand-implementing `cache_key()` and `cache_prefix()` for every entity is
+//! mechanical and easy to get subtly wrong (wrong field cloned, prefix typo'd
+//! differently from the one used elsewhere). This derive generates both from
+//! two attributes instead:
+//!
+//! ```ignore
+//! use cache_kit::CacheEntity;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Clone, Serialize, Deserialize, CacheEntity)]
+//! #[cache(prefix = "employment")]
+//! struct Employment {
+//!     #[cache(key)]
+//!     id: String,
+//!     employer_name: String,
+//! }
+//! ```
+//!
+//! `type Key` is inferred from the `#[cache(key)]` field's type, `cache_key()`
+//! clones it, and `cache_prefix()` returns the `prefix` literal. Exactly one
+//! field must carry `#[cache(key)]`; zero or multiple is a compile error.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+mod memoize;
+
+#[proc_macro_derive(CacheEntity, attributes(cache))]
+pub fn derive_cache_entity(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let prefix = match cache_prefix(&input) {
+        Ok(prefix) => prefix,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let key_field = match cache_key_field(&input) {
+        Ok(field) => field,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let key_ty = &key_field.ty;
+    let key_ident = key_field.ident.as_ref().expect("named field has an ident");
+
+    let expanded = quote! {
+        impl #impl_generics ::cache_kit::CacheEntity for #ident #ty_generics #where_clause {
+            type Key = #key_ty;
+
+            fn cache_key(&self) -> Self::Key {
+                self.#key_ident.clone()
+            }
+
+            fn cache_prefix() -> &'static str {
+                #prefix
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+/// Pull `prefix` out of `#[cache(prefix = "...")]` on the struct.
+fn cache_prefix(input: &DeriveInput) -> syn::Result<String> {
+    for attr in &input.attrs {
+        if !attr.path().is_ident("cache") {
+            continue;
+        }
+
+        let mut prefix = None;
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("prefix") {
+                let lit: syn::LitStr = meta.value()?.parse()?;
+                prefix = Some(lit.value());
+                Ok(())
+            } else {
+                Err(meta.error("unsupported #[cache(..)] key, expected `prefix`"))
+            }
+        })?;
+
+        if let Some(prefix) = prefix {
+            return Ok(prefix);
+        }
+    }
+
+    Err(syn::Error::new_spanned(
+        &input.ident,
+        "#[derive(CacheEntity)] requires #[cache(prefix = \"...\")] on the struct",
+    ))
+}
+
+/// Find the single field annotated `#[cache(key)]`.
+fn cache_key_field(input: &DeriveInput) -> syn::Result<syn::Field> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "#[derive(CacheEntity)] only supports structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "#[derive(CacheEntity)] requires named fields",
+        ));
+    };
+
+    let mut matches: Vec<&syn::Field> = fields
+        .named
+        .iter()
+        .filter(|field| field.attrs.iter().any(is_cache_key_attr))
+        .collect();
+
+    match matches.len() {
+        0 => Err(syn::Error::new_spanned(
+            &input.ident,
+            "#[derive(CacheEntity)] requires exactly one field annotated #[cache(key)], found none",
+        )),
+        1 => Ok(matches.remove(0).clone()),
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            "#[derive(CacheEntity)] requires exactly one field annotated #[cache(key)], found more than one",
+        )),
+    }
+}
+
+/// Whether `attr` is `#[cache(key)]`.
+fn is_cache_key_attr(attr: &syn::Attribute) -> bool {
+    if !attr.path().is_ident("cache") {
+        return false;
+    }
+    let mut is_key = false;
+    let _ = attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("key") {
+            is_key = true;
+        }
+        Ok(())
+    });
+    is_key
+}
+
+/// `#[cache_kit(backend = ..., prefix = "...", ttl = "30s")]` memoizes an
+/// `async fn(..) -> Result<T, E>` (`T: Serialize + DeserializeOwned`)
+/// through any `cache_kit::CacheBackend`, the same round trip
+/// `CacheExpander::with`/`CacheFeed` hand-wire, without the feeder
+/// boilerplate - see [`memoize`] for the full attribute grammar and
+/// generated code.
+#[proc_macro_attribute]
+pub fn cache_kit(attr: TokenStream, item: TokenStream) -> TokenStream {
+    memoize::expand(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,298 @@
+//! Implementation of `#[cache_kit::cache_kit]`, the function-memoization
+//! attribute macro. Split out of `lib.rs` since the grammar (parsing the
+//! attribute args) and the codegen (rewriting the function) are each
+//! substantial on their own.
+//!
+//! # Grammar
+//!
+//! ```ignore
+//! #[cache_kit::cache_kit(
+//!     backend = BACKEND_EXPR,   // required: anything implementing CacheBackend
+//!     prefix = "users",         // required: cache key namespace
+//!     ttl = "30s",              // optional: "500ms" / "30s" / "5m" / "1h"; omit for no TTL
+//!     key = |id| id.to_string(),// optional: custom key derivation, still namespaced by `prefix`
+//!     refresh,                  // optional flag: see below
+//! )]
+//! async fn get_user(id: u64) -> Result<User, MyError> { .. }
+//! ```
+//!
+//! Wraps the function body so a call:
+//! 1. Builds a cache key under `prefix`, either by hashing the `Debug`
+//!    representation of the arguments (default) or via `key` if given.
+//! 2. Without `refresh`: does a `CacheBackend::get` on `backend` - a hit
+//!    deserializes and returns it, skipping the original body entirely
+//!    (`CacheStrategy::Refresh`'s shape: prefer cache, fall back to doing
+//!    the work). With `refresh`: skips the cache read and always runs the
+//!    original body (`CacheStrategy::Bypass`'s shape).
+//! 3. Either way, on a cache miss (or with `refresh`), runs the original
+//!    body, and on `Ok`, serializes and `CacheBackend::set`s the result
+//!    under `ttl` before returning it.
+//!
+//! Cache reads/writes that fail (a down backend, a bad payload) are logged
+//! and otherwise ignored rather than propagated - a memoized function should
+//! degrade to "always does the work", never to "errors because the cache
+//! did".
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Expr, FnArg, Ident, ItemFn, Lit, LitStr, Meta, Pat, Token};
+
+/// Parsed `#[cache_kit(...)]` attribute arguments.
+struct Args {
+    backend: Expr,
+    prefix: LitStr,
+    ttl: Option<LitStr>,
+    key: Option<Expr>,
+    refresh: bool,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let metas = Punctuated::<Meta, Token![,]>::parse_terminated(input)?;
+
+        let mut backend = None;
+        let mut prefix = None;
+        let mut ttl = None;
+        let mut key = None;
+        let mut refresh = false;
+
+        for meta in metas {
+            match &meta {
+                Meta::NameValue(nv) if nv.path.is_ident("backend") => {
+                    backend = Some(nv.value.clone());
+                }
+                Meta::NameValue(nv) if nv.path.is_ident("prefix") => {
+                    prefix = Some(expect_lit_str(&nv.value)?);
+                }
+                Meta::NameValue(nv) if nv.path.is_ident("ttl") => {
+                    ttl = Some(expect_lit_str(&nv.value)?);
+                }
+                Meta::NameValue(nv) if nv.path.is_ident("key") => {
+                    key = Some(nv.value.clone());
+                }
+                Meta::Path(path) if path.is_ident("refresh") => {
+                    refresh = true;
+                }
+                other => {
+                    return Err(syn::Error::new_spanned(
+                        other,
+                        "unsupported #[cache_kit(..)] key, expected one of: \
+                         backend, prefix, ttl, key, refresh",
+                    ));
+                }
+            }
+        }
+
+        Ok(Args {
+            backend: backend
+                .ok_or_else(|| input.error("#[cache_kit(..)] requires `backend = ...`"))?,
+            prefix: prefix
+                .ok_or_else(|| input.error("#[cache_kit(..)] requires `prefix = \"...\"`"))?,
+            ttl,
+            key,
+            refresh,
+        })
+    }
+}
+
+fn expect_lit_str(expr: &Expr) -> syn::Result<LitStr> {
+    match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Str(s) => Ok(s.clone()),
+            other => Err(syn::Error::new_spanned(other, "expected a string literal")),
+        },
+        other => Err(syn::Error::new_spanned(other, "expected a string literal")),
+    }
+}
+
+/// Parse a duration literal like `"30s"`, `"500ms"`, `"5m"`, `"1h"` into a
+/// `::std::time::Duration::from_millis(..)` expression, at macro-expansion
+/// time - so a typo'd unit is a compile error here, not a silent no-op TTL
+/// at runtime.
+fn parse_ttl(lit: &LitStr) -> syn::Result<TokenStream> {
+    let raw = lit.value();
+    let (digits, unit) = raw
+        .find(|c: char| !c.is_ascii_digit())
+        .map(|i| raw.split_at(i))
+        .ok_or_else(|| {
+            syn::Error::new_spanned(lit, "expected a duration like \"30s\", with a unit suffix")
+        })?;
+
+    let value: u64 = digits
+        .parse()
+        .map_err(|_| syn::Error::new_spanned(lit, "expected a duration starting with a number"))?;
+
+    let millis = match unit {
+        "ms" => value,
+        "s" => value.saturating_mul(1_000),
+        "m" => value.saturating_mul(60_000),
+        "h" => value.saturating_mul(3_600_000),
+        other => {
+            return Err(syn::Error::new_spanned(
+                lit,
+                format!(
+                    "unrecognized duration unit \"{other}\" - expected one of ms, s, m, h"
+                ),
+            ))
+        }
+    };
+
+    Ok(quote! { ::std::time::Duration::from_millis(#millis) })
+}
+
+/// Simple identifier patterns only (`id: u64`, not `(a, b): (u64, u64)`) -
+/// covers the common memoization case without having to invent a naming
+/// scheme for destructured arguments.
+fn arg_ident(arg: &FnArg) -> syn::Result<Ident> {
+    match arg {
+        FnArg::Receiver(recv) => Err(syn::Error::new_spanned(
+            recv,
+            "#[cache_kit(..)] does not support methods taking `self`",
+        )),
+        FnArg::Typed(pat_type) => match pat_type.pat.as_ref() {
+            Pat::Ident(pat_ident) => Ok(pat_ident.ident.clone()),
+            other => Err(syn::Error::new_spanned(
+                other,
+                "#[cache_kit(..)] requires simple identifier arguments, not patterns",
+            )),
+        },
+    }
+}
+
+pub(crate) fn expand(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let args: Args = syn::parse2(attr)?;
+    let input_fn: ItemFn = syn::parse2(item)?;
+
+    if input_fn.sig.asyncness.is_none() {
+        return Err(syn::Error::new_spanned(
+            &input_fn.sig,
+            "#[cache_kit(..)] only supports `async fn`",
+        ));
+    }
+    if !input_fn.sig.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input_fn.sig.generics,
+            "#[cache_kit(..)] does not support generic functions",
+        ));
+    }
+
+    let arg_idents = input_fn
+        .sig
+        .inputs
+        .iter()
+        .map(arg_ident)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let vis = &input_fn.vis;
+    let ident = &input_fn.sig.ident;
+    let inputs = &input_fn.sig.inputs;
+    let output = &input_fn.sig.output;
+    let block = &input_fn.block;
+    let attrs = &input_fn.attrs;
+
+    let inner_ident = format_ident!("__cache_kit_inner_{}", ident);
+    let backend = &args.backend;
+    let prefix = &args.prefix;
+    let refresh = args.refresh;
+
+    let ttl_expr = match &args.ttl {
+        Some(lit) => {
+            let millis = parse_ttl(lit)?;
+            quote! { ::std::option::Option::Some(#millis) }
+        }
+        None => quote! { ::std::option::Option::None },
+    };
+
+    let key_expr = match &args.key {
+        Some(custom) => quote_spanned! { custom.span() =>
+            ::std::format!("{}:{}", #prefix, (#custom)(#(&#arg_idents),*))
+        },
+        None => quote! {
+            {
+                let mut __cache_kit_hasher = ::std::collections::hash_map::DefaultHasher::new();
+                ::std::hash::Hash::hash(
+                    &::std::format!("{:?}", (#(&#arg_idents),*)),
+                    &mut __cache_kit_hasher,
+                );
+                ::std::format!(
+                    "{}:{:x}",
+                    #prefix,
+                    ::std::hash::Hasher::finish(&__cache_kit_hasher)
+                )
+            }
+        },
+    };
+
+    let cache_read = if refresh {
+        quote! {}
+    } else {
+        quote! {
+            match ::cache_kit::CacheBackend::get(&#backend, &__cache_kit_key).await {
+                ::std::result::Result::Ok(::std::option::Option::Some(__cache_kit_bytes)) => {
+                    match ::cache_kit::serialization::deserialize_from_cache(&__cache_kit_bytes) {
+                        ::std::result::Result::Ok(__cache_kit_value) => {
+                            return ::std::result::Result::Ok(__cache_kit_value);
+                        }
+                        ::std::result::Result::Err(__cache_kit_err) => {
+                            ::log::warn!(
+                                "cache_kit: failed to deserialize cached value for {}: {}",
+                                __cache_kit_key, __cache_kit_err
+                            );
+                        }
+                    }
+                }
+                ::std::result::Result::Ok(::std::option::Option::None) => {}
+                ::std::result::Result::Err(__cache_kit_err) => {
+                    ::log::warn!(
+                        "cache_kit: backend get failed for {}: {}",
+                        __cache_kit_key, __cache_kit_err
+                    );
+                }
+            }
+        }
+    };
+
+    let expanded = quote! {
+        #(#attrs)*
+        #vis async fn #ident(#inputs) #output {
+            async fn #inner_ident(#inputs) #output #block
+
+            let __cache_kit_key: ::std::string::String = #key_expr;
+
+            #cache_read
+
+            let __cache_kit_result = #inner_ident(#(#arg_idents),*).await?;
+
+            match ::cache_kit::serialization::serialize_for_cache(&__cache_kit_result) {
+                ::std::result::Result::Ok(__cache_kit_bytes) => {
+                    if let ::std::result::Result::Err(__cache_kit_err) = ::cache_kit::CacheBackend::set(
+                        &#backend,
+                        &__cache_kit_key,
+                        __cache_kit_bytes,
+                        #ttl_expr,
+                    )
+                    .await
+                    {
+                        ::log::warn!(
+                            "cache_kit: backend set failed for {}: {}",
+                            __cache_kit_key, __cache_kit_err
+                        );
+                    }
+                }
+                ::std::result::Result::Err(__cache_kit_err) => {
+                    ::log::warn!(
+                        "cache_kit: failed to serialize result for {}: {}",
+                        __cache_kit_key, __cache_kit_err
+                    );
+                }
+            }
+
+            ::std::result::Result::Ok(__cache_kit_result)
+        }
+    };
+
+    Ok(expanded)
+}
@@ -17,6 +17,9 @@ pub struct ApiError {
     pub http_code: HttpStatusCode,
     /// Error body
     pub body: ErrorBody,
+    /// Seconds the client should wait before retrying, if set. Sent as a
+    /// `Retry-After` header (e.g. on `CapacityExceeded`).
+    pub retry_after: Option<u32>,
 }
 
 /// Error body serialized in JSON responses
@@ -49,6 +52,7 @@ impl ApiError {
         Self {
             http_code,
             body: ErrorBody::default(),
+            retry_after: None,
         }
     }
 
@@ -86,16 +90,24 @@ impl ApiError {
         self.body.error_code = Some(code);
         self
     }
+
+    /// Set the `Retry-After` hint, in seconds.
+    pub fn retry_after(mut self, seconds: u32) -> Self {
+        self.retry_after = Some(seconds);
+        self
+    }
 }
 
 /// Implement ResponseError for Actix integration
 impl ResponseError for ApiError {
     fn error_response(&self) -> HttpResponse {
         let body = serde_json::to_string(&self.body).unwrap_or_default();
-
-        HttpResponse::build(self.http_code)
-            .append_header((header::CONTENT_TYPE, "application/problem+json"))
-            .body(body)
+        let mut response = HttpResponse::build(self.http_code);
+        response.append_header((header::CONTENT_TYPE, "application/problem+json"));
+        if let Some(seconds) = self.retry_after {
+            response.append_header((header::RETRY_AFTER, seconds.to_string()));
+        }
+        response.body(body)
     }
 
     fn status_code(&self) -> HttpStatusCode {
@@ -103,10 +115,78 @@ impl ResponseError for ApiError {
     }
 }
 
-/// Convert cache-kit errors to ApiError
+/// Convert cache-kit errors to ApiError, mapping each variant to the HTTP
+/// status and stable `error_code` clients can branch on instead of parsing
+/// the message string.
 impl From<cache_kit::error::Error> for ApiError {
     fn from(err: cache_kit::error::Error) -> Self {
-        ApiError::internal(err)
+        use cache_kit::error::Error as CacheError;
+
+        match &err {
+            CacheError::SerializationError(_) | CacheError::DeserializationError(_) => {
+                ApiError::new(HttpStatusCode::INTERNAL_SERVER_ERROR)
+                    .title("Serialization Error")
+                    .detail(err.to_string())
+                    .error_code(1001)
+            }
+            CacheError::Timeout(_) => ApiError::new(HttpStatusCode::GATEWAY_TIMEOUT)
+                .title("Timeout")
+                .detail(err.to_string())
+                .error_code(1002),
+            CacheError::CapacityExceeded(_) => {
+                ApiError::new(HttpStatusCode::SERVICE_UNAVAILABLE)
+                    .title("Capacity Exceeded")
+                    .detail(err.to_string())
+                    .error_code(1003)
+                    .retry_after(5)
+            }
+            CacheError::RateLimited(wait) => ApiError::new(HttpStatusCode::TOO_MANY_REQUESTS)
+                .title("Rate Limited")
+                .detail(err.to_string())
+                .error_code(1011)
+                .retry_after(wait.as_secs().max(1) as u32),
+            CacheError::CacheMiss => ApiError::new(HttpStatusCode::NOT_FOUND)
+                .title("Key Not Found")
+                .detail(err.to_string())
+                .error_code(1004),
+            CacheError::BackendError(_) | CacheError::Backend { .. } => {
+                ApiError::new(HttpStatusCode::BAD_GATEWAY)
+                    .title("Backend Unavailable")
+                    .detail(err.to_string())
+                    .error_code(1005)
+            }
+            CacheError::RepositoryError(_) => ApiError::new(HttpStatusCode::BAD_GATEWAY)
+                .title("Repository Error")
+                .detail(err.to_string())
+                .error_code(1006),
+            CacheError::ValidationError(_) => ApiError::new(HttpStatusCode::BAD_REQUEST)
+                .title("Validation Error")
+                .detail(err.to_string())
+                .error_code(1007),
+            CacheError::ConfigError(_) => ApiError::new(HttpStatusCode::INTERNAL_SERVER_ERROR)
+                .title("Configuration Error")
+                .detail(err.to_string())
+                .error_code(1008),
+            CacheError::NotImplemented(_) => ApiError::new(HttpStatusCode::NOT_IMPLEMENTED)
+                .title("Not Implemented")
+                .detail(err.to_string())
+                .error_code(1009),
+            CacheError::InvalidCacheEntry(_)
+            | CacheError::VersionMismatch { .. }
+            | CacheError::ChecksumMismatch { .. }
+            | CacheError::CodecMismatch { .. }
+            | CacheError::UnsupportedLegacyVersion { .. }
+            | CacheError::MigrationMissing { .. } => {
+                ApiError::new(HttpStatusCode::INTERNAL_SERVER_ERROR)
+                    .title("Cache Corruption")
+                    .detail(err.to_string())
+                    .error_code(1010)
+            }
+            // `Other`, and any variant that only exists behind a cache-kit
+            // feature (e.g. `DecryptionError` under `encryption`).
+            #[allow(unreachable_patterns)]
+            _ => ApiError::internal(err),
+        }
     }
 }
 
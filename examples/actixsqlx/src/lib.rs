@@ -4,6 +4,7 @@
 //! while keeping main.rs as the binary entry point.
 
 pub mod error;
+pub mod middleware;
 pub mod models;
 pub mod repository;
 pub mod routes;
@@ -0,0 +1,199 @@
+//! Transparent response caching middleware.
+//!
+//! Route handlers like [`crate::routes::get_user`]/[`crate::routes::update_user`]
+//! each hand-roll a call into their service's `CacheService` and remember to
+//! invalidate on mutation. `CacheMiddleware` does that at the HTTP layer
+//! instead: it caches successful `GET` response bodies keyed by request path
+//! (plus a configurable set of `Vary` headers), serves them on subsequent
+//! hits, and invalidates everything under a route prefix whenever a
+//! `POST`/`PUT`/`PATCH`/`DELETE` lands on it.
+//!
+//! # Note on this example
+//!
+//! The routes here cache at the entity layer via each service's own
+//! `CacheService<InMemoryBackend>` (see [`crate::services::UserService`]),
+//! not through a single app-wide `cache_service` field on `AppState` (this
+//! example's `AppState` is a generic `TypeId`-keyed service registry, not a
+//! fixed set of fields). `CacheMiddleware` is built around its own backend
+//! clone instead and installed with `App::wrap`, e.g.:
+//!
+//! ```ignore
+//! let backend = InMemoryBackend::new();
+//! let cache_middleware = CacheMiddleware::new(backend.clone())
+//!     .with_ttl(Duration::from_secs(30))
+//!     .with_vary(vec!["Authorization".to_string()]);
+//!
+//! App::new()
+//!     .wrap(cache_middleware)
+//!     .app_data(web::Data::new(app_state))
+//!     // ...
+//! ```
+
+use actix_web::{
+    body::{BoxBody, EitherBody, MessageBody},
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    http::Method,
+    HttpResponse,
+};
+use cache_kit::backend::CacheBackend;
+use futures_util::future::{ready, LocalBoxFuture, Ready};
+use std::rc::Rc;
+use std::time::Duration;
+
+/// Builder for a [`CacheMiddleware`] - install via `App::wrap`.
+pub struct CacheMiddleware<B> {
+    backend: B,
+    ttl: Option<Duration>,
+    vary_headers: Vec<String>,
+}
+
+impl<B: CacheBackend + 'static> CacheMiddleware<B> {
+    /// Build a middleware caching through `backend`.
+    pub fn new(backend: B) -> Self {
+        CacheMiddleware {
+            backend,
+            ttl: None,
+            vary_headers: Vec::new(),
+        }
+    }
+
+    /// TTL applied to cached responses. Defaults to the backend's own
+    /// default (no expiration, for `InMemoryBackend`).
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Header names whose values are folded into the cache key alongside the
+    /// request path, so e.g. per-user responses behind `Authorization` don't
+    /// collide in the cache.
+    pub fn with_vary(mut self, headers: Vec<String>) -> Self {
+        self.vary_headers = headers;
+        self
+    }
+}
+
+impl<S, Bd, B> Transform<S, ServiceRequest> for CacheMiddleware<B>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<Bd>, Error = actix_web::Error> + 'static,
+    S::Future: 'static,
+    Bd: MessageBody + 'static,
+    B: CacheBackend + 'static,
+{
+    type Response = ServiceResponse<EitherBody<Bd, BoxBody>>;
+    type Error = actix_web::Error;
+    type Transform = CacheMiddlewareService<S, B>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(CacheMiddlewareService {
+            service: Rc::new(service),
+            backend: self.backend.clone(),
+            ttl: self.ttl,
+            vary_headers: self.vary_headers.clone(),
+        }))
+    }
+}
+
+pub struct CacheMiddlewareService<S, B> {
+    service: Rc<S>,
+    backend: B,
+    ttl: Option<Duration>,
+    vary_headers: Vec<String>,
+}
+
+/// Cache key for a `GET` request: the path plus the configured `Vary`
+/// header values, so responses that differ per-header don't collide.
+fn cache_key(req: &ServiceRequest, vary_headers: &[String]) -> String {
+    let mut key = format!("http:{}", req.path());
+    for header in vary_headers {
+        if let Some(value) = req.headers().get(header).and_then(|v| v.to_str().ok()) {
+            key.push(':');
+            key.push_str(header);
+            key.push('=');
+            key.push_str(value);
+        }
+    }
+    key
+}
+
+/// The route prefix a mutation invalidates, e.g. `/users/123` -> `/users`.
+fn route_prefix(path: &str) -> String {
+    format!(
+        "http:/{}",
+        path.trim_start_matches('/')
+            .split('/')
+            .next()
+            .unwrap_or("")
+    )
+}
+
+impl<S, Bd, B> Service<ServiceRequest> for CacheMiddlewareService<S, B>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<Bd>, Error = actix_web::Error> + 'static,
+    S::Future: 'static,
+    Bd: MessageBody + 'static,
+    B: CacheBackend + 'static,
+{
+    type Response = ServiceResponse<EitherBody<Bd, BoxBody>>;
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let method = req.method().clone();
+        let backend = self.backend.clone();
+        let ttl = self.ttl;
+        let vary_headers = self.vary_headers.clone();
+        let service = self.service.clone();
+
+        if method == Method::GET {
+            let key = cache_key(&req, &vary_headers);
+
+            Box::pin(async move {
+                if let Some(body) = backend.get(&key).await.unwrap_or(None) {
+                    let response = HttpResponse::Ok()
+                        .content_type("application/json")
+                        .body(body);
+                    return Ok(req.into_response(response).map_into_right_body());
+                }
+
+                let res = service.call(req).await?;
+                if !res.status().is_success() {
+                    return Ok(res.map_into_left_body());
+                }
+
+                let (req, res) = res.into_parts();
+                let (res, body) = res.into_parts();
+                let bytes = actix_web::body::to_bytes(body)
+                    .await
+                    .unwrap_or_default();
+
+                let _ = backend.set(&key, bytes.to_vec(), ttl).await;
+
+                let res = res.set_body(BoxBody::new(bytes));
+                Ok(ServiceResponse::new(req, res).map_into_right_body())
+            })
+        } else if matches!(
+            method,
+            Method::POST | Method::PUT | Method::PATCH | Method::DELETE
+        ) {
+            let prefix = route_prefix(req.path());
+
+            Box::pin(async move {
+                let res = service.call(req).await?;
+                if res.status().is_success() {
+                    let _ = backend.invalidate_prefix(&prefix).await;
+                }
+                Ok(res.map_into_left_body())
+            })
+        } else {
+            Box::pin(async move {
+                let res = service.call(req).await?;
+                Ok(res.map_into_left_body())
+            })
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! Built-in admin HTTP endpoint exposing metrics and health (feature `admin`).
+//!
+//! Wraps a tiny [`axum`] router around a backend's health check and a
+//! [`HistogramMetrics`] handle, so a service gets a Prometheus scrape target
+//! and a readiness probe with one call - [`crate::service::CacheService::serve_admin`] -
+//! instead of wiring up its own router for it. Kept behind the `admin`
+//! feature so pulling in `cache-kit` by default still has no web framework
+//! dependency.
+//!
+//! The request that motivated this module asked for `/metrics` to render
+//! `PrometheusMetrics::render_prometheus()`; that type (behind the `metrics`
+//! feature) only forwards to the `metrics` crate's recording facade and has
+//! no such method, so this serves [`HistogramMetrics::render_prometheus`]
+//! instead - the in-process histogram type that actually owns renderable
+//! data.
+
+use crate::backend::CacheBackend;
+use crate::expander::CacheExpander;
+use crate::observability::HistogramMetrics;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::Router;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+/// Configuration for [`crate::service::CacheService::serve_admin`].
+///
+/// `metrics_path`/`health_path` default to `/metrics`/`/health`, following the
+/// convention most Prometheus-scraped Rust services already use.
+#[derive(Clone, Debug)]
+pub struct AdminConfig {
+    /// Address the admin router binds to.
+    pub addr: SocketAddr,
+    /// Path serving Prometheus text exposition of the cache's latency histograms.
+    pub metrics_path: String,
+    /// Path serving a 200/503 readiness probe backed by `CacheBackend::health_check`.
+    pub health_path: String,
+}
+
+impl AdminConfig {
+    /// Configuration bound to `addr`, with default `/metrics`/`/health` paths.
+    pub fn new(addr: SocketAddr) -> Self {
+        AdminConfig {
+            addr,
+            metrics_path: "/metrics".to_string(),
+            health_path: "/health".to_string(),
+        }
+    }
+
+    /// Override the metrics path.
+    pub fn with_metrics_path(mut self, path: impl Into<String>) -> Self {
+        self.metrics_path = path.into();
+        self
+    }
+
+    /// Override the health path.
+    pub fn with_health_path(mut self, path: impl Into<String>) -> Self {
+        self.health_path = path.into();
+        self
+    }
+}
+
+struct AdminState<B: CacheBackend> {
+    expander: Arc<CacheExpander<B>>,
+    metrics: Arc<HistogramMetrics>,
+}
+
+async fn metrics_handler<B: CacheBackend + 'static>(
+    State(state): State<Arc<AdminState<B>>>,
+) -> impl IntoResponse {
+    (
+        [("content-type", "text/plain; version=0.0.4")],
+        state.metrics.render_prometheus(),
+    )
+}
+
+async fn health_handler<B: CacheBackend + 'static>(
+    State(state): State<Arc<AdminState<B>>>,
+) -> impl IntoResponse {
+    match state.expander.backend().health_check().await {
+        Ok(true) => StatusCode::OK,
+        Ok(false) | Err(_) => StatusCode::SERVICE_UNAVAILABLE,
+    }
+}
+
+/// Build the admin router over `expander`/`metrics`, without binding or serving it.
+///
+/// Split out from [`serve`] so tests can exercise the routes in-process via
+/// `axum::Router::oneshot` instead of opening a real socket.
+pub(crate) fn router<B: CacheBackend + 'static>(
+    config: &AdminConfig,
+    expander: Arc<CacheExpander<B>>,
+    metrics: Arc<HistogramMetrics>,
+) -> Router {
+    let state = Arc::new(AdminState { expander, metrics });
+    Router::new()
+        .route(&config.metrics_path, get(metrics_handler::<B>))
+        .route(&config.health_path, get(health_handler::<B>))
+        .with_state(state)
+}
+
+/// Bind `config.addr` and serve the admin router until the process exits or
+/// the returned future resolves.
+///
+/// # Errors
+/// Returns `Err` if the address can't be bound.
+pub(crate) async fn serve<B: CacheBackend + 'static>(
+    config: AdminConfig,
+    expander: Arc<CacheExpander<B>>,
+    metrics: Arc<HistogramMetrics>,
+) -> crate::error::Result<()> {
+    let addr = config.addr;
+    let app = router(&config, expander, metrics);
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .map_err(|e| crate::error::Error::ConfigError(format!("admin bind {addr} failed: {e}")))?;
+
+    info!("✓ Admin endpoint listening on {}", addr);
+    axum::serve(listener, app)
+        .await
+        .map_err(|e| crate::error::Error::BackendError(format!("admin server error: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::InMemoryBackend;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    fn test_expander() -> Arc<CacheExpander<InMemoryBackend>> {
+        Arc::new(CacheExpander::new(InMemoryBackend::new()))
+    }
+
+    #[tokio::test]
+    async fn test_metrics_endpoint_renders_prometheus_text() {
+        let metrics = Arc::new(HistogramMetrics::new());
+        metrics.record_hit("key", std::time::Duration::from_micros(10));
+
+        let config = AdminConfig::new(([127, 0, 0, 1], 0).into());
+        let app = router(&config, test_expander(), metrics);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/metrics")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("Failed to call /metrics");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get("content-type").unwrap(),
+            "text/plain; version=0.0.4"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_health_endpoint_returns_200_when_backend_is_healthy() {
+        let config = AdminConfig::new(([127, 0, 0, 1], 0).into());
+        let app = router(&config, test_expander(), Arc::new(HistogramMetrics::new()));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/health")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("Failed to call /health");
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_config_paths_are_customizable() {
+        let config = AdminConfig::new(([127, 0, 0, 1], 0).into())
+            .with_metrics_path("/admin/metrics")
+            .with_health_path("/admin/health");
+        let app = router(&config, test_expander(), Arc::new(HistogramMetrics::new()));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/admin/metrics")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("Failed to call /admin/metrics");
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
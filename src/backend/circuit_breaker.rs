@@ -0,0 +1,337 @@
+//! Circuit breaker wrapper for any [`CacheBackend`].
+//!
+//! Mirrors [`crate::resilience::ResilientRepository`]'s breaker, but sits in
+//! front of the cache backend instead of the repository behind it: once a
+//! backend (e.g. a remote Redis) trips, `get`/`set`/`delete` return
+//! immediately with a miss/no-op instead of hammering a dead connection on
+//! every request, leaving `CacheExpander` to fall through to the
+//! `DataRepository` exactly as it would on any other miss.
+
+use super::CacheBackend;
+use crate::error::Result;
+use crate::observability::CacheMetrics;
+use crate::resilience::BreakerState;
+use std::sync::atomic::{AtomicU32, AtomicU8, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+const BREAKER_CLOSED: u8 = 0;
+const BREAKER_OPEN: u8 = 1;
+const BREAKER_HALF_OPEN: u8 = 2;
+
+/// Configuration for [`CircuitBreakerBackend`].
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures before the breaker opens.
+    pub failure_threshold: u32,
+    /// How long the breaker stays open before allowing a half-open trial.
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        CircuitBreakerConfig {
+            failure_threshold: 5,
+            cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Wraps any [`CacheBackend`] with a closed/open/half-open circuit breaker.
+///
+/// While `Closed`, every call passes through to the wrapped backend
+/// normally, and a consecutive run of retryable failures (see
+/// [`crate::error::Error::is_retryable`]) reaching `config.failure_threshold`
+/// opens the breaker. While `Open`, `get` returns `Ok(None)` and
+/// `set`/`delete` return `Ok(())` without touching the wrapped backend at
+/// all - exactly what a cache miss looks like, so `CacheExpander` falls
+/// through to the `DataRepository` instead of erroring on every request.
+/// Once `config.cooldown` has elapsed, the next call moves the breaker to
+/// `HalfOpen` and is let through as a trial, as is every call made while it
+/// stays `HalfOpen`: the first success closes the breaker, the first
+/// retryable failure reopens it and restarts the cooldown.
+///
+/// Distinct from [`crate::backend::RecoveringBackend`], which degrades only
+/// after retrying *within a single call* (plus a corruption-recovery key
+/// reset) - good for a backend that occasionally hiccups, but still pays one
+/// full retry-plus-reset cycle of latency per request while it's down.
+/// `CircuitBreakerBackend` instead tracks failures *across* calls and, once
+/// tripped, skips the wrapped backend entirely until the cooldown elapses -
+/// so a sustained outage costs one slow call, not one per request. The two
+/// compose if you want both: wrap a `CircuitBreakerBackend` around a
+/// `RecoveringBackend` (or vice versa).
+///
+/// # Example
+///
+/// ```no_run
+/// use cache_kit::backend::{CircuitBreakerBackend, CircuitBreakerConfig, InMemoryBackend};
+///
+/// # async fn example() -> cache_kit::Result<()> {
+/// # let redis_like_backend = InMemoryBackend::new();
+/// let backend = CircuitBreakerBackend::new(redis_like_backend, CircuitBreakerConfig::default());
+/// backend.set("key", b"value".to_vec(), None).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CircuitBreakerBackend<B> {
+    inner: B,
+    config: CircuitBreakerConfig,
+    state: Arc<AtomicU8>,
+    consecutive_failures: Arc<AtomicU32>,
+    opened_at: Arc<Mutex<Option<Instant>>>,
+    metrics: Option<Arc<dyn CacheMetrics>>,
+}
+
+impl<B: CacheBackend> CircuitBreakerBackend<B> {
+    /// Wrap `inner` with the given breaker configuration.
+    pub fn new(inner: B, config: CircuitBreakerConfig) -> Self {
+        CircuitBreakerBackend {
+            inner,
+            config,
+            state: Arc::new(AtomicU8::new(BREAKER_CLOSED)),
+            consecutive_failures: Arc::new(AtomicU32::new(0)),
+            opened_at: Arc::new(Mutex::new(None)),
+            metrics: None,
+        }
+    }
+
+    /// Report every open/reopen transition to `metrics` via
+    /// [`CacheMetrics::record_error`], so a backend wrapped with this can
+    /// feed the same dashboard an expander or service already reports hits
+    /// and misses through, instead of polling
+    /// [`CircuitBreakerBackend::state`] by hand.
+    pub fn with_metrics(mut self, metrics: impl CacheMetrics + 'static) -> Self {
+        self.metrics = Some(Arc::new(metrics));
+        self
+    }
+
+    /// Borrow the wrapped backend.
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Current circuit breaker state.
+    pub fn state(&self) -> BreakerState {
+        match self.state.load(Ordering::SeqCst) {
+            BREAKER_OPEN => BreakerState::Open,
+            BREAKER_HALF_OPEN => BreakerState::HalfOpen,
+            _ => BreakerState::Closed,
+        }
+    }
+
+    /// `true` once the breaker has opened - i.e. calls are currently being
+    /// skipped (`Open`) or let through as trials (`HalfOpen`).
+    pub fn is_tripped(&self) -> bool {
+        self.state.load(Ordering::SeqCst) != BREAKER_CLOSED
+    }
+
+    /// `false` if `Open` and the cooldown hasn't elapsed yet (the caller
+    /// should short-circuit); `true` otherwise, transitioning `Open` ->
+    /// `HalfOpen` once the cooldown has passed so this call becomes the
+    /// trial.
+    fn guard(&self) -> bool {
+        if self.state.load(Ordering::SeqCst) == BREAKER_OPEN {
+            let elapsed = self
+                .opened_at
+                .lock()
+                .expect("lock poisoned")
+                .map(|t| t.elapsed());
+            match elapsed {
+                Some(elapsed) if elapsed >= self.config.cooldown => {
+                    self.state.store(BREAKER_HALF_OPEN, Ordering::SeqCst);
+                    true
+                }
+                _ => false,
+            }
+        } else {
+            true
+        }
+    }
+
+    fn record_success(&self) {
+        self.consecutive_failures.store(0, Ordering::SeqCst);
+        self.state.store(BREAKER_CLOSED, Ordering::SeqCst);
+        *self.opened_at.lock().expect("lock poisoned") = None;
+    }
+
+    fn record_failure(&self, key: &str) {
+        // A failed half-open trial reopens the breaker immediately, without
+        // waiting for the threshold again.
+        if self.state.load(Ordering::SeqCst) == BREAKER_HALF_OPEN {
+            self.open_breaker(key, "circuit breaker reopened: half-open probe failed");
+            return;
+        }
+
+        let failures = self.consecutive_failures.fetch_add(1, Ordering::SeqCst) + 1;
+        if failures >= self.config.failure_threshold {
+            self.open_breaker(key, "circuit breaker opened: failure threshold reached");
+        }
+    }
+
+    fn open_breaker(&self, key: &str, reason: &str) {
+        self.state.store(BREAKER_OPEN, Ordering::SeqCst);
+        *self.opened_at.lock().expect("lock poisoned") = Some(Instant::now());
+        if let Some(metrics) = &self.metrics {
+            metrics.record_error(key, reason);
+        }
+    }
+
+    /// Run `op` against the wrapped backend through the breaker:
+    /// short-circuit to `degraded` while `Open`, otherwise run it and record
+    /// the resulting success/failure. Only retryable errors (see
+    /// [`crate::error::Error::is_retryable`]) count as breaker failures, so
+    /// a `ValidationError`/`SerializationError` - a caller bug, not an
+    /// outage - doesn't trip the breaker.
+    async fn call<V, F, Fut>(&self, key: &str, degraded: V, op: F) -> Result<V>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<V>>,
+    {
+        if !self.guard() {
+            return Ok(degraded);
+        }
+
+        match op().await {
+            Ok(value) => {
+                self.record_success();
+                Ok(value)
+            }
+            Err(e) => {
+                if e.is_retryable() {
+                    self.record_failure(key);
+                }
+                Err(e)
+            }
+        }
+    }
+}
+
+impl<B: CacheBackend> CacheBackend for CircuitBreakerBackend<B> {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        self.call(key, None, || self.inner.get(key)).await
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<()> {
+        self.call(key, (), || self.inner.set(key, value, ttl)).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.call(key, (), || self.inner.delete(key)).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        self.call("__health__", false, || self.inner.health_check())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::mock::MockBackend;
+    use crate::error::Error;
+    use crate::repository::FailurePolicy;
+
+    fn fast_config(failure_threshold: u32) -> CircuitBreakerConfig {
+        CircuitBreakerConfig {
+            failure_threshold,
+            cooldown: Duration::from_millis(20),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_successful_calls_keep_the_breaker_closed() {
+        let backend = CircuitBreakerBackend::new(MockBackend::new(), fast_config(2));
+
+        backend.set("key", vec![1], None).await.expect("set should succeed");
+        assert_eq!(backend.get("key").await.expect("get should succeed"), Some(vec![1]));
+        assert_eq!(backend.state(), BreakerState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_breaker_opens_after_consecutive_failures() {
+        let mock = MockBackend::new();
+        mock.set_failure(FailurePolicy::Always(Error::BackendError("down".to_string())));
+        let backend = CircuitBreakerBackend::new(mock, fast_config(2));
+
+        assert!(backend.set("key", vec![1], None).await.is_err());
+        assert_eq!(backend.state(), BreakerState::Closed);
+        assert!(backend.set("key", vec![1], None).await.is_err());
+        assert_eq!(backend.state(), BreakerState::Open);
+
+        // While open, calls degrade instead of reaching the inner backend.
+        assert_eq!(backend.get("key").await.expect("get should degrade"), None);
+    }
+
+    #[tokio::test]
+    async fn test_half_open_probe_success_closes_breaker() {
+        let mock = MockBackend::new();
+        mock.set_failure(FailurePolicy::Always(Error::BackendError("down".to_string())));
+        let backend = CircuitBreakerBackend::new(mock.clone(), fast_config(1));
+
+        assert!(backend.set("key", vec![1], None).await.is_err());
+        assert_eq!(backend.state(), BreakerState::Open);
+
+        tokio::time::sleep(Duration::from_millis(25)).await;
+        mock.clear_failures();
+
+        backend.set("key", vec![2], None).await.expect("probe set should succeed");
+        assert_eq!(backend.state(), BreakerState::Closed);
+        assert_eq!(backend.get("key").await.expect("get should succeed"), Some(vec![2]));
+    }
+
+    #[tokio::test]
+    async fn test_half_open_probe_failure_reopens_breaker() {
+        let mock = MockBackend::new();
+        mock.set_failure(FailurePolicy::Always(Error::BackendError("down".to_string())));
+        let backend = CircuitBreakerBackend::new(mock, fast_config(1));
+
+        assert!(backend.set("key", vec![1], None).await.is_err());
+        assert_eq!(backend.state(), BreakerState::Open);
+
+        tokio::time::sleep(Duration::from_millis(25)).await;
+
+        // Still down, so the half-open trial fails and the breaker reopens.
+        assert!(backend.set("key", vec![1], None).await.is_err());
+        assert_eq!(backend.state(), BreakerState::Open);
+    }
+
+    #[tokio::test]
+    async fn test_non_retryable_error_does_not_trip_breaker() {
+        let mock = MockBackend::new();
+        mock.set_failure(FailurePolicy::Always(Error::ValidationError(
+            "bad input".to_string(),
+        )));
+        let backend = CircuitBreakerBackend::new(mock, fast_config(1));
+
+        assert!(backend.get("key").await.is_err());
+        assert_eq!(backend.state(), BreakerState::Closed);
+    }
+
+    #[derive(Default)]
+    struct RecordingMetrics {
+        errors: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl CacheMetrics for RecordingMetrics {
+        fn record_error(&self, key: &str, error: &str) {
+            self.errors
+                .lock()
+                .expect("lock poisoned")
+                .push(format!("{key}: {error}"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_trip_is_reported_through_cache_metrics() {
+        let mock = MockBackend::new();
+        mock.set_failure(FailurePolicy::Always(Error::BackendError("down".to_string())));
+        let metrics = Arc::new(RecordingMetrics::default());
+        let backend = CircuitBreakerBackend::new(mock, fast_config(1)).with_metrics(metrics.clone());
+
+        assert!(backend.set("key", vec![1], None).await.is_err());
+        assert_eq!(backend.state(), BreakerState::Open);
+        assert_eq!(metrics.errors.lock().expect("lock poisoned").len(), 1);
+    }
+}
@@ -0,0 +1,229 @@
+//! Transparent value compression wrapper for any [`CacheBackend`].
+//!
+//! Large serialized payloads (product listings, paginated results) can blow
+//! up an in-memory backend's footprint. `CompressedBackend` gzips values
+//! above a configurable size threshold before handing them to the wrapped
+//! backend, and transparently decompresses on read. Small/hot values stay
+//! under the threshold and pay no compression cost at all.
+//!
+//! Requires the `compression` feature.
+
+use super::CacheBackend;
+use crate::error::{Error, Result};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use std::io::{Read, Write};
+use std::time::Duration;
+
+/// One-byte tag prefixed to every stored value, identifying how the rest of
+/// the bytes are encoded.
+const CODEC_NONE: u8 = 0;
+const CODEC_GZIP: u8 = 1;
+
+/// Default size threshold, in bytes, above which values are compressed.
+const DEFAULT_THRESHOLD: usize = 1024;
+
+/// Wraps any [`CacheBackend`] with transparent gzip compression for values
+/// above a size threshold.
+///
+/// # Example
+///
+/// ```no_run
+/// use cache_kit::backend::{CompressedBackend, InMemoryBackend};
+///
+/// # async fn example() -> cache_kit::Result<()> {
+/// let backend = CompressedBackend::new(InMemoryBackend::new());
+///
+/// backend.set("listing:1", vec![0u8; 4096], None).await?;
+/// let value = backend.get("listing:1").await?;
+/// assert_eq!(value.map(|v| v.len()), Some(4096));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CompressedBackend<B> {
+    inner: B,
+    threshold: usize,
+}
+
+impl<B: CacheBackend> CompressedBackend<B> {
+    /// Wrap `inner`, compressing values at or above [`DEFAULT_THRESHOLD`]
+    /// (1 KiB).
+    pub fn new(inner: B) -> Self {
+        CompressedBackend {
+            inner,
+            threshold: DEFAULT_THRESHOLD,
+        }
+    }
+
+    /// Wrap `inner`, compressing values at or above `threshold` bytes.
+    pub fn with_threshold(inner: B, threshold: usize) -> Self {
+        CompressedBackend { inner, threshold }
+    }
+
+    /// Borrow the wrapped backend.
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    fn encode(&self, value: Vec<u8>) -> Result<Vec<u8>> {
+        if value.len() < self.threshold {
+            let mut encoded = Vec::with_capacity(value.len() + 1);
+            encoded.push(CODEC_NONE);
+            encoded.extend(value);
+            return Ok(encoded);
+        }
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(&value)
+            .map_err(|e| Error::SerializationError(format!("gzip compression failed: {e}")))?;
+        let compressed = encoder
+            .finish()
+            .map_err(|e| Error::SerializationError(format!("gzip compression failed: {e}")))?;
+
+        let mut encoded = Vec::with_capacity(compressed.len() + 1);
+        encoded.push(CODEC_GZIP);
+        encoded.extend(compressed);
+        Ok(encoded)
+    }
+
+    fn decode(&self, encoded: Vec<u8>) -> Result<Vec<u8>> {
+        let (tag, payload) = encoded.split_first().ok_or_else(|| {
+            Error::DeserializationError("compressed value missing codec tag".to_string())
+        })?;
+
+        match *tag {
+            CODEC_NONE => Ok(payload.to_vec()),
+            CODEC_GZIP => {
+                let mut decoder = GzDecoder::new(payload);
+                let mut decoded = Vec::new();
+                decoder.read_to_end(&mut decoded).map_err(|e| {
+                    Error::DeserializationError(format!("gzip decompression failed: {e}"))
+                })?;
+                Ok(decoded)
+            }
+            other => Err(Error::DeserializationError(format!(
+                "unknown compression codec tag: {other}"
+            ))),
+        }
+    }
+}
+
+impl<B: CacheBackend> CacheBackend for CompressedBackend<B> {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        match self.inner.get(key).await? {
+            Some(encoded) => Ok(Some(self.decode(encoded)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<()> {
+        let encoded = self.encode(value)?;
+        self.inner.set(key, encoded, ttl).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.inner.delete(key).await
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool> {
+        self.inner.exists(key).await
+    }
+
+    async fn mget(&self, keys: &[&str]) -> Result<Vec<Option<Vec<u8>>>> {
+        let values = self.inner.mget(keys).await?;
+        values
+            .into_iter()
+            .map(|value| value.map(|v| self.decode(v)).transpose())
+            .collect()
+    }
+
+    async fn mdelete(&self, keys: &[&str]) -> Result<()> {
+        self.inner.mdelete(keys).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        self.inner.health_check().await
+    }
+
+    async fn clear_all(&self) -> Result<()> {
+        self.inner.clear_all().await
+    }
+
+    async fn invalidate_prefix(&self, prefix: &str) -> Result<()> {
+        self.inner.invalidate_prefix(prefix).await
+    }
+
+    async fn scan_prefix(&self, prefix: &str) -> Result<Vec<String>> {
+        self.inner.scan_prefix(prefix).await
+    }
+
+    async fn delete_prefix(&self, prefix: &str) -> Result<u64> {
+        self.inner.delete_prefix(prefix).await
+    }
+
+    async fn set_with_tags(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        ttl: Option<Duration>,
+        tags: &[&str],
+    ) -> Result<()> {
+        let encoded = self.encode(value)?;
+        self.inner.set_with_tags(key, encoded, ttl, tags).await
+    }
+
+    async fn invalidate_tag(&self, tag: &str) -> Result<()> {
+        self.inner.invalidate_tag(tag).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::InMemoryBackend;
+
+    #[tokio::test]
+    async fn test_small_value_stored_verbatim() {
+        let backend = CompressedBackend::new(InMemoryBackend::new());
+        let small = vec![1, 2, 3];
+
+        backend.set("key", small.clone(), None).await.expect("Failed to set");
+        let raw = backend.inner().get("key").await.expect("Failed to get").unwrap();
+        assert_eq!(raw[0], CODEC_NONE);
+
+        let roundtripped = backend.get("key").await.expect("Failed to get");
+        assert_eq!(roundtripped, Some(small));
+    }
+
+    #[tokio::test]
+    async fn test_large_value_is_compressed_and_decompresses_correctly() {
+        let backend = CompressedBackend::with_threshold(InMemoryBackend::new(), 16);
+        let large = vec![42u8; 4096];
+
+        backend.set("key", large.clone(), None).await.expect("Failed to set");
+        let raw = backend.inner().get("key").await.expect("Failed to get").unwrap();
+        assert_eq!(raw[0], CODEC_GZIP);
+        assert!(raw.len() < large.len(), "highly compressible data should shrink");
+
+        let roundtripped = backend.get("key").await.expect("Failed to get");
+        assert_eq!(roundtripped, Some(large));
+    }
+
+    #[tokio::test]
+    async fn test_mget_decompresses_mixed_small_and_large_values() {
+        let backend = CompressedBackend::with_threshold(InMemoryBackend::new(), 16);
+        let small = vec![1, 2, 3];
+        let large = vec![7u8; 2048];
+
+        backend.set("small", small.clone(), None).await.expect("Failed to set");
+        backend.set("large", large.clone(), None).await.expect("Failed to set");
+
+        let values = backend
+            .mget(&["small", "large", "missing"])
+            .await
+            .expect("Failed to mget");
+
+        assert_eq!(values, vec![Some(small), Some(large), None]);
+    }
+}
@@ -0,0 +1,283 @@
+//! Content-addressed value deduplication wrapper for any [`CacheBackend`].
+//!
+//! Many logical cache keys (a product listing shared across several filter
+//! combinations, a user record mirrored under both `user:{id}` and
+//! `email:{address}`) end up caching byte-identical payloads. `set` normally
+//! stores that payload once per key, so the same bytes get duplicated across
+//! the backend. `ContentAddressedBackend` instead BLAKE3-hashes the value and
+//! stores the bytes once under `cas:{digest}`, with the logical key holding
+//! only a small pointer record to that digest - so N keys sharing one payload
+//! pay for the payload once, not N times.
+//!
+//! Requires the `content-addressing` feature.
+//!
+//! **Scope note:** deleting a logical key only removes its pointer record,
+//! never the underlying blob - two keys can point at the same digest, so a
+//! `delete` on one can't safely know the blob is unreferenced elsewhere
+//! without reference counting, which this wrapper doesn't implement. Blobs
+//! therefore accumulate for the lifetime of the backend; pair this with a
+//! backend that already expires entries (or periodically `clear_all`s) if
+//! that's not acceptable for a given deployment.
+
+use super::CacheBackend;
+use crate::error::{Error, Result};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Prefix under which content blobs are stored, namespaced away from logical
+/// keys so a pointer record and a blob can never collide.
+const BLOB_PREFIX: &str = "cas:";
+
+/// Digest length BLAKE3 is truncated to by default (the full 32-byte hash).
+/// Shorter digests save space per pointer record at the cost of a higher
+/// collision probability across unrelated values.
+const DEFAULT_DIGEST_LEN: usize = 32;
+
+/// Point-in-time snapshot of a [`ContentAddressedBackend`]'s counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DedupStats {
+    /// Number of `set` calls whose digest already had a blob stored, so no
+    /// new blob write was needed.
+    pub dedup_hits: u64,
+    /// Number of `set` calls that wrote a new, previously-unseen blob.
+    pub blobs_written: u64,
+}
+
+fn digest_hex(value: &[u8], digest_len: usize) -> String {
+    let hash = blake3::hash(value);
+    hex_encode(&hash.as_bytes()[..digest_len.min(hash.as_bytes().len())])
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    let mut s = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        s.push_str(&format!("{:02x}", b));
+    }
+    s
+}
+
+fn blob_key(digest: &str) -> String {
+    format!("{BLOB_PREFIX}{digest}")
+}
+
+/// Wraps any [`CacheBackend`], storing each distinct value once under its
+/// BLAKE3 digest instead of once per logical key.
+///
+/// # Example
+///
+/// ```no_run
+/// use cache_kit::backend::{ContentAddressedBackend, InMemoryBackend};
+///
+/// # async fn example() -> cache_kit::Result<()> {
+/// let backend = ContentAddressedBackend::new(InMemoryBackend::new());
+///
+/// backend.set("product:1", b"shared payload".to_vec(), None).await?;
+/// backend.set("product:2", b"shared payload".to_vec(), None).await?;
+/// assert_eq!(backend.stats().blobs_written, 1);
+/// assert_eq!(backend.stats().dedup_hits, 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ContentAddressedBackend<B> {
+    inner: B,
+    digest_len: usize,
+    integrity_check: bool,
+    dedup_hits: std::sync::Arc<AtomicU64>,
+    blobs_written: std::sync::Arc<AtomicU64>,
+}
+
+impl<B: CacheBackend> ContentAddressedBackend<B> {
+    /// Wrap `inner`, deduplicating on the full 32-byte BLAKE3 digest, without
+    /// re-hashing on read.
+    pub fn new(inner: B) -> Self {
+        ContentAddressedBackend {
+            inner,
+            digest_len: DEFAULT_DIGEST_LEN,
+            integrity_check: false,
+            dedup_hits: std::sync::Arc::new(AtomicU64::new(0)),
+            blobs_written: std::sync::Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Truncate digests to `digest_len` bytes (at most 32) instead of the
+    /// full BLAKE3 hash, trading a smaller pointer record for a higher
+    /// collision probability across unrelated values.
+    pub fn with_digest_len(mut self, digest_len: usize) -> Self {
+        self.digest_len = digest_len.clamp(1, DEFAULT_DIGEST_LEN);
+        self
+    }
+
+    /// Re-hash a blob on every `get` and compare against the digest the
+    /// pointer record named, returning [`Error::InvalidCacheEntry`] on
+    /// mismatch instead of silently serving corrupted bytes.
+    pub fn with_integrity_check(mut self) -> Self {
+        self.integrity_check = true;
+        self
+    }
+
+    /// Borrow the wrapped backend.
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Current dedup counters. See [`DedupStats`].
+    pub fn stats(&self) -> DedupStats {
+        DedupStats {
+            dedup_hits: self.dedup_hits.load(Ordering::Relaxed),
+            blobs_written: self.blobs_written.load(Ordering::Relaxed),
+        }
+    }
+
+    async fn store_blob(&self, digest: &str, value: Vec<u8>) -> Result<()> {
+        let key = blob_key(digest);
+        if self.inner.exists(&key).await? {
+            self.dedup_hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(());
+        }
+        self.blobs_written.fetch_add(1, Ordering::Relaxed);
+        self.inner.set(&key, value, None).await
+    }
+
+    async fn load_blob(&self, digest: &str) -> Result<Option<Vec<u8>>> {
+        let value = self.inner.get(&blob_key(digest)).await?;
+        if let (Some(value), true) = (&value, self.integrity_check) {
+            let actual = digest_hex(value, self.digest_len);
+            if actual != digest {
+                return Err(Error::InvalidCacheEntry(format!(
+                    "content-addressed blob for digest {digest} re-hashed to {actual}"
+                )));
+            }
+        }
+        Ok(value)
+    }
+}
+
+impl<B: CacheBackend> CacheBackend for ContentAddressedBackend<B> {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let Some(pointer) = self.inner.get(key).await? else {
+            return Ok(None);
+        };
+        let digest = String::from_utf8(pointer)
+            .map_err(|e| Error::InvalidCacheEntry(format!("non-UTF8 content-addressed pointer: {e}")))?;
+        self.load_blob(&digest).await
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<()> {
+        let digest = digest_hex(&value, self.digest_len);
+        self.store_blob(&digest, value).await?;
+        self.inner.set(key, digest.into_bytes(), ttl).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        // Only the pointer is removed - see the module docs' scope note on
+        // why the blob itself is left behind.
+        self.inner.delete(key).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        self.inner.health_check().await
+    }
+
+    async fn clear_all(&self) -> Result<()> {
+        self.inner.clear_all().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::InMemoryBackend;
+
+    #[tokio::test]
+    async fn test_identical_values_share_one_blob() {
+        let backend = ContentAddressedBackend::new(InMemoryBackend::new());
+
+        backend
+            .set("product:1", b"shared payload".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        backend
+            .set("product:2", b"shared payload".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        assert_eq!(backend.stats().blobs_written, 1);
+        assert_eq!(backend.stats().dedup_hits, 1);
+
+        assert_eq!(
+            backend.get("product:1").await.expect("Failed to get"),
+            Some(b"shared payload".to_vec())
+        );
+        assert_eq!(
+            backend.get("product:2").await.expect("Failed to get"),
+            Some(b"shared payload".to_vec())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_distinct_values_get_distinct_blobs() {
+        let backend = ContentAddressedBackend::new(InMemoryBackend::new());
+
+        backend.set("a", b"one".to_vec(), None).await.expect("Failed to set");
+        backend.set("b", b"two".to_vec(), None).await.expect("Failed to set");
+
+        assert_eq!(backend.stats().blobs_written, 2);
+        assert_eq!(backend.stats().dedup_hits, 0);
+    }
+
+    #[tokio::test]
+    async fn test_deleting_one_key_leaves_shared_blob_for_the_other() {
+        let backend = ContentAddressedBackend::new(InMemoryBackend::new());
+
+        backend.set("a", b"shared".to_vec(), None).await.expect("Failed to set");
+        backend.set("b", b"shared".to_vec(), None).await.expect("Failed to set");
+
+        backend.delete("a").await.expect("Failed to delete");
+
+        assert_eq!(backend.get("a").await.expect("Failed to get"), None);
+        assert_eq!(
+            backend.get("b").await.expect("Failed to get"),
+            Some(b"shared".to_vec())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_integrity_check_detects_corrupted_blob() {
+        let backend = ContentAddressedBackend::new(InMemoryBackend::new()).with_integrity_check();
+
+        backend.set("a", b"original".to_vec(), None).await.expect("Failed to set");
+
+        let digest = String::from_utf8(
+            backend
+                .inner()
+                .get("a")
+                .await
+                .expect("Failed to get")
+                .expect("pointer should exist"),
+        )
+        .expect("pointer should be UTF8");
+        backend
+            .inner()
+            .set(&blob_key(&digest), b"tampered".to_vec(), None)
+            .await
+            .expect("Failed to corrupt blob");
+
+        let result = backend.get("a").await;
+        assert!(matches!(result, Err(Error::InvalidCacheEntry(_))));
+    }
+
+    #[tokio::test]
+    async fn test_truncated_digest_len_is_clamped_and_used() {
+        let backend = ContentAddressedBackend::new(InMemoryBackend::new()).with_digest_len(4);
+
+        backend.set("a", b"value".to_vec(), None).await.expect("Failed to set");
+        let pointer = backend
+            .inner()
+            .get("a")
+            .await
+            .expect("Failed to get")
+            .expect("pointer should exist");
+        assert_eq!(pointer.len(), 8, "4-byte digest should hex-encode to 8 characters");
+    }
+}
@@ -0,0 +1,66 @@
+//! No-op [`CacheBackend`] for deployments that want caching turned off
+//! without recompiling - e.g. an incident-response kill switch, or a
+//! `CACHE_BACKEND` config value of "none" in an environment that doesn't
+//! have Redis provisioned yet.
+//!
+//! Every `get` reports a miss and every `set`/`delete` is silently dropped,
+//! so `CacheExpander` routes every call straight through to the
+//! `DataRepository` exactly as it would on a real cache miss - callers don't
+//! need a separate "caching is off" code path.
+
+use super::CacheBackend;
+use crate::error::Result;
+use std::time::Duration;
+
+/// A [`CacheBackend`] that stores nothing: every `get` is a miss, every
+/// `set`/`delete` is a no-op. See the module docs for why you'd want this.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DisabledBackend;
+
+impl DisabledBackend {
+    /// Construct a new disabled backend. Stateless, so every instance
+    /// behaves identically.
+    pub fn new() -> Self {
+        DisabledBackend
+    }
+}
+
+impl CacheBackend for DisabledBackend {
+    async fn get(&self, _key: &str) -> Result<Option<Vec<u8>>> {
+        Ok(None)
+    }
+
+    async fn set(&self, _key: &str, _value: Vec<u8>, _ttl: Option<Duration>) -> Result<()> {
+        Ok(())
+    }
+
+    async fn delete(&self, _key: &str) -> Result<()> {
+        Ok(())
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_disabled_backend_get_is_always_a_miss_even_after_set() {
+        let backend = DisabledBackend::new();
+        backend
+            .set("key", vec![1, 2, 3], None)
+            .await
+            .expect("set should be a no-op");
+        assert_eq!(backend.get("key").await.expect("get should succeed"), None);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_backend_delete_and_health_check_succeed() {
+        let backend = DisabledBackend::new();
+        backend.delete("key").await.expect("delete should be a no-op");
+        assert!(backend.health_check().await.expect("health check should succeed"));
+    }
+}
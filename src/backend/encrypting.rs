@@ -0,0 +1,361 @@
+//! Transparent at-rest encryption wrapper for any [`CacheBackend`].
+//!
+//! Wraps every value in ChaCha20-Poly1305 before handing it to the inner
+//! backend, and decrypts on read, so entities cached in a shared Redis or
+//! Memcached instance are unreadable to anyone with raw store access.
+//!
+//! Requires the `encryption` feature.
+
+use super::CacheBackend;
+use crate::error::{Error, Result};
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Byte length of the random nonce prefixed to every stored value.
+const NONCE_LEN: usize = 12;
+
+/// Supplies the 32-byte AEAD key [`EncryptingBackend`] encrypts/decrypts a
+/// given backend key under.
+///
+/// Implementors may return the same key for every call (a single shared
+/// secret, see [`StaticKeyProvider`]) or vary it per key - e.g. by the
+/// `cache_prefix()` segment of `key` (everything before the first `:`, the
+/// same ad hoc convention every `CacheEntity` key is already built from) -
+/// to support per-entity-type keys and future key rotation.
+pub trait KeyProvider: Send + Sync {
+    /// The 32-byte key to use for `key`.
+    fn key_for(&self, key: &str) -> [u8; 32];
+}
+
+/// A [`KeyProvider`] that returns the same key for every backend key.
+///
+/// What [`EncryptingBackend::new`] uses under the hood; reach for
+/// [`EncryptingBackend::with_key_provider`] instead when different entity
+/// types need different keys.
+#[derive(Clone)]
+pub struct StaticKeyProvider(pub [u8; 32]);
+
+impl KeyProvider for StaticKeyProvider {
+    fn key_for(&self, _key: &str) -> [u8; 32] {
+        self.0
+    }
+}
+
+/// Wraps any [`CacheBackend`] with transparent ChaCha20-Poly1305 encryption.
+///
+/// Each stored value is `nonce || ciphertext || tag`: a fresh random 12-byte
+/// nonce per `set`, authenticated-encrypted under the key a [`KeyProvider`]
+/// returns for that backend key. The key string itself is passed as
+/// associated data, so a ciphertext copied onto a different key fails
+/// authentication instead of silently decrypting there. `get` splits the
+/// nonce back off and decrypts, returning [`Error::DecryptionError`] on
+/// authentication failure - corruption, an AAD/key mismatch, or a value
+/// encrypted under a different key entirely.
+///
+/// # Example
+///
+/// ```no_run
+/// use cache_kit::backend::{EncryptingBackend, InMemoryBackend};
+///
+/// # async fn example() -> cache_kit::Result<()> {
+/// let key = [0u8; 32]; // in practice, load from a secret store
+/// let backend = EncryptingBackend::new(InMemoryBackend::new(), &key);
+///
+/// backend.set("key", b"secret".to_vec(), None).await?;
+/// let value = backend.get("key").await?;
+/// assert_eq!(value, Some(b"secret".to_vec()));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct EncryptingBackend<B> {
+    inner: B,
+    key_provider: Arc<dyn KeyProvider>,
+}
+
+impl<B: CacheBackend> EncryptingBackend<B> {
+    /// Wrap `inner`, encrypting every value under the same `key`.
+    pub fn new(inner: B, key: &[u8; 32]) -> Self {
+        Self::with_key_provider(inner, StaticKeyProvider(*key))
+    }
+
+    /// Wrap `inner`, deriving the encryption key per backend key from
+    /// `key_provider` - e.g. a provider keyed by `CacheEntity::cache_prefix()`
+    /// so different entity types are encrypted under different keys.
+    pub fn with_key_provider(inner: B, key_provider: impl KeyProvider + 'static) -> Self {
+        EncryptingBackend {
+            inner,
+            key_provider: Arc::new(key_provider),
+        }
+    }
+
+    /// Borrow the wrapped backend.
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    fn cipher_for(&self, key: &str) -> ChaCha20Poly1305 {
+        ChaCha20Poly1305::new(Key::from_slice(&self.key_provider.key_for(key)))
+    }
+
+    fn encrypt(&self, key: &str, value: Vec<u8>) -> Result<Vec<u8>> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher_for(key)
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: &value,
+                    aad: key.as_bytes(),
+                },
+            )
+            .map_err(|e| Error::SerializationError(format!("encryption failed: {e}")))?;
+
+        let mut encoded = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+        encoded.extend_from_slice(&nonce);
+        encoded.extend(ciphertext);
+        Ok(encoded)
+    }
+
+    fn decrypt(&self, key: &str, encoded: Vec<u8>) -> Result<Vec<u8>> {
+        if encoded.len() < NONCE_LEN {
+            return Err(Error::DecryptionError(
+                "encrypted value shorter than nonce".to_string(),
+            ));
+        }
+
+        let (nonce_bytes, ciphertext) = encoded.split_at(NONCE_LEN);
+        let nonce = Nonce::from_slice(nonce_bytes);
+        self.cipher_for(key)
+            .decrypt(
+                nonce,
+                Payload {
+                    msg: ciphertext,
+                    aad: key.as_bytes(),
+                },
+            )
+            .map_err(|_| Error::DecryptionError("authentication failed".to_string()))
+    }
+}
+
+impl<B: CacheBackend> CacheBackend for EncryptingBackend<B> {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        match self.inner.get(key).await? {
+            Some(encoded) => Ok(Some(self.decrypt(key, encoded)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<()> {
+        let encoded = self.encrypt(key, value)?;
+        self.inner.set(key, encoded, ttl).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.inner.delete(key).await
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool> {
+        self.inner.exists(key).await
+    }
+
+    async fn mget(&self, keys: &[&str]) -> Result<Vec<Option<Vec<u8>>>> {
+        let values = self.inner.mget(keys).await?;
+        keys.iter()
+            .zip(values)
+            .map(|(key, value)| value.map(|v| self.decrypt(key, v)).transpose())
+            .collect()
+    }
+
+    async fn mdelete(&self, keys: &[&str]) -> Result<()> {
+        self.inner.mdelete(keys).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        self.inner.health_check().await
+    }
+
+    async fn clear_all(&self) -> Result<()> {
+        self.inner.clear_all().await
+    }
+
+    async fn invalidate_prefix(&self, prefix: &str) -> Result<()> {
+        self.inner.invalidate_prefix(prefix).await
+    }
+
+    async fn scan_prefix(&self, prefix: &str) -> Result<Vec<String>> {
+        self.inner.scan_prefix(prefix).await
+    }
+
+    async fn delete_prefix(&self, prefix: &str) -> Result<u64> {
+        self.inner.delete_prefix(prefix).await
+    }
+
+    async fn set_with_tags(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        ttl: Option<Duration>,
+        tags: &[&str],
+    ) -> Result<()> {
+        let encoded = self.encrypt(key, value)?;
+        self.inner.set_with_tags(key, encoded, ttl, tags).await
+    }
+
+    async fn invalidate_tag(&self, tag: &str) -> Result<()> {
+        self.inner.invalidate_tag(tag).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::InMemoryBackend;
+
+    #[tokio::test]
+    async fn test_roundtrip_encrypts_and_decrypts() {
+        let key = [7u8; 32];
+        let backend = EncryptingBackend::new(InMemoryBackend::new(), &key);
+
+        backend
+            .set("key", b"secret value".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        let raw = backend.inner().get("key").await.expect("Failed to get").unwrap();
+        assert_ne!(raw, b"secret value".to_vec(), "stored bytes must not be plaintext");
+
+        let roundtripped = backend.get("key").await.expect("Failed to get");
+        assert_eq!(roundtripped, Some(b"secret value".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn test_each_set_uses_a_fresh_nonce() {
+        let key = [3u8; 32];
+        let backend = EncryptingBackend::new(InMemoryBackend::new(), &key);
+
+        backend.set("a", b"same".to_vec(), None).await.expect("Failed to set");
+        backend.set("b", b"same".to_vec(), None).await.expect("Failed to set");
+
+        let raw_a = backend.inner().get("a").await.expect("Failed to get").unwrap();
+        let raw_b = backend.inner().get("b").await.expect("Failed to get").unwrap();
+        assert_ne!(
+            raw_a, raw_b,
+            "identical plaintexts must not produce identical ciphertext"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_decrypting_with_wrong_key_fails() {
+        let backend = EncryptingBackend::new(InMemoryBackend::new(), &[1u8; 32]);
+        backend.set("key", b"secret".to_vec(), None).await.expect("Failed to set");
+
+        let raw = backend.inner().get("key").await.expect("Failed to get").unwrap();
+        let wrong_key_backend = EncryptingBackend::new(InMemoryBackend::new(), &[2u8; 32]);
+        wrong_key_backend
+            .inner()
+            .set("key", raw, None)
+            .await
+            .expect("Failed to set");
+
+        let result = wrong_key_backend.get("key").await;
+        assert!(matches!(result, Err(Error::DecryptionError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_corrupted_ciphertext_fails_authentication() {
+        let backend = EncryptingBackend::new(InMemoryBackend::new(), &[9u8; 32]);
+        backend.set("key", b"secret".to_vec(), None).await.expect("Failed to set");
+
+        let mut raw = backend.inner().get("key").await.expect("Failed to get").unwrap();
+        let last = raw.len() - 1;
+        raw[last] ^= 0xFF;
+        backend.inner().set("key", raw, None).await.expect("Failed to set");
+
+        let result = backend.get("key").await;
+        assert!(matches!(result, Err(Error::DecryptionError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_relocating_ciphertext_to_another_key_fails_authentication() {
+        let backend = EncryptingBackend::new(InMemoryBackend::new(), &[4u8; 32]);
+        backend.set("user:1", b"secret".to_vec(), None).await.expect("Failed to set");
+
+        let raw = backend
+            .inner()
+            .get("user:1")
+            .await
+            .expect("Failed to get")
+            .unwrap();
+        // Copy the ciphertext onto a different key under the same key
+        // material - the key string is bound in as associated data, so this
+        // must fail even though the AEAD key itself is unchanged.
+        backend
+            .inner()
+            .set("user:2", raw, None)
+            .await
+            .expect("Failed to set");
+
+        let result = backend.get("user:2").await;
+        assert!(matches!(result, Err(Error::DecryptionError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_with_key_provider_uses_different_keys_per_entity_prefix() {
+        struct PerPrefixKeyProvider;
+        impl KeyProvider for PerPrefixKeyProvider {
+            fn key_for(&self, key: &str) -> [u8; 32] {
+                let prefix = key.split(':').next().unwrap_or(key);
+                let mut k = [0u8; 32];
+                k[0] = prefix.len() as u8;
+                k[1..1 + prefix.len().min(31)]
+                    .copy_from_slice(&prefix.as_bytes()[..prefix.len().min(31)]);
+                k
+            }
+        }
+
+        let backend =
+            EncryptingBackend::with_key_provider(InMemoryBackend::new(), PerPrefixKeyProvider);
+        backend
+            .set("user:1", b"alice@example.com".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        backend
+            .set("order:1", b"alice@example.com".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        let user_raw = backend.inner().get("user:1").await.expect("Failed to get").unwrap();
+        let order_raw = backend.inner().get("order:1").await.expect("Failed to get").unwrap();
+        assert_ne!(
+            user_raw, order_raw,
+            "identical plaintexts under different prefixes must diverge once keyed separately"
+        );
+
+        assert_eq!(
+            backend.get("user:1").await.expect("Failed to get"),
+            Some(b"alice@example.com".to_vec())
+        );
+        assert_eq!(
+            backend.get("order:1").await.expect("Failed to get"),
+            Some(b"alice@example.com".to_vec())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mget_decrypts_each_value() {
+        let key = [5u8; 32];
+        let backend = EncryptingBackend::new(InMemoryBackend::new(), &key);
+
+        backend.set("a", b"one".to_vec(), None).await.expect("Failed to set");
+        backend.set("b", b"two".to_vec(), None).await.expect("Failed to set");
+
+        let values = backend
+            .mget(&["a", "b", "missing"])
+            .await
+            .expect("Failed to mget");
+        assert_eq!(values, vec![Some(b"one".to_vec()), Some(b"two".to_vec()), None]);
+    }
+}
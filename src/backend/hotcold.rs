@@ -0,0 +1,523 @@
+//! Tiered hot/cold cache backend composing two other backends.
+//!
+//! Pairs a fast, usually size-bounded "hot" backend (e.g. `InMemoryBackend`)
+//! with a larger, usually slower "cold" backend (e.g. `RedisBackend`). Reads
+//! check hot first and promote cold hits back into hot; writes go to both
+//! tiers so either one alone still serves correct data.
+
+use super::{CacheBackend, RecoveringBackend, RecoveryPolicy};
+use crate::error::Result;
+use std::time::Duration;
+
+/// Composes a hot backend and a cold backend into a single tiered backend.
+///
+/// Typical pairing: a bounded `InMemoryBackend` (process-local L1, e.g. in
+/// front of a shared `RedisBackend` L2) so hot keys are served without a
+/// network round-trip. Because this implements `CacheBackend` like any other
+/// backend, `CacheExpander`'s `CacheStrategy::Invalidate` and `Bypass` need no
+/// special-casing: `Invalidate` evicts via `delete` (both tiers), and
+/// `Bypass` never touches the backend at all.
+///
+/// # Semantics
+///
+/// - **`get`**: Check `hot` first. On a hot miss, fall back to `cold`; a cold
+///   hit is promoted back into `hot` before being returned, capped at
+///   `l1_ttl` (see [`HotColdBackend::with_l1_ttl`]) if one is configured.
+/// - **`set`**: Write-through to both `hot` and `cold`. The hot write uses
+///   `ttl` capped at `l1_ttl`, so L1 never outlives a deliberately short local
+///   cache window even when the caller asks for a long `ttl`.
+/// - **`delete`**/**`mdelete`**: Demote by removing from both tiers, so a
+///   stale hot copy can't resurface after the cold entry is gone.
+/// - **`mget`**: Resolve as many keys as possible from `hot`, then issue a
+///   single `cold.mget` for only the residual (hot-miss) keys - not one
+///   `cold` round trip per miss - promoting every cold hit back into hot.
+///
+/// # Example
+///
+/// ```no_run
+/// use cache_kit::backend::{HotColdBackend, InMemoryBackend};
+/// use std::time::Duration;
+///
+/// # async fn example() -> cache_kit::Result<()> {
+/// let hot = InMemoryBackend::with_capacity(1000);
+/// let cold = InMemoryBackend::new(); // stand-in for a Redis/Memcached backend
+/// let backend = HotColdBackend::new(hot, cold).with_l1_ttl(Duration::from_secs(30));
+///
+/// backend.set("key", b"value".to_vec(), None).await?;
+/// let value = backend.get("key").await?;
+/// assert!(value.is_some());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct HotColdBackend<H, C> {
+    hot: H,
+    cold: C,
+    /// Upper bound on the TTL used for hot-tier writes and promotions.
+    /// `None` (the default) means the hot tier uses whatever TTL the caller
+    /// (or a cold-hit promotion) would otherwise use, unbounded.
+    l1_ttl: Option<Duration>,
+}
+
+impl<H, C> HotColdBackend<H, C>
+where
+    H: CacheBackend,
+    C: CacheBackend,
+{
+    /// Create a tiered backend from a hot and a cold backend.
+    pub fn new(hot: H, cold: C) -> Self {
+        HotColdBackend {
+            hot,
+            cold,
+            l1_ttl: None,
+        }
+    }
+
+    /// Cap the hot tier's TTL at `ttl`, regardless of what the cold tier (or
+    /// the caller's `set`) uses. Keeps a process-local L1 from holding data
+    /// long after it's gone stale in a shared L2, at the cost of more
+    /// frequent L1 repopulation.
+    pub fn with_l1_ttl(mut self, ttl: Duration) -> Self {
+        self.l1_ttl = Some(ttl);
+        self
+    }
+
+    /// Borrow the hot tier (useful for tier-specific diagnostics like `stats()`).
+    pub fn hot(&self) -> &H {
+        &self.hot
+    }
+
+    /// Borrow the cold tier.
+    pub fn cold(&self) -> &C {
+        &self.cold
+    }
+
+    /// TTL to use for a hot-tier write given the caller's requested `ttl`:
+    /// the shorter of `ttl` and `l1_ttl`, or whichever one is set.
+    fn hot_ttl(&self, ttl: Option<Duration>) -> Option<Duration> {
+        match (ttl, self.l1_ttl) {
+            (Some(ttl), Some(l1_ttl)) => Some(ttl.min(l1_ttl)),
+            (Some(ttl), None) => Some(ttl),
+            (None, l1_ttl) => l1_ttl,
+        }
+    }
+}
+
+impl<H, C> HotColdBackend<H, RecoveringBackend<C>>
+where
+    H: CacheBackend,
+    C: CacheBackend,
+{
+    /// Like [`HotColdBackend::new`], but wraps `cold` in a
+    /// [`RecoveringBackend`] configured with [`RecoveryPolicy::BlackHole`]:
+    /// once a `cold` operation has failed `retry_count` retries (plus the
+    /// key-reset attempt `RecoveringBackend` always tries first), it degrades
+    /// to "read as a miss, drop the write" instead of propagating the error.
+    /// `hot` is never wrapped - a process-local backend isn't expected to go
+    /// unreachable the way a shared one (Redis, Memcached) is.
+    ///
+    /// Use this constructor instead of [`HotColdBackend::new`] when `cold` is
+    /// a remote backend you don't want taking the rest of the service down
+    /// with it: a dead `cold` then just means every `get` falls through to
+    /// (and promotes from) whatever's still warm in `hot`.
+    pub fn new_resilient(hot: H, cold: C, retry_count: u32) -> Self {
+        HotColdBackend::new(hot, RecoveringBackend::new(cold, RecoveryPolicy::BlackHole, retry_count))
+    }
+}
+
+impl<H, C> CacheBackend for HotColdBackend<H, C>
+where
+    H: CacheBackend,
+    C: CacheBackend,
+{
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        if let Some(value) = self.hot.get(key).await? {
+            debug!("✓ HotCold GET {} -> HOT HIT", key);
+            return Ok(Some(value));
+        }
+
+        match self.cold.get(key).await? {
+            Some(value) => {
+                debug!("✓ HotCold GET {} -> COLD HIT, promoting to hot", key);
+                // Best-effort: a failed promotion shouldn't fail the read itself.
+                if let Err(e) = self.hot.set(key, value.clone(), self.hot_ttl(None)).await {
+                    warn!("⚠ HotCold promote {} to hot failed: {}", key, e);
+                }
+                Ok(Some(value))
+            }
+            None => {
+                debug!("✓ HotCold GET {} -> MISS", key);
+                Ok(None)
+            }
+        }
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<()> {
+        self.hot.set(key, value.clone(), self.hot_ttl(ttl)).await?;
+        self.cold.set(key, value, ttl).await?;
+        debug!("✓ HotCold SET {} (both tiers)", key);
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.hot.delete(key).await?;
+        self.cold.delete(key).await?;
+        debug!("✓ HotCold DELETE {} (both tiers)", key);
+        Ok(())
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool> {
+        if self.hot.exists(key).await? {
+            return Ok(true);
+        }
+        self.cold.exists(key).await
+    }
+
+    /// Resolve as many `keys` as possible from `hot` alone, then issue a
+    /// single `cold.mget` for only the keys that missed hot - not one
+    /// `cold` round trip per miss - promoting every cold hit back into hot
+    /// (same as a single-key `get`) before merging everything back into
+    /// `keys`'s original order.
+    async fn mget(&self, keys: &[&str]) -> Result<Vec<Option<Vec<u8>>>> {
+        let hot_results = self.hot.mget(keys).await?;
+
+        let mut missing_keys = Vec::new();
+        for (key, value) in keys.iter().zip(&hot_results) {
+            if value.is_none() {
+                missing_keys.push(*key);
+            }
+        }
+
+        if missing_keys.is_empty() {
+            debug!("✓ HotCold MGET {} keys -> all HOT HIT", keys.len());
+            return Ok(hot_results);
+        }
+
+        let cold_results = self.cold.mget(&missing_keys).await?;
+        let mut cold_by_key = std::collections::HashMap::with_capacity(missing_keys.len());
+        for (key, value) in missing_keys.iter().zip(cold_results) {
+            if let Some(value) = value {
+                // Best-effort: a failed promotion shouldn't fail the read itself.
+                if let Err(e) = self.hot.set(key, value.clone(), self.hot_ttl(None)).await {
+                    warn!("⚠ HotCold promote {} to hot failed: {}", key, e);
+                }
+                cold_by_key.insert(*key, value);
+            }
+        }
+
+        debug!(
+            "✓ HotCold MGET {} keys ({} hot hit, {} cold mget)",
+            keys.len(),
+            keys.len() - missing_keys.len(),
+            missing_keys.len()
+        );
+
+        Ok(hot_results
+            .into_iter()
+            .zip(keys)
+            .map(|(hot_value, key)| hot_value.or_else(|| cold_by_key.get(key).cloned()))
+            .collect())
+    }
+
+    async fn mdelete(&self, keys: &[&str]) -> Result<()> {
+        self.hot.mdelete(keys).await?;
+        self.cold.mdelete(keys).await?;
+        debug!("✓ HotCold MDELETE {} keys (both tiers)", keys.len());
+        Ok(())
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        Ok(self.hot.health_check().await? && self.cold.health_check().await?)
+    }
+
+    async fn clear_all(&self) -> Result<()> {
+        self.hot.clear_all().await?;
+        self.cold.clear_all().await?;
+        warn!("⚠ HotCold CLEAR_ALL executed - both tiers cleared!");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::InMemoryBackend;
+
+    #[tokio::test]
+    async fn test_hotcold_set_writes_through_both_tiers() {
+        let hot = InMemoryBackend::new();
+        let cold = InMemoryBackend::new();
+        let backend = HotColdBackend::new(hot, cold);
+
+        backend
+            .set("key1", b"value1".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        assert_eq!(
+            backend.hot().get("key1").await.expect("Failed to get"),
+            Some(b"value1".to_vec())
+        );
+        assert_eq!(
+            backend.cold().get("key1").await.expect("Failed to get"),
+            Some(b"value1".to_vec())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hotcold_get_prefers_hot_tier() {
+        let hot = InMemoryBackend::new();
+        let cold = InMemoryBackend::new();
+        hot.set("key1", b"hot_value".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        cold.set("key1", b"cold_value".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        let backend = HotColdBackend::new(hot, cold);
+
+        assert_eq!(
+            backend.get("key1").await.expect("Failed to get"),
+            Some(b"hot_value".to_vec())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hotcold_cold_hit_promotes_to_hot() {
+        let hot = InMemoryBackend::new();
+        let cold = InMemoryBackend::new();
+        cold.set("key1", b"cold_value".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        let backend = HotColdBackend::new(hot, cold);
+
+        assert_eq!(
+            backend.get("key1").await.expect("Failed to get"),
+            Some(b"cold_value".to_vec())
+        );
+
+        // Promoted into hot, so a direct hot lookup now also hits.
+        assert_eq!(
+            backend.hot().get("key1").await.expect("Failed to get"),
+            Some(b"cold_value".to_vec())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hotcold_miss_in_both_tiers() {
+        let backend = HotColdBackend::new(InMemoryBackend::new(), InMemoryBackend::new());
+
+        assert_eq!(
+            backend.get("nonexistent").await.expect("Failed to get"),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hotcold_delete_removes_from_both_tiers() {
+        let hot = InMemoryBackend::new();
+        let cold = InMemoryBackend::new();
+        let backend = HotColdBackend::new(hot, cold);
+
+        backend
+            .set("key1", b"value1".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        backend.delete("key1").await.expect("Failed to delete");
+
+        assert_eq!(
+            backend.hot().get("key1").await.expect("Failed to get"),
+            None
+        );
+        assert_eq!(
+            backend.cold().get("key1").await.expect("Failed to get"),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hotcold_l1_ttl_caps_hot_tier_write() {
+        let hot = InMemoryBackend::new();
+        let cold = InMemoryBackend::new();
+        let backend = HotColdBackend::new(hot, cold).with_l1_ttl(Duration::from_millis(50));
+
+        backend
+            .set("key1", b"value1".to_vec(), Some(Duration::from_secs(60)))
+            .await
+            .expect("Failed to set");
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        // Hot entry expired at the capped TTL even though the caller asked
+        // for a much longer one; the cold entry is unaffected.
+        assert_eq!(
+            backend.hot().get("key1").await.expect("Failed to get"),
+            None
+        );
+        assert_eq!(
+            backend.cold().get("key1").await.expect("Failed to get"),
+            Some(b"value1".to_vec())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hotcold_l1_ttl_caps_promotion_from_cold() {
+        let hot = InMemoryBackend::new();
+        let cold = InMemoryBackend::new();
+        cold.set("key1", b"cold_value".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        let backend = HotColdBackend::new(hot, cold).with_l1_ttl(Duration::from_millis(50));
+
+        assert_eq!(
+            backend.get("key1").await.expect("Failed to get"),
+            Some(b"cold_value".to_vec())
+        );
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        // The promoted hot copy expired at the capped L1 TTL; a fresh get
+        // falls through to cold and re-promotes.
+        assert_eq!(
+            backend.hot().get("key1").await.expect("Failed to get"),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hotcold_resilient_degrades_dead_cold_to_miss_on_get() {
+        use crate::backend::MockBackend;
+        use crate::error::Error;
+        use crate::repository::FailurePolicy;
+
+        let hot = InMemoryBackend::new();
+        let cold = MockBackend::new();
+        cold.set_failure(FailurePolicy::Always(Error::BackendError(
+            "redis unreachable".to_string(),
+        )));
+
+        let backend = HotColdBackend::new_resilient(hot, cold, 1);
+
+        // A dead cold tier degrades to a miss instead of erroring the get.
+        assert_eq!(backend.get("key1").await.expect("Failed to get"), None);
+    }
+
+    #[tokio::test]
+    async fn test_hotcold_resilient_still_serves_hot_hits_with_dead_cold() {
+        use crate::backend::MockBackend;
+        use crate::error::Error;
+        use crate::repository::FailurePolicy;
+
+        let hot = InMemoryBackend::new();
+        hot.set("key1", b"hot_value".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        let cold = MockBackend::new();
+        cold.set_failure(FailurePolicy::Always(Error::BackendError(
+            "redis unreachable".to_string(),
+        )));
+
+        let backend = HotColdBackend::new_resilient(hot, cold, 1);
+
+        // hot still serves the key even though cold would error if consulted.
+        assert_eq!(
+            backend.get("key1").await.expect("Failed to get"),
+            Some(b"hot_value".to_vec())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hotcold_exists_checks_both_tiers() {
+        let hot = InMemoryBackend::new();
+        let cold = InMemoryBackend::new();
+        cold.set("key1", b"value".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        let backend = HotColdBackend::new(hot, cold);
+
+        assert!(backend.exists("key1").await.expect("Failed to check"));
+        assert!(!backend
+            .exists("nonexistent")
+            .await
+            .expect("Failed to check"));
+    }
+
+    #[tokio::test]
+    async fn test_hotcold_mget_merges_hot_hits_and_cold_residual_in_order() {
+        let hot = InMemoryBackend::new();
+        let cold = InMemoryBackend::new();
+        hot.set("key1", b"hot1".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        cold.set("key2", b"cold2".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        let backend = HotColdBackend::new(hot, cold);
+
+        let results = backend
+            .mget(&["key1", "key2", "key3"])
+            .await
+            .expect("Failed to mget");
+
+        assert_eq!(
+            results,
+            vec![Some(b"hot1".to_vec()), Some(b"cold2".to_vec()), None]
+        );
+
+        // The cold hit was promoted into hot.
+        assert_eq!(
+            backend.hot().get("key2").await.expect("Failed to get"),
+            Some(b"cold2".to_vec())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hotcold_mget_skips_cold_entirely_when_all_keys_are_hot() {
+        use crate::backend::MockBackend;
+
+        let hot = InMemoryBackend::new();
+        let cold = MockBackend::new();
+        cold.set_failure(crate::repository::FailurePolicy::Always(
+            crate::error::Error::BackendError("should never be called".to_string()),
+        ));
+        hot.set("key1", b"hot1".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        let backend = HotColdBackend::new(hot, cold);
+
+        let results = backend.mget(&["key1"]).await.expect("Failed to mget");
+        assert_eq!(results, vec![Some(b"hot1".to_vec())]);
+    }
+
+    #[tokio::test]
+    async fn test_hotcold_mdelete_removes_from_both_tiers() {
+        let hot = InMemoryBackend::new();
+        let cold = InMemoryBackend::new();
+        let backend = HotColdBackend::new(hot, cold);
+
+        backend
+            .set("key1", b"value1".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        backend
+            .set("key2", b"value2".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        backend
+            .mdelete(&["key1", "key2"])
+            .await
+            .expect("Failed to mdelete");
+
+        assert_eq!(backend.hot().get("key1").await.expect("Failed to get"), None);
+        assert_eq!(backend.cold().get("key1").await.expect("Failed to get"), None);
+        assert_eq!(backend.hot().get("key2").await.expect("Failed to get"), None);
+        assert_eq!(backend.cold().get("key2").await.expect("Failed to get"), None);
+    }
+}
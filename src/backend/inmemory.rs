@@ -1,14 +1,31 @@
 //! In-memory cache backend (default, thread-safe, async).
 //!
 //! Uses DashMap for lock-free concurrent access with per-key sharding.
-//! Automatically handles TTL expiration on access.
+//! Automatically handles TTL expiration on access, and optionally bounds
+//! memory usage with LRU eviction.
+//!
+//! Implements the same [`CacheBackend`] trait [`crate::expander::CacheExpander::new`]
+//! accepts, so `with::<User, _, _>(..., CacheStrategy::Fresh)` runs entirely
+//! in-process - no Memcached/Redis dependency needed for tests or low-latency
+//! hot paths. [`InMemoryBackend::with_capacity`] bounds it by entry count
+//! with LRU eviction; see its doc for the byte-budgeted and Window-TinyLFU
+//! variants.
 
 use super::CacheBackend;
-use crate::error::Result;
-use dashmap::DashMap;
-use std::sync::Arc;
+use crate::error::{Error, Result};
+use crate::observability::CacheMetrics;
+use dashmap::{DashMap, DashSet};
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use std::time::Instant;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::Mutex as AsyncMutex;
 
 /// In-memory cache entry with optional expiration.
 struct CacheEntry {
@@ -27,6 +44,821 @@ impl CacheEntry {
     }
 }
 
+fn now_unix_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// On-disk representation of one entry for
+/// [`InMemoryBackend::save_snapshot`]/[`InMemoryBackend::load_snapshot`].
+///
+/// `CacheEntry::expires_at` is an `Instant`, which is meaningless once
+/// serialized (a new process has its own, unrelated `Instant` clock), so it
+/// can't be persisted directly. Instead this stores `ttl_secs` - the entry's
+/// *remaining* time-to-live as of `saved_at_unix_secs` - letting
+/// `load_snapshot` re-derive a correct expiry by subtracting however much
+/// wall-clock time has passed since the snapshot was taken.
+#[derive(Serialize, Deserialize)]
+struct SnapshotEntry {
+    key: String,
+    data: Vec<u8>,
+    ttl_secs: Option<u64>,
+    saved_at_unix_secs: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Snapshot {
+    entries: Vec<SnapshotEntry>,
+}
+
+/// One slot in the intrusive LRU list, linked by slot index rather than raw
+/// pointers - this crate stays unsafe-free, and a slab of indices gives the
+/// same O(1) link/unlink a pointer-based doubly linked list would.
+struct LruNode {
+    key: String,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+/// O(1) recency tracker for [`InMemoryBackend`]'s LRU eviction.
+///
+/// `index` maps a key to its slot in `nodes`; `nodes` is a slab (freed slots
+/// are recycled via `free` instead of shifting the vector) threaded into a
+/// doubly linked list from `head` (most-recently-used) to `tail` (least).
+/// `touch` unlinks and re-links a node at the head in O(1); eviction pops
+/// `tail` in O(1) - no linear scan of a `VecDeque` for either.
+struct LruList {
+    nodes: Vec<LruNode>,
+    index: HashMap<String, usize>,
+    head: Option<usize>,
+    tail: Option<usize>,
+    free: Vec<usize>,
+}
+
+impl LruList {
+    fn new() -> Self {
+        LruList {
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            head: None,
+            tail: None,
+            free: Vec::new(),
+        }
+    }
+
+    /// Unlink `slot` from the list without freeing it.
+    fn unlink(&mut self, slot: usize) {
+        let (prev, next) = (self.nodes[slot].prev, self.nodes[slot].next);
+        match prev {
+            Some(p) => self.nodes[p].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(n) => self.nodes[n].prev = prev,
+            None => self.tail = prev,
+        }
+        self.nodes[slot].prev = None;
+        self.nodes[slot].next = None;
+    }
+
+    /// Link `slot` in as the new head (most-recently-used).
+    fn link_at_head(&mut self, slot: usize) {
+        self.nodes[slot].prev = None;
+        self.nodes[slot].next = self.head;
+        if let Some(old_head) = self.head {
+            self.nodes[old_head].prev = Some(slot);
+        }
+        self.head = Some(slot);
+        if self.tail.is_none() {
+            self.tail = Some(slot);
+        }
+    }
+
+    /// Mark `key` as most-recently-used, inserting it if new.
+    fn touch(&mut self, key: &str) {
+        if let Some(&slot) = self.index.get(key) {
+            self.unlink(slot);
+            self.link_at_head(slot);
+            return;
+        }
+
+        let slot = match self.free.pop() {
+            Some(slot) => {
+                self.nodes[slot] = LruNode {
+                    key: key.to_string(),
+                    prev: None,
+                    next: None,
+                };
+                slot
+            }
+            None => {
+                self.nodes.push(LruNode {
+                    key: key.to_string(),
+                    prev: None,
+                    next: None,
+                });
+                self.nodes.len() - 1
+            }
+        };
+        self.index.insert(key.to_string(), slot);
+        self.link_at_head(slot);
+    }
+
+    /// Insert `key` at the head if it isn't already tracked; a no-op for an
+    /// already-tracked key, unlike [`LruList::touch`]. Used to maintain
+    /// [`InMemoryBackend`]'s FIFO tracker, where later reads of an entry
+    /// must not disturb its original insertion order.
+    fn insert_if_absent(&mut self, key: &str) {
+        if self.index.contains_key(key) {
+            return;
+        }
+        self.touch(key);
+    }
+
+    /// Remove `key` from the list, e.g. alongside a store deletion.
+    fn remove(&mut self, key: &str) {
+        if let Some(slot) = self.index.remove(key) {
+            self.unlink(slot);
+            self.free.push(slot);
+        }
+    }
+
+    /// Evict and return the least-recently-used key, if any.
+    fn pop_lru(&mut self) -> Option<String> {
+        let slot = self.tail?;
+        let key = self.nodes[slot].key.clone();
+        self.unlink(slot);
+        self.index.remove(&key);
+        self.free.push(slot);
+        Some(key)
+    }
+
+    fn clear(&mut self) {
+        self.nodes.clear();
+        self.index.clear();
+        self.head = None;
+        self.tail = None;
+        self.free.clear();
+    }
+}
+
+/// Which entry to evict when [`InMemoryBackend::with_byte_capacity`]'s budget
+/// is exceeded, or which admission/eviction strategy
+/// [`InMemoryBackend::with_eviction`] uses for a `max_entries` capacity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EvictionPolicy {
+    /// Evict the least-recently-used entry (the one not read or written the
+    /// longest).
+    Lru,
+    /// Evict the least-frequently-used entry, breaking ties by recency
+    /// within the same frequency.
+    Lfu,
+    /// Window-TinyLFU: a small LRU admission window feeds a larger
+    /// segmented-LRU main region, and a newcomer only displaces the main
+    /// region's own LRU victim if a Count-Min Sketch estimates it's been
+    /// accessed more often. See [`WindowTinyLfu`] for the implementation.
+    /// Only meaningful for a `max_entries` capacity set via
+    /// [`InMemoryBackend::with_eviction`] - a `max_bytes` budget falls back
+    /// to `Lru` behavior for this policy, since the sketch reasons about
+    /// access counts per key, not byte weight.
+    TinyLfu,
+    /// Evict in strict insertion order, ignoring access recency/frequency
+    /// entirely - the entry that has been in the cache the longest goes
+    /// first, even if it was just read.
+    Fifo,
+}
+
+/// Pluggable weight function for [`InMemoryBackend::with_weight_limit`]/
+/// [`InMemoryBackend::with_byte_capacity`] budgets.
+///
+/// The default (no weigher attached) charges a value's raw byte length
+/// against the budget. Attach one via
+/// [`InMemoryBackend::with_weigher`] to account for something else instead -
+/// e.g. a fixed per-entry overhead for metadata, or an entry's
+/// *uncompressed* size when the backend actually stores compressed bytes.
+///
+/// Implemented for any `Fn(&str, &[u8]) -> usize + Send + Sync` closure, so
+/// most callers never need to name a type for this.
+pub trait Weigher: Send + Sync {
+    /// Weight to charge `value` (stored under `key`) against the backend's
+    /// byte budget.
+    fn weight(&self, key: &str, value: &[u8]) -> usize;
+}
+
+impl<F> Weigher for F
+where
+    F: Fn(&str, &[u8]) -> usize + Send + Sync,
+{
+    fn weight(&self, key: &str, value: &[u8]) -> usize {
+        self(key, value)
+    }
+}
+
+/// Why an entry left [`InMemoryBackend`], passed to a listener registered
+/// with [`InMemoryBackend::with_eviction_listener`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RemovalCause {
+    /// Its TTL had already passed, caught by lazy removal on `get` or by the
+    /// background reaper (see [`InMemoryBackend::with_reaper`]).
+    Expired,
+    /// `set`/`set_with_tags` overwrote it with a new value under the same
+    /// key.
+    Replaced,
+    /// `max_entries`/`max_bytes` pressure (LRU, LFU, or Window-TinyLFU
+    /// admission) evicted it to make room for something else.
+    Size,
+    /// A caller removed it directly - `delete`, `mdelete`, `clear_all`,
+    /// `invalidate_prefix`, or `invalidate_tag`.
+    Explicit,
+}
+
+/// One slot in [`LfuList`]'s per-frequency intrusive linked list.
+struct LfuNode {
+    key: String,
+    freq: u64,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+/// Head/tail slot indices for one frequency bucket in [`LfuList`].
+struct LfuBucket {
+    head: Option<usize>,
+    tail: Option<usize>,
+}
+
+/// Frequency-bucketed recency tracker for [`InMemoryBackend`]'s LFU eviction.
+///
+/// Mirrors [`LruList`]'s slab-of-indices design, except nodes are linked
+/// within a frequency bucket (`buckets[freq]`) instead of one global list.
+/// `touch` increments a key's frequency and migrates it from its old bucket
+/// to the new one in O(1); eviction pops the head (least-recently-touched)
+/// of the lowest populated bucket, advancing `min_freq` past any buckets an
+/// explicit `remove` left empty - amortized O(1), not worst-case, since that
+/// advance can scan forward over frequencies with no remaining entries.
+struct LfuList {
+    nodes: Vec<LfuNode>,
+    index: HashMap<String, usize>,
+    buckets: HashMap<u64, LfuBucket>,
+    free: Vec<usize>,
+    min_freq: u64,
+}
+
+impl LfuList {
+    fn new() -> Self {
+        LfuList {
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            buckets: HashMap::new(),
+            free: Vec::new(),
+            min_freq: 0,
+        }
+    }
+
+    /// Unlink `slot` from its current bucket, pruning the bucket if it's now empty.
+    fn unlink(&mut self, slot: usize) {
+        let freq = self.nodes[slot].freq;
+        let (prev, next) = (self.nodes[slot].prev, self.nodes[slot].next);
+
+        match prev {
+            Some(p) => self.nodes[p].next = next,
+            None => {
+                if let Some(bucket) = self.buckets.get_mut(&freq) {
+                    bucket.head = next;
+                }
+            }
+        }
+        match next {
+            Some(n) => self.nodes[n].prev = prev,
+            None => {
+                if let Some(bucket) = self.buckets.get_mut(&freq) {
+                    bucket.tail = prev;
+                }
+            }
+        }
+        self.nodes[slot].prev = None;
+        self.nodes[slot].next = None;
+
+        if self.buckets.get(&freq).is_some_and(|b| b.head.is_none()) {
+            self.buckets.remove(&freq);
+        }
+    }
+
+    /// Link `slot` (whose `freq` field is already set) at the tail of its
+    /// frequency bucket, creating the bucket if needed.
+    fn link_at_tail(&mut self, slot: usize, freq: u64) {
+        let bucket = self.buckets.entry(freq).or_insert(LfuBucket { head: None, tail: None });
+        self.nodes[slot].prev = bucket.tail;
+        self.nodes[slot].next = None;
+        match bucket.tail {
+            Some(old_tail) => self.nodes[old_tail].next = Some(slot),
+            None => bucket.head = Some(slot),
+        }
+        bucket.tail = Some(slot);
+    }
+
+    /// Record an access: bump `key`'s frequency (inserting it at frequency 1
+    /// if new).
+    fn touch(&mut self, key: &str) {
+        if let Some(&slot) = self.index.get(key) {
+            let new_freq = self.nodes[slot].freq + 1;
+            self.unlink(slot);
+            self.nodes[slot].freq = new_freq;
+            self.link_at_tail(slot, new_freq);
+            return;
+        }
+
+        let slot = match self.free.pop() {
+            Some(slot) => {
+                self.nodes[slot] = LfuNode {
+                    key: key.to_string(),
+                    freq: 1,
+                    prev: None,
+                    next: None,
+                };
+                slot
+            }
+            None => {
+                self.nodes.push(LfuNode {
+                    key: key.to_string(),
+                    freq: 1,
+                    prev: None,
+                    next: None,
+                });
+                self.nodes.len() - 1
+            }
+        };
+        self.index.insert(key.to_string(), slot);
+        self.link_at_tail(slot, 1);
+        self.min_freq = 1;
+    }
+
+    /// Remove `key` from the tracker, e.g. alongside a store deletion.
+    fn remove(&mut self, key: &str) {
+        if let Some(slot) = self.index.remove(key) {
+            self.unlink(slot);
+            self.free.push(slot);
+        }
+    }
+
+    /// Evict and return the least-frequently-used key, if any.
+    fn pop_lfu(&mut self) -> Option<String> {
+        if self.index.is_empty() {
+            return None;
+        }
+        while !self.buckets.contains_key(&self.min_freq) {
+            self.min_freq += 1;
+        }
+
+        let slot = self.buckets[&self.min_freq]
+            .head
+            .expect("non-empty bucket must have a head");
+        let key = self.nodes[slot].key.clone();
+        self.unlink(slot);
+        self.index.remove(&key);
+        self.free.push(slot);
+        Some(key)
+    }
+
+    fn clear(&mut self) {
+        self.nodes.clear();
+        self.index.clear();
+        self.buckets.clear();
+        self.free.clear();
+        self.min_freq = 0;
+    }
+}
+
+/// Number of independent hash rows (and per-key 4-bit counters) in
+/// [`CountMinSketch`], and the number of stripes [`WindowTinyLfu`] splits its
+/// bookkeeping into.
+const CMS_DEPTH: usize = 4;
+
+/// Count-Min Sketch: `CMS_DEPTH` independent rows of 4-bit saturating
+/// counters, packed two per byte, used by [`WindowTinyLfu`] to estimate how
+/// often a key has been accessed without keeping an exact per-key counter
+/// forever. Collisions can only inflate a row's count, never deflate it, so
+/// the frequency estimate is the minimum across rows.
+struct CountMinSketch {
+    width: usize,
+    counters: Vec<u8>,
+    seeds: [u64; CMS_DEPTH],
+    additions: u64,
+    reset_threshold: u64,
+}
+
+impl CountMinSketch {
+    fn new(width: usize, reset_threshold: u64) -> Self {
+        let width = width.max(1);
+        let bytes_per_row = (width + 1) / 2;
+        CountMinSketch {
+            width,
+            counters: vec![0u8; bytes_per_row * CMS_DEPTH],
+            seeds: [
+                0x9E37_79B9_7F4A_7C15,
+                0xC2B2_AE3D_27D4_EB4F,
+                0x1656_67B1_9E37_79F9,
+                0x27D4_EB2F_1656_67C5,
+            ],
+            additions: 0,
+            reset_threshold: reset_threshold.max(1),
+        }
+    }
+
+    fn bytes_per_row(&self) -> usize {
+        (self.width + 1) / 2
+    }
+
+    /// Byte offset (within the whole `counters` buffer) and nibble of
+    /// `key`'s counter in `row`.
+    fn slot(&self, row: usize, key: &str) -> (usize, bool) {
+        let mut hasher = DefaultHasher::new();
+        self.seeds[row].hash(&mut hasher);
+        key.hash(&mut hasher);
+        let column = (hasher.finish() as usize) % self.width;
+        (row * self.bytes_per_row() + column / 2, column % 2 == 1)
+    }
+
+    fn counter(&self, row: usize, key: &str) -> u8 {
+        let (byte_index, high_nibble) = self.slot(row, key);
+        let byte = self.counters[byte_index];
+        if high_nibble {
+            byte >> 4
+        } else {
+            byte & 0x0F
+        }
+    }
+
+    fn set_counter(&mut self, row: usize, key: &str, value: u8) {
+        let (byte_index, high_nibble) = self.slot(row, key);
+        let byte = self.counters[byte_index];
+        self.counters[byte_index] = if high_nibble {
+            (byte & 0x0F) | (value << 4)
+        } else {
+            (byte & 0xF0) | value
+        };
+    }
+
+    /// Record one access to `key`, incrementing its estimated frequency
+    /// (saturating at 15 per row) and aging the whole sketch once
+    /// `reset_threshold` total increments have accumulated.
+    fn record(&mut self, key: &str) {
+        for row in 0..CMS_DEPTH {
+            let current = self.counter(row, key);
+            if current < 15 {
+                self.set_counter(row, key, current + 1);
+            }
+        }
+
+        self.additions += 1;
+        if self.additions >= self.reset_threshold {
+            self.age();
+        }
+    }
+
+    /// Estimated access frequency of `key` (0-15): the minimum counter across
+    /// all rows.
+    fn estimate(&self, key: &str) -> u8 {
+        (0..CMS_DEPTH)
+            .map(|row| self.counter(row, key))
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Halve every counter. Without this, long-lived hot keys would
+    /// eventually saturate every counter they touch and the sketch would
+    /// lose all discriminating power between an old favorite and a key that
+    /// merely got lucky with a recent burst.
+    fn age(&mut self) {
+        for byte in &mut self.counters {
+            let low = (*byte & 0x0F) >> 1;
+            let high = ((*byte >> 4) & 0x0F) >> 1;
+            *byte = (high << 4) | low;
+        }
+        self.additions = 0;
+    }
+}
+
+/// One stripe of [`WindowTinyLfu`]'s admission/eviction bookkeeping: an
+/// admission window (plain LRU, all new keys land here first) plus a main
+/// region split into probation and protected segments (segmented LRU), and
+/// the frequency sketch used to arbitrate admission once the window
+/// overflows.
+struct TinyLfuShard {
+    window: LruList,
+    probation: LruList,
+    protected: LruList,
+    sketch: CountMinSketch,
+    window_capacity: usize,
+    probation_capacity: usize,
+    protected_capacity: usize,
+}
+
+impl TinyLfuShard {
+    fn new(
+        window_capacity: usize,
+        probation_capacity: usize,
+        protected_capacity: usize,
+        sketch_width: usize,
+        reset_threshold: u64,
+    ) -> Self {
+        TinyLfuShard {
+            window: LruList::new(),
+            probation: LruList::new(),
+            protected: LruList::new(),
+            sketch: CountMinSketch::new(sketch_width, reset_threshold),
+            window_capacity: window_capacity.max(1),
+            probation_capacity: probation_capacity.max(1),
+            protected_capacity: protected_capacity.max(1),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.window.index.len() + self.probation.index.len() + self.protected.index.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.window_capacity + self.probation_capacity + self.protected_capacity
+    }
+
+    fn remove(&mut self, key: &str) {
+        self.window.remove(key);
+        self.probation.remove(key);
+        self.protected.remove(key);
+    }
+
+    fn clear(&mut self) {
+        self.window.clear();
+        self.probation.clear();
+        self.protected.clear();
+    }
+
+    /// Record an access to `key` - either a hit on a tracked entry
+    /// (`is_new = false`) or a brand-new insert (`is_new = true`) - and
+    /// return a key evicted as a result of a lost admission contest, if any.
+    fn access(&mut self, key: &str, is_new: bool) -> Option<String> {
+        self.sketch.record(key);
+
+        if !is_new {
+            if self.protected.index.contains_key(key) {
+                self.protected.touch(key);
+                return None;
+            }
+            if self.probation.index.contains_key(key) {
+                return self.promote_to_protected(key);
+            }
+            if self.window.index.contains_key(key) {
+                self.window.touch(key);
+                return None;
+            }
+            // Not tracked under any segment (e.g. it expired and is being
+            // re-inserted under the same key) - fall through and treat it as
+            // a fresh admission.
+        }
+
+        self.admit_new(key)
+    }
+
+    /// A probation hit is promoted straight to protected; if protected then
+    /// overflows, its own LRU victim is demoted back to probation rather
+    /// than evicted outright - protected/probation is a size split within
+    /// the main region, not a capacity ceiling on its own.
+    fn promote_to_protected(&mut self, key: &str) -> Option<String> {
+        self.probation.remove(key);
+        self.protected.touch(key);
+
+        if self.protected.index.len() > self.protected_capacity {
+            if let Some(demoted) = self.protected.pop_lru() {
+                self.probation.touch(&demoted);
+            }
+        }
+        None
+    }
+
+    /// Admit a brand-new key into the window; once the window overflows,
+    /// its LRU victim becomes a candidate for the main region and, if the
+    /// main region is already full, must win a frequency contest against
+    /// probation's own LRU victim to get in.
+    fn admit_new(&mut self, key: &str) -> Option<String> {
+        self.window.touch(key);
+
+        if self.window.index.len() <= self.window_capacity {
+            return None;
+        }
+
+        let candidate = self
+            .window
+            .pop_lru()
+            .expect("window over capacity must have an LRU entry to pop");
+
+        if self.len() < self.capacity() {
+            // Main region still has room - admit for free, no contest needed.
+            self.probation.touch(&candidate);
+            return None;
+        }
+
+        let victim = self.probation.tail.map(|slot| self.probation.nodes[slot].key.clone());
+
+        match victim {
+            None => {
+                // No probation victim to contest against (e.g. protected is
+                // full but probation happens to be momentarily empty) -
+                // admit the candidate directly rather than stall it.
+                self.probation.touch(&candidate);
+                None
+            }
+            Some(victim_key) => {
+                if self.sketch.estimate(&candidate) > self.sketch.estimate(&victim_key) {
+                    self.probation.remove(&victim_key);
+                    self.probation.touch(&candidate);
+                    Some(victim_key)
+                } else {
+                    Some(candidate)
+                }
+            }
+        }
+    }
+}
+
+/// Split a stripe's entry budget into window (~1%) and main-region
+/// probation/protected (~20%/80% of the remainder) capacities, per the
+/// Window-TinyLFU layout. Each segment is guaranteed at least one slot, so a
+/// very small per-stripe budget is honored approximately rather than
+/// collapsing to a zero-capacity segment.
+fn window_tiny_lfu_shard_capacities(per_shard: usize) -> (usize, usize, usize) {
+    if per_shard <= 3 {
+        return (1, 1, 1);
+    }
+
+    let window = (per_shard / 100).max(1);
+    let main = per_shard - window;
+    let protected = (main * 4 / 5).max(1);
+    let probation = (main - protected).max(1);
+    (window, probation, protected)
+}
+
+/// Number of stripes [`WindowTinyLfu`] shards its bookkeeping into, so a
+/// `get`/`set` on one key never contends with recency/frequency updates for
+/// an unrelated key - `DashMap` itself has no global ordering to contend
+/// over, but the admission/eviction lists sitting behind it otherwise would.
+const TINY_LFU_STRIPES: usize = 16;
+
+/// Window-TinyLFU admission/eviction policy used by
+/// [`InMemoryBackend::with_window_tiny_lfu_capacity`]. Keys are routed to one
+/// of several independently-locked [`TinyLfuShard`]s by hash, so unrelated
+/// keys' bookkeeping never blocks on the same mutex.
+struct WindowTinyLfu {
+    shards: Vec<Mutex<TinyLfuShard>>,
+}
+
+impl WindowTinyLfu {
+    fn new(max_entries: usize) -> Self {
+        let max_entries = max_entries.max(1);
+        let shard_count = (max_entries / 3).clamp(1, TINY_LFU_STRIPES);
+        let per_shard = (max_entries / shard_count).max(1);
+        let (window_capacity, probation_capacity, protected_capacity) =
+            window_tiny_lfu_shard_capacities(per_shard);
+        let sketch_width = (per_shard * 4).next_power_of_two().max(16);
+        let reset_threshold = (max_entries as u64).saturating_mul(10).max(64);
+
+        let shards = (0..shard_count)
+            .map(|_| {
+                Mutex::new(TinyLfuShard::new(
+                    window_capacity,
+                    probation_capacity,
+                    protected_capacity,
+                    sketch_width,
+                    reset_threshold,
+                ))
+            })
+            .collect();
+
+        WindowTinyLfu { shards }
+    }
+
+    fn shard_index(&self, key: &str) -> usize {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        (hasher.finish() as usize) % self.shards.len()
+    }
+
+    /// Record an access to `key`, returning a key evicted as the result of a
+    /// lost admission contest, if any.
+    fn access(&self, key: &str, is_new: bool) -> Option<String> {
+        let index = self.shard_index(key);
+        self.shards[index]
+            .lock()
+            .expect("TinyLFU shard lock poisoned")
+            .access(key, is_new)
+    }
+
+    fn remove(&self, key: &str) {
+        let index = self.shard_index(key);
+        self.shards[index]
+            .lock()
+            .expect("TinyLFU shard lock poisoned")
+            .remove(key);
+    }
+
+    fn clear(&self) {
+        for shard in &self.shards {
+            shard.lock().expect("TinyLFU shard lock poisoned").clear();
+        }
+    }
+}
+
+/// Weight to charge `value` (stored under `key`) via `weigher` if attached,
+/// else raw byte length. Free function (rather than an `InMemoryBackend`
+/// method) so the background reaper task in [`run_reaper`] - which only
+/// holds the `Arc`-cloned pieces of state it needs, not a whole backend -
+/// can apply the same accounting as [`InMemoryBackend::weight_of`].
+fn weigh(weigher: &Option<Arc<dyn Weigher>>, key: &str, value: &[u8]) -> usize {
+    match weigher {
+        Some(weigher) => weigher.weight(key, value),
+        None => value.len(),
+    }
+}
+
+/// Handle to the background task spawned by
+/// [`InMemoryBackend::with_reaper`]. Aborts the task on drop, so it only
+/// actually stops once the last clone of the owning [`InMemoryBackend`]
+/// goes away - every clone shares this handle behind an `Arc`, the same way
+/// they already share `store`/`lru`/`lfu`.
+struct ReaperHandle(tokio::task::JoinHandle<()>);
+
+impl Drop for ReaperHandle {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Background loop for [`InMemoryBackend::with_reaper`]: wake up every
+/// `interval`, sweep expired entries out of `store`, and keep `total_bytes`
+/// and both recency/frequency trackers consistent with the removal.
+///
+/// Each tick is skipped (after still bumping `stop_evictions_changes`, so a
+/// caller can tell a tick happened) while `stop_evictions` is set, letting a
+/// bulk scan or range-hold pause the reaper for a stable view of `store`
+/// without the two racing.
+///
+/// Walks `store` via `DashMap::iter`, which locks one internal shard at a
+/// time as it advances rather than the whole map at once - this already
+/// gives the "don't hold one long lock across the whole cache" property a
+/// hand-rolled shard walk would, without reaching into DashMap internals.
+#[allow(clippy::too_many_arguments)]
+async fn run_reaper(
+    store: Arc<DashMap<String, CacheEntry>>,
+    lru: Arc<Mutex<LruList>>,
+    lfu: Arc<Mutex<LfuList>>,
+    tiny_lfu: Option<Arc<WindowTinyLfu>>,
+    weigher: Option<Arc<dyn Weigher>>,
+    eviction_listener: Option<Arc<dyn Fn(&str, &[u8], RemovalCause) + Send + Sync>>,
+    total_bytes: Arc<AtomicUsize>,
+    reaped_entries: Arc<AtomicU64>,
+    reaped_bytes: Arc<AtomicU64>,
+    stop_evictions: Arc<AtomicBool>,
+    stop_evictions_changes: Arc<AtomicU64>,
+    interval: Duration,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.tick().await; // first tick fires immediately; skip it
+
+    loop {
+        ticker.tick().await;
+        stop_evictions_changes.fetch_add(1, Ordering::Relaxed);
+
+        if stop_evictions.load(Ordering::Acquire) {
+            continue;
+        }
+
+        let expired: Vec<String> = store
+            .iter()
+            .filter(|entry| entry.is_expired())
+            .map(|entry| entry.key().clone())
+            .collect();
+
+        for key in expired {
+            let Some((_, entry)) = store.remove(&key) else {
+                continue;
+            };
+            let size = weigh(&weigher, &key, &entry.data);
+            lru.lock().expect("LRU lock poisoned").remove(&key);
+            lfu.lock().expect("LFU lock poisoned").remove(&key);
+            if let Some(tiny_lfu) = &tiny_lfu {
+                tiny_lfu.remove(&key);
+            }
+            if let Some(listener) = &eviction_listener {
+                listener(&key, &entry.data, RemovalCause::Expired);
+            }
+            total_bytes.fetch_sub(size, Ordering::Relaxed);
+            reaped_entries.fetch_add(1, Ordering::Relaxed);
+            reaped_bytes.fetch_add(size as u64, Ordering::Relaxed);
+            debug!("✓ InMemory REAP {} (expired)", key);
+        }
+    }
+}
+
 /// Thread-safe async in-memory cache backend.
 ///
 /// Uses DashMap for lock-free concurrent access with fine-grained per-key sharding.
@@ -59,340 +891,2128 @@ impl CacheEntry {
 #[derive(Clone)]
 pub struct InMemoryBackend {
     store: Arc<DashMap<String, CacheEntry>>,
+    /// Recency order via an O(1) intrusive list; `store` remains the source
+    /// of truth for entry data.
+    lru: Arc<Mutex<LruList>>,
+    /// Frequency order, maintained alongside `lru` regardless of which
+    /// eviction policy (if any) is active, so switching policies never needs
+    /// a backfill.
+    lfu: Arc<Mutex<LfuList>>,
+    /// Insertion order, maintained alongside `lru`/`lfu` regardless of which
+    /// eviction policy (if any) is active, for `EvictionPolicy::Fifo`.
+    /// Unlike `lru`, reads never move an entry within this list.
+    fifo: Arc<Mutex<LruList>>,
+    /// Maximum number of entries before `policy`-driven eviction kicks in.
+    /// `None` = unbounded.
+    max_entries: Option<usize>,
+    /// Maximum total value bytes before `policy`-driven eviction kicks in.
+    /// `None` = unbounded. Independent of `max_entries` - a backend can use
+    /// either knob, both, or neither.
+    max_bytes: Option<usize>,
+    /// Eviction policy used once `max_entries` or `max_bytes` is exceeded.
+    /// Irrelevant when both are `None`.
+    policy: EvictionPolicy,
+    /// Custom weight function for `max_bytes` accounting. `None` charges a
+    /// value's raw byte length, via [`InMemoryBackend::weight_of`].
+    weigher: Option<Arc<dyn Weigher>>,
+    /// Running total of tracked value weight, for `max_bytes` enforcement
+    /// and `stats()`'s O(1) `total_bytes` reading.
+    total_bytes: Arc<AtomicUsize>,
+    /// Count of evictions performed (by either `max_entries` or `max_bytes`
+    /// pressure), for surfacing through `CacheMetrics` or direct inspection.
+    eviction_count: Arc<AtomicU64>,
+    /// Reverse index from tag -> set of keys tagged with it, for `invalidate_tag`.
+    tags: Arc<DashMap<String, DashSet<String>>>,
+    /// Invoked with each evicted key, e.g. to log or record a metric on churn.
+    eviction_callback: Option<Arc<dyn Fn(&str) + Send + Sync>>,
+    /// [`CacheMetrics::record_eviction`] sink for every capacity/weight/
+    /// admission-contest eviction, set via
+    /// [`InMemoryBackend::with_eviction_metrics`]. Independent of
+    /// `eviction_callback` - this exists so a backend can feed the same
+    /// `CacheMetrics` instance an expander/service already reports hits and
+    /// misses through, without the caller wiring up its own callback.
+    eviction_metrics: Option<Arc<dyn CacheMetrics>>,
+    /// Invoked with the key, value, and cause of every removal (lazy
+    /// expiry, the background reaper, capacity/weight eviction, `set`
+    /// overwriting an old value, or an explicit delete/clear), set via
+    /// [`InMemoryBackend::with_eviction_listener`]. Unlike
+    /// `eviction_callback`, this also sees the removed value and fires for
+    /// every removal path, not just capacity/weight eviction.
+    eviction_listener: Option<Arc<dyn Fn(&str, &[u8], RemovalCause) + Send + Sync>>,
+    /// Window-TinyLFU admission/eviction bookkeeping, used in place of `lru`
+    /// when set via [`InMemoryBackend::with_window_tiny_lfu_capacity`].
+    /// `max_entries` still holds the target so `stats()`/`len()` reporting
+    /// stays meaningful, but `evict_if_over_capacity` is bypassed in favor of
+    /// this policy's own admission contest.
+    tiny_lfu: Option<Arc<WindowTinyLfu>>,
+    /// Background expiration sweep started by
+    /// [`InMemoryBackend::with_reaper`]. `None` until attached; aborted once
+    /// the last clone of this backend is dropped (see [`ReaperHandle`]).
+    reaper: Option<Arc<ReaperHandle>>,
+    /// Set by [`InMemoryBackend::pause_reaper`] to skip the reaper's next
+    /// sweeps until [`InMemoryBackend::resume_reaper`]; lets a bulk scan or
+    /// range-hold get a stable view of `store` without racing a concurrent
+    /// sweep. Has no effect on `evict_if_over_capacity`/
+    /// `evict_if_over_byte_budget`, which this doesn't pause.
+    stop_evictions: Arc<AtomicBool>,
+    /// Bumped once per reaper tick regardless of outcome (paused or not), so
+    /// a caller can compare it before/after a guarded section to tell
+    /// whether any sweep was attempted during it.
+    stop_evictions_changes: Arc<AtomicU64>,
+    /// Entries removed by the background reaper since construction, for
+    /// `stats()`.
+    reaped_entries: Arc<AtomicU64>,
+    /// Weight reclaimed by the background reaper since construction, for
+    /// `stats()`.
+    reaped_bytes: Arc<AtomicU64>,
+    /// Per-key lock held while a `get_with` miss is computing its value, so
+    /// concurrent callers for the same key block on this instead of each
+    /// running `init` ("single-flight"). Mirrors
+    /// `CacheExpander::singleflight_fetch`'s `inflight` map. Entries are
+    /// removed once no other caller still holds a reference to the lock.
+    inflight: Arc<DashMap<String, Arc<AsyncMutex<()>>>>,
 }
 
 impl InMemoryBackend {
-    /// Create a new in-memory cache backend.
+    /// Create a new in-memory cache backend with no size limit.
     pub fn new() -> Self {
         InMemoryBackend {
             store: Arc::new(DashMap::new()),
+            lru: Arc::new(Mutex::new(LruList::new())),
+            lfu: Arc::new(Mutex::new(LfuList::new())),
+            fifo: Arc::new(Mutex::new(LruList::new())),
+            max_entries: None,
+            max_bytes: None,
+            policy: EvictionPolicy::Lru,
+            weigher: None,
+            total_bytes: Arc::new(AtomicUsize::new(0)),
+            eviction_count: Arc::new(AtomicU64::new(0)),
+            tags: Arc::new(DashMap::new()),
+            eviction_callback: None,
+            eviction_metrics: None,
+            eviction_listener: None,
+            tiny_lfu: None,
+            reaper: None,
+            stop_evictions: Arc::new(AtomicBool::new(false)),
+            stop_evictions_changes: Arc::new(AtomicU64::new(0)),
+            reaped_entries: Arc::new(AtomicU64::new(0)),
+            reaped_bytes: Arc::new(AtomicU64::new(0)),
+            inflight: Arc::new(DashMap::new()),
         }
     }
 
-    /// Get the current number of entries in cache.
-    pub async fn len(&self) -> usize {
-        self.store.len()
+    /// Create a size-bounded cache backend that evicts the least-recently-used
+    /// entry once `max_entries` would be exceeded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cache_kit::backend::InMemoryBackend;
+    ///
+    /// let backend = InMemoryBackend::with_capacity(1000);
+    /// ```
+    pub fn with_capacity(max_entries: usize) -> Self {
+        InMemoryBackend {
+            max_entries: Some(max_entries),
+            ..InMemoryBackend::new()
+        }
     }
 
-    /// Check if cache is empty.
-    pub async fn is_empty(&self) -> bool {
-        self.store.is_empty()
+    /// Switch the eviction strategy used once [`InMemoryBackend::with_capacity`]'s
+    /// `max_entries` would be exceeded. Defaults to `EvictionPolicy::Lru` if
+    /// never called.
+    ///
+    /// `EvictionPolicy::TinyLfu` allocates the same Window-TinyLFU
+    /// bookkeeping as [`InMemoryBackend::with_window_tiny_lfu_capacity`],
+    /// sized from whatever `max_entries` is already set - call this after
+    /// `with_capacity`, not before, or there's no capacity yet to size the
+    /// sketch from and the policy switch is a no-op.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cache_kit::backend::{InMemoryBackend, EvictionPolicy};
+    ///
+    /// let backend = InMemoryBackend::with_capacity(1000).with_eviction(EvictionPolicy::Lfu);
+    /// ```
+    pub fn with_eviction(mut self, policy: EvictionPolicy) -> Self {
+        self.policy = policy;
+        self.tiny_lfu = match policy {
+            EvictionPolicy::TinyLfu => self
+                .max_entries
+                .map(|max_entries| Arc::new(WindowTinyLfu::new(max_entries))),
+            EvictionPolicy::Lru | EvictionPolicy::Lfu | EvictionPolicy::Fifo => None,
+        };
+        self
     }
 
-    /// Get memory statistics.
-    pub async fn stats(&self) -> CacheStats {
-        let total_bytes: usize = self.store.iter().map(|entry| entry.data.len()).sum();
-        let expired_count = self.store.iter().filter(|entry| entry.is_expired()).count();
+    /// Create a capacity-bounded cache backend that admits and evicts
+    /// entries under a Window-TinyLFU policy instead of plain LRU.
+    ///
+    /// A small admission window (~1% of `max_entries`) takes every new key;
+    /// once it overflows, the window's LRU victim only displaces an entry in
+    /// the larger main region (split into probation/protected segments,
+    /// ~20%/80%) if a Count-Min Sketch estimates the candidate has been
+    /// accessed more often than the main region's own LRU victim. This
+    /// protects a hot working set from being flushed out by a scan of
+    /// one-shot keys, which plain LRU (see [`InMemoryBackend::with_capacity`])
+    /// is vulnerable to.
+    ///
+    /// This is a separate constructor rather than a change to
+    /// `with_capacity`'s own eviction behavior, since existing callers of
+    /// `with_capacity` depend on its deterministic recency-only eviction.
+    /// Equivalent to `with_capacity(max_entries).with_eviction(EvictionPolicy::TinyLfu)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cache_kit::backend::InMemoryBackend;
+    ///
+    /// let backend = InMemoryBackend::with_window_tiny_lfu_capacity(1000);
+    /// ```
+    pub fn with_window_tiny_lfu_capacity(max_entries: usize) -> Self {
+        InMemoryBackend {
+            max_entries: Some(max_entries),
+            tiny_lfu: Some(Arc::new(WindowTinyLfu::new(max_entries))),
+            ..InMemoryBackend::new()
+        }
+    }
 
-        CacheStats {
-            total_entries: self.store.len(),
-            expired_entries: expired_count,
-            total_bytes,
+    /// Create a byte-budgeted cache backend that evicts entries under
+    /// `policy` once the total size of cached values would exceed
+    /// `max_bytes`. Unlike [`InMemoryBackend::with_capacity`], which bounds
+    /// entry *count*, this bounds entry *size* - the more useful knob when
+    /// entries vary widely in size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cache_kit::backend::{InMemoryBackend, EvictionPolicy};
+    ///
+    /// let backend = InMemoryBackend::with_byte_capacity(1_000_000, EvictionPolicy::Lfu);
+    /// ```
+    pub fn with_byte_capacity(max_bytes: usize, policy: EvictionPolicy) -> Self {
+        InMemoryBackend {
+            max_bytes: Some(max_bytes),
+            policy,
+            ..InMemoryBackend::new()
         }
     }
 
-    /// Print cache statistics to debug log.
-    pub async fn log_stats(&self) {
-        let stats = self.stats().await;
-        debug!(
-            "Cache Stats: {} entries ({} expired), {} bytes",
-            stats.total_entries, stats.expired_entries, stats.total_bytes
-        );
+    /// Create a weight-bounded cache backend that evicts entries (LRU) once
+    /// the total weight of cached values would exceed `max_bytes`.
+    ///
+    /// Sugar for [`InMemoryBackend::with_byte_capacity`]`(max_bytes,
+    /// EvictionPolicy::Lru)` under the name services reaching for a RAM
+    /// ceiling (rather than an eviction-algorithm choice) are more likely to
+    /// look for. Weight defaults to raw value length; attach a
+    /// [`Weigher`] via [`InMemoryBackend::with_weigher`] to account for
+    /// metadata overhead or a different notion of size.
+    ///
+    /// A single value whose own weight exceeds `max_bytes` is rejected
+    /// outright - `set` becomes a no-op for it - rather than evicting every
+    /// other entry trying to make room for something that will never fit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cache_kit::backend::InMemoryBackend;
+    ///
+    /// let backend = InMemoryBackend::with_weight_limit(1_000_000);
+    /// ```
+    pub fn with_weight_limit(max_bytes: usize) -> Self {
+        InMemoryBackend::with_byte_capacity(max_bytes, EvictionPolicy::Lru)
     }
-}
 
-impl Default for InMemoryBackend {
-    fn default() -> Self {
-        Self::new()
+    /// Attach a custom [`Weigher`] for `max_bytes` accounting (see
+    /// [`InMemoryBackend::with_weight_limit`]/
+    /// [`InMemoryBackend::with_byte_capacity`]). Has no effect if neither is
+    /// in use.
+    pub fn with_weigher<W>(mut self, weigher: W) -> Self
+    where
+        W: Weigher + 'static,
+    {
+        self.weigher = Some(Arc::new(weigher));
+        self
     }
-}
 
-impl CacheBackend for InMemoryBackend {
-    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
-        // Check if entry exists and is not expired
-        if let Some(entry) = self.store.get(key) {
-            if !entry.is_expired() {
-                debug!("✓ InMemory GET {} -> HIT", key);
-                return Ok(Some(entry.data.clone()));
-            }
+    /// Weight to charge `value` (stored under `key`) against `max_bytes`,
+    /// via the attached [`Weigher`] if any, else raw byte length.
+    fn weight_of(&self, key: &str, value: &[u8]) -> usize {
+        weigh(&self.weigher, key, value)
+    }
+
+    /// Number of evictions performed by `max_entries` or `max_bytes`
+    /// pressure since construction.
+    pub fn eviction_count(&self) -> u64 {
+        self.eviction_count.load(Ordering::Relaxed)
+    }
+
+    /// Observe each key evicted by `max_entries`/`max_bytes` pressure (under
+    /// whichever eviction policy is active, including a lost Window-TinyLFU
+    /// admission contest), e.g. to log churn
+    /// or feed a metric - analogous to a `CacheFeed::on_miss` hook, but for
+    /// the backend rather than a single operation.
+    pub fn with_eviction_callback<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.eviction_callback = Some(Arc::new(callback));
+        self
+    }
+
+    /// Observe every entry that leaves the cache - by lazy expiry, the
+    /// background reaper, capacity/weight eviction, `set` replacing an old
+    /// value, or an explicit delete/clear - along with its value and a
+    /// [`RemovalCause`] explaining why.
+    ///
+    /// Use this to react to data leaving the cache: invalidate a downstream,
+    /// decrement a counter, write the value back to the backing store. The
+    /// listener runs after the removal is already committed and any
+    /// DashMap shard guard released, so it's safe for it to call back into
+    /// this same backend without deadlocking.
+    pub fn with_eviction_listener<F>(mut self, listener: F) -> Self
+    where
+        F: Fn(&str, &[u8], RemovalCause) + Send + Sync + 'static,
+    {
+        self.eviction_listener = Some(Arc::new(listener));
+        self
+    }
+
+    /// Report every capacity/weight/admission-contest eviction to `metrics`
+    /// via [`CacheMetrics::record_eviction`], so a backend configured with
+    /// [`InMemoryBackend::with_capacity`]/`with_eviction`/`with_byte_capacity`
+    /// can feed the same dashboard an expander or service already reports
+    /// hits and misses through, instead of wiring a separate
+    /// [`InMemoryBackend::with_eviction_callback`] by hand.
+    pub fn with_eviction_metrics(mut self, metrics: impl CacheMetrics + 'static) -> Self {
+        self.eviction_metrics = Some(Arc::new(metrics));
+        self
+    }
+
+    /// Start a background task that sweeps `store` for expired entries every
+    /// `interval`, rather than relying solely on lazy removal on `get`/`set`.
+    ///
+    /// Useful for keys that expire but are never read again - without a
+    /// reaper they'd sit in memory (and count against `max_bytes`) until
+    /// something happens to touch them. Reclaimed counts are surfaced via
+    /// [`InMemoryBackend::stats`]'s `reaped_entries`/`reaped_bytes`.
+    ///
+    /// Call [`InMemoryBackend::pause_reaper`] before a bulk scan or
+    /// range-hold that needs a stable view of `store`, and
+    /// [`InMemoryBackend::resume_reaper`] after. The task itself is stopped
+    /// automatically once the last clone of this backend is dropped.
+    ///
+    /// Attach [`InMemoryBackend::with_eviction_listener`] (and `with_weigher`,
+    /// if used) *before* this call - the spawned task captures a snapshot of
+    /// each at spawn time, so one attached afterward won't be seen by it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use cache_kit::backend::InMemoryBackend;
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let backend = InMemoryBackend::new().with_reaper(Duration::from_secs(60));
+    /// # let _ = backend;
+    /// # }
+    /// ```
+    pub fn with_reaper(self, interval: Duration) -> Self {
+        let handle = tokio::spawn(run_reaper(
+            Arc::clone(&self.store),
+            Arc::clone(&self.lru),
+            Arc::clone(&self.lfu),
+            self.tiny_lfu.clone(),
+            self.weigher.clone(),
+            self.eviction_listener.clone(),
+            Arc::clone(&self.total_bytes),
+            Arc::clone(&self.reaped_entries),
+            Arc::clone(&self.reaped_bytes),
+            Arc::clone(&self.stop_evictions),
+            Arc::clone(&self.stop_evictions_changes),
+            interval,
+        ));
+
+        InMemoryBackend {
+            reaper: Some(Arc::new(ReaperHandle(handle))),
+            ..self
         }
+    }
+
+    /// Pause the background reaper (if any) before a bulk scan or
+    /// range-hold that needs `store` to stay put, so the two don't race.
+    /// Has no effect on `max_entries`/`max_bytes` eviction, which still runs
+    /// on `set`. A no-op if [`InMemoryBackend::with_reaper`] was never
+    /// called.
+    pub fn pause_reaper(&self) {
+        self.stop_evictions.store(true, Ordering::Release);
+    }
+
+    /// Resume a reaper previously paused with
+    /// [`InMemoryBackend::pause_reaper`].
+    pub fn resume_reaper(&self) {
+        self.stop_evictions.store(false, Ordering::Release);
+    }
+
+    /// Number of reaper ticks observed since construction, whether or not
+    /// each one found anything expired or was itself paused. Compare a
+    /// reading from before and after a [`InMemoryBackend::pause_reaper`]-guarded
+    /// section to confirm no sweep raced it.
+    pub fn stop_evictions_changes(&self) -> u64 {
+        self.stop_evictions_changes.load(Ordering::Relaxed)
+    }
+
+    /// Stop the background reaper started by
+    /// [`InMemoryBackend::with_reaper`], if any - a no-op otherwise. The
+    /// reaper is also stopped automatically once the last clone of this
+    /// backend is dropped; call this to cancel it earlier.
+    pub fn stop_reaper(&self) {
+        if let Some(reaper) = &self.reaper {
+            reaper.0.abort();
+        }
+    }
+
+    /// Store a value with an explicit TTL.
+    ///
+    /// Equivalent to `set(key, value, Some(ttl))`, provided for readability
+    /// at call sites that always set a lifetime.
+    pub async fn set_with_ttl(&self, key: &str, value: Vec<u8>, ttl: Duration) -> Result<()> {
+        self.set(key, value, Some(ttl)).await
+    }
+
+    /// Create a backend pre-populated from a snapshot file written by
+    /// [`InMemoryBackend::save_snapshot`] - sugar for `InMemoryBackend::new()`
+    /// followed by [`InMemoryBackend::load_snapshot`], for the common case of
+    /// warm-starting a fresh backend from disk instead of loading into one
+    /// that might already hold entries.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use cache_kit::backend::InMemoryBackend;
+    ///
+    /// # fn example() -> cache_kit::Result<()> {
+    /// let backend = InMemoryBackend::with_persistence("cache.snapshot")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_persistence<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let backend = InMemoryBackend::new();
+        backend.load_snapshot(path)?;
+        Ok(backend)
+    }
+
+    /// Serialize every live (non-expired) entry to `path` as a single
+    /// snapshot file, for [`InMemoryBackend::load_snapshot`]/
+    /// [`InMemoryBackend::with_persistence`] to repopulate on the next boot.
+    ///
+    /// Meant to be called on shutdown (e.g. from an axum service's graceful
+    /// shutdown hook) so a restart warm-starts instead of sending every key
+    /// back to the repository at once.
+    pub fn save_snapshot<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let saved_at_unix_secs = now_unix_secs();
+
+        let entries: Vec<SnapshotEntry> = self
+            .store
+            .iter()
+            .filter(|item| !item.is_expired())
+            .map(|item| SnapshotEntry {
+                key: item.key().clone(),
+                data: item.data.clone(),
+                ttl_secs: item
+                    .expires_at
+                    .map(|exp| exp.saturating_duration_since(Instant::now()).as_secs()),
+                saved_at_unix_secs,
+            })
+            .collect();
+
+        debug!("✓ InMemory snapshot: saving {} entries to {}", entries.len(), path.as_ref().display());
+        let bytes = postcard::to_allocvec(&Snapshot { entries })
+            .map_err(|e| Error::SerializationError(e.to_string()))?;
+        std::fs::write(path, bytes)?;
+        Ok(())
+    }
+
+    /// Repopulate this backend from a snapshot file written by
+    /// [`InMemoryBackend::save_snapshot`]. A missing file is treated as
+    /// "nothing to load" rather than an error, since the first boot of a new
+    /// deployment has no snapshot yet.
+    ///
+    /// Each entry's remaining TTL is re-derived from its stored
+    /// `saved_at_unix_secs` (see [`SnapshotEntry`]); one whose TTL would
+    /// already have elapsed by now is dropped instead of loaded with a
+    /// zero/negative TTL. Entries already carry their own `CKIT` magic and
+    /// schema version (written by whatever `CacheEntity` produced them), so
+    /// an entry whose envelope fails that check - or whose schema version
+    /// isn't [`crate::serialization::CURRENT_SCHEMA_VERSION`] - is dropped
+    /// individually (and logged) the same way `CacheEntity::deserialize_from_cache`
+    /// would reject it, rather than aborting the whole load.
+    pub fn load_snapshot<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let bytes = match std::fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                debug!("✓ InMemory snapshot {} not found, starting empty", path.display());
+                return Ok(());
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let snapshot: Snapshot =
+            postcard::from_bytes(&bytes).map_err(|e| Error::DeserializationError(e.to_string()))?;
+
+        let now = now_unix_secs();
+        let mut loaded = 0usize;
+
+        for entry in snapshot.entries {
+            match crate::serialization::decode_version_and_payload(&entry.data) {
+                Ok((version, _)) if version != crate::serialization::CURRENT_SCHEMA_VERSION => {
+                    warn!(
+                        "⚠ InMemory snapshot entry {} dropped: schema version {} != current {}",
+                        entry.key,
+                        version,
+                        crate::serialization::CURRENT_SCHEMA_VERSION
+                    );
+                    continue;
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    warn!("⚠ InMemory snapshot entry {} dropped: {}", entry.key, e);
+                    continue;
+                }
+            }
+
+            let ttl = match entry.ttl_secs {
+                Some(ttl_secs) => {
+                    let elapsed = now.saturating_sub(entry.saved_at_unix_secs);
+                    if elapsed >= ttl_secs {
+                        debug!(
+                            "✓ InMemory snapshot entry {} dropped: TTL elapsed since snapshot",
+                            entry.key
+                        );
+                        continue;
+                    }
+                    Some(Duration::from_secs(ttl_secs - elapsed))
+                }
+                None => None,
+            };
+
+            let size = self.weight_of(&entry.key, &entry.data);
+            self.store.insert(entry.key.clone(), CacheEntry::new(entry.data, ttl));
+            self.total_bytes.fetch_add(size, Ordering::Relaxed);
+            self.touch(&entry.key);
+            loaded += 1;
+        }
+
+        debug!("✓ InMemory snapshot {} loaded: {} entries", path.display(), loaded);
+        Ok(())
+    }
+
+    /// Mark `key` as most-recently-used and bump its access frequency, so
+    /// either tracker is ready regardless of which eviction policy (if any)
+    /// is active.
+    fn touch(&self, key: &str) {
+        self.lru.lock().expect("LRU lock poisoned").touch(key);
+        self.lfu.lock().expect("LFU lock poisoned").touch(key);
+        self.fifo.lock().expect("FIFO lock poisoned").insert_if_absent(key);
+    }
+
+    /// Remove `key` from all trackers (used alongside store removal).
+    fn untrack(&self, key: &str) {
+        self.lru.lock().expect("LRU lock poisoned").remove(key);
+        self.lfu.lock().expect("LFU lock poisoned").remove(key);
+        self.fifo.lock().expect("FIFO lock poisoned").remove(key);
+    }
+
+    /// Keys currently stored under `"{prefix}:"`, shared by
+    /// `invalidate_prefix` and `scan_prefix` so they agree on what "under a
+    /// prefix" means.
+    fn matching_prefix_keys(&self, prefix: &str) -> Vec<String> {
+        let needle = format!("{}:", prefix);
+        self.store
+            .iter()
+            .map(|entry| entry.key().clone())
+            .filter(|key| key.starts_with(&needle))
+            .collect()
+    }
+
+    /// Remove `key` from the store and both trackers, returning its weight
+    /// if it was present so callers can keep `total_bytes` accurate. Fires
+    /// `eviction_listener` (if any) with `cause`, after the `DashMap` remove
+    /// has already returned - no shard guard is held at that point.
+    fn remove_tracked(&self, key: &str, cause: RemovalCause) -> Option<usize> {
+        let removed = self.store.remove(key);
+        self.untrack(key);
+        if let Some(tiny_lfu) = &self.tiny_lfu {
+            tiny_lfu.remove(key);
+        }
+
+        let (_, entry) = removed?;
+        let size = self.weight_of(key, &entry.data);
+        if let Some(listener) = &self.eviction_listener {
+            listener(key, &entry.data, cause);
+        }
+        self.total_bytes.fetch_sub(size, Ordering::Relaxed);
+        Some(size)
+    }
+
+    /// Evict entries under `policy` until the store is within `max_entries`.
+    fn evict_if_over_capacity(&self) {
+        let Some(max_entries) = self.max_entries else {
+            return;
+        };
+
+        while self.store.len() > max_entries {
+            // TinyLfu's admission/eviction runs through `tiny_lfu.access`
+            // instead (see `set`), so this loop never actually sees that
+            // policy; fall back to plain LRU so the match stays exhaustive.
+            let victim = match self.policy {
+                EvictionPolicy::Lru | EvictionPolicy::TinyLfu => {
+                    self.lru.lock().expect("LRU lock poisoned").pop_lru()
+                }
+                EvictionPolicy::Lfu => self.lfu.lock().expect("LFU lock poisoned").pop_lfu(),
+                EvictionPolicy::Fifo => self.fifo.lock().expect("FIFO lock poisoned").pop_lru(),
+            };
+
+            match victim {
+                Some(key) => {
+                    if let Some((_, entry)) = self.store.remove(&key) {
+                        let size = self.weight_of(&key, &entry.data);
+                        self.total_bytes.fetch_sub(size, Ordering::Relaxed);
+                        if let Some(listener) = &self.eviction_listener {
+                            listener(&key, &entry.data, RemovalCause::Size);
+                        }
+                    }
+                    match self.policy {
+                        EvictionPolicy::Lru | EvictionPolicy::TinyLfu => {
+                            self.lfu.lock().expect("LFU lock poisoned").remove(&key);
+                        }
+                        EvictionPolicy::Lfu => {
+                            self.lru.lock().expect("LRU lock poisoned").remove(&key);
+                        }
+                        EvictionPolicy::Fifo => {
+                            self.lru.lock().expect("LRU lock poisoned").remove(&key);
+                            self.lfu.lock().expect("LFU lock poisoned").remove(&key);
+                        }
+                    }
+                    self.fifo.lock().expect("FIFO lock poisoned").remove(&key);
+                    self.eviction_count.fetch_add(1, Ordering::Relaxed);
+                    if let Some(callback) = &self.eviction_callback {
+                        callback(&key);
+                    }
+                    if let Some(metrics) = &self.eviction_metrics {
+                        metrics.record_eviction(&key);
+                    }
+                    debug!(
+                        "✓ InMemory {:?} EVICT {} (capacity: {})",
+                        self.policy, key, max_entries
+                    );
+                }
+                None => break,
+            }
+        }
+    }
+
+    /// Evict entries under `policy` until tracked value bytes are within
+    /// `max_bytes`.
+    fn evict_if_over_byte_budget(&self) {
+        let Some(max_bytes) = self.max_bytes else {
+            return;
+        };
+
+        while self.total_bytes.load(Ordering::Relaxed) > max_bytes {
+            // TinyLfu falls back to Lru here: the sketch estimates access
+            // frequency per key, not per byte, so it has nothing useful to
+            // say about which entry to drop to meet a weight budget.
+            let victim = match self.policy {
+                EvictionPolicy::Lru | EvictionPolicy::TinyLfu => {
+                    self.lru.lock().expect("LRU lock poisoned").pop_lru()
+                }
+                EvictionPolicy::Lfu => self.lfu.lock().expect("LFU lock poisoned").pop_lfu(),
+                EvictionPolicy::Fifo => self.fifo.lock().expect("FIFO lock poisoned").pop_lru(),
+            };
+
+            match victim {
+                Some(key) => {
+                    if let Some((_, entry)) = self.store.remove(&key) {
+                        let size = self.weight_of(&key, &entry.data);
+                        self.total_bytes.fetch_sub(size, Ordering::Relaxed);
+                        if let Some(listener) = &self.eviction_listener {
+                            listener(&key, &entry.data, RemovalCause::Size);
+                        }
+                    }
+                    match self.policy {
+                        EvictionPolicy::Lru | EvictionPolicy::TinyLfu => {
+                            self.lfu.lock().expect("LFU lock poisoned").remove(&key);
+                        }
+                        EvictionPolicy::Lfu => {
+                            self.lru.lock().expect("LRU lock poisoned").remove(&key);
+                        }
+                        EvictionPolicy::Fifo => {
+                            self.lru.lock().expect("LRU lock poisoned").remove(&key);
+                            self.lfu.lock().expect("LFU lock poisoned").remove(&key);
+                        }
+                    }
+                    self.fifo.lock().expect("FIFO lock poisoned").remove(&key);
+                    self.eviction_count.fetch_add(1, Ordering::Relaxed);
+                    if let Some(callback) = &self.eviction_callback {
+                        callback(&key);
+                    }
+                    if let Some(metrics) = &self.eviction_metrics {
+                        metrics.record_eviction(&key);
+                    }
+                    debug!(
+                        "✓ InMemory {:?} EVICT {} (byte budget: {})",
+                        self.policy, key, max_bytes
+                    );
+                }
+                None => break,
+            }
+        }
+    }
+
+    /// Get the current number of entries in cache.
+    pub async fn len(&self) -> usize {
+        self.store.len()
+    }
+
+    /// Check if cache is empty.
+    pub async fn is_empty(&self) -> bool {
+        self.store.is_empty()
+    }
+
+    /// Get memory statistics.
+    ///
+    /// `total_bytes` reads the running weight total tracked on every
+    /// `set`/removal - O(1), not a scan of the whole map.
+    pub async fn stats(&self) -> CacheStats {
+        let expired_count = self.store.iter().filter(|entry| entry.is_expired()).count();
+
+        CacheStats {
+            total_entries: self.store.len(),
+            expired_entries: expired_count,
+            total_bytes: self.total_bytes.load(Ordering::Relaxed),
+            evictions: self.eviction_count.load(Ordering::Relaxed),
+            reaped_entries: self.reaped_entries.load(Ordering::Relaxed),
+            reaped_bytes: self.reaped_bytes.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Print cache statistics to debug log.
+    pub async fn log_stats(&self) {
+        let stats = self.stats().await;
+        debug!(
+            "Cache Stats: {} entries ({} expired), {} bytes",
+            stats.total_entries, stats.expired_entries, stats.total_bytes
+        );
+    }
+
+    /// Shared implementation backing [`CacheBackend::incr`]/[`CacheBackend::decr`].
+    ///
+    /// Uses `DashMap::entry`'s `and_modify`/`or_insert_with` so the whole
+    /// read-decide-write sequence runs under one shard lock - two racing
+    /// callers can't both observe a miss and both "win" the initialization,
+    /// the same guarantee `MemcachedBackend::counter_op` gets from `ADD`.
+    fn counter_op(
+        &self,
+        key: &str,
+        delta: u64,
+        init: u64,
+        ttl: Option<Duration>,
+        is_decrement: bool,
+    ) -> Result<u64> {
+        let mut outcome: Result<u64> = Ok(init);
+        let mut old_size = 0usize;
+        let mut new_size = 0usize;
+        let mut inserted = false;
+
+        self.store
+            .entry(key.to_string())
+            .and_modify(|entry| {
+                old_size = entry.data.len();
+                if entry.is_expired() {
+                    *entry = CacheEntry::new(init.to_string().into_bytes(), ttl);
+                    new_size = entry.data.len();
+                    return;
+                }
+                match parse_counter(&entry.data) {
+                    Some(current) => {
+                        let updated = if is_decrement {
+                            current.saturating_sub(delta)
+                        } else {
+                            current.saturating_add(delta)
+                        };
+                        *entry = CacheEntry::new(updated.to_string().into_bytes(), ttl);
+                        new_size = entry.data.len();
+                        outcome = Ok(updated);
+                    }
+                    None => {
+                        new_size = old_size;
+                        outcome = Err(Error::InvalidCacheEntry(format!(
+                            "value at {} is not a valid counter",
+                            key
+                        )));
+                    }
+                }
+            })
+            .or_insert_with(|| {
+                inserted = true;
+                new_size = init.to_string().len();
+                CacheEntry::new(init.to_string().into_bytes(), ttl)
+            });
+
+        let value = outcome?;
+
+        if inserted {
+            self.total_bytes.fetch_add(new_size, Ordering::Relaxed);
+            self.touch(key);
+        } else if new_size != old_size {
+            if new_size > old_size {
+                self.total_bytes.fetch_add(new_size - old_size, Ordering::Relaxed);
+            } else {
+                self.total_bytes.fetch_sub(old_size - new_size, Ordering::Relaxed);
+            }
+            self.touch(key);
+        }
+
+        debug!(
+            "✓ InMemory {} {} by {} -> {}",
+            if is_decrement { "DECR" } else { "INCR" },
+            key,
+            delta,
+            value
+        );
+        Ok(value)
+    }
+}
+
+impl Default for InMemoryBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CacheBackend for InMemoryBackend {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        // Check if entry exists and is not expired
+        if let Some(entry) = self.store.get(key) {
+            if !entry.is_expired() {
+                debug!("✓ InMemory GET {} -> HIT", key);
+                let data = entry.data.clone();
+                drop(entry);
+                if let Some(tiny_lfu) = &self.tiny_lfu {
+                    tiny_lfu.access(key, false);
+                } else {
+                    self.touch(key);
+                }
+                return Ok(Some(data));
+            }
+        }
+
+        // Remove expired entry if it exists
+        self.remove_tracked(key, RemovalCause::Expired);
+        debug!("✓ InMemory GET {} -> MISS", key);
+        Ok(None)
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<()> {
+        let new_size = self.weight_of(key, &value);
+
+        if let Some(max_bytes) = self.max_bytes {
+            if new_size > max_bytes {
+                warn!(
+                    "⚠ InMemory SET {} bypassed cache: weight {} exceeds budget {}",
+                    key, new_size, max_bytes
+                );
+                return Ok(());
+            }
+        }
+
+        let entry = CacheEntry::new(value, ttl);
+        let old = self.store.insert(key.to_string(), entry);
+        let old_size = old.as_ref().map(|old| self.weight_of(key, &old.data));
+
+        self.total_bytes.fetch_add(new_size, Ordering::Relaxed);
+        if let Some(old_size) = old_size {
+            self.total_bytes.fetch_sub(old_size, Ordering::Relaxed);
+        }
+        if let Some(old) = &old {
+            if let Some(listener) = &self.eviction_listener {
+                listener(key, &old.data, RemovalCause::Replaced);
+            }
+        }
+
+        if let Some(tiny_lfu) = &self.tiny_lfu {
+            let is_new = old_size.is_none();
+            if let Some(evicted) = tiny_lfu.access(key, is_new) {
+                if let Some((_, entry)) = self.store.remove(&evicted) {
+                    let size = self.weight_of(&evicted, &entry.data);
+                    self.total_bytes.fetch_sub(size, Ordering::Relaxed);
+                    if let Some(listener) = &self.eviction_listener {
+                        listener(&evicted, &entry.data, RemovalCause::Size);
+                    }
+                }
+                self.eviction_count.fetch_add(1, Ordering::Relaxed);
+                if let Some(callback) = &self.eviction_callback {
+                    callback(&evicted);
+                }
+                if let Some(metrics) = &self.eviction_metrics {
+                    metrics.record_eviction(&evicted);
+                }
+                debug!("✓ InMemory WindowTinyLFU EVICT {} (lost admission contest)", evicted);
+            }
+        } else {
+            self.touch(key);
+            self.evict_if_over_capacity();
+            self.evict_if_over_byte_budget();
+        }
+
+        if let Some(d) = ttl {
+            debug!("✓ InMemory SET {} (TTL: {:?})", key, d);
+        } else {
+            debug!("✓ InMemory SET {}", key);
+        }
+
+        Ok(())
+    }
+
+    /// Override of the default [`CacheBackend::set_stream`]: reject a stream
+    /// whose declared [`crate::streaming::CacheData::size_hint`] already
+    /// exceeds `max_bytes` before buffering it, instead of buffering the
+    /// whole thing (the expensive part `set_stream` exists to avoid) only to
+    /// have `set()` bypass it afterward. A stream with no size hint falls
+    /// through to the default behavior, since there's nothing to check yet.
+    async fn set_stream(
+        &self,
+        key: &str,
+        data: crate::streaming::CacheData,
+        ttl: Option<Duration>,
+    ) -> Result<()> {
+        if let (Some(max_bytes), Some(hint)) = (self.max_bytes, data.size_hint()) {
+            if hint > max_bytes as u64 {
+                warn!(
+                    "⚠ InMemory SET_STREAM {} bypassed cache: declared size {} exceeds budget {}",
+                    key, hint, max_bytes
+                );
+                return Ok(());
+            }
+        }
+
+        let bytes = data.into_bytes().await?;
+        self.set(key, bytes, ttl).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.remove_tracked(key, RemovalCause::Explicit);
+        debug!("✓ InMemory DELETE {}", key);
+        Ok(())
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool> {
+        if let Some(entry) = self.store.get(key) {
+            return Ok(!entry.is_expired());
+        }
+
+        Ok(false)
+    }
+
+    async fn ttl(&self, key: &str) -> Result<Option<Duration>> {
+        let Some(entry) = self.store.get(key) else {
+            return Ok(None);
+        };
+        if entry.is_expired() {
+            return Ok(None);
+        }
+        Ok(entry
+            .expires_at
+            .map(|exp| exp.saturating_duration_since(Instant::now())))
+    }
+
+    async fn mget(&self, keys: &[&str]) -> Result<Vec<Option<Vec<u8>>>> {
+        let results: Vec<Option<Vec<u8>>> = keys
+            .iter()
+            .map(|k| {
+                if let Some(entry) = self.store.get(*k) {
+                    if entry.is_expired() {
+                        None
+                    } else {
+                        Some(entry.data.clone())
+                    }
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        debug!("✓ InMemory MGET {} keys", keys.len());
+        Ok(results)
+    }
+
+    async fn mdelete(&self, keys: &[&str]) -> Result<()> {
+        for key in keys {
+            self.remove_tracked(key, RemovalCause::Explicit);
+        }
+
+        debug!("✓ InMemory MDELETE {} keys", keys.len());
+        Ok(())
+    }
+
+    /// Single-flight override of [`CacheBackend::get_with`]'s default: a
+    /// per-key lock (`inflight`) serializes concurrent misses for the same
+    /// key instead of letting every racing caller run `init`, mirroring
+    /// `CacheExpander::singleflight_fetch` and `CacheService::get_or_load`'s
+    /// own per-key-mutex coalescing.
+    async fn get_with<F, Fut>(&self, key: &str, init: F, ttl: Option<Duration>) -> Result<Vec<u8>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<Vec<u8>>>,
+    {
+        if let Some(value) = self.get(key).await? {
+            return Ok(value);
+        }
+
+        let lock = self
+            .inflight
+            .entry(key.to_string())
+            .or_insert_with(|| Arc::new(AsyncMutex::new(())))
+            .clone();
+        let _guard = lock.lock().await;
+
+        // Another caller may have populated the cache while we waited.
+        // A count of 2 is the map's own handle plus this caller's `lock`,
+        // i.e. no other caller is waiting on the slot.
+        if let Some(value) = self.get(key).await? {
+            self.inflight.remove_if(key, |_, l| Arc::strong_count(l) == 2);
+            return Ok(value);
+        }
+
+        let result = init().await;
+        self.inflight.remove_if(key, |_, l| Arc::strong_count(l) == 2);
+
+        let value = result?;
+        self.set(key, value.clone(), ttl).await?;
+        Ok(value)
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        // In-memory backend is always healthy
+        Ok(true)
+    }
+
+    async fn clear_all(&self) -> Result<()> {
+        if let Some(listener) = &self.eviction_listener {
+            for entry in self.store.iter() {
+                listener(entry.key(), &entry.data, RemovalCause::Explicit);
+            }
+        }
+        self.store.clear();
+        self.lru.lock().expect("LRU lock poisoned").clear();
+        self.lfu.lock().expect("LFU lock poisoned").clear();
+        self.fifo.lock().expect("FIFO lock poisoned").clear();
+        if let Some(tiny_lfu) = &self.tiny_lfu {
+            tiny_lfu.clear();
+        }
+        self.total_bytes.store(0, Ordering::Relaxed);
+        self.tags.clear();
+        warn!("⚠ InMemory CLEAR_ALL executed - all cache cleared!");
+        Ok(())
+    }
+
+    async fn invalidate_prefix(&self, prefix: &str) -> Result<()> {
+        let matching = self.matching_prefix_keys(prefix);
+
+        for key in &matching {
+            self.remove_tracked(key, RemovalCause::Explicit);
+        }
+
+        debug!(
+            "✓ InMemory INVALIDATE_PREFIX {} ({} keys)",
+            prefix,
+            matching.len()
+        );
+        Ok(())
+    }
+
+    async fn scan_prefix(&self, prefix: &str) -> Result<Vec<String>> {
+        Ok(self.matching_prefix_keys(prefix))
+    }
+
+    async fn set_with_tags(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        ttl: Option<Duration>,
+        tags: &[&str],
+    ) -> Result<()> {
+        self.set(key, value, ttl).await?;
+
+        // `set` bypasses the cache (a no-op) for a value whose weight alone
+        // exceeds `max_bytes` - don't tag a key that was never actually
+        // stored.
+        if self.store.contains_key(key) {
+            for tag in tags {
+                self.tags
+                    .entry(tag.to_string())
+                    .or_default()
+                    .insert(key.to_string());
+            }
+        }
+
+        debug!("✓ InMemory SET {} (tags: {:?})", key, tags);
+        Ok(())
+    }
+
+    async fn invalidate_tag(&self, tag: &str) -> Result<()> {
+        if let Some((_, keys)) = self.tags.remove(tag) {
+            for key in keys.iter() {
+                self.remove_tracked(key.as_str(), RemovalCause::Explicit);
+            }
+            debug!("✓ InMemory INVALIDATE_TAG {} ({} keys)", tag, keys.len());
+        }
+        Ok(())
+    }
+
+    async fn incr(&self, key: &str, delta: u64, init: u64, ttl: Option<Duration>) -> Result<u64> {
+        self.counter_op(key, delta, init, ttl, false)
+    }
+
+    async fn decr(&self, key: &str, delta: u64, init: u64, ttl: Option<Duration>) -> Result<u64> {
+        self.counter_op(key, delta, init, ttl, true)
+    }
+}
+
+/// Parse a counter's stored bytes as a plain ASCII decimal integer, mirroring
+/// how memcached itself stores `INCR`/`DECR` counters as text.
+fn parse_counter(bytes: &[u8]) -> Option<u64> {
+    std::str::from_utf8(bytes).ok()?.parse().ok()
+}
+
+/// Cache statistics.
+#[derive(Clone, Debug)]
+pub struct CacheStats {
+    pub total_entries: usize,
+    pub expired_entries: usize,
+    pub total_bytes: usize,
+    /// Entries evicted by `max_entries`/`max_bytes`/Window-TinyLFU admission
+    /// pressure since construction (same count as
+    /// [`InMemoryBackend::eviction_count`]).
+    pub evictions: u64,
+    /// Entries removed by the background reaper (see
+    /// [`InMemoryBackend::with_reaper`]) since construction. Always `0` if no
+    /// reaper is attached.
+    pub reaped_entries: u64,
+    /// Weight reclaimed by the background reaper since construction. Always
+    /// `0` if no reaper is attached.
+    pub reaped_bytes: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_inmemory_backend_set_get() {
+        let backend = InMemoryBackend::new();
+
+        backend
+            .set("key1", b"value1".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        let result = backend.get("key1").await.expect("Failed to get");
+        assert_eq!(result, Some(b"value1".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_miss() {
+        let backend = InMemoryBackend::new();
+
+        let result = backend.get("nonexistent").await.expect("Failed to get");
+        assert_eq!(result, None);
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_delete() {
+        let backend = InMemoryBackend::new();
+
+        backend
+            .set("key1", b"value1".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        assert!(backend
+            .exists("key1")
+            .await
+            .expect("Failed to check exists"));
+
+        backend.delete("key1").await.expect("Failed to delete");
+        assert!(!backend
+            .exists("key1")
+            .await
+            .expect("Failed to check exists"));
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_ttl_expiration() {
+        let backend = InMemoryBackend::new();
+
+        backend
+            .set("key1", b"value1".to_vec(), Some(Duration::from_millis(100)))
+            .await
+            .expect("Failed to set");
+
+        // Should be present immediately
+        assert!(backend.get("key1").await.expect("Failed to get").is_some());
+
+        // Wait for expiration
+        tokio::time::sleep(Duration::from_millis(150)).await;
+
+        // Should be expired now
+        assert!(backend.get("key1").await.expect("Failed to get").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_mget() {
+        let backend = InMemoryBackend::new();
+
+        backend
+            .set("key1", b"value1".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        backend
+            .set("key2", b"value2".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        let results = backend
+            .mget(&["key1", "key2", "key3"])
+            .await
+            .expect("Failed to mget");
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0], Some(b"value1".to_vec()));
+        assert_eq!(results[1], Some(b"value2".to_vec()));
+        assert_eq!(results[2], None);
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_mdelete() {
+        let backend = InMemoryBackend::new();
+
+        backend
+            .set("key1", b"value1".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        backend
+            .set("key2", b"value2".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        backend
+            .set("key3", b"value3".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        assert_eq!(backend.len().await, 3);
+
+        backend
+            .mdelete(&["key1", "key2"])
+            .await
+            .expect("Failed to mdelete");
+
+        assert_eq!(backend.len().await, 1);
+        assert!(backend.get("key3").await.expect("Failed to get").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_clear_all() {
+        let backend = InMemoryBackend::new();
+
+        backend
+            .set("key1", b"value1".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        backend
+            .set("key2", b"value2".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        assert_eq!(backend.len().await, 2);
+
+        backend.clear_all().await.expect("Failed to clear");
+
+        assert_eq!(backend.len().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_stats() {
+        let backend = InMemoryBackend::new();
+
+        backend
+            .set("key1", b"value_with_data".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        backend
+            .set("key2", b"data".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        let stats = backend.stats().await;
+        assert_eq!(stats.total_entries, 2);
+        assert_eq!(stats.expired_entries, 0);
+        assert!(stats.total_bytes > 0);
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_clone() {
+        let backend1 = InMemoryBackend::new();
+        backend1
+            .set("key", b"value".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        let backend2 = backend1.clone();
+
+        // Both backends share the same store
+        let value = backend2.store.get("key").map(|e| e.data.clone());
+        assert_eq!(value, Some(b"value".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_with_capacity_evicts_lru() {
+        let backend = InMemoryBackend::with_capacity(2);
+
+        backend
+            .set("key1", b"value1".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        backend
+            .set("key2", b"value2".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        backend
+            .set("key3", b"value3".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        // key1 was least-recently-used and should be evicted
+        assert_eq!(backend.get("key1").await.expect("Failed to get"), None);
+        assert!(backend.get("key2").await.expect("Failed to get").is_some());
+        assert!(backend.get("key3").await.expect("Failed to get").is_some());
+        assert_eq!(backend.len().await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_with_capacity_get_refreshes_recency() {
+        let backend = InMemoryBackend::with_capacity(2);
+
+        backend
+            .set("key1", b"value1".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        backend
+            .set("key2", b"value2".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        // Touch key1 so key2 becomes the LRU entry
+        backend.get("key1").await.expect("Failed to get");
+
+        backend
+            .set("key3", b"value3".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        assert!(backend.get("key1").await.expect("Failed to get").is_some());
+        assert_eq!(backend.get("key2").await.expect("Failed to get"), None);
+        assert!(backend.get("key3").await.expect("Failed to get").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_eviction_callback_observes_evicted_key() {
+        use std::sync::Mutex;
+
+        let evicted = Arc::new(Mutex::new(Vec::new()));
+        let evicted_clone = evicted.clone();
+        let backend = InMemoryBackend::with_capacity(1)
+            .with_eviction_callback(move |key| evicted_clone.lock().expect("lock poisoned").push(key.to_string()));
+
+        backend
+            .set("key1", b"value1".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        backend
+            .set("key2", b"value2".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        assert_eq!(*evicted.lock().expect("lock poisoned"), vec!["key1"]);
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_with_eviction_lfu_switches_entry_capacity_policy() {
+        let backend = InMemoryBackend::with_capacity(2).with_eviction(EvictionPolicy::Lfu);
+
+        backend.set("key1", b"value1".to_vec(), None).await.expect("Failed to set");
+        backend.set("key2", b"value2".to_vec(), None).await.expect("Failed to set");
+
+        // Access key1 repeatedly so key2 is the least-frequently-used entry,
+        // even though key1 is also the least-recently-touched right now -
+        // plain LRU would evict key1 here, LFU must evict key2 instead.
+        backend.get("key1").await.expect("Failed to get");
+        backend.get("key1").await.expect("Failed to get");
+
+        backend.set("key3", b"value3".to_vec(), None).await.expect("Failed to set");
+
+        assert!(backend.get("key1").await.expect("Failed to get").is_some());
+        assert_eq!(backend.get("key2").await.expect("Failed to get"), None);
+        assert!(backend.get("key3").await.expect("Failed to get").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_with_eviction_fifo_ignores_reads() {
+        let backend = InMemoryBackend::with_capacity(2).with_eviction(EvictionPolicy::Fifo);
+
+        backend.set("key1", b"value1".to_vec(), None).await.expect("Failed to set");
+        backend.set("key2", b"value2".to_vec(), None).await.expect("Failed to set");
+
+        // Repeatedly reading key1 would save it from plain LRU eviction, but
+        // FIFO only cares about insertion order - key1 was inserted first,
+        // so it's still the one evicted.
+        backend.get("key1").await.expect("Failed to get");
+        backend.get("key1").await.expect("Failed to get");
+
+        backend.set("key3", b"value3".to_vec(), None).await.expect("Failed to set");
+
+        assert_eq!(backend.get("key1").await.expect("Failed to get"), None);
+        assert!(backend.get("key2").await.expect("Failed to get").is_some());
+        assert!(backend.get("key3").await.expect("Failed to get").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_with_eviction_tiny_lfu_protects_hot_key() {
+        // Same scenario as the window_tiny_lfu_protects_hot_key_from_one_shot_churn
+        // test below, built via the with_capacity/with_eviction builder path
+        // instead of the dedicated with_window_tiny_lfu_capacity constructor.
+        let backend = InMemoryBackend::with_capacity(3).with_eviction(EvictionPolicy::TinyLfu);
+
+        backend.set("hot", b"v".to_vec(), None).await.expect("Failed to set");
+        for _ in 0..10 {
+            backend.get("hot").await.expect("Failed to get");
+        }
+
+        for i in 0..20 {
+            backend
+                .set(&format!("churn{i}"), b"v".to_vec(), None)
+                .await
+                .expect("Failed to set");
+        }
+
+        assert!(backend.get("hot").await.expect("Failed to get").is_some());
+        assert!(backend.eviction_count() > 0);
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_with_eviction_metrics_records_evictions() {
+        let metrics = Arc::new(crate::observability::AtomicMetrics::new());
+        let backend = InMemoryBackend::with_capacity(1).with_eviction_metrics(metrics.clone());
+
+        backend.set("key1", b"value1".to_vec(), None).await.expect("Failed to set");
+        backend.set("key2", b"value2".to_vec(), None).await.expect("Failed to set");
+
+        assert_eq!(metrics.snapshot().evictions, 1);
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_with_capacity_repeated_churn_stays_bounded() {
+        // Regression guard for the slab-based LruList: repeatedly inserting
+        // past capacity should keep reusing freed slots, not just work once.
+        let backend = InMemoryBackend::with_capacity(3);
+
+        for i in 0..50 {
+            backend
+                .set(&format!("key{}", i), vec![i as u8], None)
+                .await
+                .expect("Failed to set");
+        }
+
+        assert_eq!(backend.len().await, 3);
+        assert!(backend.get("key49").await.expect("Failed to get").is_some());
+        assert!(backend.get("key0").await.expect("Failed to get").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_set_with_ttl() {
+        let backend = InMemoryBackend::new();
+
+        backend
+            .set_with_ttl("key1", b"value1".to_vec(), Duration::from_millis(50))
+            .await
+            .expect("Failed to set");
+
+        assert!(backend.get("key1").await.expect("Failed to get").is_some());
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert_eq!(backend.get("key1").await.expect("Failed to get"), None);
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_round_trip_preserves_entries() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("cache-kit-snapshot-{}.bin", std::process::id()));
+
+        let backend = InMemoryBackend::new();
+        backend
+            .set("key1", b"value1".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        backend
+            .set("key2", b"value2".to_vec(), Some(Duration::from_secs(300)))
+            .await
+            .expect("Failed to set");
+
+        backend.save_snapshot(&path).expect("Failed to save snapshot");
+
+        let restored = InMemoryBackend::with_persistence(&path).expect("Failed to load snapshot");
+        assert_eq!(
+            restored.get("key1").await.expect("Failed to get"),
+            Some(b"value1".to_vec())
+        );
+        assert_eq!(
+            restored.get("key2").await.expect("Failed to get"),
+            Some(b"value2".to_vec())
+        );
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_skips_entries_whose_ttl_elapsed_before_load() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("cache-kit-snapshot-ttl-{}.bin", std::process::id()));
+
+        let snapshot = Snapshot {
+            entries: vec![SnapshotEntry {
+                key: "stale".to_string(),
+                data: b"value".to_vec(),
+                ttl_secs: Some(10),
+                // Saved far enough in the past that the TTL has long elapsed.
+                saved_at_unix_secs: now_unix_secs().saturating_sub(3600),
+            }],
+        };
+        let bytes = postcard::to_allocvec(&snapshot).unwrap();
+        std::fs::write(&path, bytes).unwrap();
+
+        let backend = InMemoryBackend::with_persistence(&path).expect("Failed to load snapshot");
+        assert_eq!(backend.len().await, 0);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_drops_entry_with_invalid_magic() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("cache-kit-snapshot-bad-{}.bin", std::process::id()));
+
+        let snapshot = Snapshot {
+            entries: vec![SnapshotEntry {
+                key: "corrupt".to_string(),
+                data: b"not an envelope".to_vec(),
+                ttl_secs: None,
+                saved_at_unix_secs: now_unix_secs(),
+            }],
+        };
+        let bytes = postcard::to_allocvec(&snapshot).unwrap();
+        std::fs::write(&path, bytes).unwrap();
+
+        let backend = InMemoryBackend::with_persistence(&path).expect("Failed to load snapshot");
+        assert_eq!(backend.len().await, 0);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_load_snapshot_missing_file_starts_empty() {
+        let path = std::env::temp_dir().join("cache-kit-snapshot-does-not-exist.bin");
+        let _ = std::fs::remove_file(&path);
+
+        let backend = InMemoryBackend::with_persistence(&path).expect("Failed to load snapshot");
+        assert_eq!(backend.len().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_invalidate_prefix() {
+        let backend = InMemoryBackend::new();
+
+        backend
+            .set("product:1", b"a".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        backend
+            .set("product:2", b"b".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        backend
+            .set("order:1", b"c".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        backend
+            .invalidate_prefix("product")
+            .await
+            .expect("Failed to invalidate prefix");
+
+        assert_eq!(backend.get("product:1").await.expect("Failed to get"), None);
+        assert_eq!(backend.get("product:2").await.expect("Failed to get"), None);
+        assert!(backend.get("order:1").await.expect("Failed to get").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_scan_prefix_lists_without_deleting() {
+        let backend = InMemoryBackend::new();
+
+        backend.set("product:1", b"a".to_vec(), None).await.expect("Failed to set");
+        backend.set("product:2", b"b".to_vec(), None).await.expect("Failed to set");
+        backend.set("order:1", b"c".to_vec(), None).await.expect("Failed to set");
+
+        let mut keys = backend.scan_prefix("product").await.expect("Failed to scan prefix");
+        keys.sort();
+        assert_eq!(keys, vec!["product:1".to_string(), "product:2".to_string()]);
+        assert!(backend.get("product:1").await.expect("Failed to get").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_delete_prefix_returns_count_removed() {
+        let backend = InMemoryBackend::new();
+
+        backend.set("product:1", b"a".to_vec(), None).await.expect("Failed to set");
+        backend.set("product:2", b"b".to_vec(), None).await.expect("Failed to set");
+        backend.set("order:1", b"c".to_vec(), None).await.expect("Failed to set");
+
+        let deleted = backend.delete_prefix("product").await.expect("Failed to delete prefix");
+        assert_eq!(deleted, 2);
+        assert_eq!(backend.get("product:1").await.expect("Failed to get"), None);
+        assert!(backend.get("order:1").await.expect("Failed to get").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_invalidate_tag() {
+        let backend = InMemoryBackend::new();
+
+        backend
+            .set_with_tags("invoice:1", b"a".to_vec(), None, &["customer:42"])
+            .await
+            .expect("Failed to set");
+        backend
+            .set_with_tags("invoice:2", b"b".to_vec(), None, &["customer:42"])
+            .await
+            .expect("Failed to set");
+        backend
+            .set_with_tags("invoice:3", b"c".to_vec(), None, &["customer:99"])
+            .await
+            .expect("Failed to set");
+
+        backend
+            .invalidate_tag("customer:42")
+            .await
+            .expect("Failed to invalidate tag");
+
+        assert_eq!(backend.get("invoice:1").await.expect("Failed to get"), None);
+        assert_eq!(backend.get("invoice:2").await.expect("Failed to get"), None);
+        assert!(backend.get("invoice:3").await.expect("Failed to get").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_thread_safe() {
+        use std::sync::Arc;
+
+        let backend = Arc::new(InMemoryBackend::new());
+        let mut handles = vec![];
+
+        for i in 0..10 {
+            let backend_clone = Arc::clone(&backend);
+            let handle = tokio::spawn(async move {
+                let b = (*backend_clone).clone();
+                let key = format!("key_{}", i);
+                let value = format!("value_{}", i);
+                b.set(&key, value.into_bytes(), None)
+                    .await
+                    .expect("Failed to set");
+            });
+            handles.push(handle);
+        }
+
+        for handle in handles {
+            handle.await.expect("Task failed");
+        }
+
+        assert!(backend.clone().len().await >= 10);
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_with_byte_capacity_lru_evicts_oldest() {
+        let backend = InMemoryBackend::with_byte_capacity(15, EvictionPolicy::Lru);
+
+        backend.set("key1", vec![0u8; 10], None).await.expect("Failed to set");
+        backend.set("key2", vec![0u8; 10], None).await.expect("Failed to set");
+
+        assert_eq!(backend.get("key1").await.expect("Failed to get"), None);
+        assert!(backend.get("key2").await.expect("Failed to get").is_some());
+        assert_eq!(backend.eviction_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_with_byte_capacity_lfu_evicts_least_frequent() {
+        let backend = InMemoryBackend::with_byte_capacity(25, EvictionPolicy::Lfu);
+
+        backend.set("key1", vec![0u8; 10], None).await.expect("Failed to set");
+        backend.set("key2", vec![0u8; 10], None).await.expect("Failed to set");
+
+        // Access key1 repeatedly so key2 is the least-frequently-used entry.
+        backend.get("key1").await.expect("Failed to get");
+        backend.get("key1").await.expect("Failed to get");
 
-        // Remove expired entry if it exists
-        self.store.remove(key);
-        debug!("✓ InMemory GET {} -> MISS", key);
-        Ok(None)
+        backend.set("key3", vec![0u8; 10], None).await.expect("Failed to set");
+
+        assert!(backend.get("key1").await.expect("Failed to get").is_some());
+        assert_eq!(backend.get("key2").await.expect("Failed to get"), None);
+        assert!(backend.get("key3").await.expect("Failed to get").is_some());
     }
 
-    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<()> {
-        let entry = CacheEntry::new(value, ttl);
-        self.store.insert(key.to_string(), entry);
+    #[tokio::test]
+    async fn test_inmemory_backend_with_byte_capacity_replacing_a_key_updates_tracked_bytes() {
+        let backend = InMemoryBackend::with_byte_capacity(15, EvictionPolicy::Lru);
 
-        if let Some(d) = ttl {
-            debug!("✓ InMemory SET {} (TTL: {:?})", key, d);
-        } else {
-            debug!("✓ InMemory SET {}", key);
-        }
+        backend.set("key1", vec![0u8; 10], None).await.expect("Failed to set");
+        // Shrinking key1 in place must not leave stale bytes counted against
+        // the budget, or a later unrelated set would evict needlessly.
+        backend.set("key1", vec![0u8; 2], None).await.expect("Failed to set");
+        backend.set("key2", vec![0u8; 10], None).await.expect("Failed to set");
 
-        Ok(())
+        assert!(backend.get("key1").await.expect("Failed to get").is_some());
+        assert!(backend.get("key2").await.expect("Failed to get").is_some());
+        assert_eq!(backend.eviction_count(), 0);
     }
 
-    async fn delete(&self, key: &str) -> Result<()> {
-        self.store.remove(key);
-        debug!("✓ InMemory DELETE {}", key);
-        Ok(())
-    }
+    #[tokio::test]
+    async fn test_inmemory_backend_with_weight_limit_evicts_lru_like_byte_capacity() {
+        let backend = InMemoryBackend::with_weight_limit(15);
 
-    async fn exists(&self, key: &str) -> Result<bool> {
-        if let Some(entry) = self.store.get(key) {
-            return Ok(!entry.is_expired());
-        }
+        backend.set("key1", vec![0u8; 10], None).await.expect("Failed to set");
+        backend.set("key2", vec![0u8; 10], None).await.expect("Failed to set");
 
-        Ok(false)
+        assert_eq!(backend.get("key1").await.expect("Failed to get"), None);
+        assert!(backend.get("key2").await.expect("Failed to get").is_some());
+        assert_eq!(backend.eviction_count(), 1);
     }
 
-    async fn mget(&self, keys: &[&str]) -> Result<Vec<Option<Vec<u8>>>> {
-        let results: Vec<Option<Vec<u8>>> = keys
-            .iter()
-            .map(|k| {
-                if let Some(entry) = self.store.get(*k) {
-                    if entry.is_expired() {
-                        None
-                    } else {
-                        Some(entry.data.clone())
-                    }
-                } else {
-                    None
-                }
-            })
-            .collect();
+    #[tokio::test]
+    async fn test_inmemory_backend_with_weight_limit_rejects_oversized_value_without_evicting_others() {
+        let backend = InMemoryBackend::with_weight_limit(15);
 
-        debug!("✓ InMemory MGET {} keys", keys.len());
-        Ok(results)
+        backend.set("key1", vec![0u8; 10], None).await.expect("Failed to set");
+        // This value alone exceeds the whole budget - it must be rejected,
+        // not admitted at the cost of flushing every other entry.
+        backend.set("toolarge", vec![0u8; 100], None).await.expect("Failed to set");
+
+        assert!(backend.get("key1").await.expect("Failed to get").is_some());
+        assert_eq!(backend.get("toolarge").await.expect("Failed to get"), None);
+        assert_eq!(backend.eviction_count(), 0);
     }
 
-    async fn mdelete(&self, keys: &[&str]) -> Result<()> {
-        for key in keys {
-            self.store.remove(*key);
-        }
+    #[tokio::test]
+    async fn test_inmemory_backend_with_weigher_accounts_for_custom_weight() {
+        // Weigh every entry as a fixed 10 bytes regardless of its actual
+        // length, so a budget of 25 only ever has room for two entries.
+        let backend =
+            InMemoryBackend::with_weight_limit(25).with_weigher(|_key: &str, _value: &[u8]| 10);
+
+        backend.set("key1", vec![0u8; 1], None).await.expect("Failed to set");
+        backend.set("key2", vec![0u8; 1], None).await.expect("Failed to set");
+        backend.set("key3", vec![0u8; 1], None).await.expect("Failed to set");
+
+        assert_eq!(backend.get("key1").await.expect("Failed to get"), None);
+        assert!(backend.get("key2").await.expect("Failed to get").is_some());
+        assert!(backend.get("key3").await.expect("Failed to get").is_some());
 
-        debug!("✓ InMemory MDELETE {} keys", keys.len());
-        Ok(())
+        let stats = backend.stats().await;
+        assert_eq!(stats.total_bytes, 20);
     }
 
-    async fn health_check(&self) -> Result<bool> {
-        // In-memory backend is always healthy
-        Ok(true)
-    }
+    #[tokio::test]
+    async fn test_inmemory_backend_stats_total_bytes_is_accurate_without_iterating() {
+        let backend = InMemoryBackend::new();
 
-    async fn clear_all(&self) -> Result<()> {
-        self.store.clear();
-        warn!("⚠ InMemory CLEAR_ALL executed - all cache cleared!");
-        Ok(())
+        backend.set("key1", vec![0u8; 4], None).await.expect("Failed to set");
+        backend.set("key2", vec![0u8; 6], None).await.expect("Failed to set");
+
+        let stats = backend.stats().await;
+        assert_eq!(stats.total_bytes, 10);
+
+        backend.delete("key1").await.expect("Failed to delete");
+        let stats = backend.stats().await;
+        assert_eq!(stats.total_bytes, 6);
     }
-}
 
-/// Cache statistics.
-#[derive(Clone, Debug)]
-pub struct CacheStats {
-    pub total_entries: usize,
-    pub expired_entries: usize,
-    pub total_bytes: usize,
-}
+    #[tokio::test]
+    async fn test_inmemory_backend_eviction_count_tracks_capacity_evictions() {
+        let backend = InMemoryBackend::with_capacity(1);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        backend.set("key1", b"value1".to_vec(), None).await.expect("Failed to set");
+        backend.set("key2", b"value2".to_vec(), None).await.expect("Failed to set");
+
+        assert_eq!(backend.eviction_count(), 1);
+    }
 
     #[tokio::test]
-    async fn test_inmemory_backend_set_get() {
-        let backend = InMemoryBackend::new();
+    async fn test_inmemory_backend_window_tiny_lfu_protects_hot_key_from_one_shot_churn() {
+        // max_entries = 3 keeps this single-shard (shard_count = max_entries / 3),
+        // so the admission contest below is deterministic.
+        let backend = InMemoryBackend::with_window_tiny_lfu_capacity(3);
+
+        backend.set("hot", b"v".to_vec(), None).await.expect("Failed to set");
+        for _ in 0..5 {
+            backend.get("hot").await.expect("Failed to get");
+        }
 
-        backend
-            .set("key1", b"value1".to_vec(), None)
-            .await
-            .expect("Failed to set");
+        // Flood past capacity with keys accessed only once each. Plain LRU
+        // would evict "hot" the moment it aged out of recency; Window-TinyLFU
+        // should keep rejecting these cold one-shot candidates instead.
+        for i in 0..10 {
+            backend
+                .set(&format!("cold{}", i), b"v".to_vec(), None)
+                .await
+                .expect("Failed to set");
+        }
 
-        let result = backend.get("key1").await.expect("Failed to get");
-        assert_eq!(result, Some(b"value1".to_vec()));
+        assert!(backend.get("hot").await.expect("Failed to get").is_some());
+        assert!(backend.eviction_count() > 0);
     }
 
     #[tokio::test]
-    async fn test_inmemory_backend_miss() {
-        let backend = InMemoryBackend::new();
+    async fn test_inmemory_backend_window_tiny_lfu_stays_bounded_under_churn() {
+        let backend = InMemoryBackend::with_window_tiny_lfu_capacity(50);
+
+        for i in 0..500 {
+            backend
+                .set(&format!("key{}", i), vec![i as u8], None)
+                .await
+                .expect("Failed to set");
+        }
 
-        let result = backend.get("nonexistent").await.expect("Failed to get");
-        assert_eq!(result, None);
+        // Per-shard rounding means the bound is approximate, not exact - see
+        // `window_tiny_lfu_shard_capacities` - but it must never grow
+        // unbounded the way the pre-existing lazy-TTL-only behavior did.
+        assert!(backend.len().await <= 100);
+        assert!(backend.eviction_count() > 0);
     }
 
     #[tokio::test]
-    async fn test_inmemory_backend_delete() {
-        let backend = InMemoryBackend::new();
+    async fn test_inmemory_backend_window_tiny_lfu_stats_reports_evictions() {
+        let backend = InMemoryBackend::with_window_tiny_lfu_capacity(3);
+
+        for i in 0..10 {
+            backend
+                .set(&format!("key{}", i), b"v".to_vec(), None)
+                .await
+                .expect("Failed to set");
+        }
+
+        let stats = backend.stats().await;
+        assert_eq!(stats.evictions, backend.eviction_count());
+        assert!(stats.evictions > 0);
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_with_reaper_removes_expired_entries() {
+        let backend = InMemoryBackend::new().with_reaper(Duration::from_millis(20));
 
         backend
-            .set("key1", b"value1".to_vec(), None)
+            .set("key1", b"value1".to_vec(), Some(Duration::from_millis(10)))
             .await
             .expect("Failed to set");
-        assert!(backend
-            .exists("key1")
-            .await
-            .expect("Failed to check exists"));
 
-        backend.delete("key1").await.expect("Failed to delete");
-        assert!(!backend
-            .exists("key1")
-            .await
-            .expect("Failed to check exists"));
+        // Bypass the lazy get-side removal entirely - don't touch "key1" at
+        // all, so the only thing that can remove it is the reaper.
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        assert_eq!(backend.len().await, 0);
+        let stats = backend.stats().await;
+        assert_eq!(stats.reaped_entries, 1);
+        assert_eq!(stats.reaped_bytes, 6);
     }
 
     #[tokio::test]
-    async fn test_inmemory_backend_ttl_expiration() {
-        let backend = InMemoryBackend::new();
+    async fn test_inmemory_backend_pause_reaper_suspends_sweeps() {
+        let backend = InMemoryBackend::new().with_reaper(Duration::from_millis(20));
+        backend.pause_reaper();
 
         backend
-            .set("key1", b"value1".to_vec(), Some(Duration::from_millis(100)))
+            .set("key1", b"value1".to_vec(), Some(Duration::from_millis(10)))
             .await
             .expect("Failed to set");
 
-        // Should be present immediately
-        assert!(backend.get("key1").await.expect("Failed to get").is_some());
+        tokio::time::sleep(Duration::from_millis(200)).await;
 
-        // Wait for expiration
-        tokio::time::sleep(Duration::from_millis(150)).await;
+        // Paused: the reaper ticked, but the expired entry was left alone.
+        assert_eq!(backend.len().await, 1);
+        assert_eq!(backend.stats().await.reaped_entries, 0);
+        assert!(backend.stop_evictions_changes() > 0);
 
-        // Should be expired now
-        assert!(backend.get("key1").await.expect("Failed to get").is_none());
+        backend.resume_reaper();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(backend.len().await, 0);
     }
 
     #[tokio::test]
-    async fn test_inmemory_backend_mget() {
-        let backend = InMemoryBackend::new();
+    async fn test_inmemory_backend_stop_evictions_changes_counts_ticks() {
+        let backend = InMemoryBackend::new().with_reaper(Duration::from_millis(20));
 
-        backend
-            .set("key1", b"value1".to_vec(), None)
-            .await
-            .expect("Failed to set");
-        backend
-            .set("key2", b"value2".to_vec(), None)
-            .await
-            .expect("Failed to set");
+        tokio::time::sleep(Duration::from_millis(110)).await;
 
-        let results = backend
-            .mget(&["key1", "key2", "key3"])
-            .await
-            .expect("Failed to mget");
+        // ~5 ticks in 110ms at a 20ms interval - assert a handful happened,
+        // without pinning the exact count to scheduler timing.
+        assert!(backend.stop_evictions_changes() >= 3);
+    }
 
-        assert_eq!(results.len(), 3);
-        assert_eq!(results[0], Some(b"value1".to_vec()));
-        assert_eq!(results[1], Some(b"value2".to_vec()));
-        assert_eq!(results[2], None);
+    #[tokio::test]
+    async fn test_inmemory_backend_dropping_last_clone_stops_reaper() {
+        let backend = InMemoryBackend::new().with_reaper(Duration::from_millis(20));
+        // Reach past the public API (legal: same module) to keep the tick
+        // counter alive after every `InMemoryBackend` clone is gone, so we
+        // can tell whether the background task is still running.
+        let ticks = Arc::clone(&backend.stop_evictions_changes);
+        drop(backend);
+
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        let snapshot = ticks.load(Ordering::Relaxed);
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert_eq!(ticks.load(Ordering::Relaxed), snapshot);
     }
 
     #[tokio::test]
-    async fn test_inmemory_backend_mdelete() {
-        let backend = InMemoryBackend::new();
+    async fn test_inmemory_backend_eviction_listener_observes_expired_removal() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let seen_clone = Arc::clone(&seen);
+
+        let backend = InMemoryBackend::new().with_eviction_listener(move |key, value, cause| {
+            seen_clone
+                .lock()
+                .expect("lock poisoned")
+                .push((key.to_string(), value.to_vec(), cause));
+        });
 
         backend
-            .set("key1", b"value1".to_vec(), None)
+            .set("key1", b"value1".to_vec(), Some(Duration::from_millis(10)))
             .await
             .expect("Failed to set");
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        assert_eq!(backend.get("key1").await.expect("Failed to get"), None);
+
+        let seen = seen.lock().expect("lock poisoned");
+        assert_eq!(seen.len(), 1);
+        assert_eq!(seen[0], ("key1".to_string(), b"value1".to_vec(), RemovalCause::Expired));
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_eviction_listener_observes_replaced_and_explicit_removal() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let seen_clone = Arc::clone(&seen);
+
+        let backend = InMemoryBackend::new().with_eviction_listener(move |key, value, cause| {
+            seen_clone
+                .lock()
+                .expect("lock poisoned")
+                .push((key.to_string(), value.to_vec(), cause));
+        });
+
         backend
-            .set("key2", b"value2".to_vec(), None)
+            .set("key1", b"old".to_vec(), None)
             .await
             .expect("Failed to set");
         backend
-            .set("key3", b"value3".to_vec(), None)
+            .set("key1", b"new".to_vec(), None)
             .await
             .expect("Failed to set");
+        backend.delete("key1").await.expect("Failed to delete");
 
-        assert_eq!(backend.len().await, 3);
+        let seen = seen.lock().expect("lock poisoned");
+        assert_eq!(seen.len(), 2);
+        assert_eq!(seen[0], ("key1".to_string(), b"old".to_vec(), RemovalCause::Replaced));
+        assert_eq!(seen[1], ("key1".to_string(), b"new".to_vec(), RemovalCause::Explicit));
+    }
 
-        backend
-            .mdelete(&["key1", "key2"])
-            .await
-            .expect("Failed to mdelete");
+    #[tokio::test]
+    async fn test_inmemory_backend_eviction_listener_observes_size_eviction() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let seen_clone = Arc::clone(&seen);
+
+        let backend = InMemoryBackend::with_capacity(1).with_eviction_listener(move |key, value, cause| {
+            seen_clone
+                .lock()
+                .expect("lock poisoned")
+                .push((key.to_string(), value.to_vec(), cause));
+        });
+
+        backend.set("key1", b"value1".to_vec(), None).await.expect("Failed to set");
+        backend.set("key2", b"value2".to_vec(), None).await.expect("Failed to set");
+
+        let seen = seen.lock().expect("lock poisoned");
+        assert_eq!(seen.len(), 1);
+        assert_eq!(seen[0], ("key1".to_string(), b"value1".to_vec(), RemovalCause::Size));
+    }
 
-        assert_eq!(backend.len().await, 1);
-        assert!(backend.get("key3").await.expect("Failed to get").is_some());
+    #[tokio::test]
+    async fn test_inmemory_backend_eviction_listener_observes_clear_all() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let seen_clone = Arc::clone(&seen);
+
+        let backend = InMemoryBackend::new().with_eviction_listener(move |key, value, cause| {
+            seen_clone
+                .lock()
+                .expect("lock poisoned")
+                .push((key.to_string(), value.to_vec(), cause));
+        });
+
+        backend.set("key1", b"value1".to_vec(), None).await.expect("Failed to set");
+        backend.clear_all().await.expect("Failed to clear_all");
+
+        let seen = seen.lock().expect("lock poisoned");
+        assert_eq!(seen.len(), 1);
+        assert_eq!(seen[0], ("key1".to_string(), b"value1".to_vec(), RemovalCause::Explicit));
     }
 
     #[tokio::test]
-    async fn test_inmemory_backend_clear_all() {
+    async fn test_inmemory_backend_get_with_coalesces_concurrent_misses() {
+        let backend = Arc::new(InMemoryBackend::new());
+        let init_count = Arc::new(AtomicUsize::new(0));
+
+        let mut handles = vec![];
+        for _ in 0..10 {
+            let backend = Arc::clone(&backend);
+            let init_count = Arc::clone(&init_count);
+            handles.push(tokio::spawn(async move {
+                backend
+                    .get_with(
+                        "stampede",
+                        || async move {
+                            init_count.fetch_add(1, Ordering::SeqCst);
+                            tokio::time::sleep(Duration::from_millis(20)).await;
+                            Ok(b"loaded".to_vec())
+                        },
+                        None,
+                    )
+                    .await
+            }));
+        }
+
+        for handle in handles {
+            assert_eq!(
+                handle.await.expect("Task failed").expect("Failed to get_with"),
+                b"loaded".to_vec()
+            );
+        }
+
+        assert_eq!(init_count.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_get_with_runs_independently_per_key() {
         let backend = InMemoryBackend::new();
 
-        backend
-            .set("key1", b"value1".to_vec(), None)
+        let a = backend
+            .get_with("a", || async { Ok(b"a-value".to_vec()) }, None)
             .await
-            .expect("Failed to set");
-        backend
-            .set("key2", b"value2".to_vec(), None)
+            .expect("Failed to get_with");
+        let b = backend
+            .get_with("b", || async { Ok(b"b-value".to_vec()) }, None)
             .await
-            .expect("Failed to set");
+            .expect("Failed to get_with");
 
-        assert_eq!(backend.len().await, 2);
+        assert_eq!(a, b"a-value".to_vec());
+        assert_eq!(b, b"b-value".to_vec());
+    }
 
-        backend.clear_all().await.expect("Failed to clear");
+    #[tokio::test]
+    async fn test_inmemory_backend_get_with_skips_init_on_hit() {
+        let backend = InMemoryBackend::new();
+        backend.set("key", b"cached".to_vec(), None).await.expect("Failed to set");
 
-        assert_eq!(backend.len().await, 0);
+        let value = backend
+            .get_with("key", || async { panic!("init should not run on a cache hit") }, None)
+            .await
+            .expect("Failed to get_with");
+
+        assert_eq!(value, b"cached".to_vec());
     }
 
     #[tokio::test]
-    async fn test_inmemory_backend_stats() {
+    async fn test_inmemory_backend_get_with_clears_inflight_slot_on_error() {
         let backend = InMemoryBackend::new();
 
-        backend
-            .set("key1", b"value_with_data".to_vec(), None)
+        let err = backend
+            .get_with(
+                "key",
+                || async { Err::<Vec<u8>, _>(crate::error::Error::NotImplemented("boom".to_string())) },
+                None,
+            )
+            .await;
+        assert!(err.is_err());
+
+        // The failed attempt must not leave the key permanently stuck - a
+        // retry should run `init` again and succeed.
+        let value = backend
+            .get_with("key", || async { Ok(b"retried".to_vec()) }, None)
             .await
-            .expect("Failed to set");
+            .expect("Failed to get_with");
+        assert_eq!(value, b"retried".to_vec());
+    }
+
+    #[tokio::test]
+    async fn test_inmemory_backend_get_with_stores_value_with_requested_ttl() {
+        let backend = InMemoryBackend::new();
+
         backend
-            .set("key2", b"data".to_vec(), None)
+            .get_with("key", || async { Ok(b"value".to_vec()) }, Some(Duration::from_millis(10)))
             .await
-            .expect("Failed to set");
+            .expect("Failed to get_with");
+        assert_eq!(backend.get("key").await.expect("Failed to get"), Some(b"value".to_vec()));
 
-        let stats = backend.stats().await;
-        assert_eq!(stats.total_entries, 2);
-        assert_eq!(stats.expired_entries, 0);
-        assert!(stats.total_bytes > 0);
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(backend.get("key").await.expect("Failed to get"), None);
     }
 
     #[tokio::test]
-    async fn test_inmemory_backend_clone() {
-        let backend1 = InMemoryBackend::new();
-        backend1
-            .set("key", b"value".to_vec(), None)
-            .await
-            .expect("Failed to set");
+    async fn test_inmemory_backend_set_stream_bypasses_cache_over_declared_size() {
+        let backend = InMemoryBackend::with_byte_capacity(4, EvictionPolicy::Lru);
 
-        let backend2 = backend1.clone();
+        backend
+            .set_stream(
+                "key",
+                crate::streaming::CacheData::from_bytes(vec![0; 10]),
+                None,
+            )
+            .await
+            .expect("Failed to set_stream");
 
-        // Both backends share the same store
-        let value = backend2.store.get("key").map(|e| e.data.clone());
-        assert_eq!(value, Some(b"value".to_vec()));
+        assert_eq!(backend.get("key").await.expect("Failed to get"), None);
     }
 
     #[tokio::test]
-    async fn test_inmemory_backend_thread_safe() {
-        use std::sync::Arc;
-
-        let backend = Arc::new(InMemoryBackend::new());
-        let mut handles = vec![];
-
-        for i in 0..10 {
-            let backend_clone = Arc::clone(&backend);
-            let handle = tokio::spawn(async move {
-                let b = (*backend_clone).clone();
-                let key = format!("key_{}", i);
-                let value = format!("value_{}", i);
-                b.set(&key, value.into_bytes(), None)
-                    .await
-                    .expect("Failed to set");
-            });
-            handles.push(handle);
-        }
+    async fn test_inmemory_backend_set_stream_under_budget_is_stored() {
+        let backend = InMemoryBackend::with_byte_capacity(100, EvictionPolicy::Lru);
 
-        for handle in handles {
-            handle.await.expect("Task failed");
-        }
+        backend
+            .set_stream(
+                "key",
+                crate::streaming::CacheData::from_bytes(vec![1, 2, 3]),
+                None,
+            )
+            .await
+            .expect("Failed to set_stream");
 
-        assert!(backend.clone().len().await >= 10);
+        assert_eq!(backend.get("key").await.expect("Failed to get"), Some(vec![1, 2, 3]));
     }
 }
@@ -0,0 +1,783 @@
+//! Instrumented cache backend that counts hits, misses, and mutations.
+//!
+//! Wraps any [`CacheBackend`] and records lock-free counters for every
+//! `get`/`set`/`delete`/invalidation call, so tests can assert a cache hit
+//! actually occurred (instead of just comparing response bodies across two
+//! requests) and production deployments have a metrics surface to scrape.
+//!
+//! Alongside the aggregate [`CacheStats`], [`InstrumentedBackend`] also
+//! tracks per-key [`AccessCounts`] (reads, writes, deletes, and bytes
+//! moved), via [`InstrumentedBackend::metrics`]/
+//! [`InstrumentedBackend::total_metrics`]. This turns a timing-only
+//! benchmark into a behavioral regression guard - e.g. asserting a
+//! `CacheStrategy::Refresh` hit does exactly one read and zero writes, while
+//! a miss does one read plus one write. [`InstrumentedBackend::with_whitelist`]
+//! excludes chosen keys (hot config keys, say) from that per-key accounting.
+
+use super::CacheBackend;
+use crate::error::Result;
+use crate::observability::{CacheMetrics, LatencyPercentiles, StatsCollector};
+use dashmap::{DashMap, DashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Point-in-time snapshot of an [`InstrumentedBackend`]'s counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Number of `get`/`mget` lookups that found a value.
+    pub hits: u64,
+    /// Number of `get`/`mget` lookups that found nothing.
+    pub misses: u64,
+    /// Number of `set`/`set_with_tags` calls.
+    pub inserts: u64,
+    /// Successful `delete` calls, plus the number of keys passed to
+    /// `mdelete`, the count `delete_prefix` reports removed, and one per
+    /// `clear_all` call (not the number of keys it cleared).
+    pub evictions: u64,
+    /// Number of `invalidate_prefix`/`invalidate_tag` calls.
+    pub invalidations: u64,
+    /// Number of calls to any wrapped method that returned `Err`.
+    pub errors: u64,
+    /// Total bytes passed to `set`/`set_with_tags` calls, across every key.
+    pub bytes_written: u64,
+}
+
+impl CacheStats {
+    /// Fraction of lookups (`get`/`mget`) that were hits, in `[0.0, 1.0]`.
+    ///
+    /// `0.0` if there have been no lookups at all, rather than `NaN` from a
+    /// `0 / 0` division - an idle cache reads as "no hits yet", not an error.
+    pub fn hit_ratio(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+#[derive(Default)]
+struct Counters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    inserts: AtomicU64,
+    evictions: AtomicU64,
+    invalidations: AtomicU64,
+    errors: AtomicU64,
+    bytes_written: AtomicU64,
+}
+
+/// One row of [`BackendStatsSnapshot`]: count, throughput, and latency
+/// percentiles for a single operation class (`hit`/`miss`/`set`/`delete`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OpStatsRow {
+    pub operation: &'static str,
+    pub count: u64,
+    pub ops_per_sec: f64,
+    pub percentiles: LatencyPercentiles,
+}
+
+/// Point-in-time latency/throughput report from
+/// [`InstrumentedBackend::stats_snapshot`], covering the time since the
+/// backend was constructed.
+///
+/// # Example
+///
+/// ```
+/// use cache_kit::backend::{InMemoryBackend, InstrumentedBackend};
+///
+/// let backend = InstrumentedBackend::new(InMemoryBackend::new());
+/// let table = backend.stats_snapshot().to_string();
+/// assert!(table.contains("| hit |"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackendStatsSnapshot {
+    /// Hits over `hits + misses`, as a value in `[0.0, 1.0]`.
+    pub hit_rate: f64,
+    /// Calls to any wrapped method that returned `Err`, from [`CacheStats::errors`].
+    pub errors: u64,
+    /// One row per operation class, in `hit`/`miss`/`set`/`delete` order.
+    pub rows: Vec<OpStatsRow>,
+}
+
+impl std::fmt::Display for BackendStatsSnapshot {
+    /// Render as a Markdown table, suitable for pasting into a runbook or
+    /// printing to a terminal as plain ASCII.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "| operation | count | ops/sec | p50 (µs) | p95 (µs) | p99 (µs) |")?;
+        writeln!(f, "|---|---|---|---|---|---|")?;
+        for row in &self.rows {
+            writeln!(
+                f,
+                "| {} | {} | {:.1} | {:.1} | {:.1} | {:.1} |",
+                row.operation,
+                row.count,
+                row.ops_per_sec,
+                row.percentiles.p50_us,
+                row.percentiles.p95_us,
+                row.percentiles.p99_us
+            )?;
+        }
+        writeln!(
+            f,
+            "\nHit rate: {:.1}%, errors: {}",
+            self.hit_rate * 100.0,
+            self.errors
+        )
+    }
+}
+
+/// Per-key read/write/delete access counts, tracked alongside the aggregate
+/// [`CacheStats`] so a test or benchmark can assert on the exact shape of a
+/// single key's traffic - e.g. "a `Refresh` hit does one read and zero
+/// writes" - rather than only a hit ratio across the whole backend.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AccessCounts {
+    /// Number of `get`/`mget` lookups for this key, hit or miss.
+    pub reads: u64,
+    /// Number of `set`/`mset`/`set_with_tags` calls for this key.
+    pub writes: u64,
+    /// Number of `delete`/`mdelete` calls for this key.
+    pub deletes: u64,
+    /// Total bytes returned by reads that found a value.
+    pub bytes_read: u64,
+    /// Total bytes passed to writes.
+    pub bytes_written: u64,
+}
+
+#[derive(Default)]
+struct AtomicAccessCounts {
+    reads: AtomicU64,
+    writes: AtomicU64,
+    deletes: AtomicU64,
+    bytes_read: AtomicU64,
+    bytes_written: AtomicU64,
+}
+
+impl AtomicAccessCounts {
+    fn snapshot(&self) -> AccessCounts {
+        AccessCounts {
+            reads: self.reads.load(Ordering::Relaxed),
+            writes: self.writes.load(Ordering::Relaxed),
+            deletes: self.deletes.load(Ordering::Relaxed),
+            bytes_read: self.bytes_read.load(Ordering::Relaxed),
+            bytes_written: self.bytes_written.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Wraps any [`CacheBackend`] with atomic hit/miss/insert/eviction/invalidation
+/// counters, exposed via [`InstrumentedBackend::stats`].
+///
+/// # Example
+///
+/// ```no_run
+/// use cache_kit::backend::{InstrumentedBackend, InMemoryBackend};
+///
+/// # async fn example() -> cache_kit::Result<()> {
+/// let backend = InstrumentedBackend::new(InMemoryBackend::new());
+///
+/// backend.get("user:1").await?; // miss
+/// backend.set("user:1", b"alice".to_vec(), None).await?;
+/// backend.get("user:1").await?; // hit
+///
+/// let stats = backend.stats();
+/// assert_eq!(stats.hits, 1);
+/// assert_eq!(stats.misses, 1);
+/// assert_eq!(stats.inserts, 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct InstrumentedBackend<B> {
+    inner: B,
+    counters: Arc<Counters>,
+    access: Arc<DashMap<String, AtomicAccessCounts>>,
+    whitelist: Arc<DashSet<String>>,
+    /// Per-operation-class latency, for [`InstrumentedBackend::stats_snapshot`].
+    latency: Arc<StatsCollector>,
+    created_at: Instant,
+}
+
+impl<B: CacheBackend> InstrumentedBackend<B> {
+    /// Wrap `inner` with a fresh set of counters.
+    pub fn new(inner: B) -> Self {
+        InstrumentedBackend {
+            inner,
+            counters: Arc::new(Counters::default()),
+            access: Arc::new(DashMap::new()),
+            whitelist: Arc::new(DashSet::new()),
+            latency: Arc::new(StatsCollector::new()),
+            created_at: Instant::now(),
+        }
+    }
+
+    /// Exclude `keys` from [`InstrumentedBackend::metrics`]/
+    /// [`InstrumentedBackend::total_metrics`] - e.g. hot config keys that
+    /// shouldn't count against a cache-efficiency budget. Accesses to a
+    /// whitelisted key still happen and are still reflected in the aggregate
+    /// [`CacheStats`] from [`InstrumentedBackend::stats`]; only the per-key
+    /// access counts ignore them.
+    pub fn with_whitelist<I, K>(self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<String>,
+    {
+        for key in keys {
+            self.whitelist.insert(key.into());
+        }
+        self
+    }
+
+    /// Borrow the wrapped backend (useful for backend-specific diagnostics).
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Read/write/delete access counts for one key, or the zero value if
+    /// it's never been accessed (or is whitelisted).
+    pub fn metrics(&self, key: &str) -> AccessCounts {
+        self.access
+            .get(key)
+            .map(|entry| entry.snapshot())
+            .unwrap_or_default()
+    }
+
+    /// Access counts summed across every tracked (non-whitelisted) key.
+    pub fn total_metrics(&self) -> AccessCounts {
+        let mut total = AccessCounts::default();
+        for entry in self.access.iter() {
+            let counts = entry.snapshot();
+            total.reads += counts.reads;
+            total.writes += counts.writes;
+            total.deletes += counts.deletes;
+            total.bytes_read += counts.bytes_read;
+            total.bytes_written += counts.bytes_written;
+        }
+        total
+    }
+
+    fn record_read(&self, key: &str, bytes: usize) {
+        if self.whitelist.contains(key) {
+            return;
+        }
+        let entry = self.access.entry(key.to_string()).or_default();
+        entry.reads.fetch_add(1, Ordering::Relaxed);
+        entry.bytes_read.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    fn record_write(&self, key: &str, bytes: usize) {
+        if self.whitelist.contains(key) {
+            return;
+        }
+        let entry = self.access.entry(key.to_string()).or_default();
+        entry.writes.fetch_add(1, Ordering::Relaxed);
+        entry.bytes_written.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    fn record_delete(&self, key: &str) {
+        if self.whitelist.contains(key) {
+            return;
+        }
+        self.access.entry(key.to_string()).or_default().deletes.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Snapshot of all counters at this instant.
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.counters.hits.load(Ordering::Relaxed),
+            misses: self.counters.misses.load(Ordering::Relaxed),
+            inserts: self.counters.inserts.load(Ordering::Relaxed),
+            evictions: self.counters.evictions.load(Ordering::Relaxed),
+            invalidations: self.counters.invalidations.load(Ordering::Relaxed),
+            errors: self.counters.errors.load(Ordering::Relaxed),
+            bytes_written: self.counters.bytes_written.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Reset every counter to zero without touching the wrapped backend's data.
+    ///
+    /// Only resets the aggregate [`CacheStats`] from `stats()` - see
+    /// [`InstrumentedBackend::reset_metrics`] for the per-key access counts.
+    pub fn reset_stats(&self) {
+        self.counters.hits.store(0, Ordering::Relaxed);
+        self.counters.misses.store(0, Ordering::Relaxed);
+        self.counters.inserts.store(0, Ordering::Relaxed);
+        self.counters.evictions.store(0, Ordering::Relaxed);
+        self.counters.invalidations.store(0, Ordering::Relaxed);
+        self.counters.errors.store(0, Ordering::Relaxed);
+        self.counters.bytes_written.store(0, Ordering::Relaxed);
+    }
+
+    /// Clear every key's access counts, without touching `stats()`'s
+    /// aggregate counters or the wrapped backend's data.
+    pub fn reset_metrics(&self) {
+        self.access.clear();
+    }
+
+    /// Print the current counters and hit ratio to the debug log, for a
+    /// quick capacity/TTL-tuning check without wiring up a metrics scrape.
+    pub fn log_stats(&self) {
+        let stats = self.stats();
+        debug!(
+            "Cache Stats: {} hits, {} misses ({:.1}% hit ratio), {} inserts, {} evictions, \
+             {} invalidations, {} errors, {} bytes written",
+            stats.hits,
+            stats.misses,
+            stats.hit_ratio() * 100.0,
+            stats.inserts,
+            stats.evictions,
+            stats.invalidations,
+            stats.errors,
+            stats.bytes_written
+        );
+    }
+
+    /// Per-operation-class latency and throughput since this backend was
+    /// constructed - count, ops/sec, and p50/p95/p99 latency for each of
+    /// `hit`/`miss`/`set`/`delete`, plus the overall hit rate. Renders as a
+    /// Markdown table via its `Display` impl.
+    pub fn stats_snapshot(&self) -> BackendStatsSnapshot {
+        let elapsed_secs = self.created_at.elapsed().as_secs_f64();
+        let snapshot = self.latency.snapshot();
+        let ops_per_sec = |count: u64| {
+            if elapsed_secs <= 0.0 {
+                0.0
+            } else {
+                count as f64 / elapsed_secs
+            }
+        };
+
+        BackendStatsSnapshot {
+            hit_rate: snapshot.hit_ratio,
+            errors: self.counters.errors.load(Ordering::Relaxed),
+            rows: vec![
+                OpStatsRow {
+                    operation: "hit",
+                    count: snapshot.hits,
+                    ops_per_sec: ops_per_sec(snapshot.hits),
+                    percentiles: snapshot.hit,
+                },
+                OpStatsRow {
+                    operation: "miss",
+                    count: snapshot.misses,
+                    ops_per_sec: ops_per_sec(snapshot.misses),
+                    percentiles: snapshot.miss,
+                },
+                OpStatsRow {
+                    operation: "set",
+                    count: snapshot.sets,
+                    ops_per_sec: ops_per_sec(snapshot.sets),
+                    percentiles: snapshot.set,
+                },
+                OpStatsRow {
+                    operation: "delete",
+                    count: snapshot.deletes,
+                    ops_per_sec: ops_per_sec(snapshot.deletes),
+                    percentiles: snapshot.delete,
+                },
+            ],
+        }
+    }
+
+    fn record_lookup(&self, found: bool) {
+        if found {
+            self.counters.hits.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.counters.misses.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+impl<B: CacheBackend> InstrumentedBackend<B> {
+    fn record_error(&self) {
+        self.counters.errors.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+impl<B: CacheBackend> CacheBackend for InstrumentedBackend<B> {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let start = Instant::now();
+        let value = match self.inner.get(key).await {
+            Ok(value) => value,
+            Err(e) => {
+                self.record_error();
+                return Err(e);
+            }
+        };
+        let found = value.is_some();
+        self.record_lookup(found);
+        self.record_read(key, value.as_ref().map_or(0, Vec::len));
+        if found {
+            self.latency.record_hit(key, start.elapsed());
+        } else {
+            self.latency.record_miss(key, start.elapsed());
+        }
+        Ok(value)
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<()> {
+        let bytes = value.len();
+        let start = Instant::now();
+        if let Err(e) = self.inner.set(key, value, ttl).await {
+            self.record_error();
+            return Err(e);
+        }
+        self.counters.inserts.fetch_add(1, Ordering::Relaxed);
+        self.counters
+            .bytes_written
+            .fetch_add(bytes as u64, Ordering::Relaxed);
+        self.record_write(key, bytes);
+        self.latency.record_set(key, start.elapsed());
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        let start = Instant::now();
+        if let Err(e) = self.inner.delete(key).await {
+            self.record_error();
+            return Err(e);
+        }
+        self.counters.evictions.fetch_add(1, Ordering::Relaxed);
+        self.record_delete(key);
+        self.latency.record_delete(key, start.elapsed());
+        Ok(())
+    }
+
+    async fn gets(&self, key: &str) -> Result<Option<(Vec<u8>, u64)>> {
+        self.inner.gets(key).await
+    }
+
+    async fn cas(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>, cas_token: u64) -> Result<bool> {
+        self.inner.cas(key, value, ttl, cas_token).await
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool> {
+        self.inner.exists(key).await
+    }
+
+    async fn mget(&self, keys: &[&str]) -> Result<Vec<Option<Vec<u8>>>> {
+        let values = match self.inner.mget(keys).await {
+            Ok(values) => values,
+            Err(e) => {
+                self.record_error();
+                return Err(e);
+            }
+        };
+        for (key, value) in keys.iter().zip(&values) {
+            self.record_lookup(value.is_some());
+            self.record_read(key, value.as_ref().map_or(0, Vec::len));
+        }
+        Ok(values)
+    }
+
+    async fn mdelete(&self, keys: &[&str]) -> Result<()> {
+        if let Err(e) = self.inner.mdelete(keys).await {
+            self.record_error();
+            return Err(e);
+        }
+        self.counters
+            .evictions
+            .fetch_add(keys.len() as u64, Ordering::Relaxed);
+        for key in keys {
+            self.record_delete(key);
+        }
+        Ok(())
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        self.inner.health_check().await
+    }
+
+    async fn clear_all(&self) -> Result<()> {
+        if let Err(e) = self.inner.clear_all().await {
+            self.record_error();
+            return Err(e);
+        }
+        self.counters.evictions.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    async fn invalidate_prefix(&self, prefix: &str) -> Result<()> {
+        if let Err(e) = self.inner.invalidate_prefix(prefix).await {
+            self.record_error();
+            return Err(e);
+        }
+        self.counters.invalidations.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    async fn scan_prefix(&self, prefix: &str) -> Result<Vec<String>> {
+        self.inner.scan_prefix(prefix).await
+    }
+
+    async fn delete_prefix(&self, prefix: &str) -> Result<u64> {
+        let deleted = match self.inner.delete_prefix(prefix).await {
+            Ok(deleted) => deleted,
+            Err(e) => {
+                self.record_error();
+                return Err(e);
+            }
+        };
+        self.counters.evictions.fetch_add(deleted, Ordering::Relaxed);
+        Ok(deleted)
+    }
+
+    async fn set_with_tags(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        ttl: Option<Duration>,
+        tags: &[&str],
+    ) -> Result<()> {
+        let bytes = value.len();
+        if let Err(e) = self.inner.set_with_tags(key, value, ttl, tags).await {
+            self.record_error();
+            return Err(e);
+        }
+        self.counters.inserts.fetch_add(1, Ordering::Relaxed);
+        self.counters
+            .bytes_written
+            .fetch_add(bytes as u64, Ordering::Relaxed);
+        self.record_write(key, bytes);
+        Ok(())
+    }
+
+    async fn invalidate_tag(&self, tag: &str) -> Result<()> {
+        if let Err(e) = self.inner.invalidate_tag(tag).await {
+            self.record_error();
+            return Err(e);
+        }
+        self.counters.invalidations.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::InMemoryBackend;
+    use crate::error::Error;
+
+    #[derive(Clone)]
+    struct AlwaysErrBackend;
+
+    impl CacheBackend for AlwaysErrBackend {
+        async fn get(&self, _key: &str) -> Result<Option<Vec<u8>>> {
+            Err(Error::BackendError("always fails".to_string()))
+        }
+
+        async fn set(&self, _key: &str, _value: Vec<u8>, _ttl: Option<Duration>) -> Result<()> {
+            Err(Error::BackendError("always fails".to_string()))
+        }
+
+        async fn delete(&self, _key: &str) -> Result<()> {
+            Err(Error::BackendError("always fails".to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_records_hit_and_miss() {
+        let backend = InstrumentedBackend::new(InMemoryBackend::new());
+
+        backend.get("key").await.expect("Failed to get");
+        backend
+            .set("key", vec![1, 2, 3], None)
+            .await
+            .expect("Failed to set");
+        backend.get("key").await.expect("Failed to get");
+
+        let stats = backend.stats();
+        assert_eq!(stats.hits, 1);
+        assert_eq!(stats.misses, 1);
+        assert_eq!(stats.inserts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_delete_and_invalidate_prefix_are_counted() {
+        let backend = InstrumentedBackend::new(InMemoryBackend::new());
+        backend
+            .set("key", vec![1], None)
+            .await
+            .expect("Failed to set");
+
+        backend.delete("key").await.expect("Failed to delete");
+        let _ = backend.invalidate_prefix("unused").await;
+
+        let stats = backend.stats();
+        assert_eq!(stats.evictions, 1);
+        assert_eq!(stats.invalidations, 1);
+    }
+
+    #[tokio::test]
+    async fn test_reset_stats_zeroes_counters() {
+        let backend = InstrumentedBackend::new(InMemoryBackend::new());
+        backend
+            .set("key", vec![1], None)
+            .await
+            .expect("Failed to set");
+        backend.get("key").await.expect("Failed to get");
+
+        backend.reset_stats();
+
+        assert_eq!(backend.stats(), CacheStats::default());
+    }
+
+    #[tokio::test]
+    async fn test_hit_ratio_reflects_hits_and_misses() {
+        let backend = InstrumentedBackend::new(InMemoryBackend::new());
+        backend
+            .set("key", vec![1], None)
+            .await
+            .expect("Failed to set");
+
+        backend.get("key").await.expect("Failed to get"); // hit
+        backend.get("key").await.expect("Failed to get"); // hit
+        backend.get("missing").await.expect("Failed to get"); // miss
+
+        assert_eq!(backend.stats().hit_ratio(), 2.0 / 3.0);
+    }
+
+    #[test]
+    fn test_hit_ratio_with_no_lookups_is_zero_not_nan() {
+        assert_eq!(CacheStats::default().hit_ratio(), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_tracks_reads_writes_and_bytes_per_key() {
+        let backend = InstrumentedBackend::new(InMemoryBackend::new());
+
+        backend.get("key").await.expect("Failed to get"); // miss, 0 bytes
+        backend
+            .set("key", vec![1, 2, 3], None)
+            .await
+            .expect("Failed to set");
+        backend.get("key").await.expect("Failed to get"); // hit, 3 bytes
+        backend.delete("key").await.expect("Failed to delete");
+
+        let metrics = backend.metrics("key");
+        assert_eq!(metrics.reads, 2);
+        assert_eq!(metrics.writes, 1);
+        assert_eq!(metrics.deletes, 1);
+        assert_eq!(metrics.bytes_read, 3);
+        assert_eq!(metrics.bytes_written, 3);
+    }
+
+    #[tokio::test]
+    async fn test_whitelisted_key_is_excluded_from_metrics_but_not_stats() {
+        let backend = InstrumentedBackend::new(InMemoryBackend::new()).with_whitelist(["config:flags"]);
+
+        backend
+            .set("config:flags", vec![1], None)
+            .await
+            .expect("Failed to set");
+        backend.get("config:flags").await.expect("Failed to get");
+
+        assert_eq!(backend.metrics("config:flags"), AccessCounts::default());
+        assert_eq!(backend.total_metrics(), AccessCounts::default());
+        assert_eq!(backend.stats().hits, 1);
+        assert_eq!(backend.stats().inserts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_total_metrics_sums_across_keys() {
+        let backend = InstrumentedBackend::new(InMemoryBackend::new());
+
+        backend.set("a", vec![1], None).await.expect("Failed to set");
+        backend.set("b", vec![1, 2], None).await.expect("Failed to set");
+        backend.get("a").await.expect("Failed to get");
+        backend.get("b").await.expect("Failed to get");
+
+        let total = backend.total_metrics();
+        assert_eq!(total.writes, 2);
+        assert_eq!(total.reads, 2);
+        assert_eq!(total.bytes_written, 3);
+    }
+
+    #[tokio::test]
+    async fn test_reset_metrics_clears_access_counts_but_not_stats() {
+        let backend = InstrumentedBackend::new(InMemoryBackend::new());
+        backend.set("key", vec![1], None).await.expect("Failed to set");
+
+        backend.reset_metrics();
+
+        assert_eq!(backend.metrics("key"), AccessCounts::default());
+        assert_eq!(backend.stats().inserts, 1, "reset_metrics should not touch stats()");
+    }
+
+    #[tokio::test]
+    async fn test_failed_calls_are_counted_as_errors_not_hits_or_misses() {
+        let backend = InstrumentedBackend::new(AlwaysErrBackend);
+
+        let _ = backend.get("key").await;
+        let _ = backend.set("key", vec![1], None).await;
+        let _ = backend.delete("key").await;
+
+        let stats = backend.stats();
+        assert_eq!(stats.errors, 3);
+        assert_eq!(stats.hits, 0);
+        assert_eq!(stats.misses, 0);
+        assert_eq!(stats.inserts, 0);
+        assert_eq!(stats.evictions, 0);
+    }
+
+    #[tokio::test]
+    async fn test_stats_bytes_written_sums_across_keys() {
+        let backend = InstrumentedBackend::new(InMemoryBackend::new());
+
+        backend.set("a", vec![1, 2, 3], None).await.expect("Failed to set");
+        backend.set("b", vec![1, 2], None).await.expect("Failed to set");
+
+        assert_eq!(backend.stats().bytes_written, 5);
+    }
+
+    #[tokio::test]
+    async fn test_stats_snapshot_reports_counts_and_errors() {
+        let backend = InstrumentedBackend::new(InMemoryBackend::new());
+
+        backend.get("key").await.expect("Failed to get"); // miss
+        backend
+            .set("key", vec![1, 2, 3], None)
+            .await
+            .expect("Failed to set");
+        backend.get("key").await.expect("Failed to get"); // hit
+        backend.delete("key").await.expect("Failed to delete");
+
+        let snapshot = backend.stats_snapshot();
+        let row = |op: &str| snapshot.rows.iter().find(|r| r.operation == op).unwrap();
+        assert_eq!(row("hit").count, 1);
+        assert_eq!(row("miss").count, 1);
+        assert_eq!(row("set").count, 1);
+        assert_eq!(row("delete").count, 1);
+        assert_eq!(snapshot.errors, 0);
+
+        let table = snapshot.to_string();
+        assert!(table.contains("| hit | 1 |"));
+        assert!(table.contains("Hit rate:"));
+    }
+
+    #[tokio::test]
+    async fn test_stats_snapshot_counts_errors() {
+        let backend = InstrumentedBackend::new(AlwaysErrBackend);
+
+        let _ = backend.get("key").await;
+        let _ = backend.set("key", vec![1], None).await;
+
+        assert_eq!(backend.stats_snapshot().errors, 2);
+    }
+
+    #[tokio::test]
+    async fn test_clone_shares_counters() {
+        let backend = InstrumentedBackend::new(InMemoryBackend::new());
+        let clone = backend.clone();
+
+        clone
+            .set("key", vec![1], None)
+            .await
+            .expect("Failed to set");
+
+        assert_eq!(backend.stats().inserts, 1);
+    }
+}
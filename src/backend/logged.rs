@@ -0,0 +1,506 @@
+//! Write-ahead operation log with periodic checkpoints, for warm-starting a
+//! backend after a restart instead of coming back up cold.
+//!
+//! [`InMemoryBackend::save_snapshot`]/[`load_snapshot`](InMemoryBackend::load_snapshot)
+//! already let a backend persist its *whole* state, but taking a full
+//! snapshot on every `set`/`delete` would be far too slow to do inline with
+//! every write. [`LoggedBackend`] instead appends each mutation to an
+//! operation log (cheap, sequential) and only serializes a full checkpoint
+//! every [`LoggedBackend::with_checkpoint_every`] operations. Recovery loads the
+//! most recent checkpoint, then replays only the log records written after
+//! it - deterministic, and far cheaper than replaying from the beginning of
+//! time on every restart.
+//!
+//! Critical invariants:
+//! - Operation sequence numbers are strictly monotonic and totally ordered
+//!   (a single `AtomicU64` counter, not wall-clock time, which can repeat or
+//!   go backwards).
+//! - Replaying an operation is idempotent - re-applying the same `set`/
+//!   `delete` twice leaves the backend in the same state as applying it once.
+//! - A checkpoint write fences the log: only records strictly after the
+//!   checkpoint's sequence number are kept, so older ones can be GC'd.
+//!
+//! This is the crate's "journaled backend" / "op-log plus checkpoint" layer:
+//! an `AtomicU64` sequence counter is used as the sort key rather than a
+//! string-sortable timestamp, since two operations logged in the same
+//! process can't tie under it the way two wall-clock timestamps taken in
+//! quick succession could; `checkpoint()`'s log-fencing is this design's
+//! equivalent of collapsing consecutive keep-state points down to the latest
+//! one. `recover()` rebuilds `inner`'s own state in place rather than
+//! returning a separate map, the same "wrap and mutate the inner backend"
+//! shape every other decorator in this module (`RecoveringBackend`,
+//! `CircuitBreakerBackend`, ...) uses.
+
+use super::CacheBackend;
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Take a full checkpoint after this many logged operations, absent an
+/// explicit [`LoggedBackend::with_checkpoint_every`] override.
+pub const KEEP_STATE_EVERY: u64 = 64;
+
+/// One logged mutation, replayed in [`LoggedBackend::recover`] to rebuild
+/// state past the last checkpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum Operation {
+    Set {
+        key: String,
+        value: Vec<u8>,
+        ttl_secs: Option<u64>,
+    },
+    Delete {
+        key: String,
+    },
+}
+
+/// A logged operation together with the sequence number it was appended
+/// under. See the module docs for why `seq` is a counter and not a
+/// timestamp.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct OperationRecord {
+    seq: u64,
+    op: Operation,
+}
+
+/// Pluggable destination for [`LoggedBackend`]'s operation log - a file, or
+/// another [`CacheBackend`] (e.g. a durable one backing an in-memory hot
+/// tier, mirroring [`super::HotColdBackend`]'s layering).
+///
+/// Records must come back from [`LogSink::replay_since`] in ascending `seq`
+/// order; implementations that can't guarantee that ordering natively (e.g.
+/// an unordered store) must sort before returning.
+#[allow(async_fn_in_trait)]
+pub trait LogSink: Send + Sync {
+    /// Durably append `record`. Must complete before the caller applies the
+    /// operation to the underlying backend - that's what makes it
+    /// *write-ahead*.
+    async fn append(&self, record: &OperationRecord) -> Result<()>;
+
+    /// All records with `seq > after_seq`, oldest first.
+    async fn replay_since(&self, after_seq: u64) -> Result<Vec<OperationRecord>>;
+
+    /// Drop every record with `seq <= checkpoint_seq` - safe once a
+    /// checkpoint has captured everything up to and including that sequence
+    /// number.
+    async fn truncate_before(&self, checkpoint_seq: u64) -> Result<()>;
+}
+
+fn encode_record(record: &OperationRecord) -> Result<Vec<u8>> {
+    postcard::to_allocvec(record).map_err(|e| Error::SerializationError(e.to_string()))
+}
+
+/// Append-only, length-prefixed operation log file.
+///
+/// Each record is written as a 4-byte little-endian length followed by its
+/// postcard encoding. [`LogSink::truncate_before`] rewrites the file via a
+/// temp-file-plus-rename, the same pattern
+/// [`InMemoryBackend::save_snapshot`](super::InMemoryBackend::save_snapshot)
+/// uses for its own file writes.
+pub struct FileLogSink {
+    path: PathBuf,
+    // Guards every read/write against the file so appends and
+    // truncate-and-rewrite never interleave.
+    lock: Mutex<()>,
+}
+
+impl FileLogSink {
+    /// Log to `path`, creating it on first append if it doesn't exist yet.
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        FileLogSink {
+            path: path.as_ref().to_path_buf(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    fn read_all_locked(&self) -> Result<Vec<OperationRecord>> {
+        let bytes = match std::fs::read(&self.path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut records = Vec::new();
+        let mut offset = 0;
+        while offset + 4 <= bytes.len() {
+            let len = u32::from_le_bytes(bytes[offset..offset + 4].try_into().expect("4 bytes")) as usize;
+            offset += 4;
+            if offset + len > bytes.len() {
+                warn!(
+                    "⚠ FileLogSink: {} has a truncated trailing record, ignoring it",
+                    self.path.display()
+                );
+                break;
+            }
+            let record: OperationRecord = postcard::from_bytes(&bytes[offset..offset + len])
+                .map_err(|e| Error::DeserializationError(e.to_string()))?;
+            offset += len;
+            records.push(record);
+        }
+        Ok(records)
+    }
+}
+
+impl LogSink for FileLogSink {
+    async fn append(&self, record: &OperationRecord) -> Result<()> {
+        let _guard = self.lock.lock().expect("lock poisoned");
+        let bytes = encode_record(record)?;
+        let mut file = std::fs::OpenOptions::new().create(true).append(true).open(&self.path)?;
+        file.write_all(&(bytes.len() as u32).to_le_bytes())?;
+        file.write_all(&bytes)?;
+        file.flush()?;
+        Ok(())
+    }
+
+    async fn replay_since(&self, after_seq: u64) -> Result<Vec<OperationRecord>> {
+        let _guard = self.lock.lock().expect("lock poisoned");
+        Ok(self
+            .read_all_locked()?
+            .into_iter()
+            .filter(|r| r.seq > after_seq)
+            .collect())
+    }
+
+    async fn truncate_before(&self, checkpoint_seq: u64) -> Result<()> {
+        let _guard = self.lock.lock().expect("lock poisoned");
+        let kept: Vec<OperationRecord> = self
+            .read_all_locked()?
+            .into_iter()
+            .filter(|r| r.seq > checkpoint_seq)
+            .collect();
+
+        let tmp_path = self.path.with_extension("tmp");
+        let mut tmp = std::fs::File::create(&tmp_path)?;
+        for record in &kept {
+            let bytes = encode_record(record)?;
+            tmp.write_all(&(bytes.len() as u32).to_le_bytes())?;
+            tmp.write_all(&bytes)?;
+        }
+        tmp.flush()?;
+        std::fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+/// A [`CacheBackend`] whose complete state can be serialized to, and
+/// restored from, a file - the capability [`LoggedBackend`] needs to take
+/// and recover from checkpoints.
+///
+/// [`InMemoryBackend`](super::InMemoryBackend) implements this in terms of
+/// its existing `save_snapshot`/`load_snapshot`.
+pub trait Checkpointable: CacheBackend {
+    /// Serialize the backend's full live state to `path`.
+    fn save_checkpoint(&self, path: &Path) -> Result<()>;
+
+    /// Restore state from `path`, written by a prior `save_checkpoint`. A
+    /// missing file is "nothing to restore", not an error - the same
+    /// contract as `InMemoryBackend::load_snapshot`.
+    fn load_checkpoint(&self, path: &Path) -> Result<()>;
+}
+
+impl Checkpointable for super::InMemoryBackend {
+    fn save_checkpoint(&self, path: &Path) -> Result<()> {
+        self.save_snapshot(path)
+    }
+
+    fn load_checkpoint(&self, path: &Path) -> Result<()> {
+        self.load_snapshot(path)
+    }
+}
+
+/// Wraps a [`Checkpointable`] backend with a write-ahead operation log, so
+/// [`LoggedBackend::recover`] can rebuild state after a restart instead of
+/// starting cold. See the module docs for the checkpoint+log design.
+///
+/// `S` is the log sink - [`FileLogSink`] by default, or any other
+/// [`LogSink`] (e.g. one backed by another `CacheBackend`).
+pub struct LoggedBackend<B, S = FileLogSink> {
+    inner: B,
+    sink: S,
+    checkpoint_path: PathBuf,
+    seq: AtomicU64,
+    since_checkpoint: AtomicU64,
+    checkpoint_every: u64,
+}
+
+impl<B: Checkpointable> LoggedBackend<B, FileLogSink> {
+    /// Wrap `inner`, logging operations to `log_path` and checkpointing full
+    /// state to `checkpoint_path` every [`KEEP_STATE_EVERY`] operations.
+    /// Call [`LoggedBackend::recover`] after construction to warm-start from
+    /// whatever's already on disk.
+    pub fn new(inner: B, log_path: impl AsRef<Path>, checkpoint_path: impl AsRef<Path>) -> Self {
+        LoggedBackend {
+            inner,
+            sink: FileLogSink::new(log_path),
+            checkpoint_path: checkpoint_path.as_ref().to_path_buf(),
+            seq: AtomicU64::new(0),
+            since_checkpoint: AtomicU64::new(0),
+            checkpoint_every: KEEP_STATE_EVERY,
+        }
+    }
+}
+
+impl<B: Checkpointable, S: LogSink> LoggedBackend<B, S> {
+    /// Wrap `inner` with a caller-supplied log `sink` instead of the default
+    /// [`FileLogSink`] - for logging to another `CacheBackend`, for example.
+    pub fn with_sink(inner: B, sink: S, checkpoint_path: impl AsRef<Path>) -> Self {
+        LoggedBackend {
+            inner,
+            sink,
+            checkpoint_path: checkpoint_path.as_ref().to_path_buf(),
+            seq: AtomicU64::new(0),
+            since_checkpoint: AtomicU64::new(0),
+            checkpoint_every: KEEP_STATE_EVERY,
+        }
+    }
+
+    /// Override how many operations accumulate between automatic
+    /// checkpoints (default [`KEEP_STATE_EVERY`]).
+    pub fn with_checkpoint_every(mut self, checkpoint_every: u64) -> Self {
+        self.checkpoint_every = checkpoint_every;
+        self
+    }
+
+    /// Borrow the wrapped backend.
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Restore state from the most recent checkpoint (if any), then replay
+    /// every logged operation after it. Call this once, right after
+    /// construction, before serving any traffic.
+    pub async fn recover(&self) -> Result<()> {
+        self.inner.load_checkpoint(&self.checkpoint_path)?;
+        let checkpoint_seq = read_checkpoint_seq(&self.checkpoint_path)?;
+
+        let mut records = self.sink.replay_since(checkpoint_seq).await?;
+        records.sort_by_key(|r| r.seq);
+
+        let mut max_seq = checkpoint_seq;
+        for record in &records {
+            self.apply(&record.op).await?;
+            max_seq = max_seq.max(record.seq);
+        }
+
+        self.seq.store(max_seq + 1, Ordering::SeqCst);
+        self.since_checkpoint.store(records.len() as u64, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Serialize the wrapped backend's current state and fence the log:
+    /// records up to and including the checkpoint's sequence number are
+    /// dropped, since they're now captured in the checkpoint itself.
+    pub async fn checkpoint(&self) -> Result<()> {
+        let checkpoint_seq = self.seq.load(Ordering::SeqCst).saturating_sub(1);
+        self.inner.save_checkpoint(&self.checkpoint_path)?;
+        write_checkpoint_seq(&self.checkpoint_path, checkpoint_seq)?;
+        self.sink.truncate_before(checkpoint_seq).await?;
+        self.since_checkpoint.store(0, Ordering::SeqCst);
+        Ok(())
+    }
+
+    async fn apply(&self, op: &Operation) -> Result<()> {
+        match op {
+            Operation::Set { key, value, ttl_secs } => {
+                self.inner
+                    .set(key, value.clone(), ttl_secs.map(Duration::from_secs))
+                    .await
+            }
+            Operation::Delete { key } => self.inner.delete(key).await,
+        }
+    }
+
+    async fn log_and_apply(&self, op: Operation) -> Result<()> {
+        let seq = self.seq.fetch_add(1, Ordering::SeqCst);
+        self.sink.append(&OperationRecord { seq, op: op.clone() }).await?;
+        self.apply(&op).await?;
+
+        if self.since_checkpoint.fetch_add(1, Ordering::SeqCst) + 1 >= self.checkpoint_every {
+            self.checkpoint().await?;
+        }
+        Ok(())
+    }
+}
+
+/// The checkpoint's sequence fence lives next to it as
+/// `{checkpoint_path}.seq` - a small, independently-written file so
+/// `Checkpointable::save_checkpoint`'s format doesn't need to know about
+/// sequence numbers at all.
+fn checkpoint_seq_path(checkpoint_path: &Path) -> PathBuf {
+    let mut file_name = checkpoint_path.as_os_str().to_owned();
+    file_name.push(".seq");
+    PathBuf::from(file_name)
+}
+
+fn read_checkpoint_seq(checkpoint_path: &Path) -> Result<u64> {
+    let path = checkpoint_seq_path(checkpoint_path);
+    match std::fs::read(&path) {
+        Ok(bytes) => postcard::from_bytes(&bytes).map_err(|e| Error::DeserializationError(e.to_string())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(0),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn write_checkpoint_seq(checkpoint_path: &Path, seq: u64) -> Result<()> {
+    let path = checkpoint_seq_path(checkpoint_path);
+    let bytes = postcard::to_allocvec(&seq).map_err(|e| Error::SerializationError(e.to_string()))?;
+    std::fs::write(path, bytes)?;
+    Ok(())
+}
+
+impl<B: Checkpointable, S: LogSink> CacheBackend for LoggedBackend<B, S> {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        self.inner.get(key).await
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<()> {
+        self.log_and_apply(Operation::Set {
+            key: key.to_string(),
+            value,
+            ttl_secs: ttl.map(|d| d.as_secs()),
+        })
+        .await
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.log_and_apply(Operation::Delete { key: key.to_string() }).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        self.inner.health_check().await
+    }
+
+    async fn clear_all(&self) -> Result<()> {
+        self.inner.clear_all().await
+    }
+}
+
+impl Clone for FileLogSink {
+    fn clone(&self) -> Self {
+        FileLogSink::new(&self.path)
+    }
+}
+
+impl<B: Clone, S: Clone> Clone for LoggedBackend<B, S> {
+    fn clone(&self) -> Self {
+        LoggedBackend {
+            inner: self.inner.clone(),
+            sink: self.sink.clone(),
+            checkpoint_path: self.checkpoint_path.clone(),
+            seq: AtomicU64::new(self.seq.load(Ordering::SeqCst)),
+            since_checkpoint: AtomicU64::new(self.since_checkpoint.load(Ordering::SeqCst)),
+            checkpoint_every: self.checkpoint_every,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::InMemoryBackend;
+
+    fn temp_paths(name: &str) -> (PathBuf, PathBuf) {
+        let dir = std::env::temp_dir();
+        let pid = std::process::id();
+        (
+            dir.join(format!("cache_kit_logged_{name}_{pid}.log")),
+            dir.join(format!("cache_kit_logged_{name}_{pid}.checkpoint")),
+        )
+    }
+
+    fn cleanup(log_path: &Path, checkpoint_path: &Path) {
+        let _ = std::fs::remove_file(log_path);
+        let _ = std::fs::remove_file(checkpoint_path);
+        let _ = std::fs::remove_file(checkpoint_seq_path(checkpoint_path));
+    }
+
+    #[tokio::test]
+    async fn test_recover_replays_operations_logged_since_last_checkpoint() {
+        let (log_path, checkpoint_path) = temp_paths("replay");
+        cleanup(&log_path, &checkpoint_path);
+
+        {
+            let backend = LoggedBackend::new(InMemoryBackend::new(), &log_path, &checkpoint_path)
+                .with_checkpoint_every(1000);
+            backend.recover().await.expect("Failed to recover");
+            backend.set("a", vec![1], None).await.expect("Failed to set");
+            backend.set("b", vec![2], None).await.expect("Failed to set");
+            backend.delete("a").await.expect("Failed to delete");
+        }
+
+        let restarted = LoggedBackend::new(InMemoryBackend::new(), &log_path, &checkpoint_path)
+            .with_checkpoint_every(1000);
+        restarted.recover().await.expect("Failed to recover");
+
+        assert_eq!(restarted.get("a").await.expect("Failed to get"), None);
+        assert_eq!(restarted.get("b").await.expect("Failed to get"), Some(vec![2]));
+
+        cleanup(&log_path, &checkpoint_path);
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_fences_the_log_so_replay_only_sees_newer_ops() {
+        let (log_path, checkpoint_path) = temp_paths("fence");
+        cleanup(&log_path, &checkpoint_path);
+
+        {
+            let backend = LoggedBackend::new(InMemoryBackend::new(), &log_path, &checkpoint_path)
+                .with_checkpoint_every(1000);
+            backend.recover().await.expect("Failed to recover");
+            backend.set("a", vec![1], None).await.expect("Failed to set");
+            backend.checkpoint().await.expect("Failed to checkpoint");
+            backend.set("b", vec![2], None).await.expect("Failed to set");
+        }
+
+        let records = FileLogSink::new(&log_path)
+            .replay_since(0)
+            .await
+            .expect("Failed to replay");
+        assert_eq!(records.len(), 1, "checkpointed record should have been truncated");
+
+        let restarted = LoggedBackend::new(InMemoryBackend::new(), &log_path, &checkpoint_path)
+            .with_checkpoint_every(1000);
+        restarted.recover().await.expect("Failed to recover");
+
+        assert_eq!(restarted.get("a").await.expect("Failed to get"), Some(vec![1]));
+        assert_eq!(restarted.get("b").await.expect("Failed to get"), Some(vec![2]));
+
+        cleanup(&log_path, &checkpoint_path);
+    }
+
+    #[tokio::test]
+    async fn test_automatic_checkpoint_after_configured_operation_count() {
+        let (log_path, checkpoint_path) = temp_paths("auto");
+        cleanup(&log_path, &checkpoint_path);
+
+        let backend =
+            LoggedBackend::new(InMemoryBackend::new(), &log_path, &checkpoint_path).with_checkpoint_every(2);
+        backend.recover().await.expect("Failed to recover");
+        backend.set("a", vec![1], None).await.expect("Failed to set");
+        backend.set("b", vec![2], None).await.expect("Failed to set");
+
+        let records = FileLogSink::new(&log_path)
+            .replay_since(0)
+            .await
+            .expect("Failed to replay");
+        assert!(records.is_empty(), "hitting checkpoint_every should have fenced the log");
+
+        cleanup(&log_path, &checkpoint_path);
+    }
+
+    #[tokio::test]
+    async fn test_recover_with_no_prior_state_starts_empty() {
+        let (log_path, checkpoint_path) = temp_paths("fresh");
+        cleanup(&log_path, &checkpoint_path);
+
+        let backend = LoggedBackend::new(InMemoryBackend::new(), &log_path, &checkpoint_path);
+        backend.recover().await.expect("Failed to recover");
+        assert_eq!(backend.get("missing").await.expect("Failed to get"), None);
+
+        cleanup(&log_path, &checkpoint_path);
+    }
+}
@@ -0,0 +1,451 @@
+//! Deterministic mock backend for testing code that talks to a [`CacheBackend`].
+//!
+//! Integration suites that need a real Redis/Memcached server often guard
+//! every test with an availability check and skip silently when one isn't
+//! running, which means CI can pass without ever exercising the cache logic.
+//! `MockBackend` is a full `CacheBackend` over an in-process map, driven by a
+//! [`VirtualClock`] so TTL expiration is instant and deterministic instead of
+//! requiring a real `tokio::time::sleep`, with the same failure-injection and
+//! call-recording affordances [`crate::repository::InMemoryRepository`]
+//! provides on the repository side.
+
+use super::CacheBackend;
+use crate::error::Result;
+use crate::repository::FailurePolicy;
+use dashmap::DashMap;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A controllable virtual clock for [`MockBackend`] TTL expiration.
+///
+/// Driving expiration off `Instant::now()` forces tests to either sleep past
+/// the real TTL (slow) or race the scheduler (flaky). `VirtualClock` tracks
+/// elapsed time as a plain counter that only moves when `advance` is called,
+/// so a test can jump straight past an entry's TTL in zero wall-clock time.
+#[derive(Clone, Default)]
+pub struct VirtualClock {
+    elapsed_nanos: Arc<AtomicU64>,
+}
+
+impl VirtualClock {
+    /// Create a new clock starting at time zero.
+    pub fn new() -> Self {
+        VirtualClock::default()
+    }
+
+    /// Move the clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        self.elapsed_nanos
+            .fetch_add(duration.as_nanos() as u64, Ordering::SeqCst);
+    }
+
+    /// Time elapsed since this clock was created.
+    pub fn now(&self) -> Duration {
+        Duration::from_nanos(self.elapsed_nanos.load(Ordering::SeqCst))
+    }
+}
+
+struct MockEntry {
+    data: Vec<u8>,
+    expires_at: Option<Duration>,
+}
+
+impl MockEntry {
+    fn is_expired(&self, clock: &VirtualClock) -> bool {
+        self.expires_at.is_some_and(|exp| clock.now() > exp)
+    }
+}
+
+/// Per-operation call counters recorded by [`MockBackend`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MockBackendStats {
+    pub get_calls: usize,
+    pub set_calls: usize,
+    pub delete_calls: usize,
+    pub mget_calls: usize,
+    pub mdelete_calls: usize,
+    pub mset_calls: usize,
+    pub exists_calls: usize,
+    pub clear_all_calls: usize,
+    pub health_check_calls: usize,
+}
+
+/// In-process [`CacheBackend`] for deterministic tests, with a controllable
+/// clock, fault injection, and a call recorder.
+///
+/// # Example
+///
+/// ```
+/// use cache_kit::backend::{CacheBackend, MockBackend};
+/// use std::time::Duration;
+///
+/// # async fn example() -> cache_kit::Result<()> {
+/// let backend = MockBackend::new();
+/// backend.set("user:1", b"alice".to_vec(), Some(Duration::from_secs(60))).await?;
+/// assert_eq!(backend.get("user:1").await?, Some(b"alice".to_vec()));
+///
+/// // Jump past the TTL without sleeping.
+/// backend.clock().advance(Duration::from_secs(61));
+/// assert_eq!(backend.get("user:1").await?, None);
+///
+/// assert_eq!(backend.stats().get_calls, 2);
+/// assert_eq!(backend.recorded_keys(), vec!["user:1", "user:1", "user:1"]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct MockBackend {
+    store: Arc<DashMap<String, MockEntry>>,
+    clock: VirtualClock,
+    failure_policy: Arc<Mutex<FailurePolicy>>,
+    calls_since_policy_set: Arc<AtomicUsize>,
+    latency: Arc<Mutex<Option<Duration>>>,
+    recorded_keys: Arc<Mutex<Vec<String>>>,
+    stats: Arc<Mutex<MockBackendStats>>,
+}
+
+impl MockBackend {
+    /// Create a new, empty mock backend with its own virtual clock.
+    pub fn new() -> Self {
+        MockBackend {
+            store: Arc::new(DashMap::new()),
+            clock: VirtualClock::new(),
+            failure_policy: Arc::new(Mutex::new(FailurePolicy::None)),
+            calls_since_policy_set: Arc::new(AtomicUsize::new(0)),
+            latency: Arc::new(Mutex::new(None)),
+            recorded_keys: Arc::new(Mutex::new(Vec::new())),
+            stats: Arc::new(Mutex::new(MockBackendStats::default())),
+        }
+    }
+
+    /// Borrow this backend's [`VirtualClock`], to advance time past a TTL
+    /// without sleeping.
+    pub fn clock(&self) -> &VirtualClock {
+        &self.clock
+    }
+
+    /// Install a failure policy applied to every subsequent operation (get,
+    /// set, delete, exists, mget, mdelete, mset, clear_all, health_check).
+    ///
+    /// Replacing the policy resets the `AfterCalls` counter.
+    pub fn set_failure(&self, policy: FailurePolicy) {
+        *self.failure_policy.lock().expect("lock poisoned") = policy;
+        self.calls_since_policy_set.store(0, Ordering::SeqCst);
+    }
+
+    /// Clear any failure policy and artificial latency, returning the
+    /// backend to normal operation. Stored entries and call stats are left
+    /// untouched.
+    pub fn clear_failures(&self) {
+        *self.failure_policy.lock().expect("lock poisoned") = FailurePolicy::None;
+        self.calls_since_policy_set.store(0, Ordering::SeqCst);
+        *self.latency.lock().expect("lock poisoned") = None;
+    }
+
+    /// Make every subsequent operation sleep for `delay` before resolving,
+    /// to simulate a slow backend.
+    pub fn set_latency(&self, delay: Duration) {
+        *self.latency.lock().expect("lock poisoned") = Some(delay);
+    }
+
+    /// Every key passed to `get`/`set`/`delete`/`exists`, in call order,
+    /// since creation or the last `reset_stats()`. `mget`/`mdelete` record
+    /// each key they were given individually.
+    pub fn recorded_keys(&self) -> Vec<String> {
+        self.recorded_keys.lock().expect("lock poisoned").clone()
+    }
+
+    /// Per-operation call counts since creation or the last `reset_stats()`.
+    pub fn stats(&self) -> MockBackendStats {
+        *self.stats.lock().expect("lock poisoned")
+    }
+
+    /// Reset call counters and the recorded key log. Stored entries, the
+    /// clock, and any configured failure policy are left untouched.
+    pub fn reset_stats(&self) {
+        *self.stats.lock().expect("lock poisoned") = MockBackendStats::default();
+        self.recorded_keys.lock().expect("lock poisoned").clear();
+    }
+
+    fn record_key(&self, key: &str) {
+        self.recorded_keys
+            .lock()
+            .expect("lock poisoned")
+            .push(key.to_string());
+    }
+
+    /// Apply any configured artificial latency, then decide whether this
+    /// call should fail per the installed [`FailurePolicy`].
+    async fn check_failure(&self) -> Result<()> {
+        let delay = *self.latency.lock().expect("lock poisoned");
+        if let Some(delay) = delay {
+            tokio::time::sleep(delay).await;
+        }
+
+        let policy = self.failure_policy.lock().expect("lock poisoned").clone();
+        match policy {
+            FailurePolicy::None => Ok(()),
+            FailurePolicy::Always(error) => Err(error),
+            FailurePolicy::AfterCalls { after_calls, error } => {
+                let calls = self.calls_since_policy_set.fetch_add(1, Ordering::SeqCst);
+                if calls >= after_calls {
+                    Err(error)
+                } else {
+                    Ok(())
+                }
+            }
+            FailurePolicy::Probabilistic { probability, error } => {
+                if next_unit_f64(&self.calls_since_policy_set) < probability {
+                    Err(error)
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+}
+
+/// Deterministic pseudo-randomness for `FailurePolicy::Probabilistic`,
+/// derived from the same call counter `AfterCalls` uses rather than a
+/// separate RNG field - good enough for "fail roughly X% of the time" in a
+/// test double, and keeps `MockBackend` free of an extra piece of state.
+fn next_unit_f64(counter: &AtomicUsize) -> f64 {
+    let calls = counter.fetch_add(1, Ordering::SeqCst) as u64;
+    let mut x = calls.wrapping_mul(0x9E3779B97F4A7C15).wrapping_add(1);
+    x ^= x >> 33;
+    x = x.wrapping_mul(0xFF51AFD7ED558CCD);
+    x ^= x >> 33;
+    (x >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+}
+
+impl Default for MockBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CacheBackend for MockBackend {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        self.stats.lock().expect("lock poisoned").get_calls += 1;
+        self.record_key(key);
+        self.check_failure().await?;
+
+        if let Some(entry) = self.store.get(key) {
+            if !entry.is_expired(&self.clock) {
+                return Ok(Some(entry.data.clone()));
+            }
+        }
+        self.store.remove(key);
+        Ok(None)
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<()> {
+        self.stats.lock().expect("lock poisoned").set_calls += 1;
+        self.record_key(key);
+        self.check_failure().await?;
+
+        let expires_at = ttl.map(|d| self.clock.now() + d);
+        self.store
+            .insert(key.to_string(), MockEntry { data: value, expires_at });
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.stats.lock().expect("lock poisoned").delete_calls += 1;
+        self.record_key(key);
+        self.check_failure().await?;
+
+        self.store.remove(key);
+        Ok(())
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool> {
+        self.stats.lock().expect("lock poisoned").exists_calls += 1;
+        self.record_key(key);
+        self.check_failure().await?;
+
+        Ok(self
+            .store
+            .get(key)
+            .is_some_and(|entry| !entry.is_expired(&self.clock)))
+    }
+
+    async fn mget(&self, keys: &[&str]) -> Result<Vec<Option<Vec<u8>>>> {
+        self.stats.lock().expect("lock poisoned").mget_calls += 1;
+        for key in keys {
+            self.record_key(key);
+        }
+        self.check_failure().await?;
+
+        Ok(keys
+            .iter()
+            .map(|key| {
+                self.store.get(*key).and_then(|entry| {
+                    if entry.is_expired(&self.clock) {
+                        None
+                    } else {
+                        Some(entry.data.clone())
+                    }
+                })
+            })
+            .collect())
+    }
+
+    async fn mdelete(&self, keys: &[&str]) -> Result<()> {
+        self.stats.lock().expect("lock poisoned").mdelete_calls += 1;
+        for key in keys {
+            self.record_key(key);
+        }
+        self.check_failure().await?;
+
+        for key in keys {
+            self.store.remove(*key);
+        }
+        Ok(())
+    }
+
+    async fn mset(&self, entries: &[(&str, Vec<u8>, Option<Duration>)]) -> Result<()> {
+        self.stats.lock().expect("lock poisoned").mset_calls += 1;
+        for (key, _, _) in entries {
+            self.record_key(key);
+        }
+        self.check_failure().await?;
+
+        for (key, value, ttl) in entries {
+            let expires_at = ttl.map(|d| self.clock.now() + d);
+            self.store.insert(
+                key.to_string(),
+                MockEntry {
+                    data: value.clone(),
+                    expires_at,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        self.stats.lock().expect("lock poisoned").health_check_calls += 1;
+        self.check_failure().await?;
+        Ok(true)
+    }
+
+    async fn clear_all(&self) -> Result<()> {
+        self.stats.lock().expect("lock poisoned").clear_all_calls += 1;
+        self.check_failure().await?;
+
+        self.store.clear();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    #[tokio::test]
+    async fn test_mock_backend_set_get() {
+        let backend = MockBackend::new();
+        backend.set("key1", b"value1".to_vec(), None).await.expect("Failed to set");
+        assert_eq!(backend.get("key1").await.expect("Failed to get"), Some(b"value1".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn test_mock_backend_virtual_clock_expires_ttl_without_sleeping() {
+        let backend = MockBackend::new();
+        backend
+            .set("key1", b"value1".to_vec(), Some(Duration::from_secs(30)))
+            .await
+            .expect("Failed to set");
+
+        assert!(backend.get("key1").await.expect("Failed to get").is_some());
+
+        backend.clock().advance(Duration::from_secs(31));
+
+        assert_eq!(backend.get("key1").await.expect("Failed to get"), None);
+    }
+
+    #[tokio::test]
+    async fn test_mock_backend_records_keys_and_call_counts() {
+        let backend = MockBackend::new();
+        backend.set("a", b"1".to_vec(), None).await.expect("Failed to set");
+        backend.get("a").await.expect("Failed to get");
+        backend.get("b").await.expect("Failed to get");
+
+        let stats = backend.stats();
+        assert_eq!(stats.set_calls, 1);
+        assert_eq!(stats.get_calls, 2);
+        assert_eq!(backend.recorded_keys(), vec!["a", "a", "b"]);
+    }
+
+    #[tokio::test]
+    async fn test_mock_backend_reset_stats_clears_counters_not_data() {
+        let backend = MockBackend::new();
+        backend.set("a", b"1".to_vec(), None).await.expect("Failed to set");
+        backend.reset_stats();
+
+        assert_eq!(backend.stats(), MockBackendStats::default());
+        assert_eq!(backend.get("a").await.expect("Failed to get"), Some(b"1".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn test_mock_backend_always_failure_policy_errors_every_call() {
+        let backend = MockBackend::new();
+        backend.set_failure(FailurePolicy::Always(Error::BackendError("down".to_string())));
+
+        let result = backend.get("key1").await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_mock_backend_after_calls_failure_policy_fails_once_exhausted() {
+        let backend = MockBackend::new();
+        backend.set_failure(FailurePolicy::AfterCalls {
+            after_calls: 2,
+            error: Error::BackendError("down".to_string()),
+        });
+
+        backend.get("key1").await.expect("First call should succeed");
+        backend.get("key1").await.expect("Second call should succeed");
+        assert!(backend.get("key1").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_mock_backend_clear_failures_restores_normal_operation() {
+        let backend = MockBackend::new();
+        backend.set_failure(FailurePolicy::Always(Error::BackendError("down".to_string())));
+        backend.clear_failures();
+
+        backend.set("key1", b"value1".to_vec(), None).await.expect("Failed to set");
+        assert_eq!(backend.get("key1").await.expect("Failed to get"), Some(b"value1".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn test_mock_backend_mget_mdelete() {
+        let backend = MockBackend::new();
+        backend.set("a", b"1".to_vec(), None).await.expect("Failed to set");
+        backend.set("b", b"2".to_vec(), None).await.expect("Failed to set");
+
+        let results = backend.mget(&["a", "b", "c"]).await.expect("Failed to mget");
+        assert_eq!(results, vec![Some(b"1".to_vec()), Some(b"2".to_vec()), None]);
+
+        backend.mdelete(&["a", "b"]).await.expect("Failed to mdelete");
+        assert_eq!(backend.get("a").await.expect("Failed to get"), None);
+    }
+
+    #[tokio::test]
+    async fn test_mock_backend_mset_writes_all_entries_in_one_call() {
+        let backend = MockBackend::new();
+        backend
+            .mset(&[("a", b"1".to_vec(), None), ("b", b"2".to_vec(), None)])
+            .await
+            .expect("Failed to mset");
+
+        assert_eq!(backend.get("a").await.expect("Failed to get"), Some(b"1".to_vec()));
+        assert_eq!(backend.get("b").await.expect("Failed to get"), Some(b"2".to_vec()));
+        assert_eq!(backend.stats().mset_calls, 1);
+        assert_eq!(backend.stats().set_calls, 0);
+    }
+}
@@ -1,19 +1,71 @@
 //! Cache backend implementations.
 
 use crate::error::Result;
+use std::future::Future;
 use std::time::Duration;
 
+pub mod circuit_breaker;
+#[cfg(feature = "compression")]
+pub mod compressed;
+#[cfg(feature = "content-addressing")]
+pub mod content_addressed;
+pub mod disabled;
+#[cfg(feature = "encryption")]
+pub mod encrypting;
+pub mod hotcold;
 pub mod inmemory;
+pub mod instrumented;
+pub mod logged;
 #[cfg(feature = "memcached")]
 pub mod memcached;
+pub mod mock;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+pub mod recovering;
 #[cfg(feature = "redis")]
 pub mod redis;
+#[cfg(feature = "rocksdb")]
+pub mod rocksdb;
+pub mod scoped;
+pub mod selectable;
+#[cfg(feature = "sled")]
+pub mod sled;
+pub mod tiered;
 
-pub use inmemory::InMemoryBackend;
+pub use circuit_breaker::{CircuitBreakerBackend, CircuitBreakerConfig};
+#[cfg(feature = "compression")]
+pub use compressed::CompressedBackend;
+#[cfg(feature = "content-addressing")]
+pub use content_addressed::{ContentAddressedBackend, DedupStats};
+pub use disabled::DisabledBackend;
+#[cfg(feature = "encryption")]
+pub use encrypting::{EncryptingBackend, KeyProvider, StaticKeyProvider};
+pub use hotcold::HotColdBackend;
+pub use inmemory::{EvictionPolicy, InMemoryBackend, RemovalCause, Weigher};
+pub use instrumented::{AccessCounts, BackendStatsSnapshot, CacheStats, InstrumentedBackend, OpStatsRow};
+pub use logged::{Checkpointable, FileLogSink, LogSink, LoggedBackend, KEEP_STATE_EVERY};
 #[cfg(feature = "memcached")]
-pub use memcached::{MemcachedBackend, MemcachedConfig};
+pub use memcached::{MemcachedBackend, MemcachedConfig, MemcachedPoolStats};
+#[cfg(all(feature = "memcached", feature = "memcached-tls"))]
+pub use memcached::TlsConfig;
+pub use mock::{MockBackend, MockBackendStats, VirtualClock};
+#[cfg(feature = "postgres")]
+pub use postgres::{PostgresBackend, PostgresConfig};
+pub use recovering::{RecoveringBackend, RecoveryPolicy};
 #[cfg(feature = "redis")]
-pub use redis::{PoolStats, RedisBackend, RedisConfig};
+pub use redis::{
+    hash_slot, CachedScript, ClusterTopology, Decision, DistributedLock, LockGuard, PoolStats,
+    RateLimiter, RedisBackend, RedisConfig, RedisInvalidationBus, SentinelConfig,
+};
+#[cfg(feature = "redis-cluster")]
+pub use redis::{RedisClusterBackend, RedisClusterConfig};
+#[cfg(feature = "rocksdb")]
+pub use rocksdb::{RocksDbBackend, RocksDbConfig};
+pub use scoped::ScopedBackend;
+pub use selectable::{Backend, CacheFactoryConfig};
+#[cfg(feature = "sled")]
+pub use sled::{SledBackend, SledConfig};
+pub use tiered::TieredBackend;
 
 /// Trait for cache backend implementations.
 ///
@@ -90,6 +142,155 @@ pub trait CacheBackend: Send + Sync + Clone {
         Ok(())
     }
 
+    /// Bulk set operation (optional optimization), mirroring how `mget`
+    /// coalesces reads. This is the batched-write primitive for warming many
+    /// keys at once (sometimes called `set_many` elsewhere).
+    ///
+    /// `entries` is `(key, value, ttl)` triples. Default implementation calls
+    /// `set()` for each entry - one round-trip per entry. Override for batch
+    /// efficiency (e.g., a Redis pipeline), which cuts N round-trips down to
+    /// one flush. `RedisBackend` does exactly that, queuing a `SET`/`SETEX`
+    /// per entry into one `redis::pipe()` flush.
+    ///
+    /// # Errors
+    /// Returns `Err` if backend error occurs
+    async fn mset(&self, entries: &[(&str, Vec<u8>, Option<Duration>)]) -> Result<()> {
+        for (key, value, ttl) in entries {
+            self.set(key, value.clone(), *ttl).await?;
+        }
+        Ok(())
+    }
+
+    /// Re-arm a key's TTL without rewriting its value, for
+    /// [`crate::observability::TtlPolicy::Sliding`] (refresh-on-read).
+    ///
+    /// Default implementation round-trips through `get`/`set` since not
+    /// every backend has a native "touch" primitive; override when one
+    /// exists (e.g. Redis `EXPIRE`) to avoid resending the value.
+    ///
+    /// A missing key is not an error - there's nothing to re-arm.
+    ///
+    /// # Errors
+    /// Returns `Err` if backend error occurs
+    async fn expire(&self, key: &str, ttl: Duration) -> Result<()> {
+        if let Some(value) = self.get(key).await? {
+            self.set(key, value, Some(ttl)).await?;
+        }
+        Ok(())
+    }
+
+    /// Read `key`'s remaining time-to-live, if it has one - the read-side
+    /// counterpart to [`CacheBackend::expire`], for callers that need to
+    /// judge a hit's freshness (e.g. a "minimum remaining life" guard that
+    /// treats an about-to-expire hit as a miss) rather than just its
+    /// presence. `Ok(None)` covers both "no such key" and "key exists but
+    /// carries no TTL" - backends with no native TTL introspection should
+    /// return `Err(Error::NotImplemented)` instead of guessing.
+    ///
+    /// # Errors
+    /// Returns `Err(Error::NotImplemented)` for backends with no native way
+    /// to read a key's remaining TTL.
+    async fn ttl(&self, _key: &str) -> Result<Option<Duration>> {
+        Err(crate::error::Error::NotImplemented(
+            "ttl not implemented for this backend".to_string(),
+        ))
+    }
+
+    /// Read `key`, running `init` to produce and store a value on a miss -
+    /// "compute if absent" for raw cache bytes, the backend-level analogue of
+    /// [`crate::service::CacheService::get_or_load`].
+    ///
+    /// Default implementation does **not** coalesce concurrent misses: each
+    /// caller that sees a miss runs `init` independently, so N callers racing
+    /// on a cold key cause N calls to `init` ("cache stampede"). Override
+    /// this when the backend can cheaply serialize misses per key -
+    /// [`InMemoryBackend`] does, the same way `mget`/`mset` are overridden
+    /// there for batch efficiency.
+    ///
+    /// If `init` errors, nothing is stored and the error is returned as-is.
+    ///
+    /// # Errors
+    /// Returns `Err` if the backend errors, or if `init` does.
+    async fn get_with<F, Fut>(&self, key: &str, init: F, ttl: Option<Duration>) -> Result<Vec<u8>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Vec<u8>>>,
+    {
+        if let Some(value) = self.get(key).await? {
+            return Ok(value);
+        }
+
+        let value = init().await?;
+        self.set(key, value.clone(), ttl).await?;
+        Ok(value)
+    }
+
+    /// Store a payload that may arrive as a stream of chunks instead of one
+    /// fully-materialized buffer, for large entities (file bodies, rendered
+    /// documents) a caller doesn't want to hold in memory all at once.
+    ///
+    /// Default implementation buffers the whole thing via
+    /// [`crate::streaming::CacheData::into_bytes`] and calls `set()` - no
+    /// memory savings, just API parity. Override when the backend can accept
+    /// chunks natively (e.g. writing each chunk to its own key) to actually
+    /// bound peak memory.
+    ///
+    /// # Errors
+    /// Returns `Err` if backend error occurs, or if `data` is a stream that
+    /// yields one.
+    async fn set_stream(
+        &self,
+        key: &str,
+        data: crate::streaming::CacheData,
+        ttl: Option<Duration>,
+    ) -> Result<()> {
+        let bytes = data.into_bytes().await?;
+        self.set(key, bytes, ttl).await
+    }
+
+    /// Read a value back as a [`crate::streaming::CacheData`] instead of one
+    /// fully-materialized buffer.
+    ///
+    /// Default implementation reads the whole value via `get()` then slices
+    /// it into `chunk_size`-sized pieces delivered over a channel - no memory
+    /// savings on this backend's read, but callers downstream can still
+    /// process the result chunk by chunk. Override when the backend can read
+    /// chunks natively.
+    ///
+    /// # Errors
+    /// Returns `Err` if backend error occurs
+    async fn get_stream(
+        &self,
+        key: &str,
+        chunk_size: usize,
+    ) -> Result<Option<crate::streaming::CacheData>> {
+        match self.get(key).await? {
+            Some(bytes) => Ok(Some(crate::streaming::CacheData::chunked(bytes, chunk_size))),
+            None => Ok(None),
+        }
+    }
+
+    /// Bulk [`CacheBackend::get_stream`], mirroring how [`CacheBackend::mget`]
+    /// batches [`CacheBackend::get`].
+    ///
+    /// Default implementation calls `get_stream()` for each key - no batching,
+    /// just API parity. Override for backends that can read several keys'
+    /// streams concurrently or in one round trip.
+    ///
+    /// # Errors
+    /// Returns `Err` if backend error occurs
+    async fn mget_stream(
+        &self,
+        keys: &[&str],
+        chunk_size: usize,
+    ) -> Result<Vec<Option<crate::streaming::CacheData>>> {
+        let mut results = Vec::with_capacity(keys.len());
+        for key in keys {
+            results.push(self.get_stream(key, chunk_size).await?);
+        }
+        Ok(results)
+    }
+
     /// Health check - verify backend is accessible.
     ///
     /// Used for readiness probes, circuit breakers, etc.
@@ -109,6 +310,148 @@ pub trait CacheBackend: Send + Sync + Clone {
             "clear_all not implemented for this backend".to_string(),
         ))
     }
+
+    /// Invalidate every key under `prefix` (the `CacheEntity::cache_prefix()` namespace).
+    ///
+    /// Use this to flush an entire entity class, e.g. every `product` entry
+    /// after a bulk import, instead of tracking and deleting each key by hand.
+    ///
+    /// # Errors
+    /// Returns `Err` if operation is not implemented or fails
+    async fn invalidate_prefix(&self, _prefix: &str) -> Result<()> {
+        Err(crate::error::Error::NotImplemented(
+            "invalidate_prefix not implemented for this backend".to_string(),
+        ))
+    }
+
+    /// Store a value like [`CacheBackend::set`], additionally recording it under
+    /// each of `tags` so it can later be evicted as a group via [`CacheBackend::invalidate_tag`].
+    ///
+    /// Use tags for invalidation groups that cut across prefixes, e.g. every
+    /// invoice belonging to one customer.
+    ///
+    /// Default implementation ignores `tags` and behaves exactly like `set`;
+    /// backends that support tagging should override both this and `invalidate_tag`.
+    ///
+    /// # Errors
+    /// Returns `Err` if backend error occurs
+    async fn set_with_tags(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        ttl: Option<Duration>,
+        tags: &[&str],
+    ) -> Result<()> {
+        let _ = tags;
+        self.set(key, value, ttl).await
+    }
+
+    /// Invalidate every key previously stored under `tag` via [`CacheBackend::set_with_tags`].
+    ///
+    /// # Errors
+    /// Returns `Err` if operation is not implemented or fails
+    async fn invalidate_tag(&self, _tag: &str) -> Result<()> {
+        Err(crate::error::Error::NotImplemented(
+            "invalidate_tag not implemented for this backend".to_string(),
+        ))
+    }
+
+    /// List every key stored under `prefix` (the same `"{prefix}:"` namespace
+    /// convention as [`CacheBackend::invalidate_prefix`]), without deleting
+    /// anything.
+    ///
+    /// Use this to inspect or batch-process a key family before deciding what
+    /// to do with it; [`CacheBackend::delete_prefix`] is the version that
+    /// also deletes.
+    ///
+    /// # Errors
+    /// Returns `Err` if the backend cannot enumerate its keys.
+    async fn scan_prefix(&self, _prefix: &str) -> Result<Vec<String>> {
+        Err(crate::error::Error::NotImplemented(
+            "scan_prefix not implemented for this backend".to_string(),
+        ))
+    }
+
+    /// Delete every key under `prefix`, returning how many were removed.
+    ///
+    /// Default implementation calls [`CacheBackend::scan_prefix`] then
+    /// [`CacheBackend::mdelete`] - two round-trips instead of one, so
+    /// backends that can delete-while-scanning (e.g. Redis pairing `SCAN`
+    /// with `DEL` per batch) should override this directly.
+    ///
+    /// # Errors
+    /// Returns `Err` if the backend cannot enumerate its keys, or if the
+    /// delete fails.
+    async fn delete_prefix(&self, prefix: &str) -> Result<u64> {
+        let keys = self.scan_prefix(prefix).await?;
+        let key_refs: Vec<&str> = keys.iter().map(String::as_str).collect();
+        self.mdelete(&key_refs).await?;
+        Ok(key_refs.len() as u64)
+    }
+
+    /// Read `key` together with an opaque CAS ("check-and-set") token that
+    /// changes every time the value does - memcached's `gets` unique id.
+    /// Pair with [`CacheBackend::cas`] for a read-modify-write that detects
+    /// (instead of silently losing to) a concurrent writer.
+    ///
+    /// # Errors
+    /// Returns `Err(Error::NotImplemented)` for backends with no native CAS
+    /// primitive.
+    async fn gets(&self, _key: &str) -> Result<Option<(Vec<u8>, u64)>> {
+        Err(crate::error::Error::NotImplemented(
+            "gets not implemented for this backend".to_string(),
+        ))
+    }
+
+    /// Store `value` at `key` only if its CAS token (from [`CacheBackend::gets`])
+    /// still matches `cas_token` - i.e. nothing has written to `key` since it
+    /// was read. Returns `Ok(false)` on a token mismatch (someone else won the
+    /// race) rather than an error, the same "didn't happen, here's why" shape
+    /// as a Memcached backend's `add`/`replace`.
+    ///
+    /// # Errors
+    /// Returns `Err(Error::NotImplemented)` for backends with no native CAS
+    /// primitive.
+    async fn cas(&self, _key: &str, _value: Vec<u8>, _ttl: Option<Duration>, _cas_token: u64) -> Result<bool> {
+        Err(crate::error::Error::NotImplemented(
+            "cas not implemented for this backend".to_string(),
+        ))
+    }
+
+    /// Atomically add `delta` to the counter stored at `key`, returning the
+    /// new value. On a miss (`key` doesn't exist, or has expired), stores
+    /// `init` with `ttl` and returns it instead of erroring - implementations
+    /// must do this with the same race safety as memcached's `ADD` (whichever
+    /// concurrent caller's initialization wins, the other sees its result
+    /// rather than clobbering it), not a separate exists-check-then-set.
+    ///
+    /// This is the core primitive for rate limiters and hit counters, which
+    /// need a mutate-and-read that never needs a read-modify-write race with
+    /// another caller.
+    ///
+    /// # Errors
+    /// Returns `Err(Error::NotImplemented)` for backends with no native
+    /// atomic counter primitive. Returns `Err` if `key` holds a value that
+    /// isn't a valid counter.
+    async fn incr(&self, _key: &str, _delta: u64, _init: u64, _ttl: Option<Duration>) -> Result<u64> {
+        Err(crate::error::Error::NotImplemented(
+            "incr not implemented for this backend".to_string(),
+        ))
+    }
+
+    /// Like [`CacheBackend::incr`], subtracting `delta` instead of adding it.
+    /// Clamps at 0 rather than going negative, matching memcached's own
+    /// `DECR` semantics.
+    ///
+    /// # Errors
+    /// Returns `Err(Error::NotImplemented)` for backends with no native
+    /// atomic counter primitive. Returns `Err` if `key` holds a value that
+    /// isn't a valid counter.
+    async fn decr(&self, _key: &str, _delta: u64, _init: u64, _ttl: Option<Duration>) -> Result<u64> {
+        Err(crate::error::Error::NotImplemented(
+            "decr not implemented for this backend".to_string(),
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -128,4 +471,140 @@ mod tests {
             .await
             .expect("Failed to check exists"));
     }
+
+    #[tokio::test]
+    async fn test_default_set_stream_and_get_stream_round_trip() {
+        use crate::streaming::CacheData;
+
+        let backend = crate::backend::MockBackend::new();
+        let original: Vec<u8> = (0..200).collect();
+
+        backend
+            .set_stream("blob", CacheData::chunked(original.clone(), 32), None)
+            .await
+            .expect("Failed to set_stream");
+
+        let data = backend
+            .get_stream("blob", 32)
+            .await
+            .expect("Failed to get_stream")
+            .expect("Expected a hit");
+        assert_eq!(data.into_bytes().await.expect("Failed to collect"), original);
+    }
+
+    #[tokio::test]
+    async fn test_default_mget_stream_reads_each_key() {
+        let backend = InMemoryBackend::new();
+        backend.set("a", vec![1, 2], None).await.expect("Failed to set");
+        backend.set("b", vec![3, 4], None).await.expect("Failed to set");
+
+        let results = backend
+            .mget_stream(&["a", "b", "missing"], 32)
+            .await
+            .expect("Failed to mget_stream");
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0].as_ref().expect("Expected a hit").size_hint(),
+            Some(2)
+        );
+        assert_eq!(
+            results[1].as_ref().expect("Expected a hit").size_hint(),
+            Some(2)
+        );
+        assert!(results[2].is_none());
+    }
+
+    #[tokio::test]
+    async fn test_default_scan_prefix_is_not_implemented() {
+        let backend = crate::backend::MockBackend::new();
+        let result = backend.scan_prefix("anything").await;
+        assert!(matches!(result, Err(crate::error::Error::NotImplemented(_))));
+    }
+
+    #[tokio::test]
+    async fn test_default_delete_prefix_propagates_scan_prefix_error() {
+        let backend = crate::backend::MockBackend::new();
+        let result = backend.delete_prefix("anything").await;
+        assert!(matches!(result, Err(crate::error::Error::NotImplemented(_))));
+    }
+
+    #[tokio::test]
+    async fn test_default_delete_prefix_uses_scan_prefix_and_mdelete() {
+        let backend = InMemoryBackend::new();
+        backend.set("widget:1", vec![1], None).await.expect("Failed to set");
+        backend.set("widget:2", vec![2], None).await.expect("Failed to set");
+
+        let deleted = backend.delete_prefix("widget").await.expect("Failed to delete prefix");
+        assert_eq!(deleted, 2);
+        assert_eq!(backend.get("widget:1").await.expect("Failed to get"), None);
+    }
+
+    #[tokio::test]
+    async fn test_default_get_with_runs_init_on_miss_and_stores_result() {
+        let backend = crate::backend::MockBackend::new();
+        let value = backend
+            .get_with("key", || async { Ok(vec![1, 2, 3]) }, None)
+            .await
+            .expect("Failed to get_with");
+        assert_eq!(value, vec![1, 2, 3]);
+        assert_eq!(backend.get("key").await.expect("Failed to get"), Some(vec![1, 2, 3]));
+    }
+
+    #[tokio::test]
+    async fn test_default_get_with_skips_init_on_hit() {
+        let backend = crate::backend::MockBackend::new();
+        backend.set("key", vec![9], None).await.expect("Failed to set");
+        let value = backend
+            .get_with("key", || async { panic!("init should not run on a cache hit") }, None)
+            .await
+            .expect("Failed to get_with");
+        assert_eq!(value, vec![9]);
+    }
+
+    #[tokio::test]
+    async fn test_default_get_with_does_not_coalesce_concurrent_misses() {
+        // The default implementation has no stampede protection: every
+        // concurrent miss runs `init` independently. Force real interleaving
+        // with artificial latency on the read, so every task observes a miss
+        // before any of them has stored a value - this pins that documented
+        // limitation so a future change to the default doesn't silently
+        // start coalescing without updating the doc comment.
+        let backend = std::sync::Arc::new(crate::backend::MockBackend::new());
+        backend.set_latency(Duration::from_millis(20));
+        let init_count = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+        let mut handles = vec![];
+        for _ in 0..5 {
+            let backend = backend.clone();
+            let init_count = init_count.clone();
+            handles.push(tokio::spawn(async move {
+                backend
+                    .get_with(
+                        "stampede",
+                        || async move {
+                            init_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                            Ok(vec![1])
+                        },
+                        None,
+                    )
+                    .await
+            }));
+        }
+
+        for handle in handles {
+            handle.await.expect("Task failed").expect("Failed to get_with");
+        }
+
+        assert_eq!(init_count.load(std::sync::atomic::Ordering::SeqCst), 5);
+    }
+
+    #[tokio::test]
+    async fn test_default_get_stream_miss_returns_none() {
+        let backend = InMemoryBackend::new();
+        assert!(backend
+            .get_stream("missing", 32)
+            .await
+            .expect("get_stream should not error on a miss")
+            .is_none());
+    }
 }
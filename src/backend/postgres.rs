@@ -0,0 +1,244 @@
+//! Postgres-backed cache backend.
+//!
+//! Stores serialized [`crate::entity::CacheEntity`] values in a single table
+//! keyed by the cache key (`"{cache_prefix}:{id}"`), using `sqlx`'s
+//! `PgPool` for connection pooling - the same crate
+//! [`crate::invalidation::postgres::PgInvalidator`] already depends on for
+//! `LISTEN/NOTIFY`, so a deployment wired up for Postgres-driven
+//! invalidation doesn't pull in a second, differently-pooled Postgres
+//! client just to store values here too.
+//!
+//! Unlike [`super::InMemoryBackend`], entries survive process restarts;
+//! unlike [`super::RedisBackend`], no separate cache server is needed when
+//! the application already runs against Postgres.
+
+use super::CacheBackend;
+use crate::error::Result;
+use sqlx::postgres::{PgPool, PgPoolOptions};
+use sqlx::Row;
+use std::time::Duration;
+
+/// Tuning knobs for [`PostgresBackend::connect`].
+#[derive(Debug, Clone)]
+pub struct PostgresConfig {
+    /// `postgres://` connection string.
+    pub database_url: String,
+    /// Name of the table entries are stored in. Created automatically on
+    /// [`PostgresBackend::connect`] if it doesn't exist.
+    pub table: String,
+    /// Maximum number of pooled connections.
+    pub max_connections: u32,
+}
+
+impl PostgresConfig {
+    /// Build a config from a `DATABASE_URL`-style connection string, using
+    /// the default table name and pool size.
+    pub fn from_database_url(database_url: impl Into<String>) -> Self {
+        PostgresConfig {
+            database_url: database_url.into(),
+            table: "cache_kit_entries".to_string(),
+            max_connections: 10,
+        }
+    }
+}
+
+/// Postgres-backed persistent cache backend.
+///
+/// # Example
+///
+/// ```no_run
+/// # use cache_kit::backend::{PostgresBackend, PostgresConfig, CacheBackend};
+/// # use cache_kit::error::Result;
+/// # async fn example() -> Result<()> {
+/// let backend = PostgresBackend::connect(PostgresConfig::from_database_url(
+///     "postgres://localhost/myapp",
+/// ))
+/// .await?;
+///
+/// backend.set("invoice:42", b"value".to_vec(), None).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct PostgresBackend {
+    pool: PgPool,
+    table: String,
+}
+
+impl PostgresBackend {
+    /// Connect to Postgres and ensure the entries table exists.
+    ///
+    /// # Errors
+    /// Returns `Err` if the pool fails to connect or the table cannot be
+    /// created.
+    pub async fn connect(config: PostgresConfig) -> Result<Self> {
+        let pool = PgPoolOptions::new()
+            .max_connections(config.max_connections)
+            .connect(&config.database_url)
+            .await?;
+
+        let backend = PostgresBackend {
+            pool,
+            table: config.table,
+        };
+        backend.ensure_schema().await?;
+
+        info!("✓ Postgres backend connected (table: {})", backend.table);
+        Ok(backend)
+    }
+
+    /// Reuse an existing pool (e.g. one already shared with the rest of the
+    /// application) instead of opening a dedicated one.
+    ///
+    /// # Errors
+    /// Returns `Err` if the table cannot be created.
+    pub async fn from_pool(pool: PgPool, table: impl Into<String>) -> Result<Self> {
+        let backend = PostgresBackend {
+            pool,
+            table: table.into(),
+        };
+        backend.ensure_schema().await?;
+        Ok(backend)
+    }
+
+    async fn ensure_schema(&self) -> Result<()> {
+        let sql = format!(
+            "CREATE TABLE IF NOT EXISTS {} (
+                key TEXT PRIMARY KEY,
+                value BYTEA NOT NULL,
+                expires_at TIMESTAMPTZ
+            )",
+            self.table
+        );
+        sqlx::query(&sql).execute(&self.pool).await?;
+        Ok(())
+    }
+}
+
+impl CacheBackend for PostgresBackend {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let sql = format!(
+            "SELECT value FROM {} WHERE key = $1 AND (expires_at IS NULL OR expires_at > now())",
+            self.table
+        );
+        let row = sqlx::query(&sql).bind(key).fetch_optional(&self.pool).await?;
+
+        match row {
+            Some(row) => {
+                debug!("✓ Postgres GET {} -> HIT", key);
+                Ok(Some(row.try_get::<Vec<u8>, _>("value")?))
+            }
+            None => {
+                debug!("✓ Postgres GET {} -> MISS", key);
+                Ok(None)
+            }
+        }
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<()> {
+        // NULL `$3` propagates through `now() + ($3 * interval '1 microsecond')`
+        // to a NULL `expires_at`, so a `None` TTL stores a never-expiring entry
+        // without a separate code path.
+        let sql = format!(
+            "INSERT INTO {} (key, value, expires_at)
+             VALUES ($1, $2, now() + ($3::bigint * interval '1 microsecond'))
+             ON CONFLICT (key) DO UPDATE SET value = EXCLUDED.value, expires_at = EXCLUDED.expires_at",
+            self.table
+        );
+        let ttl_micros = ttl.map(|d| d.as_micros() as i64);
+        sqlx::query(&sql)
+            .bind(key)
+            .bind(value)
+            .bind(ttl_micros)
+            .execute(&self.pool)
+            .await?;
+
+        debug!("✓ Postgres SET {} (TTL: {:?})", key, ttl);
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        let sql = format!("DELETE FROM {} WHERE key = $1", self.table);
+        sqlx::query(&sql).bind(key).execute(&self.pool).await?;
+
+        debug!("✓ Postgres DELETE {}", key);
+        Ok(())
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        Ok(sqlx::query("SELECT 1").execute(&self.pool).await.is_ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn test_backend(table: &str) -> PostgresBackend {
+        let mut config = PostgresConfig::from_database_url("postgres://localhost/cache_kit_test");
+        config.table = table.to_string();
+        let backend = PostgresBackend::connect(config)
+            .await
+            .expect("Failed to connect");
+        sqlx::query(&format!("TRUNCATE TABLE {}", backend.table))
+            .execute(&backend.pool)
+            .await
+            .expect("Failed to truncate table");
+        backend
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn test_postgres_backend_set_get() {
+        let backend = test_backend("cache_kit_test_set_get").await;
+
+        backend
+            .set("user:1", b"value1".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        let result = backend.get("user:1").await.expect("Failed to get");
+        assert_eq!(result, Some(b"value1".to_vec()));
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn test_postgres_backend_miss() {
+        let backend = test_backend("cache_kit_test_miss").await;
+
+        let result = backend.get("nonexistent:1").await.expect("Failed to get");
+        assert_eq!(result, None);
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn test_postgres_backend_delete() {
+        let backend = test_backend("cache_kit_test_delete").await;
+
+        backend
+            .set("user:1", b"value1".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        backend.delete("user:1").await.expect("Failed to delete");
+
+        assert_eq!(backend.get("user:1").await.expect("Failed to get"), None);
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn test_postgres_backend_ttl_expiration() {
+        let backend = test_backend("cache_kit_test_ttl").await;
+
+        backend
+            .set(
+                "session:1",
+                b"value1".to_vec(),
+                Some(Duration::from_secs(0)),
+            )
+            .await
+            .expect("Failed to set");
+
+        std::thread::sleep(Duration::from_millis(1100));
+        assert_eq!(backend.get("session:1").await.expect("Failed to get"), None);
+    }
+}
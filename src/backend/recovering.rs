@@ -0,0 +1,548 @@
+//! Corruption/outage recovery wrapper for any [`CacheBackend`].
+//!
+//! Persistent backends (Redis, Memcached, a future on-disk store) can return
+//! decode errors or become unreachable. Left alone, every `get`/`set` simply
+//! propagates that `Error` up through `CacheExpander` and fails the whole
+//! request. `RecoveringBackend` absorbs that: it retries a failing operation,
+//! then tries to reset the affected key (delete it and retry once more, in
+//! case the failure was corruption rather than an outage), and only once
+//! that's exhausted does it degrade according to the configured
+//! [`RecoveryPolicy`] - so a transient cache outage can degrade to
+//! database-only serving instead of erroring the whole request.
+
+use super::{CacheBackend, InMemoryBackend};
+use crate::error::Result;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// What to do once retries and a key reset have both failed to recover a
+/// [`RecoveringBackend`]'s primary backend.
+#[derive(Debug, Clone, Default)]
+pub enum RecoveryPolicy {
+    /// Spin up a transient in-memory backend and route all operations
+    /// through it for the rest of the process, so the service keeps serving
+    /// (cold) cache data instead of failing every request.
+    InMemory,
+    /// Silently drop writes and report reads as misses, letting the
+    /// `DataRepository` behind the cache keep serving data directly.
+    BlackHole,
+    /// Propagate the error once the retries and the key reset have failed,
+    /// without tripping: there is no degraded mode.
+    #[default]
+    Error,
+}
+
+/// Wraps any [`CacheBackend`], adding retry, key-reset, and degraded-mode
+/// recovery for persistent read/write failures.
+///
+/// Once the primary backend has been given up on (see [`RecoveryPolicy`]),
+/// it stays given up on until either the process restarts, or - if
+/// [`RecoveringBackend::with_cooldown`] was configured - until `cooldown`
+/// has elapsed, at which point the *next* call probes the primary backend
+/// once: success un-trips and serves from it again, failure resets the
+/// cooldown and keeps degrading. Without a cooldown there's no automatic
+/// un-tripping, since a backend that just failed `retry_count` times plus a
+/// reset attempt isn't a good candidate to keep hammering on every call.
+///
+/// Pairing `RecoveryPolicy::BlackHole` with `CacheStrategy::Refresh` gets
+/// you the "serve straight from the repository while Redis is down" mode:
+/// a blackholed `get` reports a miss, which `CacheExpander`'s `Refresh`
+/// strategy already falls through to the repository for.
+///
+/// # Example
+///
+/// ```no_run
+/// use cache_kit::backend::{RecoveringBackend, RecoveryPolicy, InMemoryBackend};
+/// use std::time::Duration;
+///
+/// # async fn example() -> cache_kit::Result<()> {
+/// # let redis_like_backend = InMemoryBackend::new();
+/// let backend = RecoveringBackend::new(redis_like_backend, RecoveryPolicy::InMemory, 2)
+///     .with_cooldown(Duration::from_secs(30));
+/// backend.set("key", b"value".to_vec(), None).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct RecoveringBackend<B> {
+    inner: B,
+    policy: RecoveryPolicy,
+    retry_count: u32,
+    tripped: std::sync::Arc<AtomicBool>,
+    tripped_at: std::sync::Arc<Mutex<Option<Instant>>>,
+    cooldown: Option<Duration>,
+    fallback: InMemoryBackend,
+}
+
+impl<B: CacheBackend> RecoveringBackend<B> {
+    /// Wrap `inner`, retrying a failing operation up to `retry_count` times
+    /// (plus one key-reset attempt) before falling back per `policy`.
+    pub fn new(inner: B, policy: RecoveryPolicy, retry_count: u32) -> Self {
+        RecoveringBackend {
+            inner,
+            policy,
+            retry_count,
+            tripped: std::sync::Arc::new(AtomicBool::new(false)),
+            tripped_at: std::sync::Arc::new(Mutex::new(None)),
+            cooldown: None,
+            fallback: InMemoryBackend::new(),
+        }
+    }
+
+    /// Once tripped, periodically probe the primary backend again after
+    /// `cooldown` instead of degrading for good. Mirrors the half-open
+    /// recovery check in [`crate::resilience::ResilientRepository`].
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = Some(cooldown);
+        self
+    }
+
+    /// Borrow the wrapped backend.
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Whether the primary backend has been given up on for this process.
+    pub fn is_tripped(&self) -> bool {
+        self.tripped.load(Ordering::SeqCst)
+    }
+
+    fn trip(&self) {
+        if !matches!(self.policy, RecoveryPolicy::Error) {
+            if !self.tripped.swap(true, Ordering::SeqCst) {
+                warn!(
+                    "⚠ RecoveringBackend: primary backend exhausted retries and reset, \
+                     degrading to {:?} for the rest of the process",
+                    self.policy
+                );
+            }
+            *self.tripped_at.lock().expect("lock poisoned") = Some(Instant::now());
+        }
+    }
+
+    /// If tripped and `cooldown` has elapsed since the last trip/probe
+    /// failure, probe the primary backend once via `op`. Returns `None` when
+    /// no probe was attempted (not tripped, no cooldown configured, or the
+    /// cooldown hasn't elapsed) - callers should fall through to their
+    /// normal tripped-vs-not-tripped handling in that case.
+    async fn try_recover<V, F, Fut>(&self, op: F) -> Option<Result<V>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<V>>,
+    {
+        let cooldown = self.cooldown?;
+        if !self.is_tripped() {
+            return None;
+        }
+        let elapsed = self
+            .tripped_at
+            .lock()
+            .expect("lock poisoned")
+            .map(|t| t.elapsed());
+        if !elapsed.is_some_and(|e| e >= cooldown) {
+            return None;
+        }
+
+        match op().await {
+            Ok(value) => {
+                self.tripped.store(false, Ordering::SeqCst);
+                *self.tripped_at.lock().expect("lock poisoned") = None;
+                info!("✓ RecoveringBackend: primary backend recovered, un-tripping");
+                Some(Ok(value))
+            }
+            Err(e) => {
+                *self.tripped_at.lock().expect("lock poisoned") = Some(Instant::now());
+                Some(Err(e))
+            }
+        }
+    }
+
+    /// Retry `op` up to `retry_count` times; on continued failure, delete
+    /// `key` (in case the failure was a corrupted entry rather than an
+    /// outage) and retry once more. Trips the backend if that also fails.
+    async fn attempt<V, F, Fut>(&self, key: &str, op: F) -> Result<V>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<V>>,
+    {
+        let mut last_err = None;
+        for _ in 0..=self.retry_count {
+            match op().await {
+                Ok(value) => return Ok(value),
+                Err(e) => last_err = Some(e),
+            }
+        }
+
+        let _ = self.inner.delete(key).await;
+        match op().await {
+            Ok(value) => Ok(value),
+            Err(e) => {
+                self.trip();
+                Err(last_err.unwrap_or(e))
+            }
+        }
+    }
+
+    async fn fallback_get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        match self.policy {
+            RecoveryPolicy::InMemory => self.fallback.get(key).await,
+            RecoveryPolicy::BlackHole => Ok(None),
+            RecoveryPolicy::Error => self.inner.get(key).await,
+        }
+    }
+
+    async fn fallback_set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<()> {
+        match self.policy {
+            RecoveryPolicy::InMemory => self.fallback.set(key, value, ttl).await,
+            RecoveryPolicy::BlackHole => Ok(()),
+            RecoveryPolicy::Error => self.inner.set(key, value, ttl).await,
+        }
+    }
+
+    async fn fallback_delete(&self, key: &str) -> Result<()> {
+        match self.policy {
+            RecoveryPolicy::InMemory => self.fallback.delete(key).await,
+            RecoveryPolicy::BlackHole => Ok(()),
+            RecoveryPolicy::Error => self.inner.delete(key).await,
+        }
+    }
+
+    async fn fallback_clear_all(&self) -> Result<()> {
+        match self.policy {
+            RecoveryPolicy::InMemory => self.fallback.clear_all().await,
+            RecoveryPolicy::BlackHole => Ok(()),
+            RecoveryPolicy::Error => self.inner.clear_all().await,
+        }
+    }
+
+    async fn fallback_invalidate_prefix(&self, prefix: &str) -> Result<()> {
+        match self.policy {
+            RecoveryPolicy::InMemory => self.fallback.invalidate_prefix(prefix).await,
+            RecoveryPolicy::BlackHole => Ok(()),
+            RecoveryPolicy::Error => self.inner.invalidate_prefix(prefix).await,
+        }
+    }
+
+    async fn fallback_scan_prefix(&self, prefix: &str) -> Result<Vec<String>> {
+        match self.policy {
+            RecoveryPolicy::InMemory => self.fallback.scan_prefix(prefix).await,
+            RecoveryPolicy::BlackHole => Ok(Vec::new()),
+            RecoveryPolicy::Error => self.inner.scan_prefix(prefix).await,
+        }
+    }
+
+    async fn fallback_delete_prefix(&self, prefix: &str) -> Result<u64> {
+        match self.policy {
+            RecoveryPolicy::InMemory => self.fallback.delete_prefix(prefix).await,
+            RecoveryPolicy::BlackHole => Ok(0),
+            RecoveryPolicy::Error => self.inner.delete_prefix(prefix).await,
+        }
+    }
+}
+
+impl<B: CacheBackend> CacheBackend for RecoveringBackend<B> {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        if self.is_tripped() {
+            if let Some(result) = self.try_recover(|| self.inner.get(key)).await {
+                return match result {
+                    Ok(value) => Ok(value),
+                    Err(_) => self.fallback_get(key).await,
+                };
+            }
+            return self.fallback_get(key).await;
+        }
+        match self.attempt(key, || self.inner.get(key)).await {
+            Ok(value) => Ok(value),
+            Err(_) if self.is_tripped() => self.fallback_get(key).await,
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<()> {
+        if self.is_tripped() {
+            if let Some(result) = self
+                .try_recover(|| self.inner.set(key, value.clone(), ttl))
+                .await
+            {
+                return match result {
+                    Ok(()) => Ok(()),
+                    Err(_) => self.fallback_set(key, value, ttl).await,
+                };
+            }
+            return self.fallback_set(key, value, ttl).await;
+        }
+        match self
+            .attempt(key, || self.inner.set(key, value.clone(), ttl))
+            .await
+        {
+            Ok(()) => Ok(()),
+            Err(_) if self.is_tripped() => self.fallback_set(key, value, ttl).await,
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        if self.is_tripped() {
+            if let Some(result) = self.try_recover(|| self.inner.delete(key)).await {
+                return match result {
+                    Ok(()) => Ok(()),
+                    Err(_) => self.fallback_delete(key).await,
+                };
+            }
+            return self.fallback_delete(key).await;
+        }
+        match self.attempt(key, || self.inner.delete(key)).await {
+            Ok(()) => Ok(()),
+            Err(_) if self.is_tripped() => self.fallback_delete(key).await,
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        if self.is_tripped() {
+            if let Some(result) = self.try_recover(|| self.inner.health_check()).await {
+                return Ok(result.unwrap_or(false));
+            }
+            return Ok(false);
+        }
+        match self.attempt("__health__", || self.inner.health_check()).await {
+            Ok(healthy) => Ok(healthy),
+            Err(_) if self.is_tripped() => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn clear_all(&self) -> Result<()> {
+        if self.is_tripped() {
+            if let Some(result) = self.try_recover(|| self.inner.clear_all()).await {
+                return match result {
+                    Ok(()) => Ok(()),
+                    Err(_) => self.fallback_clear_all().await,
+                };
+            }
+            return self.fallback_clear_all().await;
+        }
+        match self.attempt("__clear_all__", || self.inner.clear_all()).await {
+            Ok(()) => Ok(()),
+            Err(_) if self.is_tripped() => self.fallback_clear_all().await,
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn invalidate_prefix(&self, prefix: &str) -> Result<()> {
+        if self.is_tripped() {
+            if let Some(result) = self
+                .try_recover(|| self.inner.invalidate_prefix(prefix))
+                .await
+            {
+                return match result {
+                    Ok(()) => Ok(()),
+                    Err(_) => self.fallback_invalidate_prefix(prefix).await,
+                };
+            }
+            return self.fallback_invalidate_prefix(prefix).await;
+        }
+        match self
+            .attempt(prefix, || self.inner.invalidate_prefix(prefix))
+            .await
+        {
+            Ok(()) => Ok(()),
+            Err(_) if self.is_tripped() => self.fallback_invalidate_prefix(prefix).await,
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn scan_prefix(&self, prefix: &str) -> Result<Vec<String>> {
+        if self.is_tripped() {
+            if let Some(result) = self.try_recover(|| self.inner.scan_prefix(prefix)).await {
+                return match result {
+                    Ok(keys) => Ok(keys),
+                    Err(_) => self.fallback_scan_prefix(prefix).await,
+                };
+            }
+            return self.fallback_scan_prefix(prefix).await;
+        }
+        match self.attempt(prefix, || self.inner.scan_prefix(prefix)).await {
+            Ok(keys) => Ok(keys),
+            Err(_) if self.is_tripped() => self.fallback_scan_prefix(prefix).await,
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn delete_prefix(&self, prefix: &str) -> Result<u64> {
+        if self.is_tripped() {
+            if let Some(result) = self.try_recover(|| self.inner.delete_prefix(prefix)).await {
+                return match result {
+                    Ok(count) => Ok(count),
+                    Err(_) => self.fallback_delete_prefix(prefix).await,
+                };
+            }
+            return self.fallback_delete_prefix(prefix).await;
+        }
+        match self
+            .attempt(prefix, || self.inner.delete_prefix(prefix))
+            .await
+        {
+            Ok(count) => Ok(count),
+            Err(_) if self.is_tripped() => self.fallback_delete_prefix(prefix).await,
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::InMemoryBackend;
+    use crate::error::Error;
+    use crate::repository::FailurePolicy;
+
+    #[derive(Clone)]
+    struct FlakyBackend {
+        inner: InMemoryBackend,
+        failure: std::sync::Arc<std::sync::Mutex<FailurePolicy>>,
+    }
+
+    impl FlakyBackend {
+        fn new(failure: FailurePolicy) -> Self {
+            FlakyBackend {
+                inner: InMemoryBackend::new(),
+                failure: std::sync::Arc::new(std::sync::Mutex::new(failure)),
+            }
+        }
+
+        fn should_fail(&self) -> bool {
+            !matches!(*self.failure.lock().expect("Lock poisoned"), FailurePolicy::None)
+        }
+
+        fn set_failure(&self, failure: FailurePolicy) {
+            *self.failure.lock().expect("Lock poisoned") = failure;
+        }
+    }
+
+    impl CacheBackend for FlakyBackend {
+        async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+            if self.should_fail() {
+                return Err(Error::BackendError("simulated outage".to_string()));
+            }
+            self.inner.get(key).await
+        }
+
+        async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<()> {
+            if self.should_fail() {
+                return Err(Error::BackendError("simulated outage".to_string()));
+            }
+            self.inner.set(key, value, ttl).await
+        }
+
+        async fn delete(&self, key: &str) -> Result<()> {
+            self.inner.delete(key).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_healthy_primary_serves_without_tripping() {
+        let flaky = FlakyBackend::new(FailurePolicy::None);
+        let backend = RecoveringBackend::new(flaky, RecoveryPolicy::Error, 2);
+
+        backend.set("key", vec![1, 2, 3], None).await.expect("Failed to set");
+        assert_eq!(backend.get("key").await.expect("Failed to get"), Some(vec![1, 2, 3]));
+        assert!(!backend.is_tripped());
+    }
+
+    #[tokio::test]
+    async fn test_blackhole_policy_reports_misses_after_exhausting_recovery() {
+        let flaky = FlakyBackend::new(FailurePolicy::Always(Error::BackendError(
+            "down".to_string(),
+        )));
+        let backend = RecoveringBackend::new(flaky, RecoveryPolicy::BlackHole, 1);
+
+        let result = backend.set("key", vec![1], None).await;
+        assert!(result.is_ok());
+        assert!(backend.is_tripped());
+        assert_eq!(backend.get("key").await.expect("Failed to get"), None);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_policy_serves_from_fallback_after_tripping() {
+        let flaky = FlakyBackend::new(FailurePolicy::Always(Error::BackendError(
+            "down".to_string(),
+        )));
+        let backend = RecoveringBackend::new(flaky, RecoveryPolicy::InMemory, 1);
+
+        backend.set("key", vec![9], None).await.expect("Fallback set should succeed");
+        assert!(backend.is_tripped());
+        assert_eq!(backend.get("key").await.expect("Failed to get"), Some(vec![9]));
+    }
+
+    #[tokio::test]
+    async fn test_error_policy_propagates_and_never_trips() {
+        let flaky = FlakyBackend::new(FailurePolicy::Always(Error::BackendError(
+            "down".to_string(),
+        )));
+        let backend = RecoveringBackend::new(flaky, RecoveryPolicy::Error, 1);
+
+        let result = backend.get("key").await;
+        assert!(result.is_err());
+        assert!(!backend.is_tripped());
+    }
+
+    #[tokio::test]
+    async fn test_without_cooldown_stays_tripped_once_primary_recovers() {
+        let flaky = FlakyBackend::new(FailurePolicy::Always(Error::BackendError(
+            "down".to_string(),
+        )));
+        let backend = RecoveringBackend::new(flaky.clone(), RecoveryPolicy::BlackHole, 1);
+
+        backend.set("key", vec![1], None).await.expect("Blackhole set should succeed");
+        assert!(backend.is_tripped());
+
+        flaky.set_failure(FailurePolicy::None);
+        assert_eq!(backend.get("key").await.expect("Failed to get"), None);
+        assert!(backend.is_tripped(), "without a cooldown, recovery is never probed");
+    }
+
+    #[tokio::test]
+    async fn test_cooldown_untrips_once_primary_recovers() {
+        let flaky = FlakyBackend::new(FailurePolicy::Always(Error::BackendError(
+            "down".to_string(),
+        )));
+        let backend = RecoveringBackend::new(flaky.clone(), RecoveryPolicy::BlackHole, 1)
+            .with_cooldown(Duration::from_millis(20));
+
+        backend.set("key", vec![1], None).await.expect("Blackhole set should succeed");
+        assert!(backend.is_tripped());
+
+        // Still within the cooldown window - stays tripped and blackholed.
+        assert_eq!(backend.get("key").await.expect("Failed to get"), None);
+        assert!(backend.is_tripped());
+
+        flaky.set_failure(FailurePolicy::None);
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        backend.set("key", vec![2], None).await.expect("Probe set should succeed");
+        assert!(!backend.is_tripped());
+        assert_eq!(backend.get("key").await.expect("Failed to get"), Some(vec![2]));
+    }
+
+    #[tokio::test]
+    async fn test_cooldown_probe_failure_keeps_degrading() {
+        let flaky = FlakyBackend::new(FailurePolicy::Always(Error::BackendError(
+            "down".to_string(),
+        )));
+        let backend = RecoveringBackend::new(flaky, RecoveryPolicy::InMemory, 1)
+            .with_cooldown(Duration::from_millis(20));
+
+        backend.set("key", vec![1], None).await.expect("Fallback set should succeed");
+        assert!(backend.is_tripped());
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        // Primary is still down, so the probe fails and we keep serving from
+        // the fallback instead of propagating the probe's error.
+        assert_eq!(backend.get("key").await.expect("Failed to get"), Some(vec![1]));
+        assert!(backend.is_tripped());
+    }
+}
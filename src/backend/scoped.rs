@@ -0,0 +1,172 @@
+//! Per-principal cache key namespacing.
+//!
+//! Wraps any [`CacheBackend`] so every key it touches is confined to a
+//! caller-supplied namespace (tenant id, user id, role, ...). Without this,
+//! a shared backend has no notion of "whose" data a key holds, so a bug that
+//! reuses a cache key across principals leaks one user's cached payload to
+//! another. Reached via [`crate::service::CacheService::scoped`], not
+//! constructed directly in normal use.
+
+use super::CacheBackend;
+use crate::error::Result;
+use std::time::Duration;
+
+/// Confines every key passed through it to `scope`, by prefixing
+/// `"{scope}:"` onto keys before delegating to the wrapped backend.
+///
+/// Built via [`crate::service::CacheService::scoped`]; see that method's
+/// docs for the common usage pattern.
+#[derive(Clone)]
+pub struct ScopedBackend<B> {
+    inner: B,
+    scope: String,
+}
+
+impl<B: CacheBackend> ScopedBackend<B> {
+    /// Namespace `inner` under `scope`.
+    pub fn new(inner: B, scope: impl Into<String>) -> Self {
+        ScopedBackend {
+            inner,
+            scope: scope.into(),
+        }
+    }
+
+    /// The namespace this backend confines keys to.
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+
+    fn namespaced(&self, key: &str) -> String {
+        format!("{}:{}", self.scope, key)
+    }
+}
+
+impl<B: CacheBackend> CacheBackend for ScopedBackend<B> {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        self.inner.get(&self.namespaced(key)).await
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<()> {
+        self.inner.set(&self.namespaced(key), value, ttl).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.inner.delete(&self.namespaced(key)).await
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool> {
+        self.inner.exists(&self.namespaced(key)).await
+    }
+
+    async fn mget(&self, keys: &[&str]) -> Result<Vec<Option<Vec<u8>>>> {
+        let namespaced: Vec<String> = keys.iter().map(|k| self.namespaced(k)).collect();
+        let refs: Vec<&str> = namespaced.iter().map(String::as_str).collect();
+        self.inner.mget(&refs).await
+    }
+
+    async fn mdelete(&self, keys: &[&str]) -> Result<()> {
+        let namespaced: Vec<String> = keys.iter().map(|k| self.namespaced(k)).collect();
+        let refs: Vec<&str> = namespaced.iter().map(String::as_str).collect();
+        self.inner.mdelete(&refs).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        self.inner.health_check().await
+    }
+
+    /// Flush only this namespace, rather than the whole backend - clearing
+    /// one tenant/user's cache should never evict everyone else's.
+    async fn clear_all(&self) -> Result<()> {
+        self.inner.invalidate_prefix(&self.scope).await
+    }
+
+    async fn invalidate_prefix(&self, prefix: &str) -> Result<()> {
+        self.inner.invalidate_prefix(&self.namespaced(prefix)).await
+    }
+
+    /// Lists keys under `prefix` within this scope, with the `"{scope}:"`
+    /// namespace stripped back off so callers see the same keys they'd pass
+    /// to `get`/`set`.
+    async fn scan_prefix(&self, prefix: &str) -> Result<Vec<String>> {
+        let keys = self.inner.scan_prefix(&self.namespaced(prefix)).await?;
+        let own_namespace = format!("{}:", self.scope);
+        Ok(keys
+            .into_iter()
+            .map(|key| key.strip_prefix(own_namespace.as_str()).map(String::from).unwrap_or(key))
+            .collect())
+    }
+
+    async fn delete_prefix(&self, prefix: &str) -> Result<u64> {
+        self.inner.delete_prefix(&self.namespaced(prefix)).await
+    }
+
+    async fn set_with_tags(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        ttl: Option<Duration>,
+        tags: &[&str],
+    ) -> Result<()> {
+        let namespaced_tags: Vec<String> = tags.iter().map(|t| self.namespaced(t)).collect();
+        let tag_refs: Vec<&str> = namespaced_tags.iter().map(String::as_str).collect();
+        self.inner
+            .set_with_tags(&self.namespaced(key), value, ttl, &tag_refs)
+            .await
+    }
+
+    async fn invalidate_tag(&self, tag: &str) -> Result<()> {
+        self.inner.invalidate_tag(&self.namespaced(tag)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::InMemoryBackend;
+
+    #[tokio::test]
+    async fn test_scoped_backend_confines_keys_to_its_namespace() {
+        let backend = InMemoryBackend::new();
+        let alice = ScopedBackend::new(backend.clone(), "alice");
+        let bob = ScopedBackend::new(backend.clone(), "bob");
+
+        alice.set("profile", vec![1], None).await.expect("Failed to set");
+
+        assert_eq!(alice.get("profile").await.expect("Failed to get"), Some(vec![1]));
+        assert_eq!(bob.get("profile").await.expect("Failed to get"), None);
+        assert_eq!(
+            backend.get("alice:profile").await.expect("Failed to get"),
+            Some(vec![1])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_scan_prefix_strips_the_scope_namespace_back_off() {
+        let backend = InMemoryBackend::new();
+        let alice = ScopedBackend::new(backend.clone(), "alice");
+        let bob = ScopedBackend::new(backend.clone(), "bob");
+
+        alice.set("invoice:1", vec![1], None).await.expect("Failed to set");
+        alice.set("invoice:2", vec![2], None).await.expect("Failed to set");
+        bob.set("invoice:1", vec![3], None).await.expect("Failed to set");
+
+        let mut keys = alice.scan_prefix("invoice").await.expect("Failed to scan prefix");
+        keys.sort();
+        assert_eq!(keys, vec!["invoice:1".to_string(), "invoice:2".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_clear_all_only_flushes_its_own_scope() {
+        let backend = InMemoryBackend::new();
+        let alice = ScopedBackend::new(backend.clone(), "alice");
+        let bob = ScopedBackend::new(backend.clone(), "bob");
+
+        alice.set("profile", vec![1], None).await.expect("Failed to set");
+        bob.set("profile", vec![2], None).await.expect("Failed to set");
+
+        alice.clear_all().await.expect("Failed to clear");
+
+        assert_eq!(alice.get("profile").await.expect("Failed to get"), None);
+        assert_eq!(bob.get("profile").await.expect("Failed to get"), Some(vec![2]));
+    }
+}
@@ -0,0 +1,561 @@
+//! Runtime-selectable [`CacheBackend`], so the concrete backend can be chosen
+//! from config/env (e.g. `CACHE_BACKEND=redis://host:6379`) instead of being
+//! hardcoded at the call site.
+//!
+//! [`CacheService::new`](crate::CacheService::new) is already generic over any
+//! `CacheBackend`, but that pushes the concrete type up into every caller's
+//! signature. Wrapping the choice in a [`Backend`] enum keeps those callers
+//! (and things like `CacheService<Backend>`) free of the
+//! `InMemory`/`Disabled`/`Redis`/`Hybrid`/`Sled`/`Postgres` distinction entirely - only
+//! [`Backend::from_url`]/[`Backend::from_config`] need to know it.
+//!
+//! [`Backend::from_config`] is the entry point for a deployment-level
+//! decision ("is caching even on here, and if so against what") as opposed
+//! to a compile-time one (which backend *kinds* - Redis, Sled - are linked
+//! in at all, which is still decided by Cargo features): pass it a
+//! [`CacheFactoryConfig`] built from env/config instead of naming a concrete
+//! backend type at the call site.
+
+use super::{CacheBackend, DisabledBackend, InMemoryBackend};
+use crate::error::{Error, Result};
+use crate::streaming::CacheData;
+use std::time::Duration;
+
+#[cfg(feature = "postgres")]
+use super::{PostgresBackend, PostgresConfig};
+#[cfg(feature = "redis")]
+use super::{HotColdBackend, RedisBackend};
+#[cfg(feature = "sled")]
+use super::SledBackend;
+
+/// A [`CacheBackend`] chosen at runtime rather than compiled in, constructed
+/// via [`Backend::from_url`].
+///
+/// Delegates `CacheBackend` methods to whichever variant is active, so
+/// each backend's own optimized overrides (e.g. Redis `MGET`/pipelined
+/// `mset`) are preserved rather than falling back to the trait's defaults.
+/// `ttl`, `mget_stream`, `gets`, `cas`, `incr` and `decr` are not delegated:
+/// they always run the trait's default implementations, whichever variant
+/// is active.
+#[derive(Clone)]
+pub enum Backend {
+    /// In-process, non-persistent cache. Selected by `memory://` or an empty URL.
+    InMemory(InMemoryBackend),
+    /// Caching turned off: every `get` is a miss, every `set`/`delete` a
+    /// no-op. Selected by [`Backend::from_config`] when no connection string
+    /// is configured.
+    Disabled(DisabledBackend),
+    /// Redis-backed cache. Selected by a `redis://`/`rediss://` URL; requires
+    /// the `redis` feature.
+    #[cfg(feature = "redis")]
+    Redis(RedisBackend),
+    /// In-memory L1 in front of a Redis L2, via [`HotColdBackend`]. Selected
+    /// by a `hybrid://<redis-url>` connection string; requires the `redis`
+    /// feature.
+    #[cfg(feature = "redis")]
+    Hybrid(HotColdBackend<InMemoryBackend, RedisBackend>),
+    /// Embedded persistent cache. Selected by a `sled://<path>` URL; requires
+    /// the `sled` feature.
+    #[cfg(feature = "sled")]
+    Sled(SledBackend),
+    /// Postgres-backed persistent cache. Selected by a `postgres://`/
+    /// `postgresql://` URL; requires the `postgres` feature.
+    #[cfg(feature = "postgres")]
+    Postgres(PostgresBackend),
+}
+
+/// Runtime configuration consumed by [`Backend::from_config`] - the small
+/// set of knobs a deployment typically varies per environment, as opposed to
+/// which backend *kinds* are compiled in at all (that's decided by Cargo
+/// features, per the module docs).
+#[derive(Debug, Clone, Default)]
+pub struct CacheFactoryConfig {
+    /// Any scheme/connection-string format [`Backend::from_url`] accepts
+    /// (`memory://`, `redis://...`, `sled://...`, `postgres://...`), plus
+    /// `hybrid://<redis-url>`, which only [`Backend::from_config`] handles.
+    /// `None` or empty means caching is off for this
+    /// deployment, regardless of which backend features are compiled in -
+    /// selects [`Backend::Disabled`].
+    pub connection_string: Option<String>,
+    /// Default TTL applied to a `hybrid://` backend's in-memory L1 tier via
+    /// [`HotColdBackend::with_l1_ttl`]. Ignored by every other variant -
+    /// `InMemory`/`Redis`/`Sled`/`Postgres` take their TTL per-call instead.
+    pub default_ttl: Option<Duration>,
+}
+
+impl Backend {
+    /// Construct a [`Backend`] from a connection URL, dispatching on its scheme:
+    /// - `memory://` (or an empty string) -> [`Backend::InMemory`]
+    /// - `redis://...` / `rediss://...` -> [`Backend::Redis`] (feature `redis`)
+    /// - `sled://<path>` -> [`Backend::Sled`] (feature `sled`)
+    /// - `postgres://...` / `postgresql://...` -> [`Backend::Postgres`]
+    ///   (feature `postgres`)
+    ///
+    /// This is the constructor meant for a `CACHE_BACKEND` env var or
+    /// equivalent config string; for a backend that needs more than a bare
+    /// URL (e.g. Redis Sentinel), build it directly via its own config type
+    /// and wrap it with the matching variant instead.
+    ///
+    /// # Errors
+    /// Returns `Error::ConfigError` for an unrecognized or feature-gated-off
+    /// scheme, or the underlying backend's error if it fails to connect/open.
+    pub async fn from_url(url: &str) -> Result<Backend> {
+        if url.is_empty() || url.starts_with("memory://") {
+            return Ok(Backend::InMemory(InMemoryBackend::new()));
+        }
+
+        if url.starts_with("redis://") || url.starts_with("rediss://") {
+            #[cfg(feature = "redis")]
+            {
+                return Ok(Backend::Redis(RedisBackend::from_connection_string(url).await?));
+            }
+            #[cfg(not(feature = "redis"))]
+            {
+                return Err(Error::ConfigError(
+                    "redis:// backend URL requires the \"redis\" feature".to_string(),
+                ));
+            }
+        }
+
+        if let Some(path) = url.strip_prefix("sled://") {
+            #[cfg(feature = "sled")]
+            {
+                return Ok(Backend::Sled(SledBackend::open_at(path)?));
+            }
+            #[cfg(not(feature = "sled"))]
+            {
+                let _ = path;
+                return Err(Error::ConfigError(
+                    "sled:// backend URL requires the \"sled\" feature".to_string(),
+                ));
+            }
+        }
+
+        if url.starts_with("postgres://") || url.starts_with("postgresql://") {
+            #[cfg(feature = "postgres")]
+            {
+                let backend =
+                    PostgresBackend::connect(PostgresConfig::from_database_url(url)).await?;
+                return Ok(Backend::Postgres(backend));
+            }
+            #[cfg(not(feature = "postgres"))]
+            {
+                return Err(Error::ConfigError(
+                    "postgres:// backend URL requires the \"postgres\" feature".to_string(),
+                ));
+            }
+        }
+
+        Err(Error::ConfigError(format!(
+            "Unrecognized cache backend URL: {}",
+            url
+        )))
+    }
+
+    /// Construct a [`Backend`] from a [`CacheFactoryConfig`] instead of a
+    /// bare URL: a missing/empty `connection_string` selects
+    /// [`Backend::Disabled`] (caching off for this deployment), a
+    /// `hybrid://<redis-url>` scheme selects [`Backend::Hybrid`] (requires
+    /// the `redis` feature), and everything else is delegated to
+    /// [`Backend::from_url`].
+    ///
+    /// # Errors
+    /// Returns `Error::ConfigError` for `hybrid://` without the `redis`
+    /// feature, or anything [`Backend::from_url`] would reject.
+    pub async fn from_config(config: &CacheFactoryConfig) -> Result<Backend> {
+        let Some(url) = config
+            .connection_string
+            .as_deref()
+            .filter(|s| !s.is_empty())
+        else {
+            return Ok(Backend::Disabled(DisabledBackend::new()));
+        };
+
+        if let Some(redis_url) = url.strip_prefix("hybrid://") {
+            #[cfg(feature = "redis")]
+            {
+                let cold = RedisBackend::from_connection_string(redis_url).await?;
+                let mut backend = HotColdBackend::new(InMemoryBackend::new(), cold);
+                if let Some(ttl) = config.default_ttl {
+                    backend = backend.with_l1_ttl(ttl);
+                }
+                return Ok(Backend::Hybrid(backend));
+            }
+            #[cfg(not(feature = "redis"))]
+            {
+                let _ = redis_url;
+                return Err(Error::ConfigError(
+                    "hybrid:// backend URL requires the \"redis\" feature".to_string(),
+                ));
+            }
+        }
+
+        Backend::from_url(url).await
+    }
+}
+
+impl CacheBackend for Backend {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        match self {
+            Backend::InMemory(b) => b.get(key).await,
+            Backend::Disabled(b) => b.get(key).await,
+            #[cfg(feature = "redis")]
+            Backend::Redis(b) => b.get(key).await,
+            #[cfg(feature = "redis")]
+            Backend::Hybrid(b) => b.get(key).await,
+            #[cfg(feature = "sled")]
+            Backend::Sled(b) => b.get(key).await,
+            #[cfg(feature = "postgres")]
+            Backend::Postgres(b) => b.get(key).await,
+        }
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<()> {
+        match self {
+            Backend::InMemory(b) => b.set(key, value, ttl).await,
+            Backend::Disabled(b) => b.set(key, value, ttl).await,
+            #[cfg(feature = "redis")]
+            Backend::Redis(b) => b.set(key, value, ttl).await,
+            #[cfg(feature = "redis")]
+            Backend::Hybrid(b) => b.set(key, value, ttl).await,
+            #[cfg(feature = "sled")]
+            Backend::Sled(b) => b.set(key, value, ttl).await,
+            #[cfg(feature = "postgres")]
+            Backend::Postgres(b) => b.set(key, value, ttl).await,
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        match self {
+            Backend::InMemory(b) => b.delete(key).await,
+            Backend::Disabled(b) => b.delete(key).await,
+            #[cfg(feature = "redis")]
+            Backend::Redis(b) => b.delete(key).await,
+            #[cfg(feature = "redis")]
+            Backend::Hybrid(b) => b.delete(key).await,
+            #[cfg(feature = "sled")]
+            Backend::Sled(b) => b.delete(key).await,
+            #[cfg(feature = "postgres")]
+            Backend::Postgres(b) => b.delete(key).await,
+        }
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool> {
+        match self {
+            Backend::InMemory(b) => b.exists(key).await,
+            Backend::Disabled(b) => b.exists(key).await,
+            #[cfg(feature = "redis")]
+            Backend::Redis(b) => b.exists(key).await,
+            #[cfg(feature = "redis")]
+            Backend::Hybrid(b) => b.exists(key).await,
+            #[cfg(feature = "sled")]
+            Backend::Sled(b) => b.exists(key).await,
+            #[cfg(feature = "postgres")]
+            Backend::Postgres(b) => b.exists(key).await,
+        }
+    }
+
+    async fn mget(&self, keys: &[&str]) -> Result<Vec<Option<Vec<u8>>>> {
+        match self {
+            Backend::InMemory(b) => b.mget(keys).await,
+            Backend::Disabled(b) => b.mget(keys).await,
+            #[cfg(feature = "redis")]
+            Backend::Redis(b) => b.mget(keys).await,
+            #[cfg(feature = "redis")]
+            Backend::Hybrid(b) => b.mget(keys).await,
+            #[cfg(feature = "sled")]
+            Backend::Sled(b) => b.mget(keys).await,
+            #[cfg(feature = "postgres")]
+            Backend::Postgres(b) => b.mget(keys).await,
+        }
+    }
+
+    async fn mdelete(&self, keys: &[&str]) -> Result<()> {
+        match self {
+            Backend::InMemory(b) => b.mdelete(keys).await,
+            Backend::Disabled(b) => b.mdelete(keys).await,
+            #[cfg(feature = "redis")]
+            Backend::Redis(b) => b.mdelete(keys).await,
+            #[cfg(feature = "redis")]
+            Backend::Hybrid(b) => b.mdelete(keys).await,
+            #[cfg(feature = "sled")]
+            Backend::Sled(b) => b.mdelete(keys).await,
+            #[cfg(feature = "postgres")]
+            Backend::Postgres(b) => b.mdelete(keys).await,
+        }
+    }
+
+    async fn get_with<F, Fut>(&self, key: &str, init: F, ttl: Option<Duration>) -> Result<Vec<u8>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<Vec<u8>>>,
+    {
+        match self {
+            Backend::InMemory(b) => b.get_with(key, init, ttl).await,
+            Backend::Disabled(b) => b.get_with(key, init, ttl).await,
+            #[cfg(feature = "redis")]
+            Backend::Redis(b) => b.get_with(key, init, ttl).await,
+            #[cfg(feature = "redis")]
+            Backend::Hybrid(b) => b.get_with(key, init, ttl).await,
+            #[cfg(feature = "sled")]
+            Backend::Sled(b) => b.get_with(key, init, ttl).await,
+            #[cfg(feature = "postgres")]
+            Backend::Postgres(b) => b.get_with(key, init, ttl).await,
+        }
+    }
+
+    async fn mset(&self, entries: &[(&str, Vec<u8>, Option<Duration>)]) -> Result<()> {
+        match self {
+            Backend::InMemory(b) => b.mset(entries).await,
+            Backend::Disabled(b) => b.mset(entries).await,
+            #[cfg(feature = "redis")]
+            Backend::Redis(b) => b.mset(entries).await,
+            #[cfg(feature = "redis")]
+            Backend::Hybrid(b) => b.mset(entries).await,
+            #[cfg(feature = "sled")]
+            Backend::Sled(b) => b.mset(entries).await,
+            #[cfg(feature = "postgres")]
+            Backend::Postgres(b) => b.mset(entries).await,
+        }
+    }
+
+    async fn expire(&self, key: &str, ttl: Duration) -> Result<()> {
+        match self {
+            Backend::InMemory(b) => b.expire(key, ttl).await,
+            Backend::Disabled(b) => b.expire(key, ttl).await,
+            #[cfg(feature = "redis")]
+            Backend::Redis(b) => b.expire(key, ttl).await,
+            #[cfg(feature = "redis")]
+            Backend::Hybrid(b) => b.expire(key, ttl).await,
+            #[cfg(feature = "sled")]
+            Backend::Sled(b) => b.expire(key, ttl).await,
+            #[cfg(feature = "postgres")]
+            Backend::Postgres(b) => b.expire(key, ttl).await,
+        }
+    }
+
+    async fn set_stream(&self, key: &str, data: CacheData, ttl: Option<Duration>) -> Result<()> {
+        match self {
+            Backend::InMemory(b) => b.set_stream(key, data, ttl).await,
+            Backend::Disabled(b) => b.set_stream(key, data, ttl).await,
+            #[cfg(feature = "redis")]
+            Backend::Redis(b) => b.set_stream(key, data, ttl).await,
+            #[cfg(feature = "redis")]
+            Backend::Hybrid(b) => b.set_stream(key, data, ttl).await,
+            #[cfg(feature = "sled")]
+            Backend::Sled(b) => b.set_stream(key, data, ttl).await,
+            #[cfg(feature = "postgres")]
+            Backend::Postgres(b) => b.set_stream(key, data, ttl).await,
+        }
+    }
+
+    async fn get_stream(&self, key: &str, chunk_size: usize) -> Result<Option<CacheData>> {
+        match self {
+            Backend::InMemory(b) => b.get_stream(key, chunk_size).await,
+            Backend::Disabled(b) => b.get_stream(key, chunk_size).await,
+            #[cfg(feature = "redis")]
+            Backend::Redis(b) => b.get_stream(key, chunk_size).await,
+            #[cfg(feature = "redis")]
+            Backend::Hybrid(b) => b.get_stream(key, chunk_size).await,
+            #[cfg(feature = "sled")]
+            Backend::Sled(b) => b.get_stream(key, chunk_size).await,
+            #[cfg(feature = "postgres")]
+            Backend::Postgres(b) => b.get_stream(key, chunk_size).await,
+        }
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        match self {
+            Backend::InMemory(b) => b.health_check().await,
+            Backend::Disabled(b) => b.health_check().await,
+            #[cfg(feature = "redis")]
+            Backend::Redis(b) => b.health_check().await,
+            #[cfg(feature = "redis")]
+            Backend::Hybrid(b) => b.health_check().await,
+            #[cfg(feature = "sled")]
+            Backend::Sled(b) => b.health_check().await,
+            #[cfg(feature = "postgres")]
+            Backend::Postgres(b) => b.health_check().await,
+        }
+    }
+
+    async fn clear_all(&self) -> Result<()> {
+        match self {
+            Backend::InMemory(b) => b.clear_all().await,
+            Backend::Disabled(b) => b.clear_all().await,
+            #[cfg(feature = "redis")]
+            Backend::Redis(b) => b.clear_all().await,
+            #[cfg(feature = "redis")]
+            Backend::Hybrid(b) => b.clear_all().await,
+            #[cfg(feature = "sled")]
+            Backend::Sled(b) => b.clear_all().await,
+            #[cfg(feature = "postgres")]
+            Backend::Postgres(b) => b.clear_all().await,
+        }
+    }
+
+    async fn invalidate_prefix(&self, prefix: &str) -> Result<()> {
+        match self {
+            Backend::InMemory(b) => b.invalidate_prefix(prefix).await,
+            Backend::Disabled(b) => b.invalidate_prefix(prefix).await,
+            #[cfg(feature = "redis")]
+            Backend::Redis(b) => b.invalidate_prefix(prefix).await,
+            #[cfg(feature = "redis")]
+            Backend::Hybrid(b) => b.invalidate_prefix(prefix).await,
+            #[cfg(feature = "sled")]
+            Backend::Sled(b) => b.invalidate_prefix(prefix).await,
+            #[cfg(feature = "postgres")]
+            Backend::Postgres(b) => b.invalidate_prefix(prefix).await,
+        }
+    }
+
+    async fn scan_prefix(&self, prefix: &str) -> Result<Vec<String>> {
+        match self {
+            Backend::InMemory(b) => b.scan_prefix(prefix).await,
+            Backend::Disabled(b) => b.scan_prefix(prefix).await,
+            #[cfg(feature = "redis")]
+            Backend::Redis(b) => b.scan_prefix(prefix).await,
+            #[cfg(feature = "redis")]
+            Backend::Hybrid(b) => b.scan_prefix(prefix).await,
+            #[cfg(feature = "sled")]
+            Backend::Sled(b) => b.scan_prefix(prefix).await,
+            #[cfg(feature = "postgres")]
+            Backend::Postgres(b) => b.scan_prefix(prefix).await,
+        }
+    }
+
+    async fn delete_prefix(&self, prefix: &str) -> Result<u64> {
+        match self {
+            Backend::InMemory(b) => b.delete_prefix(prefix).await,
+            Backend::Disabled(b) => b.delete_prefix(prefix).await,
+            #[cfg(feature = "redis")]
+            Backend::Redis(b) => b.delete_prefix(prefix).await,
+            #[cfg(feature = "redis")]
+            Backend::Hybrid(b) => b.delete_prefix(prefix).await,
+            #[cfg(feature = "sled")]
+            Backend::Sled(b) => b.delete_prefix(prefix).await,
+            #[cfg(feature = "postgres")]
+            Backend::Postgres(b) => b.delete_prefix(prefix).await,
+        }
+    }
+
+    async fn set_with_tags(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        ttl: Option<Duration>,
+        tags: &[&str],
+    ) -> Result<()> {
+        match self {
+            Backend::InMemory(b) => b.set_with_tags(key, value, ttl, tags).await,
+            Backend::Disabled(b) => b.set_with_tags(key, value, ttl, tags).await,
+            #[cfg(feature = "redis")]
+            Backend::Redis(b) => b.set_with_tags(key, value, ttl, tags).await,
+            #[cfg(feature = "redis")]
+            Backend::Hybrid(b) => b.set_with_tags(key, value, ttl, tags).await,
+            #[cfg(feature = "sled")]
+            Backend::Sled(b) => b.set_with_tags(key, value, ttl, tags).await,
+            #[cfg(feature = "postgres")]
+            Backend::Postgres(b) => b.set_with_tags(key, value, ttl, tags).await,
+        }
+    }
+
+    async fn invalidate_tag(&self, tag: &str) -> Result<()> {
+        match self {
+            Backend::InMemory(b) => b.invalidate_tag(tag).await,
+            Backend::Disabled(b) => b.invalidate_tag(tag).await,
+            #[cfg(feature = "redis")]
+            Backend::Redis(b) => b.invalidate_tag(tag).await,
+            #[cfg(feature = "redis")]
+            Backend::Hybrid(b) => b.invalidate_tag(tag).await,
+            #[cfg(feature = "sled")]
+            Backend::Sled(b) => b.invalidate_tag(tag).await,
+            #[cfg(feature = "postgres")]
+            Backend::Postgres(b) => b.invalidate_tag(tag).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_backend_from_url_empty_and_memory_scheme_select_in_memory() {
+        for url in ["", "memory://"] {
+            let backend = Backend::from_url(url).await.expect("Failed to build backend");
+            assert!(matches!(backend, Backend::InMemory(_)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_backend_from_url_rejects_unrecognized_scheme() {
+        let result = Backend::from_url("ftp://example.com").await;
+        match result.unwrap_err() {
+            Error::ConfigError(_) => {}
+            e => panic!("Expected ConfigError, got {:?}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_backend_in_memory_delegates_get_set_delete() {
+        let backend = Backend::from_url("memory://").await.expect("Failed to build backend");
+
+        backend
+            .set("user:1", b"value1".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        assert_eq!(
+            backend.get("user:1").await.expect("Failed to get"),
+            Some(b"value1".to_vec())
+        );
+
+        backend.delete("user:1").await.expect("Failed to delete");
+        assert_eq!(backend.get("user:1").await.expect("Failed to get"), None);
+    }
+
+    #[tokio::test]
+    async fn test_from_config_with_no_connection_string_selects_disabled() {
+        let backend = Backend::from_config(&CacheFactoryConfig::default())
+            .await
+            .expect("Failed to build backend");
+        assert!(matches!(backend, Backend::Disabled(_)));
+
+        backend.set("key", vec![1], None).await.expect("set should be a no-op");
+        assert_eq!(backend.get("key").await.expect("get should succeed"), None);
+    }
+
+    #[tokio::test]
+    async fn test_from_config_empty_connection_string_also_selects_disabled() {
+        let config = CacheFactoryConfig {
+            connection_string: Some(String::new()),
+            default_ttl: None,
+        };
+        let backend = Backend::from_config(&config).await.expect("Failed to build backend");
+        assert!(matches!(backend, Backend::Disabled(_)));
+    }
+
+    #[tokio::test]
+    async fn test_from_config_delegates_non_hybrid_urls_to_from_url() {
+        let config = CacheFactoryConfig {
+            connection_string: Some("memory://".to_string()),
+            default_ttl: None,
+        };
+        let backend = Backend::from_config(&config).await.expect("Failed to build backend");
+        assert!(matches!(backend, Backend::InMemory(_)));
+    }
+
+    #[cfg(not(feature = "redis"))]
+    #[tokio::test]
+    async fn test_from_config_hybrid_without_redis_feature_is_config_error() {
+        let config = CacheFactoryConfig {
+            connection_string: Some("hybrid://localhost:6379".to_string()),
+            default_ttl: None,
+        };
+        let result = Backend::from_config(&config).await;
+        match result.unwrap_err() {
+            Error::ConfigError(_) => {}
+            e => panic!("Expected ConfigError, got {:?}", e),
+        }
+    }
+}
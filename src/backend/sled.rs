@@ -0,0 +1,370 @@
+//! Persistent embedded cache backend backed by `sled`.
+//!
+//! Like [`super::RocksDbBackend`], entries survive process restarts, but
+//! `sled` needs no separate server process or column families - just a
+//! directory on disk - which suits single-node deployments that want crash-safe
+//! caching without running Redis.
+//!
+//! `sled` has no native per-key TTL or compaction-filter hook, so expiry works
+//! like [`super::RocksDbBackend`]'s value encoding (an 8-byte expiry header in
+//! front of the payload) but is enforced two ways instead of one: lazily, when
+//! `get` notices an entry's expiry has passed, and via a periodic background
+//! sweep ([`SledConfig::sweep_interval`]) that walks the tree removing
+//! anything already expired that nothing has read since.
+
+use super::CacheBackend;
+use crate::error::{Error, Result};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Tuning knobs for [`SledBackend`].
+#[derive(Clone, Debug)]
+pub struct SledConfig {
+    /// On-disk directory for the database.
+    pub path: String,
+    /// How often the background sweep walks the tree removing expired
+    /// entries that a `get` hasn't lazily evicted already.
+    pub sweep_interval: Duration,
+}
+
+impl Default for SledConfig {
+    fn default() -> Self {
+        SledConfig {
+            path: "./cache-kit-sled".to_string(),
+            sweep_interval: Duration::from_secs(60),
+        }
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Prefix every value with an 8-byte little-endian expiry timestamp (seconds
+/// since epoch, 0 = no expiry), followed by the raw `serialize_for_cache` blob.
+fn encode_value(value: &[u8], ttl: Option<Duration>) -> Vec<u8> {
+    let expires_at = ttl.map(|d| now_secs() + d.as_secs()).unwrap_or(0);
+    let mut encoded = Vec::with_capacity(8 + value.len());
+    encoded.extend_from_slice(&expires_at.to_le_bytes());
+    encoded.extend_from_slice(value);
+    encoded
+}
+
+/// Returns `None` for values with no expiry, `Some(expires_at)` otherwise.
+fn read_expiry(value: &[u8]) -> Option<u64> {
+    let expires_at = u64::from_le_bytes(value.get(0..8)?.try_into().ok()?);
+    if expires_at == 0 {
+        None
+    } else {
+        Some(expires_at)
+    }
+}
+
+/// Strips the expiry header, returning the original `serialize_for_cache` blob.
+/// Returns `None` if the entry has expired.
+fn decode_value(value: &[u8]) -> Option<Vec<u8>> {
+    if let Some(expires_at) = read_expiry(value) {
+        if now_secs() > expires_at {
+            return None;
+        }
+    }
+    Some(value.get(8..)?.to_vec())
+}
+
+/// Remove every entry in `db` whose embedded expiry has already passed.
+fn sweep_expired(db: &sled::Db) {
+    let expired: Vec<sled::IVec> = db
+        .iter()
+        .filter_map(|entry| {
+            let (key, value) = entry.ok()?;
+            let expires_at = read_expiry(&value)?;
+            (now_secs() > expires_at).then_some(key)
+        })
+        .collect();
+
+    for key in &expired {
+        let _ = db.remove(key);
+    }
+
+    if !expired.is_empty() {
+        debug!("✓ sled sweep removed {} expired entries", expired.len());
+    }
+}
+
+/// `sled`-backed persistent cache backend.
+///
+/// # Example
+///
+/// ```no_run
+/// # use cache_kit::backend::{SledBackend, SledConfig, CacheBackend};
+/// # use cache_kit::error::Result;
+/// # async fn example() -> Result<()> {
+/// let backend = SledBackend::open(SledConfig {
+///     path: "/var/lib/myapp/cache".to_string(),
+///     ..Default::default()
+/// })?;
+///
+/// backend.set("invoice:42", b"value".to_vec(), None).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SledBackend {
+    db: Arc<sled::Db>,
+}
+
+impl SledBackend {
+    /// Open (or create) a sled-backed cache at `config.path`, and start its
+    /// background expiry sweep.
+    ///
+    /// # Errors
+    /// Returns `Err` if the database cannot be opened at the given path.
+    pub fn open(config: SledConfig) -> Result<Self> {
+        let db = sled::open(&config.path).map_err(|e| {
+            Error::BackendError(format!("Failed to open sled db at {}: {}", config.path, e))
+        })?;
+
+        info!("✓ sled backend opened at {}", config.path);
+
+        let backend = SledBackend { db: Arc::new(db) };
+        backend.spawn_sweeper(config.sweep_interval);
+        Ok(backend)
+    }
+
+    /// Open with default tuning at `path`.
+    ///
+    /// # Errors
+    /// Returns `Err` if the database cannot be opened at the given path.
+    pub fn open_at<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::open(SledConfig {
+            path: path.as_ref().display().to_string(),
+            ..Default::default()
+        })
+    }
+
+    fn spawn_sweeper(&self, interval: Duration) {
+        let db = self.db.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                sweep_expired(&db);
+            }
+        });
+    }
+
+    /// Number of entries, not counting ones whose expiry has passed but
+    /// haven't been swept or read yet.
+    pub async fn len(&self) -> usize {
+        self.db
+            .iter()
+            .filter(|entry| matches!(entry, Ok((_, value)) if decode_value(value).is_some()))
+            .count()
+    }
+
+    /// Whether every entry is either absent or logically expired.
+    pub async fn is_empty(&self) -> bool {
+        !self
+            .db
+            .iter()
+            .any(|entry| matches!(entry, Ok((_, value)) if decode_value(value).is_some()))
+    }
+}
+
+/// Run a sled operation on the blocking thread pool. `sled` fsyncs writes by
+/// default and can block on compaction/flush, so calling it straight from an
+/// `async fn` risks stalling every other task on the tokio runtime.
+async fn spawn_db_blocking<F, T>(f: F) -> Result<T>
+where
+    F: FnOnce() -> Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| Error::BackendError(format!("sled blocking task panicked: {}", e)))?
+}
+
+impl CacheBackend for SledBackend {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let key = key.to_string();
+        let db = self.db.clone();
+
+        let raw = spawn_db_blocking({
+            let key = key.clone();
+            move || {
+                db.get(key.as_bytes())
+                    .map_err(|e| Error::BackendError(format!("sled GET failed for key {}: {}", key, e)))
+            }
+        })
+        .await?;
+
+        let Some(bytes) = raw else {
+            debug!("✓ sled GET {} -> MISS", key);
+            return Ok(None);
+        };
+
+        match decode_value(&bytes) {
+            Some(value) => {
+                debug!("✓ sled GET {} -> HIT", key);
+                Ok(Some(value))
+            }
+            None => {
+                // Expired - evict it now rather than waiting for the next sweep.
+                let db = self.db.clone();
+                let evict_key = key.clone();
+                spawn_db_blocking(move || {
+                    let _ = db.remove(evict_key.as_bytes());
+                    Ok(())
+                })
+                .await?;
+                debug!("✓ sled GET {} -> MISS (expired)", key);
+                Ok(None)
+            }
+        }
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<()> {
+        let encoded = encode_value(&value, ttl);
+        let key = key.to_string();
+        let db = self.db.clone();
+
+        spawn_db_blocking({
+            let key = key.clone();
+            move || {
+                db.insert(key.as_bytes(), encoded).map_err(|e| {
+                    Error::BackendError(format!("sled SET failed for key {}: {}", key, e))
+                })
+            }
+        })
+        .await?;
+
+        debug!("✓ sled SET {} (TTL: {:?})", key, ttl);
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        let key = key.to_string();
+        let db = self.db.clone();
+
+        spawn_db_blocking({
+            let key = key.clone();
+            move || {
+                db.remove(key.as_bytes()).map_err(|e| {
+                    Error::BackendError(format!("sled DELETE failed for key {}: {}", key, e))
+                })
+            }
+        })
+        .await?;
+
+        debug!("✓ sled DELETE {}", key);
+        Ok(())
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        // A live handle to an open embedded database is always reachable;
+        // there's no network hop to probe.
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_backend(name: &str) -> SledBackend {
+        let path = std::env::temp_dir().join(format!("cache-kit-sled-test-{}", name));
+        let _ = std::fs::remove_dir_all(&path);
+        SledBackend::open_at(&path).expect("Failed to open sled backend")
+    }
+
+    #[tokio::test]
+    async fn test_sled_backend_set_get() {
+        let backend = temp_backend("set_get");
+
+        backend
+            .set("user:1", b"value1".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        let result = backend.get("user:1").await.expect("Failed to get");
+        assert_eq!(result, Some(b"value1".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn test_sled_backend_miss() {
+        let backend = temp_backend("miss");
+
+        let result = backend.get("nonexistent:1").await.expect("Failed to get");
+        assert_eq!(result, None);
+    }
+
+    #[tokio::test]
+    async fn test_sled_backend_delete() {
+        let backend = temp_backend("delete");
+
+        backend
+            .set("user:1", b"value1".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        backend.delete("user:1").await.expect("Failed to delete");
+
+        assert_eq!(backend.get("user:1").await.expect("Failed to get"), None);
+    }
+
+    #[tokio::test]
+    async fn test_sled_backend_ttl_expiration() {
+        let backend = temp_backend("ttl");
+
+        backend
+            .set(
+                "session:1",
+                b"value1".to_vec(),
+                Some(Duration::from_secs(0)),
+            )
+            .await
+            .expect("Failed to set");
+
+        // A zero-second TTL should already be in the past by the next read.
+        std::thread::sleep(Duration::from_millis(1100));
+        assert_eq!(backend.get("session:1").await.expect("Failed to get"), None);
+    }
+
+    #[tokio::test]
+    async fn test_sled_backend_len_and_is_empty_ignore_expired() {
+        let backend = temp_backend("len");
+
+        assert!(backend.is_empty().await);
+        assert_eq!(backend.len().await, 0);
+
+        backend
+            .set("user:1", b"a".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        backend
+            .set("user:2", b"b".to_vec(), Some(Duration::from_secs(0)))
+            .await
+            .expect("Failed to set");
+
+        std::thread::sleep(Duration::from_millis(1100));
+
+        assert_eq!(backend.len().await, 1);
+        assert!(!backend.is_empty().await);
+    }
+
+    #[test]
+    fn test_value_encoding_roundtrip() {
+        let original = b"payload-bytes".to_vec();
+        let encoded = encode_value(&original, None);
+        assert_eq!(decode_value(&encoded), Some(original));
+    }
+
+    #[test]
+    fn test_value_encoding_expired() {
+        let encoded = encode_value(b"payload", Some(Duration::from_secs(0)));
+        std::thread::sleep(Duration::from_millis(1100));
+        assert_eq!(decode_value(&encoded), None);
+    }
+}
@@ -0,0 +1,434 @@
+//! Two-tier cache layering a byte-budgeted hot store in front of a slower
+//! cold one, overflowing evicted entries into the cold tier asynchronously.
+//!
+//! Unlike [`super::HotColdBackend`], which writes through to both tiers on
+//! every `set`, [`TieredBackend`] writes only to the hot tier; when the hot
+//! tier's tracked byte size exceeds a configured budget, the oldest entries
+//! are evicted and handed off to a background task that writes them into the
+//! cold tier through a bounded channel. A slow or momentarily-unreachable
+//! cold tier therefore never blocks a caller's `set` - at the cost of a
+//! window where an evicted key only lives in the channel, not yet in either
+//! tier's queryable state.
+
+use super::CacheBackend;
+use crate::error::Result;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Default bound on the hot-to-cold overflow channel. Past this many pending
+/// evictions, further overflow is dropped (and logged) rather than applying
+/// backpressure to callers.
+const DEFAULT_CHANNEL_CAPACITY: usize = 1024;
+
+/// A value evicted from the hot tier, queued for the cold tier.
+struct Overflow {
+    key: String,
+    value: Vec<u8>,
+    ttl: Option<Duration>,
+}
+
+/// Tracks which keys are in the hot tier, their byte size, and insertion
+/// order, so [`TieredBackend`] can decide what to evict once `byte_budget`
+/// is exceeded without asking the (generic) hot backend for any of this.
+struct HotIndex {
+    /// Oldest-first; the front is the next eviction candidate.
+    order: VecDeque<String>,
+    sizes: HashMap<String, usize>,
+    total_bytes: usize,
+}
+
+impl HotIndex {
+    fn new() -> Self {
+        HotIndex {
+            order: VecDeque::new(),
+            sizes: HashMap::new(),
+            total_bytes: 0,
+        }
+    }
+
+    /// Record `key` as freshly written with `size` bytes, replacing any
+    /// previous record (and its contribution to `total_bytes`) for the same
+    /// key.
+    fn record(&mut self, key: &str, size: usize) {
+        if let Some(old_size) = self.sizes.remove(key) {
+            self.total_bytes -= old_size;
+            self.order.retain(|k| k != key);
+        }
+        self.order.push_back(key.to_string());
+        self.sizes.insert(key.to_string(), size);
+        self.total_bytes += size;
+    }
+
+    fn forget(&mut self, key: &str) {
+        if let Some(size) = self.sizes.remove(key) {
+            self.total_bytes -= size;
+            self.order.retain(|k| k != key);
+        }
+    }
+
+    /// Pop the oldest tracked key, if any, for eviction.
+    fn pop_oldest(&mut self) -> Option<String> {
+        let key = self.order.pop_front()?;
+        if let Some(size) = self.sizes.remove(&key) {
+            self.total_bytes -= size;
+        }
+        Some(key)
+    }
+}
+
+/// Composes a byte-budgeted hot backend with an overflow cold backend.
+///
+/// Typical pairing: a plain `InMemoryBackend` as `hot` (this backend does its
+/// own byte-budget tracking, so `hot` doesn't need its own capacity limit) in
+/// front of a `RedisBackend` or `MemcachedBackend` as `cold` for durable
+/// overflow.
+///
+/// # Semantics
+///
+/// - **`get`**: Check `hot` first. On a hot miss, fall back to `cold`; a cold
+///   hit is promoted back into `hot` (subject to the same byte budget) before
+///   being returned.
+/// - **`set`**: Write only to `hot`, then track its size. If the hot tier's
+///   tracked size now exceeds `byte_budget`, evict the oldest entries and
+///   hand each one to a background task draining a bounded channel into
+///   `cold`. A full channel drops the overflow (logged), so `set` itself
+///   never blocks on the cold tier.
+/// - **`delete`**/**`mdelete`**: Fan out to both tiers, so a stale hot or
+///   cold copy can't resurface after the other is gone - this is also what
+///   `CacheExpander`'s `CacheStrategy::Invalidate` calls, so invalidating
+///   through a `TieredBackend` already evicts both tiers with no extra
+///   wiring.
+/// - **`health_check`**: Reflects only `cold`'s reachability - the hot tier
+///   is local and assumed always reachable, so it would otherwise mask a
+///   real outage.
+///
+/// # Example
+///
+/// ```no_run
+/// use cache_kit::backend::{TieredBackend, InMemoryBackend};
+///
+/// # async fn example() -> cache_kit::Result<()> {
+/// let hot = InMemoryBackend::new();
+/// let cold = InMemoryBackend::new(); // stand-in for a Redis/Memcached backend
+/// let backend = TieredBackend::new(hot, cold, 1_000_000);
+///
+/// backend.set("key", b"value".to_vec(), None).await?;
+/// let value = backend.get("key").await?;
+/// assert!(value.is_some());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct TieredBackend<H, C> {
+    hot: H,
+    cold: C,
+    index: Arc<Mutex<HotIndex>>,
+    byte_budget: usize,
+    overflow_tx: mpsc::Sender<Overflow>,
+}
+
+impl<H, C> TieredBackend<H, C>
+where
+    H: CacheBackend,
+    C: CacheBackend + 'static,
+{
+    /// Create a tiered backend, spawning the background task that drains
+    /// evicted hot entries into `cold`.
+    ///
+    /// `byte_budget` bounds the hot tier's tracked value size (keys and
+    /// per-entry bookkeeping aren't counted); once exceeded, the oldest
+    /// entries are evicted to make room.
+    pub fn new(hot: H, cold: C, byte_budget: usize) -> Self {
+        Self::with_channel_capacity(hot, cold, byte_budget, DEFAULT_CHANNEL_CAPACITY)
+    }
+
+    /// Like [`TieredBackend::new`], but with an explicit bound on the
+    /// hot-to-cold overflow channel instead of [`DEFAULT_CHANNEL_CAPACITY`].
+    pub fn with_channel_capacity(
+        hot: H,
+        cold: C,
+        byte_budget: usize,
+        channel_capacity: usize,
+    ) -> Self {
+        let (tx, mut rx) = mpsc::channel::<Overflow>(channel_capacity);
+        let listener_cold = cold.clone();
+
+        tokio::spawn(async move {
+            while let Some(overflow) = rx.recv().await {
+                if let Err(e) = listener_cold
+                    .set(&overflow.key, overflow.value, overflow.ttl)
+                    .await
+                {
+                    warn!(
+                        "⚠ TieredBackend overflow write for {} to cold tier failed: {}",
+                        overflow.key, e
+                    );
+                }
+            }
+        });
+
+        TieredBackend {
+            hot,
+            cold,
+            index: Arc::new(Mutex::new(HotIndex::new())),
+            byte_budget,
+            overflow_tx: tx,
+        }
+    }
+
+    /// Borrow the hot tier (useful for tier-specific diagnostics).
+    pub fn hot(&self) -> &H {
+        &self.hot
+    }
+
+    /// Borrow the cold tier.
+    pub fn cold(&self) -> &C {
+        &self.cold
+    }
+
+    /// Write `value` into the hot tier and evict overflow to cold if the
+    /// byte budget is now exceeded. Shared by `set` and cold-hit promotion.
+    async fn write_hot(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<()> {
+        let size = value.len();
+        self.hot.set(key, value, ttl).await?;
+        self.index.lock().expect("lock poisoned").record(key, size);
+        self.evict_overflow().await;
+        Ok(())
+    }
+
+    /// Evict the oldest hot entries until tracked usage is back within
+    /// `byte_budget`, pushing each eviction to the cold tier via the bounded
+    /// channel. A full channel drops the overflow rather than blocking.
+    async fn evict_overflow(&self) {
+        loop {
+            let over_budget = self.index.lock().expect("lock poisoned").total_bytes > self.byte_budget;
+            if !over_budget {
+                return;
+            }
+
+            let Some(key) = self.index.lock().expect("lock poisoned").pop_oldest() else {
+                return;
+            };
+
+            let value = match self.hot.get(&key).await {
+                Ok(Some(value)) => value,
+                Ok(None) => continue,
+                Err(e) => {
+                    warn!("⚠ TieredBackend eviction read for {} failed: {}", key, e);
+                    continue;
+                }
+            };
+
+            if let Err(e) = self.hot.delete(&key).await {
+                warn!("⚠ TieredBackend eviction delete for {} failed: {}", key, e);
+            }
+
+            let overflow = Overflow {
+                key: key.clone(),
+                value,
+                ttl: None,
+            };
+            if self.overflow_tx.try_send(overflow).is_err() {
+                warn!(
+                    "⚠ TieredBackend overflow channel full, dropping eviction for {}",
+                    key
+                );
+            } else {
+                debug!("✓ TieredBackend evicted {} from hot, queued for cold", key);
+            }
+        }
+    }
+}
+
+impl<H, C> CacheBackend for TieredBackend<H, C>
+where
+    H: CacheBackend,
+    C: CacheBackend + 'static,
+{
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        if let Some(value) = self.hot.get(key).await? {
+            debug!("✓ Tiered GET {} -> HOT HIT", key);
+            return Ok(Some(value));
+        }
+
+        match self.cold.get(key).await? {
+            Some(value) => {
+                debug!("✓ Tiered GET {} -> COLD HIT, promoting to hot", key);
+                if let Err(e) = self.write_hot(key, value.clone(), None).await {
+                    warn!("⚠ Tiered promote {} to hot failed: {}", key, e);
+                }
+                Ok(Some(value))
+            }
+            None => {
+                debug!("✓ Tiered GET {} -> MISS", key);
+                Ok(None)
+            }
+        }
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<()> {
+        self.write_hot(key, value, ttl).await?;
+        debug!("✓ Tiered SET {} (hot, budget {})", key, self.byte_budget);
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.hot.delete(key).await?;
+        self.index.lock().expect("lock poisoned").forget(key);
+        self.cold.delete(key).await?;
+        debug!("✓ Tiered DELETE {} (both tiers)", key);
+        Ok(())
+    }
+
+    async fn mdelete(&self, keys: &[&str]) -> Result<()> {
+        self.hot.mdelete(keys).await?;
+        {
+            let mut index = self.index.lock().expect("lock poisoned");
+            for key in keys {
+                index.forget(key);
+            }
+        }
+        self.cold.mdelete(keys).await?;
+        debug!("✓ Tiered MDELETE {} keys (both tiers)", keys.len());
+        Ok(())
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool> {
+        if self.hot.exists(key).await? {
+            return Ok(true);
+        }
+        self.cold.exists(key).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        self.cold.health_check().await
+    }
+
+    async fn clear_all(&self) -> Result<()> {
+        self.hot.clear_all().await?;
+        *self.index.lock().expect("lock poisoned") = HotIndex::new();
+        self.cold.clear_all().await?;
+        warn!("⚠ Tiered CLEAR_ALL executed - both tiers cleared!");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::InMemoryBackend;
+
+    #[tokio::test]
+    async fn test_tiered_set_writes_only_to_hot() {
+        let hot = InMemoryBackend::new();
+        let cold = InMemoryBackend::new();
+        let backend = TieredBackend::new(hot, cold, 1_000_000);
+
+        backend
+            .set("key1", b"value1".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        assert_eq!(
+            backend.hot().get("key1").await.expect("Failed to get"),
+            Some(b"value1".to_vec())
+        );
+        assert_eq!(backend.cold().get("key1").await.expect("Failed to get"), None);
+    }
+
+    #[tokio::test]
+    async fn test_tiered_get_prefers_hot_tier() {
+        let hot = InMemoryBackend::new();
+        let cold = InMemoryBackend::new();
+        hot.set("key1", b"hot_value".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        cold.set("key1", b"cold_value".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        let backend = TieredBackend::new(hot, cold, 1_000_000);
+
+        assert_eq!(
+            backend.get("key1").await.expect("Failed to get"),
+            Some(b"hot_value".to_vec())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tiered_cold_hit_promotes_to_hot() {
+        let hot = InMemoryBackend::new();
+        let cold = InMemoryBackend::new();
+        cold.set("key1", b"cold_value".to_vec(), None)
+            .await
+            .expect("Failed to set");
+
+        let backend = TieredBackend::new(hot, cold, 1_000_000);
+
+        assert_eq!(
+            backend.get("key1").await.expect("Failed to get"),
+            Some(b"cold_value".to_vec())
+        );
+        assert_eq!(
+            backend.hot().get("key1").await.expect("Failed to get"),
+            Some(b"cold_value".to_vec())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tiered_exceeding_budget_overflows_oldest_key_to_cold() {
+        let hot = InMemoryBackend::new();
+        let cold = InMemoryBackend::new();
+        // Each value is 10 bytes; a budget of 15 only fits one.
+        let backend = TieredBackend::new(hot, cold, 15);
+
+        backend
+            .set("key1", vec![1u8; 10], None)
+            .await
+            .expect("Failed to set");
+        backend
+            .set("key2", vec![2u8; 10], None)
+            .await
+            .expect("Failed to set");
+
+        // Give the background listener a chance to drain the channel.
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        assert_eq!(backend.hot().get("key1").await.expect("Failed to get"), None);
+        assert_eq!(
+            backend.cold().get("key1").await.expect("Failed to get"),
+            Some(vec![1u8; 10])
+        );
+        assert_eq!(
+            backend.hot().get("key2").await.expect("Failed to get"),
+            Some(vec![2u8; 10])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tiered_delete_removes_from_both_tiers() {
+        let hot = InMemoryBackend::new();
+        let cold = InMemoryBackend::new();
+        let backend = TieredBackend::new(hot, cold, 1_000_000);
+
+        backend
+            .set("key1", b"value1".to_vec(), None)
+            .await
+            .expect("Failed to set");
+        backend.delete("key1").await.expect("Failed to delete");
+
+        assert_eq!(backend.hot().get("key1").await.expect("Failed to get"), None);
+        assert_eq!(backend.cold().get("key1").await.expect("Failed to get"), None);
+    }
+
+    #[tokio::test]
+    async fn test_tiered_health_check_reflects_cold_tier_only() {
+        let hot = InMemoryBackend::new();
+        let cold = InMemoryBackend::new();
+        let backend = TieredBackend::new(hot, cold, 1_000_000);
+
+        assert!(backend.health_check().await.expect("Failed health check"));
+    }
+}
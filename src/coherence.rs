@@ -0,0 +1,239 @@
+//! Cross-instance cache coherence at the [`CacheBackend`] level.
+//!
+//! [`CacheExpander::with_invalidation_bus`](crate::CacheExpander::with_invalidation_bus)
+//! already publishes to an [`InvalidationBus`] whenever `CacheStrategy::Invalidate`
+//! runs, and [`crate::invalidation::CacheInvalidator`] already consumes such a
+//! bus to delete matching keys from a backend - so the two together give
+//! coherence for writes that go through `CacheExpander::execute`. But a
+//! backend is sometimes deleted from directly (e.g. a lower-level cache
+//! warming job, or code using `CacheBackend` without an expander at all), and
+//! those deletes bypass the strategy layer entirely. [`CoherentBackend`] wraps
+//! any `CacheBackend` so *every* `delete`, regardless of how it was reached,
+//! publishes and is kept in sync across instances - composing with
+//! [`crate::backend::InMemoryBackend`], [`crate::backend::RedisBackend`], or
+//! any other implementation.
+
+use crate::backend::CacheBackend;
+use crate::error::Result;
+use crate::invalidation::InvalidationBus;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast;
+
+/// How long a just-published key is remembered, to drop the bus's own echo
+/// of that publish back to this same instance.
+///
+/// `InvalidationBus::subscribe` has no notion of "don't deliver my own
+/// publishes back to me" - that would need `publish`/`InvalidationEvent` to
+/// carry a per-instance origin tag, rippling through every existing
+/// implementation and test of the trait. Suppressing by "did *I* just publish
+/// this key" on the receive side gets the same effect without widening the
+/// trait: an incoming event for a key published locally within this window
+/// is assumed to be that publish's own echo (or a near-duplicate delete from
+/// elsewhere, which is equally safe to skip since it's already gone locally).
+const DEFAULT_ECHO_WINDOW: Duration = Duration::from_millis(200);
+
+/// Wraps `inner` so every [`CacheBackend::delete`] also publishes to `bus`,
+/// and a background task applies deletes published by other instances back
+/// to `inner`.
+///
+/// # Example
+///
+/// ```no_run
+/// use cache_kit::backend::InMemoryBackend;
+/// use cache_kit::coherence::CoherentBackend;
+/// use cache_kit::invalidation::{BroadcastInvalidationBus, InvalidationBus};
+/// use std::sync::Arc;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let bus: Arc<dyn InvalidationBus> = Arc::new(BroadcastInvalidationBus::new(16));
+/// let backend = CoherentBackend::new(InMemoryBackend::new(), bus);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CoherentBackend<B: CacheBackend> {
+    inner: B,
+    bus: Arc<dyn InvalidationBus>,
+    recently_published: Arc<Mutex<HashMap<String, Instant>>>,
+    echo_window: Duration,
+}
+
+impl<B: CacheBackend + 'static> CoherentBackend<B> {
+    /// Wrap `inner`, broadcasting its deletes on `bus` (any transport -
+    /// [`crate::invalidation::BroadcastInvalidationBus`] for in-process
+    /// fanout, a Redis/MQTT-backed implementation across processes) and
+    /// applying `bus`'s deletes back to `inner`.
+    pub fn new(inner: B, bus: Arc<dyn InvalidationBus>) -> Self {
+        Self::with_echo_window(inner, bus, DEFAULT_ECHO_WINDOW)
+    }
+
+    /// Like [`Self::new`], with an explicit self-echo suppression window.
+    pub fn with_echo_window(inner: B, bus: Arc<dyn InvalidationBus>, echo_window: Duration) -> Self {
+        let recently_published = Arc::new(Mutex::new(HashMap::new()));
+        spawn_listener(inner.clone(), bus.clone(), recently_published.clone(), echo_window);
+
+        CoherentBackend {
+            inner,
+            bus,
+            recently_published,
+            echo_window,
+        }
+    }
+
+    fn mark_published(&self, key: &str) {
+        self.recently_published
+            .lock()
+            .expect("lock poisoned")
+            .insert(key.to_string(), Instant::now());
+    }
+}
+
+fn spawn_listener<B: CacheBackend + 'static>(
+    backend: B,
+    bus: Arc<dyn InvalidationBus>,
+    recently_published: Arc<Mutex<HashMap<String, Instant>>>,
+    echo_window: Duration,
+) {
+    let mut rx = bus.subscribe();
+
+    tokio::spawn(async move {
+        loop {
+            match rx.recv().await {
+                Ok(event) => {
+                    if is_recent_echo(&recently_published, &event.key, echo_window) {
+                        debug!("⏭ Coherence echo for {} suppressed", event.key);
+                        continue;
+                    }
+                    if let Err(e) = backend.delete(&event.key).await {
+                        warn!("Failed to apply coherence invalidation for {}: {}", event.key, e);
+                    } else {
+                        debug!("✓ Applied coherence invalidation for {}", event.key);
+                    }
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    warn!("Coherence listener lagged by {} events, some invalidations were missed", n);
+                }
+            }
+        }
+    });
+}
+
+fn is_recent_echo(
+    recently_published: &Arc<Mutex<HashMap<String, Instant>>>,
+    key: &str,
+    window: Duration,
+) -> bool {
+    recently_published
+        .lock()
+        .expect("lock poisoned")
+        .get(key)
+        .is_some_and(|published_at| published_at.elapsed() < window)
+}
+
+impl<B: CacheBackend + 'static> CacheBackend for CoherentBackend<B> {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        self.inner.get(key).await
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<()> {
+        self.inner.set(key, value, ttl).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.inner.delete(key).await?;
+        self.mark_published(key);
+        self.bus.publish(key);
+        Ok(())
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        self.inner.health_check().await
+    }
+
+    async fn clear_all(&self) -> Result<()> {
+        self.inner.clear_all().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::InMemoryBackend;
+    use crate::invalidation::BroadcastInvalidationBus;
+
+    #[tokio::test]
+    async fn test_coherent_backend_delete_publishes_to_bus() {
+        let bus: Arc<dyn InvalidationBus> = Arc::new(BroadcastInvalidationBus::new(16));
+        let mut rx = bus.subscribe();
+        let backend = CoherentBackend::new(InMemoryBackend::new(), bus);
+
+        backend
+            .set("user:1", vec![1], None)
+            .await
+            .expect("Failed to set");
+        backend.delete("user:1").await.expect("Failed to delete");
+
+        let event = rx.recv().await.expect("event should be published");
+        assert_eq!(event.key, "user:1");
+    }
+
+    #[tokio::test]
+    async fn test_coherent_backend_applies_remote_deletes() {
+        let bus: Arc<dyn InvalidationBus> = Arc::new(BroadcastInvalidationBus::new(16));
+        let backend = CoherentBackend::new(InMemoryBackend::new(), bus.clone());
+
+        backend
+            .set("user:1", vec![1], None)
+            .await
+            .expect("Failed to set");
+
+        // Simulate another instance deleting the same key remotely.
+        bus.publish("user:1");
+
+        for _ in 0..50 {
+            if backend.get("user:1").await.expect("Failed to get").is_none() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        assert!(backend
+            .get("user:1")
+            .await
+            .expect("Failed to get")
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_coherent_backend_suppresses_its_own_echo() {
+        let bus: Arc<dyn InvalidationBus> = Arc::new(BroadcastInvalidationBus::new(16));
+        let backend = CoherentBackend::with_echo_window(
+            InMemoryBackend::new(),
+            bus,
+            Duration::from_secs(60),
+        );
+
+        backend
+            .set("user:1", vec![1], None)
+            .await
+            .expect("Failed to set");
+        backend.delete("user:1").await.expect("Failed to delete");
+
+        // Re-populate immediately; if the echo weren't suppressed, the
+        // listener's delayed apply of our own publish would wipe it out.
+        backend
+            .set("user:1", vec![2], None)
+            .await
+            .expect("Failed to set");
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        assert_eq!(
+            backend.get("user:1").await.expect("Failed to get"),
+            Some(vec![2])
+        );
+    }
+}
@@ -0,0 +1,416 @@
+//! Last-writer-wins CRDTs for reconciling concurrent writes to the same key
+//! across independently-written backends (e.g. one [`CacheBackend`] per
+//! region, each accepting local writes with no cross-region locking).
+//!
+//! Plain [`CacheBackend::set`] has no notion of "did someone else write this
+//! key more recently, on a different backend" - two regions racing to update
+//! the same key converge on whichever write reached a given backend last,
+//! not whichever write actually happened last. [`Lww<T>`] attaches a logical
+//! timestamp and a tie-breaking node id to a value so two copies merge
+//! deterministically; [`LwwMap<K, V>`] does the same per-field, so two
+//! regions writing different fields of the same logical record don't
+//! clobber each other. [`LwwReplicatedBackend`] wraps a set of backends (one
+//! per region) and merges on every read, writing the winner back to any
+//! replica it beat (read-repair) so replicas converge without a separate
+//! anti-entropy process.
+//!
+//! [`LwwReplicatedBackend`] is a standalone adapter rather than a
+//! [`CacheBackend`] impl: `CacheBackend`'s `get`/`set` are fixed to
+//! `Vec<u8>` with no value-type generic, so there's nowhere for it to plug
+//! in an `Lww<T>` envelope for an arbitrary `T` - the same reason
+//! [`crate::CacheExpander`] (which also serializes typed values around a
+//! `CacheBackend`) is its own type rather than a `CacheBackend` impl.
+
+use crate::backend::CacheBackend;
+use crate::error::Result;
+use crate::serialization::{deserialize_from_cache, serialize_for_cache};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::time::Duration;
+
+/// A value tagged with a logical clock and a tie-breaking node id, so two
+/// concurrent copies of the same key can be merged deterministically instead
+/// of one silently clobbering the other.
+///
+/// Ties on `timestamp` (e.g. two regions writing within the same millisecond)
+/// are broken by `node_id`, so [`Lww::merge`] is commutative and idempotent
+/// no matter which copy is inspected first - the same pair of values always
+/// merges to the same winner.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lww<T> {
+    pub value: T,
+    pub timestamp: u64,
+    pub node_id: u64,
+}
+
+impl<T> Lww<T> {
+    /// Tag `value` with `timestamp` and `node_id`.
+    pub fn new(value: T, timestamp: u64, node_id: u64) -> Self {
+        Lww {
+            value,
+            timestamp,
+            node_id,
+        }
+    }
+
+    fn wins_over(&self, other: &Lww<T>) -> bool {
+        (self.timestamp, self.node_id) >= (other.timestamp, other.node_id)
+    }
+
+    /// Merge two copies of the same key, keeping whichever has the greater
+    /// `(timestamp, node_id)`.
+    pub fn merge(self, other: Lww<T>) -> Lww<T> {
+        if self.wins_over(&other) {
+            self
+        } else {
+            other
+        }
+    }
+}
+
+/// Field-level last-writer-wins map: each key merges independently, so two
+/// regions writing different fields of the same logical record never lose
+/// either write - unlike wrapping the whole record in a single [`Lww`],
+/// where the later writer's full snapshot clobbers the other region's
+/// unrelated field.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LwwMap<K, V> {
+    entries: HashMap<K, Lww<V>>,
+}
+
+impl<K, V> Default for LwwMap<K, V> {
+    fn default() -> Self {
+        LwwMap {
+            entries: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V> LwwMap<K, V> {
+    /// An empty map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Current value for `key`, if it's been set.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.entries.get(key).map(|entry| &entry.value)
+    }
+
+    /// Number of distinct keys tracked.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// `true` if no keys have been set.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> LwwMap<K, V> {
+    /// Set `key` to `value` tagged with `timestamp`/`node_id`, merging with
+    /// any existing entry for `key` rather than overwriting it outright.
+    pub fn set(&mut self, key: K, value: V, timestamp: u64, node_id: u64) {
+        self.merge_entry(key, Lww::new(value, timestamp, node_id));
+    }
+
+    /// Merge every entry of `other` into `self`, field by field.
+    pub fn merge(&mut self, other: LwwMap<K, V>) {
+        for (key, incoming) in other.entries {
+            self.merge_entry(key, incoming);
+        }
+    }
+
+    fn merge_entry(&mut self, key: K, incoming: Lww<V>) {
+        match self.entries.remove(&key) {
+            Some(existing) => {
+                self.entries.insert(key, existing.merge(incoming));
+            }
+            None => {
+                self.entries.insert(key, incoming);
+            }
+        }
+    }
+}
+
+/// Wraps a set of same-shaped backends (one per region/cluster) with
+/// last-writer-wins reconciliation: [`LwwReplicatedBackend::set`] tags the
+/// value with a timestamp and this instance's `node_id` and writes it to
+/// every replica; [`LwwReplicatedBackend::get`] reads all replicas, keeps
+/// the one with the greatest `(timestamp, node_id)`, and read-repairs any
+/// replica that lost by writing the winner back to it.
+///
+/// Unlike [`super::backend::TieredBackend`]/[`super::backend::HotColdBackend`],
+/// which layer a fast tier in front of a slow one, every replica here is a
+/// peer - there's no hot/cold distinction, just "which write actually
+/// happened most recently".
+///
+/// # Example
+///
+/// ```no_run
+/// use cache_kit::backend::InMemoryBackend;
+/// use cache_kit::crdt::LwwReplicatedBackend;
+///
+/// # async fn example() -> cache_kit::Result<()> {
+/// let us_east = InMemoryBackend::new(); // stand-in for a per-region MemcachedBackend
+/// let eu_west = InMemoryBackend::new();
+/// let backend = LwwReplicatedBackend::new(vec![us_east, eu_west], 1);
+///
+/// backend.set("user:1:plan", &"pro".to_string(), 1_000, None).await?;
+/// let plan: Option<String> = backend.get("user:1:plan").await?;
+/// assert_eq!(plan.as_deref(), Some("pro"));
+/// # Ok(())
+/// # }
+/// ```
+pub struct LwwReplicatedBackend<B> {
+    replicas: Vec<B>,
+    node_id: u64,
+}
+
+/// Wire envelope actually stored on each replica: an [`Lww`] entry plus the
+/// TTL it was written with, in milliseconds. `Lww<T>` itself stays
+/// TTL-agnostic (it's also used bare by [`LwwMap`]) - this carries the extra
+/// bookkeeping [`LwwReplicatedBackend`] needs so read-repair can reapply the
+/// original expiry instead of writing the healed replica back as permanent.
+#[derive(Serialize, Deserialize)]
+struct ReplicatedEntry<T> {
+    entry: Lww<T>,
+    ttl_ms: Option<u64>,
+}
+
+impl<B: CacheBackend> LwwReplicatedBackend<B> {
+    /// Wrap `replicas`, tagging every write from this instance with `node_id`.
+    ///
+    /// `node_id` must be unique per writer (e.g. per region) - two writers
+    /// sharing a `node_id` can't be tie-broken and the later of their
+    /// same-timestamp writes wins arbitrarily.
+    pub fn new(replicas: Vec<B>, node_id: u64) -> Self {
+        LwwReplicatedBackend { replicas, node_id }
+    }
+
+    /// Tag `value` with `timestamp` and this instance's `node_id`, and write
+    /// it to every replica with `ttl`.
+    pub async fn set<T: Serialize + Sync>(
+        &self,
+        key: &str,
+        value: &T,
+        timestamp: u64,
+        ttl: Option<Duration>,
+    ) -> Result<()> {
+        let envelope = ReplicatedEntry {
+            entry: Lww::new(value, timestamp, self.node_id),
+            ttl_ms: ttl.map(|d| d.as_millis() as u64),
+        };
+        let bytes = serialize_for_cache(&envelope)?;
+        for replica in &self.replicas {
+            replica.set(key, bytes.clone(), ttl).await?;
+        }
+        Ok(())
+    }
+
+    /// Read `key` from every replica, keep the entry with the greatest
+    /// `(timestamp, node_id)`, and write it back (read-repair), with its
+    /// original TTL, to any replica that didn't already hold it.
+    pub async fn get<T: Serialize + DeserializeOwned + Clone>(&self, key: &str) -> Result<Option<T>> {
+        let mut winner: Option<(usize, ReplicatedEntry<T>)> = None;
+        let mut stale_replicas = Vec::new();
+
+        for (index, replica) in self.replicas.iter().enumerate() {
+            let Some(bytes) = replica.get(key).await? else {
+                stale_replicas.push(index);
+                continue;
+            };
+            let envelope: ReplicatedEntry<T> = deserialize_from_cache(&bytes)?;
+            let entry_wins = !winner
+                .as_ref()
+                .is_some_and(|(_, current)| !envelope.entry.wins_over(&current.entry));
+            if entry_wins {
+                if let Some((previous_winner, _)) = winner {
+                    stale_replicas.push(previous_winner);
+                }
+                winner = Some((index, envelope));
+            } else {
+                stale_replicas.push(index);
+            }
+        }
+
+        let Some((winner_index, winner)) = winner else {
+            return Ok(None);
+        };
+
+        let winner_bytes = serialize_for_cache(&winner)?;
+        let winner_ttl = winner.ttl_ms.map(|ms| Duration::from_millis(ms));
+        for index in stale_replicas {
+            if index == winner_index {
+                continue;
+            }
+            self.replicas[index]
+                .set(key, winner_bytes.clone(), winner_ttl)
+                .await?;
+        }
+
+        Ok(Some(winner.entry.value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::InMemoryBackend;
+
+    #[test]
+    fn test_lww_merge_keeps_greater_timestamp() {
+        let older = Lww::new("a", 100, 1);
+        let newer = Lww::new("b", 200, 1);
+
+        assert_eq!(older.merge(newer).value, "b");
+    }
+
+    #[test]
+    fn test_lww_merge_breaks_timestamp_tie_with_node_id() {
+        let low_node = Lww::new("a", 100, 1);
+        let high_node = Lww::new("b", 100, 2);
+
+        assert_eq!(low_node.clone().merge(high_node.clone()).value, "b");
+        assert_eq!(high_node.merge(low_node).value, "b");
+    }
+
+    #[test]
+    fn test_lww_map_set_merges_field_independently() {
+        let mut map = LwwMap::new();
+        map.set("name", "alice", 100, 1);
+        map.set("plan", "free", 100, 1);
+
+        // A later write to one field doesn't clobber the other.
+        map.set("plan", "pro", 200, 1);
+
+        assert_eq!(map.get(&"name"), Some(&"alice"));
+        assert_eq!(map.get(&"plan"), Some(&"pro"));
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn test_lww_map_set_rejects_stale_write() {
+        let mut map = LwwMap::new();
+        map.set("plan", "pro", 200, 1);
+        map.set("plan", "free", 100, 1); // older write, arrives after
+
+        assert_eq!(map.get(&"plan"), Some(&"pro"));
+    }
+
+    #[test]
+    fn test_lww_map_merge_combines_two_maps() {
+        let mut a = LwwMap::new();
+        a.set("x", 1, 100, 1);
+        let mut b = LwwMap::new();
+        b.set("x", 2, 200, 2);
+        b.set("y", 3, 50, 2);
+
+        a.merge(b);
+
+        assert_eq!(a.get(&"x"), Some(&2));
+        assert_eq!(a.get(&"y"), Some(&3));
+    }
+
+    #[test]
+    fn test_lww_map_is_empty() {
+        let map: LwwMap<&str, i32> = LwwMap::new();
+        assert!(map.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_replicated_backend_set_writes_to_all_replicas() {
+        let a = InMemoryBackend::new();
+        let b = InMemoryBackend::new();
+        let backend = LwwReplicatedBackend::new(vec![a, b], 1);
+
+        backend
+            .set("key", &"value".to_string(), 100, None)
+            .await
+            .expect("Failed to set");
+
+        let value: Option<String> = backend.get("key").await.expect("Failed to get");
+        assert_eq!(value.as_deref(), Some("value"));
+    }
+
+    #[tokio::test]
+    async fn test_replicated_backend_get_merges_diverged_replicas() {
+        let a = InMemoryBackend::new();
+        let b = InMemoryBackend::new();
+
+        // Simulate two regions writing directly to their own local replica.
+        let region_a = LwwReplicatedBackend::new(vec![a.clone()], 1);
+        let region_b = LwwReplicatedBackend::new(vec![b.clone()], 2);
+        region_a
+            .set("key", &"from-a".to_string(), 100, None)
+            .await
+            .expect("Failed to set");
+        region_b
+            .set("key", &"from-b".to_string(), 200, None)
+            .await
+            .expect("Failed to set");
+
+        let backend = LwwReplicatedBackend::new(vec![a.clone(), b.clone()], 3);
+        let value: Option<String> = backend.get("key").await.expect("Failed to get");
+        assert_eq!(value.as_deref(), Some("from-b"));
+
+        // Read-repair should have overwritten the stale replica `a`.
+        let healed: Option<String> = LwwReplicatedBackend::new(vec![a], 3)
+            .get("key")
+            .await
+            .expect("Failed to get");
+        assert_eq!(healed.as_deref(), Some("from-b"));
+    }
+
+    #[tokio::test]
+    async fn test_replicated_backend_read_repair_preserves_ttl() {
+        let a = InMemoryBackend::new();
+        let b = InMemoryBackend::new();
+
+        // `a` holds a stale value; `b` holds the winner, written with a
+        // short TTL.
+        a.set(
+            "key",
+            serialize_for_cache(&ReplicatedEntry {
+                entry: Lww::new("stale".to_string(), 100, 1),
+                ttl_ms: None,
+            })
+            .unwrap(),
+            None,
+        )
+        .await
+        .expect("Failed to seed stale replica");
+
+        let region_b = LwwReplicatedBackend::new(vec![b.clone()], 2);
+        region_b
+            .set(
+                "key",
+                &"fresh".to_string(),
+                200,
+                Some(Duration::from_millis(50)),
+            )
+            .await
+            .expect("Failed to set");
+
+        let backend = LwwReplicatedBackend::new(vec![a.clone(), b], 3);
+        let value: Option<String> = backend.get("key").await.expect("Failed to get");
+        assert_eq!(value.as_deref(), Some("fresh"));
+
+        // Read-repair should have healed `a` with the winner's TTL, not a
+        // permanent entry.
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        let expired = a.get("key").await.expect("Failed to get");
+        assert!(expired.is_none(), "repaired entry should have expired with the original TTL");
+    }
+
+    #[tokio::test]
+    async fn test_replicated_backend_get_returns_none_when_all_replicas_miss() {
+        let backend = LwwReplicatedBackend::new(vec![InMemoryBackend::new()], 1);
+
+        let value: Option<String> = backend.get("missing").await.expect("Failed to get");
+        assert_eq!(value, None);
+    }
+}
@@ -31,6 +31,19 @@ use std::hash::Hash;
 ///     }
 /// }
 /// ```
+///
+/// With the `derive` feature enabled, `#[derive(CacheEntity)]` generates the
+/// impl above from attributes instead:
+///
+/// ```ignore
+/// #[derive(Clone, Serialize, Deserialize, CacheEntity)]
+/// #[cache(prefix = "employment")]
+/// struct Employment {
+///     #[cache(key)]
+///     id: String,
+///     employer_name: String,
+/// }
+/// ```
 pub trait CacheEntity: Send + Sync + Serialize + for<'de> Deserialize<'de> + Clone {
     /// Type of the entity's key/ID (typically String or UUID)
     type Key: Display + Clone + Send + Sync + Eq + Hash + 'static;
@@ -47,6 +60,62 @@ pub trait CacheEntity: Send + Sync + Serialize + for<'de> Deserialize<'de> + Clo
     /// Final cache key format: `"{prefix}:{key}"`
     fn cache_prefix() -> &'static str;
 
+    /// Optional: Per-entity TTL, declared on the type instead of at every
+    /// call site.
+    ///
+    /// `CacheExpander` applies this when writing the entity to cache, after
+    /// a per-operation `OperationConfig::ttl_override` (which still wins)
+    /// but before the expander's global `TtlPolicy`. Entities that return
+    /// `None` (the default) are unaffected - the TTL is whatever the
+    /// override/policy chain already resolves to.
+    ///
+    /// Useful for short-lived data (auth tokens, rate-limit counters) that
+    /// should always expire quickly regardless of the caller's TTL policy,
+    /// or for a TTL that depends on the fetched value itself (e.g. a short
+    /// TTL for an empty/negative result, a long one for a stable record) -
+    /// `&self` already has whatever fields the decision needs, so there's no
+    /// separate per-value TTL hook on [`crate::observability::TtlPolicy`].
+    fn cache_ttl(&self) -> Option<std::time::Duration> {
+        None
+    }
+
+    /// Optional: Secondary keys this entity should also be evictable under,
+    /// declared on the value instead of at every call site.
+    ///
+    /// `CacheExpander` records these via [`crate::backend::CacheBackend::set_with_tags`]
+    /// whenever it writes the entity back to cache, so
+    /// `CacheService::invalidate_by_tag`/[`crate::backend::CacheBackend::invalidate_tag`]
+    /// can later evict it alongside every other entity sharing a tag - e.g.
+    /// an invoice tagged `customer:{customer_id}` and `number:{invoice_number}`
+    /// lets a status change for one invoice, or a new invoice for a customer,
+    /// invalidate every cached view keyed off those secondary identifiers
+    /// without tracking each one's primary key by hand.
+    ///
+    /// Entities that return an empty `Vec` (the default) are unaffected -
+    /// they're written with a plain `set`, same as before tagging existed.
+    fn cache_tags(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Optional: A Unix timestamp (seconds) after which this entity is
+    /// logically invalid, independent of however long it still has left on
+    /// its backend TTL.
+    ///
+    /// `CacheExpander` checks this against the current time on every cache
+    /// hit; once it's passed, the hit is treated the same as a cache miss -
+    /// forcing a repository re-fetch instead of serving the stale value -
+    /// regardless of the entry's TTL. Useful for data whose validity is a
+    /// domain fact rather than a cache-freshness question: an invoice whose
+    /// `due_at` has elapsed and whose status must be recomputed, say, where
+    /// TTL alone can't express "this specific entry became wrong at this
+    /// specific instant."
+    ///
+    /// Entities that return `None` (the default) are unaffected - they're
+    /// only ever evicted by TTL, same as before this existed.
+    fn cache_expires_at(&self) -> Option<u64> {
+        None
+    }
+
     /// Serialize entity for cache storage.
     ///
     /// Uses Postcard with versioned envelopes for all cache storage.
@@ -73,21 +142,55 @@ pub trait CacheEntity: Send + Sync + Serialize + for<'de> Deserialize<'de> + Clo
     /// Validates magic header and schema version before deserializing.
     /// This method is NOT overridable to ensure consistency across all entities.
     ///
+    /// If the stored entry's schema version does not match
+    /// [`crate::serialization::CURRENT_SCHEMA_VERSION`], [`Self::migrate`] is given a
+    /// chance to upgrade the raw bytes in place before falling back to
+    /// `Error::VersionMismatch`.
+    ///
     /// # Validation
     ///
     /// - Magic must be b"CKIT"
-    /// - Version must match current schema version
+    /// - Version must match current schema version, or `migrate` must succeed
     /// - Postcard deserialization must succeed
     ///
     /// # Errors
     ///
     /// - `Error::InvalidCacheEntry`: Bad magic or corrupted envelope
-    /// - `Error::VersionMismatch`: Schema version changed
+    /// - `Error::VersionMismatch`: Schema version changed and `migrate` declined to upgrade
     /// - `Error::DeserializationError`: Corrupted payload
     ///
     /// See `crate::serialization` for implementation details.
     fn deserialize_from_cache(bytes: &[u8]) -> Result<Self> {
-        crate::serialization::deserialize_from_cache(bytes)
+        match crate::serialization::deserialize_from_cache(bytes) {
+            Err(crate::error::Error::VersionMismatch { expected, found }) => {
+                let (_, payload) = crate::serialization::decode_version_and_payload(bytes)?;
+                Self::migrate(found, payload)?
+                    .ok_or(crate::error::Error::VersionMismatch { expected, found })
+            }
+            other => other,
+        }
+    }
+
+    /// Optional: Migrate a cache entry written under an older schema version.
+    ///
+    /// Called by `deserialize_from_cache` when the stored envelope's
+    /// `version` does not match `CURRENT_SCHEMA_VERSION`. Implement this to
+    /// upgrade old payloads in place instead of evicting them outright.
+    ///
+    /// # Arguments
+    /// - `old_version`: The schema version found in the stored envelope.
+    /// - `payload`: The raw Postcard payload, with the envelope's magic,
+    ///   version, and checksum already stripped off.
+    ///
+    /// # Returns
+    /// - `Ok(Some(entity))`: Successfully migrated to the current type.
+    /// - `Ok(None)`: No migration available; caller returns `Error::VersionMismatch`.
+    /// - `Err(e)`: Migration was attempted but failed.
+    ///
+    /// The default implementation declines to migrate, preserving today's
+    /// behavior of evicting entries from unknown schema versions.
+    fn migrate(_old_version: u32, _payload: &[u8]) -> Result<Option<Self>> {
+        Ok(None)
     }
 
     /// Optional: Validate entity after deserialization.
@@ -96,6 +199,17 @@ pub trait CacheEntity: Send + Sync + Serialize + for<'de> Deserialize<'de> + Clo
     fn validate(&self) -> Result<()> {
         Ok(())
     }
+
+    /// Optional: Approximate heap footprint of this entity, in bytes.
+    ///
+    /// Used by repositories and backends that track memory pressure (e.g.
+    /// `ConcurrentInMemoryRepository::approximate_size_bytes`) to decide when
+    /// to evict. The default only accounts for the entity's stack size via
+    /// `size_of::<Self>()`, which undercounts types with heap allocations
+    /// (`String`, `Vec`, ...). Override for entities where that gap matters.
+    fn heap_size(&self) -> usize {
+        std::mem::size_of::<Self>()
+    }
 }
 
 #[cfg(test)]
@@ -135,6 +249,132 @@ mod tests {
         assert_eq!(entity.value, deserialized.value);
     }
 
+    #[test]
+    fn test_migrate_default_declines() {
+        let entity = TestEntity {
+            id: "entity_123".to_string(),
+            value: "test".to_string(),
+        };
+        let bytes = entity.serialize_for_cache().unwrap();
+
+        // Force a version mismatch by tweaking the stored bytes' version field is
+        // awkward without reaching into postcard internals, so this test instead
+        // confirms the default hook itself declines unconditionally.
+        assert!(TestEntity::migrate(0, &bytes).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_migrate_hook_upgrades_old_version() {
+        #[derive(Clone, Serialize, Deserialize)]
+        struct MigratingEntity {
+            id: String,
+            value: String,
+        }
+
+        impl CacheEntity for MigratingEntity {
+            type Key = String;
+
+            fn cache_key(&self) -> Self::Key {
+                self.id.clone()
+            }
+
+            fn cache_prefix() -> &'static str {
+                "migrating"
+            }
+
+            fn migrate(old_version: u32, payload: &[u8]) -> Result<Option<Self>> {
+                if old_version == 0 {
+                    let old: String = postcard::from_bytes(payload).unwrap();
+                    Ok(Some(MigratingEntity {
+                        id: "migrated".to_string(),
+                        value: old,
+                    }))
+                } else {
+                    Ok(None)
+                }
+            }
+        }
+
+        use crate::serialization::CacheEnvelope;
+
+        let envelope = CacheEnvelope {
+            magic: crate::serialization::CACHE_MAGIC,
+            version: 0,
+            format: 0,
+            flags: 0,
+            checksum: 0,
+            payload: "irrelevant",
+        };
+        let bytes = postcard::to_allocvec(&envelope).unwrap();
+
+        let migrated = MigratingEntity::deserialize_from_cache(&bytes).unwrap();
+        assert_eq!(migrated.id, "migrated");
+        assert_eq!(migrated.value, "irrelevant");
+    }
+
+    #[test]
+    fn test_migrate_hook_composes_with_chained_cache_migrator() {
+        use crate::serialization::{CacheEnvelope, CacheMigrator};
+        use std::sync::OnceLock;
+
+        #[derive(Clone, Serialize, Deserialize)]
+        struct ChainMigratedEntity {
+            id: String,
+            value: String,
+        }
+
+        fn migrator() -> &'static CacheMigrator {
+            static MIGRATOR: OnceLock<CacheMigrator> = OnceLock::new();
+            MIGRATOR.get_or_init(|| {
+                CacheMigrator::new()
+                    // v0 stored a bare String; hop it into v1's shape.
+                    .step(0, |payload| {
+                        let old: String = postcard::from_bytes(payload).unwrap();
+                        Ok(postcard::to_allocvec(&(old, 1_u32)).unwrap())
+                    })
+                    // v1 stored `(String, u32)`; hop it into v2's shape.
+                    .step(1, |payload| {
+                        let (old, n): (String, u32) = postcard::from_bytes(payload).unwrap();
+                        Ok(postcard::to_allocvec(&format!("{}-{}", old, n)).unwrap())
+                    })
+            })
+        }
+
+        impl CacheEntity for ChainMigratedEntity {
+            type Key = String;
+
+            fn cache_key(&self) -> Self::Key {
+                self.id.clone()
+            }
+
+            fn cache_prefix() -> &'static str {
+                "chain_migrated"
+            }
+
+            fn migrate(old_version: u32, payload: &[u8]) -> Result<Option<Self>> {
+                let value: String = migrator().migrate_payload(old_version, payload)?;
+                Ok(Some(ChainMigratedEntity {
+                    id: "migrated".to_string(),
+                    value,
+                }))
+            }
+        }
+
+        let envelope = CacheEnvelope {
+            magic: crate::serialization::CACHE_MAGIC,
+            version: 0,
+            format: 0,
+            flags: 0,
+            checksum: 0,
+            payload: "original",
+        };
+        let bytes = postcard::to_allocvec(&envelope).unwrap();
+
+        let migrated = ChainMigratedEntity::deserialize_from_cache(&bytes).unwrap();
+        assert_eq!(migrated.id, "migrated");
+        assert_eq!(migrated.value, "original-1");
+    }
+
     #[test]
     fn test_cache_key_generation() {
         let entity = TestEntity {
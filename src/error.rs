@@ -1,6 +1,8 @@
 //! Error types for the cache framework.
 
 use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
 
 /// Result type for cache operations.
 pub type Result<T> = std::result::Result<T, Error>;
@@ -58,6 +60,29 @@ pub enum Error {
     /// **Recovery:** Retry the operation or fallback to database.
     BackendError(String),
 
+    /// Backend or I/O error that preserves the original error's cause chain,
+    /// for callers that need to inspect or downcast the concrete underlying
+    /// error instead of a formatted string (e.g. an `anyhow`/`tracing`
+    /// capture, or an axum error-handling layer matching on a specific
+    /// `redis::RedisError` kind).
+    ///
+    /// Produced by the `redis`/`sqlx`/io-flavored `From` conversions below in
+    /// place of `BackendError`, which only ever carries a message.
+    /// [`Error::is_backend_error`] treats the two identically, so
+    /// `CacheExpander`'s recovery policy and [`Error::is_retryable`] don't
+    /// need to care which one they got; `BackendError` itself is unchanged
+    /// and still the right choice for a hand-written message with no
+    /// underlying error value to attach.
+    ///
+    /// **Recovery:** Same as `BackendError` - retry the operation or
+    /// fallback to database.
+    Backend {
+        /// Same formatted message `BackendError` would have carried.
+        message: String,
+        /// The original error, preserved for `std::error::Error::source()`.
+        source: Arc<dyn std::error::Error + Send + Sync>,
+    },
+
     /// Data repository error (database, etc).
     ///
     /// This indicates the source repository (database) failed to fetch data.
@@ -130,6 +155,101 @@ pub enum Error {
         found: u32,
     },
 
+    /// Payload checksum stored in the envelope does not match the decoded bytes.
+    ///
+    /// Indicates the payload was corrupted or truncated in storage or
+    /// transport, as opposed to `DeserializationError` which indicates the
+    /// bytes no longer parse as `T` at all.
+    ///
+    /// **Recovery:** Evict the cache entry and recompute.
+    ChecksumMismatch {
+        /// Checksum recorded in the envelope.
+        expected: u64,
+        /// Checksum computed from the decoded payload bytes.
+        actual: u64,
+    },
+
+    /// Cache entry predates the oldest schema version this build can migrate from.
+    ///
+    /// Raised when an envelope's stored version is below
+    /// `crate::serialization::MIN_SUPPORTED_SCHEMA_VERSION`, which is distinct
+    /// from an ordinary `VersionMismatch`: the entry isn't just out of date,
+    /// it's old enough that no migration chain is expected to cover it.
+    ///
+    /// **Recovery:** Evict the entry and resync from the source of truth; do
+    /// not attempt to migrate it.
+    UnsupportedLegacyVersion {
+        /// Version found in the stored envelope.
+        found: u32,
+        /// Oldest version this build still knows how to migrate from.
+        minimum: u32,
+    },
+
+    /// A schema migration step required to reach `CURRENT_SCHEMA_VERSION` is missing.
+    ///
+    /// Raised by `CacheMigrator` when an envelope's stored version is older
+    /// than current but no registered step covers the `from -> to` hop.
+    ///
+    /// **Recovery:** Register the missing migration step, or bump
+    /// `MIN_SUPPORTED_SCHEMA_VERSION` past `from` if the version is unreachable.
+    MigrationMissing {
+        /// Version the migration chain was stuck on.
+        from: u32,
+        /// Version it needed to reach next.
+        to: u32,
+    },
+
+    /// A capacity-bounded backend or repository rejected the operation
+    /// because it's full.
+    ///
+    /// This occurs when a size- or entry-capped store (e.g. a bounded
+    /// in-memory backend) has no room for a new entry and declines to evict
+    /// to make space, rather than silently dropping data.
+    ///
+    /// **Recovery:** Retry after backing off; callers surfacing this over
+    /// HTTP should return a `Retry-After` hint.
+    CapacityExceeded(String),
+
+    /// A configured `RateLimiter` denied this refresh.
+    ///
+    /// Raised when `CacheExpander::with_rate_limiter` is configured and
+    /// `OperationConfig::rate_limit`'s cap has been exceeded for this key.
+    /// Distinct from `CapacityExceeded`, which is about storage room rather
+    /// than request pacing.
+    ///
+    /// **Recovery:** Wait at least the wrapped duration before retrying.
+    RateLimited(Duration),
+
+    /// A cache entry was read with a different [`crate::serialization::codec::Codec`]
+    /// than the one it was written with.
+    ///
+    /// Raised by `crate::serialization::codec::deserialize_with_codec` when the
+    /// codec tag recorded in the envelope doesn't match the reader's codec.
+    /// Distinct from `DeserializationError`: the bytes are intact, but the
+    /// reader is asking the wrong decoder to parse them.
+    ///
+    /// **Recovery:** Deserialize with the codec named in `found`, or evict and
+    /// recompute if that codec is no longer in use.
+    CodecMismatch {
+        /// Codec the reader was configured with.
+        expected: String,
+        /// Codec recorded in the entry's envelope.
+        found: String,
+    },
+
+    /// AEAD decryption failed for a value read through an
+    /// [`crate::backend::EncryptingBackend`].
+    ///
+    /// Raised when the stored nonce/ciphertext/tag fails authentication -
+    /// either the value was corrupted, or it was encrypted with a different
+    /// key than the one the backend was constructed with. Distinct from
+    /// `DeserializationError`: the bytes never even become plaintext.
+    ///
+    /// **Recovery:** Not automatically recoverable - the value can't be read
+    /// back with this key. Evict and recompute, or fix the key mismatch.
+    #[cfg(feature = "encryption")]
+    DecryptionError(String),
+
     /// Generic error with custom message.
     ///
     /// Used for errors that don't fit into other variants.
@@ -144,6 +264,7 @@ impl fmt::Display for Error {
             Error::ValidationError(msg) => write!(f, "Validation error: {}", msg),
             Error::CacheMiss => write!(f, "Cache miss"),
             Error::BackendError(msg) => write!(f, "Backend error: {}", msg),
+            Error::Backend { message, .. } => write!(f, "Backend error: {}", message),
             Error::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
             Error::Timeout(msg) => write!(f, "Timeout: {}", msg),
             Error::ConfigError(msg) => write!(f, "Config error: {}", msg),
@@ -158,12 +279,85 @@ impl fmt::Display for Error {
                     expected, found
                 )
             }
+            Error::ChecksumMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Cache checksum mismatch: expected {}, got {}",
+                    expected, actual
+                )
+            }
+            Error::UnsupportedLegacyVersion { found, minimum } => {
+                write!(
+                    f,
+                    "Cache entry too old to migrate: found version {}, minimum supported is {}",
+                    found, minimum
+                )
+            }
+            Error::MigrationMissing { from, to } => {
+                write!(
+                    f,
+                    "Cache migration missing: no step registered from version {} to {}",
+                    from, to
+                )
+            }
+            Error::CapacityExceeded(msg) => write!(f, "Capacity exceeded: {}", msg),
+            Error::RateLimited(retry_after) => {
+                write!(f, "Rate limited: retry after {:?}", retry_after)
+            }
+            Error::CodecMismatch { expected, found } => {
+                write!(
+                    f,
+                    "Codec mismatch: entry was encoded with '{}', but reader expected '{}'",
+                    found, expected
+                )
+            }
+            #[cfg(feature = "encryption")]
+            Error::DecryptionError(msg) => write!(f, "Decryption error: {}", msg),
             Error::Other(msg) => write!(f, "Error: {}", msg),
         }
     }
 }
 
-impl std::error::Error for Error {}
+impl Error {
+    /// Whether retrying the operation that produced this error could plausibly
+    /// succeed, for [`crate::expander::CacheExpander::with_config`]'s retry
+    /// loop to short-circuit the rest.
+    ///
+    /// `true` only for the transient, environment-caused variants
+    /// (`BackendError`, `Backend`, `RepositoryError`, `Timeout`) - everything
+    /// else (validation failures, schema/codec mismatches, corrupt entries, a
+    /// rate limiter or capacity cap saying no) will fail exactly the same way
+    /// on the next attempt, so retrying just burns time and risks a retry
+    /// storm for no chance of success.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Error::BackendError(_)
+                | Error::Backend { .. }
+                | Error::RepositoryError(_)
+                | Error::Timeout(_)
+        )
+    }
+
+    /// True for [`Error::BackendError`] or [`Error::Backend`] - the two
+    /// shapes `CacheExpander`'s recovery policy (`Fail`/`FallThrough`/
+    /// `BlackHole`) reacts to. Split out so call sites that used to
+    /// pattern-match `Error::BackendError(msg)` directly can recognize
+    /// either shape without needing to know which `From` conversion
+    /// produced it.
+    pub fn is_backend_error(&self) -> bool {
+        matches!(self, Error::BackendError(_) | Error::Backend { .. })
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Backend { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
 
 // ============================================================================
 // Conversions from other error types
@@ -172,7 +366,11 @@ impl std::error::Error for Error {}
 impl From<serde_json::Error> for Error {
     fn from(e: serde_json::Error) -> Self {
         if e.is_io() {
-            Error::BackendError(e.to_string())
+            let message = e.to_string();
+            Error::Backend {
+                message,
+                source: Arc::new(e),
+            }
         } else if e.is_syntax() {
             Error::DeserializationError(e.to_string())
         } else {
@@ -183,7 +381,11 @@ impl From<serde_json::Error> for Error {
 
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Self {
-        Error::BackendError(e.to_string())
+        let message = e.to_string();
+        Error::Backend {
+            message,
+            source: Arc::new(e),
+        }
     }
 }
 
@@ -202,7 +404,22 @@ impl From<&str> for Error {
 #[cfg(feature = "redis")]
 impl From<redis::RedisError> for Error {
     fn from(e: redis::RedisError) -> Self {
-        Error::BackendError(format!("Redis error: {}", e))
+        let message = format!("Redis error: {}", e);
+        Error::Backend {
+            message,
+            source: Arc::new(e),
+        }
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl From<sqlx::Error> for Error {
+    fn from(e: sqlx::Error) -> Self {
+        let message = format!("Postgres error: {}", e);
+        Error::Backend {
+            message,
+            source: Arc::new(e),
+        }
     }
 }
 
@@ -221,4 +438,59 @@ mod tests {
         let err: Error = "test error".into();
         assert!(matches!(err, Error::Other(_)));
     }
+
+    #[test]
+    fn test_is_retryable_true_for_transient_errors() {
+        assert!(Error::BackendError("down".to_string()).is_retryable());
+        assert!(Error::RepositoryError("down".to_string()).is_retryable());
+        assert!(Error::Timeout("slow".to_string()).is_retryable());
+    }
+
+    #[test]
+    fn test_is_retryable_false_for_deterministic_errors() {
+        assert!(!Error::ValidationError("bad".to_string()).is_retryable());
+        assert!(!Error::VersionMismatch { expected: 2, found: 1 }.is_retryable());
+        assert!(!Error::DeserializationError("corrupt".to_string()).is_retryable());
+        assert!(!Error::InvalidCacheEntry("bad magic".to_string()).is_retryable());
+    }
+
+    #[test]
+    fn test_io_error_preserves_source_and_reads_as_backend_error() {
+        let io_err = std::io::Error::new(std::io::ErrorKind::BrokenPipe, "pipe closed");
+        let err: Error = io_err.into();
+
+        assert!(matches!(err, Error::Backend { .. }));
+        assert!(err.is_backend_error());
+        assert!(err.is_retryable());
+        assert_eq!(err.to_string(), "Backend error: pipe closed");
+
+        let source = std::error::Error::source(&err).expect("source should be preserved");
+        assert_eq!(source.to_string(), "pipe closed");
+        assert!(source.downcast_ref::<std::io::Error>().is_some());
+    }
+
+    #[test]
+    fn test_serde_json_io_error_is_backend_but_syntax_error_is_not() {
+        let bad_json = "{ not valid json";
+        let parse_err: serde_json::Error =
+            serde_json::from_str::<serde_json::Value>(bad_json).unwrap_err();
+        let err: Error = parse_err.into();
+
+        assert!(matches!(err, Error::DeserializationError(_)));
+        assert!(!err.is_backend_error());
+        assert!(std::error::Error::source(&err).is_none());
+    }
+
+    #[test]
+    fn test_backend_error_and_backend_variant_both_count_as_backend_errors() {
+        let plain = Error::BackendError("down".to_string());
+        let with_source = Error::Backend {
+            message: "down".to_string(),
+            source: Arc::new(std::io::Error::other("down")),
+        };
+
+        assert!(plain.is_backend_error());
+        assert!(with_source.is_backend_error());
+        assert_eq!(plain.to_string(), with_source.to_string());
+    }
 }
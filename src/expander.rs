@@ -3,13 +3,198 @@
 use crate::backend::CacheBackend;
 use crate::entity::CacheEntity;
 use crate::error::{Error, Result};
-use crate::feed::CacheFeed;
-use crate::key::CacheKeyBuilder;
+use crate::feed::{BatchCacheFeed, CacheFeed};
+use crate::invalidation::InvalidationBus;
+use crate::key::{CacheKeyBuilder, KeyRegistry};
 use crate::observability::{CacheMetrics, NoOpMetrics, TtlPolicy};
 use crate::repository::DataRepository;
+use crate::serialization::{deserialize_from_cache, serialize_for_cache};
 use crate::strategy::CacheStrategy;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
-use std::time::{Duration, Instant};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::sync::mpsc;
+use tokio::sync::Mutex as AsyncMutex;
+
+/// Marker stored in place of an entity to negative-cache a repository miss.
+///
+/// Distinct from any valid `CacheEnvelope` (which always starts with
+/// `CACHE_MAGIC`), so a tombstone is never mistaken for - or mistakenly
+/// parsed as - a real cached entity.
+const TOMBSTONE_MARKER: &[u8] = b"CKIT_TOMBSTONE_V1";
+
+fn is_tombstone(bytes: &[u8]) -> bool {
+    bytes == TOMBSTONE_MARKER
+}
+
+/// Wraps a `CacheStrategy::StaleWhileRevalidate` entry with the soft-expiry
+/// timestamp used to decide whether a hit should also trigger a background
+/// refresh. Stored as the envelope payload in place of a bare `T`, so only
+/// entries written by that strategy carry this extra bookkeeping.
+#[derive(Serialize, Deserialize)]
+struct StaleAware<T> {
+    /// Unix timestamp (seconds) after which this value is considered stale.
+    soft_expires_at: u64,
+    value: T,
+}
+
+/// Current Unix time in seconds, saturating rather than panicking if the
+/// system clock is somehow set before the epoch.
+fn now_unix() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Deterministic xorshift64 PRNG state for [`full_jitter`], advanced on every
+/// call - mirrors `observability::JITTER_STATE`'s rationale: "spread retries
+/// out" doesn't need cryptographic randomness, and a shared counter is enough
+/// since `CacheExpander` isn't re-seeded per instance.
+static BACKOFF_JITTER_STATE: std::sync::atomic::AtomicU64 =
+    std::sync::atomic::AtomicU64::new(0x2545F4914F6CDD1D);
+
+/// "Full jitter" backoff delay: a uniform draw from `[0, max)`, per the
+/// decorrelated/full-jitter scheme in the retry loop of
+/// [`CacheExpander::with_config`]. Returns `max` unchanged (no randomization)
+/// when `max` is zero, since there's nothing to spread out.
+fn full_jitter(max: Duration) -> Duration {
+    use std::sync::atomic::Ordering;
+
+    if max.is_zero() {
+        return max;
+    }
+
+    let mut x = BACKOFF_JITTER_STATE.fetch_add(1, Ordering::Relaxed);
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    BACKOFF_JITTER_STATE.store(x, Ordering::Relaxed);
+
+    let unit = (x >> 11) as f64 * (1.0 / (1u64 << 53) as f64);
+    Duration::from_nanos((max.as_nanos() as f64 * unit) as u64)
+}
+
+/// What to do when a backend operation fails with [`Error::BackendError`]
+/// during [`CacheExpander::with`]/[`CacheExpander::with_config`].
+///
+/// Set the expander-wide default via [`CacheExpander::with_recovery_policy`],
+/// or override it for one operation via
+/// [`OperationConfig::with_recovery_policy`].
+///
+/// This is a different layer from [`crate::backend::RecoveryPolicy`]: that
+/// one wraps a concrete backend (`RecoveringBackend<B>`) and recovers inside
+/// every `CacheBackend` call the wrapped backend makes. This one lives on
+/// `CacheExpander` itself, so it applies without requiring callers to pick a
+/// wrapper type, and can be dialed per-operation via `OperationConfig` the
+/// same way `ttl_override` already is.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CacheRecoveryPolicy {
+    /// Propagate the backend error, failing the whole operation. Matches
+    /// this crate's historical behavior for reads (every `strategy_*`
+    /// propagates `backend.get`/`backend.delete` errors via `?`) - writes
+    /// already failed open before this policy existed (see the `let _ =`
+    /// call sites in `strategy_refresh`/`strategy_invalidate`/
+    /// `strategy_bypass`), so `Fail` leaves those silent too.
+    #[default]
+    Fail,
+    /// Treat the failed read as a miss (falls through to the repository) and
+    /// drop the failed write, for this operation only. The backend is
+    /// retried on the next call - there's no persistent "tripped" state.
+    FallThrough,
+    /// Like `FallThrough`, but once a backend error is seen, every
+    /// subsequent operation on this `CacheExpander` treats the backend as
+    /// permanently unavailable (reads miss, writes are skipped) without
+    /// calling it again, until the process restarts. Use this when a flaky
+    /// backend being hammered by retries is worse than running uncached.
+    BlackHole,
+}
+
+/// Shared token-bucket cap on retry attempts across every concurrent caller
+/// of a [`CacheExpander`], so a database outage can't turn one miss's
+/// `config.retry_count` into a system-wide retry storm.
+///
+/// `OperationConfig::retry_count`/backoff bound *one call's* retries; they
+/// don't bound the aggregate rate across every feeder hitting a down
+/// repository at once, since each call retries independently. A
+/// `RetryBudget` adds that missing system-wide ceiling: every retry attempt
+/// (across every operation sharing this expander) charges `retry_cost`
+/// tokens from one pool, and a successful fetch refunds `return_fraction *
+/// capacity` tokens back into it. Once the pool is empty, retries stop
+/// immediately (callers still get their first attempt, and any error is
+/// still returned to them) until enough successes refill it.
+///
+/// Install via [`CacheExpander::with_retry_budget`].
+pub struct RetryBudget {
+    tokens: std::sync::atomic::AtomicI64,
+    capacity: i64,
+    retry_cost: i64,
+    return_amount: i64,
+}
+
+impl RetryBudget {
+    /// `capacity` tokens to start, `retry_cost` charged per retry attempt,
+    /// and `return_fraction * capacity` tokens refunded per successful
+    /// fetch (clamped so the pool never exceeds `capacity`).
+    pub fn new(capacity: u32, retry_cost: u32, return_fraction: f64) -> Self {
+        let capacity = i64::from(capacity);
+        RetryBudget {
+            tokens: std::sync::atomic::AtomicI64::new(capacity),
+            capacity,
+            retry_cost: i64::from(retry_cost),
+            return_amount: (capacity as f64 * return_fraction.clamp(0.0, 1.0)).round() as i64,
+        }
+    }
+
+    /// Try to charge `retry_cost` tokens for one retry attempt. Returns
+    /// `false` (and charges nothing) if the pool doesn't have enough left.
+    fn try_acquire(&self) -> bool {
+        let mut current = self.tokens.load(std::sync::atomic::Ordering::Relaxed);
+        loop {
+            if current < self.retry_cost {
+                return false;
+            }
+            match self.tokens.compare_exchange_weak(
+                current,
+                current - self.retry_cost,
+                std::sync::atomic::Ordering::Relaxed,
+                std::sync::atomic::Ordering::Relaxed,
+            ) {
+                Ok(_) => return true,
+                Err(observed) => current = observed,
+            }
+        }
+    }
+
+    /// Refund `return_amount` tokens after a successful fetch, capped at
+    /// `capacity`.
+    fn refill(&self) {
+        let mut current = self.tokens.load(std::sync::atomic::Ordering::Relaxed);
+        loop {
+            let next = (current + self.return_amount).min(self.capacity);
+            if next == current {
+                return;
+            }
+            match self.tokens.compare_exchange_weak(
+                current,
+                next,
+                std::sync::atomic::Ordering::Relaxed,
+                std::sync::atomic::Ordering::Relaxed,
+            ) {
+                Ok(_) => return,
+                Err(observed) => current = observed,
+            }
+        }
+    }
+
+    /// Tokens currently available, for tests/observability.
+    pub fn available(&self) -> i64 {
+        self.tokens.load(std::sync::atomic::Ordering::Relaxed)
+    }
+}
 
 /// Configuration for per-operation overrides.
 ///
@@ -36,15 +221,136 @@ use std::time::{Duration, Instant};
 ///
 /// expander.with_config(&mut feeder, &repo, strategy, config).await?;
 /// ```
-#[derive(Clone, Debug, Default)]
+/// What to do when a backend operation fails with [`Error::BackendError`]
+/// during [`CacheExpander::with`]/[`CacheExpander::with_config`].
+///
+/// Set the expander-wide default via [`CacheExpander::with_recovery_policy`],
+/// or override it for one operation via
+/// [`OperationConfig::with_recovery_policy`].
+///
+/// This is a different layer from [`crate::backend::RecoveryPolicy`]: that
+/// one wraps a concrete backend (`RecoveringBackend<B>`) and recovers inside
+/// every `CacheBackend` call the wrapped backend makes. This one lives on
+/// `CacheExpander` itself, so it applies without requiring callers to pick a
+/// wrapper type, and can be dialed per-operation via `OperationConfig` the
+/// same way `ttl_override` already is.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CacheRecoveryPolicy {
+    /// Propagate the backend error, failing the whole operation. Matches
+    /// this crate's historical behavior for reads (every `strategy_*`
+    /// propagates `backend.get`/`backend.delete` errors via `?`) - writes
+    /// already failed open before this policy existed (see the `let _ =`
+    /// call sites in `strategy_refresh`/`strategy_invalidate`/
+    /// `strategy_bypass`), so `Fail` leaves those silent too.
+    #[default]
+    Fail,
+    /// Treat the failed read as a miss (falls through to the repository) and
+    /// drop the failed write, for this operation only. The backend is
+    /// retried on the next call - there's no persistent "tripped" state.
+    FallThrough,
+    /// Like `FallThrough`, but once a backend error is seen, every
+    /// subsequent operation on this `CacheExpander` treats the backend as
+    /// permanently unavailable (reads miss, writes are skipped) without
+    /// calling it again, until the process restarts. Use this when a flaky
+    /// backend being hammered by retries is worse than running uncached.
+    BlackHole,
+}
+
+/// Shared token-bucket cap on retry attempts across every concurrent caller
+/// of a [`CacheExpander`], so a database outage can't turn one miss's
+/// `config.retry_count` into a system-wide retry storm.
+///
+/// `OperationConfig::retry_count`/backoff bound *one call's* retries; they
+/// don't bound the aggregate rate across every feeder hitting a down
+/// repository at once, since each call retries independently. A
+/// `RetryBudget` adds that missing system-wide ceiling: every retry attempt
+/// (across every operation sharing this expander) charges `retry_cost`
+/// tokens from one pool, and a successful fetch refunds `return_fraction *
+/// capacity` tokens back into it. Once the pool is empty, retries stop
+/// immediately (callers still get their first attempt, and any error is
+/// still returned to them) until enough successes refill it.
+///
+/// Install via [`CacheExpander::with_retry_budget`].
+pub struct RetryBudget {
+    tokens: std::sync::atomic::AtomicI64,
+    capacity: i64,
+    retry_cost: i64,
+    return_amount: i64,
+}
+
+impl RetryBudget {
+    /// `capacity` tokens to start, `retry_cost` charged per retry attempt,
+    /// and `return_fraction * capacity` tokens refunded per successful
+    /// fetch (clamped so the pool never exceeds `capacity`).
+    pub fn new(capacity: u32, retry_cost: u32, return_fraction: f64) -> Self {
+        let capacity = i64::from(capacity);
+        RetryBudget {
+            tokens: std::sync::atomic::AtomicI64::new(capacity),
+            capacity,
+            retry_cost: i64::from(retry_cost),
+            return_amount: (capacity as f64 * return_fraction.clamp(0.0, 1.0)).round() as i64,
+        }
+    }
+
+    /// Try to charge `retry_cost` tokens for one retry attempt. Returns
+    /// `false` (and charges nothing) if the pool doesn't have enough left.
+    fn try_acquire(&self) -> bool {
+        let mut current = self.tokens.load(std::sync::atomic::Ordering::Relaxed);
+        loop {
+            if current < self.retry_cost {
+                return false;
+            }
+            match self.tokens.compare_exchange_weak(
+                current,
+                current - self.retry_cost,
+                std::sync::atomic::Ordering::Relaxed,
+                std::sync::atomic::Ordering::Relaxed,
+            ) {
+                Ok(_) => return true,
+                Err(observed) => current = observed,
+            }
+        }
+    }
+
+    /// Refund `return_amount` tokens after a successful fetch, capped at
+    /// `capacity`.
+    fn refill(&self) {
+        let mut current = self.tokens.load(std::sync::atomic::Ordering::Relaxed);
+        loop {
+            let next = (current + self.return_amount).min(self.capacity);
+            if next == current {
+                return;
+            }
+            match self.tokens.compare_exchange_weak(
+                current,
+                next,
+                std::sync::atomic::Ordering::Relaxed,
+                std::sync::atomic::Ordering::Relaxed,
+            ) {
+                Ok(_) => return,
+                Err(observed) => current = observed,
+            }
+        }
+    }
+
+    /// Tokens currently available, for tests/observability.
+    pub fn available(&self) -> i64 {
+        self.tokens.load(std::sync::atomic::Ordering::Relaxed)
+    }
+}
+
+/// See `impl Default for OperationConfig` below for defaults - not derived,
+/// since `coalesce` defaults to `true` rather than `bool`'s usual `false`.
+#[derive(Clone)]
 pub struct OperationConfig {
     /// Override the default TTL for this operation only.
     ///
     /// # Precedence and Conflict Resolution
     ///
-    /// When both `ttl_override` and the expander's `ttl_policy` could apply:
-    /// - **If `Some(duration)`**: Use this override (takes precedence)
-    /// - **If `None`**: Fall back to the expander's `ttl_policy`
+    /// The TTL actually applied on write is resolved in this order:
+    /// 1. `ttl_override` (this field), if `Some`
+    /// 2. `CacheEntity::cache_ttl()`, the entity's own declared TTL, if `Some`
+    /// 3. The expander's `ttl_policy`
     ///
     /// This allows per-operation exceptions without changing global settings.
     ///
@@ -70,9 +376,187 @@ pub struct OperationConfig {
 
     /// Number of retry attempts for this operation (0 = no retry).
     ///
-    /// If the operation fails, it will be retried up to this many times with
-    /// exponential backoff.
+    /// If the operation fails with a retryable error (see
+    /// [`OperationConfig::is_retryable`]), it will be retried up to this many
+    /// times with full-jitter exponential backoff (see
+    /// [`OperationConfig::backoff_base`]/[`OperationConfig::backoff_factor`]/
+    /// [`OperationConfig::backoff_cap`]). A non-retryable error (e.g.
+    /// `Error::ValidationError`) fails immediately regardless of this count,
+    /// since retrying it can never succeed.
     pub retry_count: u32,
+
+    /// Base delay for retry backoff (default: 100ms). On zero-indexed attempt
+    /// `k`, the retry loop sleeps a duration drawn from `[0, min(backoff_cap,
+    /// backoff_base * backoff_factor^k))` - see
+    /// [`OperationConfig::backoff_jitter`] for disabling the randomization.
+    pub backoff_base: Duration,
+
+    /// Growth factor applied per attempt in retry backoff (default: `2.0`,
+    /// i.e. classic doubling). `1.0` gives fixed-delay retries instead of
+    /// exponential ones.
+    pub backoff_factor: f64,
+
+    /// Upper bound on retry backoff delay regardless of attempt count
+    /// (default: 10s), so a long-running operation's retries don't grow
+    /// unboundedly slow.
+    pub backoff_cap: Duration,
+
+    /// Whether retry backoff is randomized ("full jitter": a uniform draw
+    /// from `[0, max)` rather than sleeping `max` itself). Default: `true`.
+    ///
+    /// Full jitter spreads out a fleet of callers that all started retrying
+    /// at the same moment (e.g. after a shared backend blips) so they don't
+    /// all retry in lockstep and re-create the exact load spike that caused
+    /// the failure. Set to `false` for deterministic backoff, e.g. in a test
+    /// asserting on retry timing.
+    pub backoff_jitter: bool,
+
+    /// Override which errors are worth retrying for this operation, in place
+    /// of the default [`Error::is_retryable`] classification. `None` (the
+    /// default) uses `Error::is_retryable`.
+    ///
+    /// Wrapped in `Arc` so `OperationConfig` stays cheaply `Clone`. Excluded
+    /// from `OperationConfig`'s `Debug` output (a closure has no useful
+    /// `Debug` representation) - see the manual `impl Debug` below.
+    pub retry_predicate: Option<Arc<dyn Fn(&Error) -> bool + Send + Sync>>,
+
+    /// TTL for a negative-cache ("tombstone") entry written when the
+    /// repository reports a key as missing.
+    ///
+    /// `None` (the default) disables negative caching entirely, matching
+    /// prior behavior: every lookup for a nonexistent key re-hits the
+    /// repository. Set this to remember the miss in the cache for `ttl`,
+    /// typically shorter than the TTL used for real hits, so a thundering
+    /// herd of lookups for a key that doesn't exist resolves from cache
+    /// instead of repeatedly hitting the database.
+    pub negative_ttl: Option<Duration>,
+
+    /// Soft TTL for `CacheStrategy::StaleWhileRevalidate` entries.
+    ///
+    /// `None` (the default) means that strategy is unavailable for this
+    /// operation - there would be no way to decide when a hit should trigger
+    /// a background refresh. Set this to however long a value should be
+    /// served without refreshing; once a hit is older than `stale_after`, it's
+    /// still returned immediately, but a background task re-fetches it from
+    /// the repository and rewrites the cache entry for next time.
+    pub stale_after: Option<Duration>,
+
+    /// Whether a failed background refresh (see
+    /// [`CacheExpander::with_stale_while_revalidate`]'s
+    /// `spawn_background_refresh`) evicts the stale entry instead of leaving
+    /// it in place.
+    ///
+    /// `false` (the default) serves the stale value until the next
+    /// successful refresh or its hard TTL lapses - the usual choice, since a
+    /// transient repository blip shouldn't turn a stale-but-available value
+    /// into a miss. Set to `true` when a failed refresh means the cached
+    /// value is actively untrustworthy (e.g. the repository reports the
+    /// entity no longer exists) and serving it further is worse than falling
+    /// back to an inline fetch on the next call.
+    pub evict_on_refresh_error: bool,
+
+    /// Whether a cache hit whose entity has passed its
+    /// [`CacheEntity::cache_expires_at`] is evicted outright rather than left
+    /// in place once its logical expiry forces a repository re-fetch.
+    ///
+    /// `false` (the default) treats the expired hit as a miss for the current
+    /// call only - it still falls through to the repository and rewrites the
+    /// entry with the freshly-fetched value's TTL and expiry - but leaves the
+    /// stale entry alone in the (unlikely) case that re-fetch never happens.
+    /// Set to `true` to delete the entry immediately once it's found to be
+    /// logically expired, e.g. when a stale hit slipping through to some
+    /// other reader before the rewrite completes would be actively wrong
+    /// rather than merely outdated.
+    pub evict_on_logical_expiry: bool,
+
+    /// Cap repository refreshes for this operation's key to `limit` events
+    /// per `period`, when the expander has a `RateLimiter` configured (see
+    /// `CacheExpander::with_rate_limiter`). `None` (the default) leaves
+    /// refreshes unpaced even if a limiter is configured.
+    #[cfg(feature = "redis")]
+    pub rate_limit: Option<(u64, Duration)>,
+
+    /// Override [`CacheExpander`]'s default [`CacheRecoveryPolicy`] for this
+    /// operation only. `None` (the default) uses the expander-wide policy
+    /// set via `CacheExpander::with_recovery_policy`.
+    pub recovery_policy: Option<CacheRecoveryPolicy>,
+
+    /// Coalesce concurrent repository fetches for the same cache key into
+    /// one (see `CacheExpander::singleflight_fetch`): `true` (the default)
+    /// means a thundering herd of misses on a cold key produces a single
+    /// repository round trip instead of one per caller. Applies to
+    /// `CacheStrategy::Refresh`, `Invalidate`, and `Bypass` - `Fresh` never
+    /// touches the repository, so it's unaffected either way.
+    ///
+    /// Set to `false` to always fetch independently, e.g. for a caller that
+    /// needs its own round trip observed per-call (a cache-busting
+    /// diagnostic endpoint, say) rather than possibly sharing one with
+    /// concurrent callers.
+    pub coalesce: bool,
+
+    /// Minimum backend TTL a `CacheStrategy::Fresh`/`Refresh` hit must still
+    /// have remaining to be served as-is. `None` (the default) serves any
+    /// hit regardless of how soon it expires.
+    ///
+    /// Set this so a caller never receives an entry that might expire mid-use
+    /// (e.g. a token a request holds onto for a few seconds after reading
+    /// it): a hit with less than this much TTL left is treated as a miss -
+    /// `Fresh` just misses, `Refresh` falls through to the repository the
+    /// same as a logically-expired hit does.
+    ///
+    /// Requires the backend to support [`crate::backend::CacheBackend::ttl`];
+    /// on a backend that returns `Error::NotImplemented` for it, this guard
+    /// is skipped and hits are served as if unset.
+    pub min_remaining_ttl: Option<Duration>,
+}
+
+impl Default for OperationConfig {
+    fn default() -> Self {
+        OperationConfig {
+            ttl_override: None,
+            retry_count: 0,
+            backoff_base: Duration::from_millis(100),
+            backoff_factor: 2.0,
+            backoff_cap: Duration::from_secs(10),
+            backoff_jitter: true,
+            retry_predicate: None,
+            negative_ttl: None,
+            stale_after: None,
+            evict_on_refresh_error: false,
+            evict_on_logical_expiry: false,
+            #[cfg(feature = "redis")]
+            rate_limit: None,
+            recovery_policy: None,
+            coalesce: true,
+            min_remaining_ttl: None,
+        }
+    }
+}
+
+impl fmt::Debug for OperationConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = f.debug_struct("OperationConfig");
+        s.field("ttl_override", &self.ttl_override)
+            .field("retry_count", &self.retry_count)
+            .field("backoff_base", &self.backoff_base)
+            .field("backoff_factor", &self.backoff_factor)
+            .field("backoff_cap", &self.backoff_cap)
+            .field("backoff_jitter", &self.backoff_jitter)
+            .field(
+                "retry_predicate",
+                &self.retry_predicate.as_ref().map(|_| "<fn>"),
+            )
+            .field("negative_ttl", &self.negative_ttl)
+            .field("stale_after", &self.stale_after)
+            .field("evict_on_refresh_error", &self.evict_on_refresh_error)
+            .field("evict_on_logical_expiry", &self.evict_on_logical_expiry);
+        #[cfg(feature = "redis")]
+        s.field("rate_limit", &self.rate_limit);
+        s.field("recovery_policy", &self.recovery_policy)
+            .field("coalesce", &self.coalesce)
+            .field("min_remaining_ttl", &self.min_remaining_ttl)
+            .finish()
+    }
 }
 
 impl OperationConfig {
@@ -101,6 +585,247 @@ impl OperationConfig {
         self.retry_count = count;
         self
     }
+
+    /// Override the base delay, growth factor, and cap used by retry backoff
+    /// (defaults: 100ms base, factor 2.0, 10s cap). Pass a `factor` of `1.0`
+    /// for fixed-delay retries instead of exponential ones.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let config = OperationConfig::default()
+    ///     .with_retry(5)
+    ///     .with_backoff(Duration::from_millis(50), 2.0, Duration::from_secs(2));
+    /// ```
+    pub fn with_backoff(mut self, base: Duration, factor: f64, max_delay: Duration) -> Self {
+        self.backoff_base = base;
+        self.backoff_factor = factor;
+        self.backoff_cap = max_delay;
+        self
+    }
+
+    /// Enable or disable full-jitter randomization of retry backoff delays
+    /// (default: enabled - see [`OperationConfig::backoff_jitter`]).
+    pub fn with_jitter(mut self, enabled: bool) -> Self {
+        self.backoff_jitter = enabled;
+        self
+    }
+
+    /// Override which errors this operation retries, in place of the default
+    /// [`Error::is_retryable`] classification.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Only retry timeouts, fail fast on everything else (including the
+    /// // backend/repository errors `Error::is_retryable` would otherwise
+    /// // retry for this operation).
+    /// let config = OperationConfig::default()
+    ///     .with_retry(3)
+    ///     .with_retry_if(|e| matches!(e, Error::Timeout(_)));
+    /// ```
+    pub fn with_retry_if(
+        mut self,
+        predicate: impl Fn(&Error) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.retry_predicate = Some(Arc::new(predicate));
+        self
+    }
+
+    /// Whether `error` should be retried for this operation: `retry_predicate`
+    /// if one was set via [`OperationConfig::with_retry_if`], otherwise
+    /// [`Error::is_retryable`].
+    pub fn is_retryable(&self, error: &Error) -> bool {
+        match &self.retry_predicate {
+            Some(predicate) => predicate(error),
+            None => error.is_retryable(),
+        }
+    }
+
+    /// Enable negative caching: a miss is remembered for `ttl` instead of
+    /// re-hitting the repository on every subsequent lookup.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let config = OperationConfig::default()
+    ///     .with_negative_ttl(Duration::from_secs(30));
+    /// ```
+    pub fn with_negative_ttl(mut self, ttl: Duration) -> Self {
+        self.negative_ttl = Some(ttl);
+        self
+    }
+
+    /// Disable negative caching for this operation (the default).
+    pub fn without_negative_caching(mut self) -> Self {
+        self.negative_ttl = None;
+        self
+    }
+
+    /// Enable `CacheStrategy::StaleWhileRevalidate` for this operation: a hit
+    /// older than `after` is still returned immediately, but also triggers a
+    /// background refresh.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let config = OperationConfig::default()
+    ///     .with_stale_after(Duration::from_secs(30));
+    /// ```
+    pub fn with_stale_after(mut self, after: Duration) -> Self {
+        self.stale_after = Some(after);
+        self
+    }
+
+    /// Evict a stale entry instead of leaving it in place when its background
+    /// refresh fails (default: `false`, serve the stale value until the next
+    /// successful refresh or hard TTL expiry).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let config = OperationConfig::default()
+    ///     .with_stale_after(Duration::from_secs(30))
+    ///     .with_evict_on_refresh_error(true);
+    /// ```
+    pub fn with_evict_on_refresh_error(mut self, evict: bool) -> Self {
+        self.evict_on_refresh_error = evict;
+        self
+    }
+
+    /// Delete a cache entry outright once it's found to be logically expired
+    /// (see [`CacheEntity::cache_expires_at`]), instead of leaving it in
+    /// place while the current call falls through to a repository re-fetch
+    /// (default: `false`).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let config = OperationConfig::default()
+    ///     .with_evict_on_logical_expiry(true);
+    /// ```
+    pub fn with_evict_on_logical_expiry(mut self, evict: bool) -> Self {
+        self.evict_on_logical_expiry = evict;
+        self
+    }
+
+    /// Cap repository refreshes for this operation's key to `limit` events
+    /// per `period`, provided the expander also has a `RateLimiter`
+    /// configured via `CacheExpander::with_rate_limiter`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let config = OperationConfig::default()
+    ///     .with_rate_limit(10, Duration::from_secs(60));
+    /// ```
+    #[cfg(feature = "redis")]
+    pub fn with_rate_limit(mut self, limit: u64, period: Duration) -> Self {
+        self.rate_limit = Some((limit, period));
+        self
+    }
+
+    /// Override the expander's default [`CacheRecoveryPolicy`] for this
+    /// operation.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let config = OperationConfig::default()
+    ///     .with_recovery_policy(CacheRecoveryPolicy::FallThrough);
+    /// ```
+    pub fn with_recovery_policy(mut self, policy: CacheRecoveryPolicy) -> Self {
+        self.recovery_policy = Some(policy);
+        self
+    }
+
+    /// Enable or disable request coalescing for this operation's repository
+    /// fetch (default: enabled - see [`OperationConfig::coalesce`]).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Opt a noisy background job out of sharing its fetch with others.
+    /// let config = OperationConfig::default().with_coalescing(false);
+    /// ```
+    pub fn with_coalescing(mut self, enabled: bool) -> Self {
+        self.coalesce = enabled;
+        self
+    }
+
+    /// Require a `Fresh`/`Refresh` hit to have at least `min` TTL remaining
+    /// to be served as-is (see [`OperationConfig::min_remaining_ttl`]).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Never hand out an entry with less than a second of life left.
+    /// let config = OperationConfig::default()
+    ///     .with_min_remaining_ttl(Duration::from_secs(1));
+    /// ```
+    pub fn with_min_remaining_ttl(mut self, min: Duration) -> Self {
+        self.min_remaining_ttl = Some(min);
+        self
+    }
+}
+
+/// Why a key was reported on a [`CacheExpander::with_eviction_listener`]
+/// channel.
+///
+/// This only covers causes the (backend-agnostic) expander can observe
+/// itself - a key leaving the cache because the backend's own TTL lapsed
+/// isn't one of them, since a generic [`CacheBackend::get`] miss is
+/// indistinguishable from a plain cold-cache miss without backend-specific
+/// cooperation (see e.g. `InMemoryBackend::with_eviction_listener` for a
+/// backend that *can* tell those apart internally).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WriteBackCause {
+    /// [`CacheStrategy::Invalidate`] removed the entry.
+    Evicted,
+    /// A stale-while-revalidate background refresh (see
+    /// [`OperationConfig::with_stale_after`]) wrote a fresh value over this
+    /// key. The bytes reported alongside this cause are the *new* value,
+    /// not the stale one it replaced, since that's what a listener
+    /// propagating changes to other nodes actually wants.
+    Replaced,
+}
+
+/// The provenance of a value returned by [`CacheExpander::with_outcome`]/
+/// [`CacheExpander::with_config_outcome`] (and their [`crate::service::CacheService`]
+/// counterparts) - served straight from the cache, fetched from the
+/// repository on a miss, or served stale while a background refresh ran.
+///
+/// Lets a caller log provenance, emit metrics, or set a response header
+/// (e.g. `X-Cache: HIT`) without threading a mutable flag through its
+/// `CacheFeed` the way inspecting `on_hit`/`on_miss` side effects would
+/// require.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CacheOutcome<T> {
+    /// Served straight from the cache backend, no repository call made.
+    Cached(T),
+    /// Not found in the cache (or past its hard TTL); fetched from the
+    /// repository and written back.
+    Fetched(T),
+    /// Served from the cache past `stale_after`, with a background refresh
+    /// spawned to repopulate it - only produced by
+    /// [`CacheExpander::with_stale_while_revalidate`].
+    Refreshed(T),
+}
+
+impl<T> CacheOutcome<T> {
+    /// Whether this value was served from the cache without the caller
+    /// waiting on a repository round trip - `true` for `Cached`/`Refreshed`,
+    /// `false` for `Fetched`.
+    pub fn is_cached(&self) -> bool {
+        !matches!(self, CacheOutcome::Fetched(_))
+    }
+
+    /// Unwrap the inner value, discarding which variant produced it.
+    pub fn into_inner(self) -> T {
+        match self {
+            CacheOutcome::Cached(v) | CacheOutcome::Fetched(v) | CacheOutcome::Refreshed(v) => v,
+        }
+    }
 }
 
 /// Core cache expander - handles cache lookup and fallback logic.
@@ -118,8 +843,48 @@ impl OperationConfig {
 /// ```
 pub struct CacheExpander<B: CacheBackend> {
     backend: B,
-    metrics: Box<dyn CacheMetrics>,
+    /// `Arc` rather than `Box` so `spawn_background_refresh`'s detached task
+    /// can hold its own handle without borrowing `self`.
+    metrics: Arc<dyn CacheMetrics>,
     pub(crate) ttl_policy: TtlPolicy,
+    /// Per-key locks used to coalesce concurrent cache misses for the same
+    /// key into a single repository fetch (see `strategy_refresh`).
+    inflight: Arc<DashMap<String, Arc<AsyncMutex<()>>>>,
+    /// Optional cross-instance invalidation broadcast; published to whenever
+    /// `CacheStrategy::Invalidate` evicts a key (see `strategy_invalidate`).
+    invalidation_bus: Option<Arc<dyn InvalidationBus>>,
+    /// Keys with a background refresh currently in flight (see
+    /// `with_stale_while_revalidate`), so a burst of stale hits on the same
+    /// key spawns exactly one refresh instead of one per caller.
+    refreshing: Arc<DashMap<String, ()>>,
+    /// Optional cross-process guard around a repository fetch on a cache
+    /// miss (see `with_locked_refresh`); `inflight` above only dedupes
+    /// callers within this one process.
+    #[cfg(feature = "redis")]
+    locked_refresh: Option<Arc<crate::backend::redis::DistributedLock>>,
+    /// Optional pacing gate on repository refreshes (see
+    /// `with_rate_limiter` and `OperationConfig::rate_limit`).
+    #[cfg(feature = "redis")]
+    rate_limiter: Option<Arc<crate::backend::redis::RateLimiter>>,
+    /// Default [`CacheRecoveryPolicy`] applied to a backend error, unless an
+    /// operation's [`OperationConfig::recovery_policy`] overrides it.
+    recovery_policy: CacheRecoveryPolicy,
+    /// Set once by `CacheRecoveryPolicy::BlackHole` after the first backend
+    /// error; once `true`, every subsequent operation treats the backend as
+    /// unavailable without calling it, regardless of that operation's own
+    /// resolved policy.
+    backend_blackholed: Arc<std::sync::atomic::AtomicBool>,
+    /// Optional write-back/coherence notification channel (see
+    /// `with_eviction_listener`); `None` means nothing is sent.
+    eviction_tx: Option<mpsc::Sender<(String, Vec<u8>, WriteBackCause)>>,
+    /// Optional system-wide cap on retry attempts (see
+    /// [`CacheExpander::with_retry_budget`]); `None` means every call's
+    /// retries are bounded only by its own `OperationConfig::retry_count`.
+    retry_budget: Option<Arc<RetryBudget>>,
+    /// Optional per-deployment key scheme override (see
+    /// [`CacheExpander::new_with_registry`]); `None` means every key comes
+    /// from the default `CacheKeyBuilder::build` (`"{prefix}:{id}"`) scheme.
+    key_registry: Option<Arc<KeyRegistry>>,
 }
 
 impl<B: CacheBackend> CacheExpander<B> {
@@ -127,14 +892,61 @@ impl<B: CacheBackend> CacheExpander<B> {
     pub fn new(backend: B) -> Self {
         CacheExpander {
             backend,
-            metrics: Box::new(NoOpMetrics),
+            metrics: Arc::new(NoOpMetrics),
             ttl_policy: TtlPolicy::default(),
+            inflight: Arc::new(DashMap::new()),
+            invalidation_bus: None,
+            refreshing: Arc::new(DashMap::new()),
+            #[cfg(feature = "redis")]
+            locked_refresh: None,
+            #[cfg(feature = "redis")]
+            rate_limiter: None,
+            recovery_policy: CacheRecoveryPolicy::default(),
+            backend_blackholed: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            eviction_tx: None,
+            retry_budget: None,
+            key_registry: None,
+        }
+    }
+
+    /// Create a new expander that routes key generation through `registry`
+    /// instead of the default `CacheKeyBuilder::build` (`"{prefix}:{id}"`)
+    /// scheme.
+    ///
+    /// For each entity type `T`, [`KeyRegistry::generate`] is consulted
+    /// with `std::any::type_name::<T>()`; a registered generator's output is
+    /// used as-is, and a type with nothing registered falls back to the
+    /// default scheme exactly as [`CacheExpander::new`] would. This enables
+    /// per-deployment key schemes (tenant prefixes, hash-sharded keys,
+    /// version-tagged keys) without changing any `CacheEntity` impl.
+    pub fn new_with_registry(backend: B, registry: Arc<KeyRegistry>) -> Self {
+        CacheExpander {
+            key_registry: Some(registry),
+            ..CacheExpander::new(backend)
         }
     }
 
+    /// The key scheme override passed to [`CacheExpander::new_with_registry`],
+    /// if any.
+    pub fn key_registry(&self) -> Option<&Arc<KeyRegistry>> {
+        self.key_registry.as_ref()
+    }
+
+    /// Build the cache key for `id`, consulting [`CacheExpander::key_registry`]
+    /// (keyed by `std::any::type_name::<T>()`) before falling back to
+    /// [`CacheKeyBuilder::build`]. Every `with`/batch/populate call builds
+    /// its key through here, so a registered generator actually participates
+    /// in cache lookups and stores instead of being dead API surface.
+    fn cache_key_for<T: CacheEntity>(&self, id: &T::Key) -> String {
+        self.key_registry
+            .as_ref()
+            .and_then(|registry| registry.generate(std::any::type_name::<T>(), id))
+            .unwrap_or_else(|| CacheKeyBuilder::build::<T>(id))
+    }
+
     /// Set custom metrics handler.
     pub fn with_metrics(mut self, metrics: Box<dyn CacheMetrics>) -> Self {
-        self.metrics = metrics;
+        self.metrics = Arc::from(metrics);
         self
     }
 
@@ -144,8 +956,98 @@ impl<B: CacheBackend> CacheExpander<B> {
         self
     }
 
+    /// Set the default [`CacheRecoveryPolicy`] applied when a backend
+    /// operation fails with [`Error::BackendError`] (default:
+    /// [`CacheRecoveryPolicy::Fail`], i.e. today's behavior). Override per
+    /// operation via [`OperationConfig::with_recovery_policy`].
+    pub fn with_recovery_policy(mut self, policy: CacheRecoveryPolicy) -> Self {
+        self.recovery_policy = policy;
+        self
+    }
+
+    /// Publish to `bus` whenever `CacheStrategy::Invalidate` evicts a key, so
+    /// sibling instances sharing this logical cache drop it too instead of
+    /// serving it until TTL expiry.
+    pub fn with_invalidation_bus(mut self, bus: Arc<dyn InvalidationBus>) -> Self {
+        self.invalidation_bus = Some(bus);
+        self
+    }
+
+    /// Get the configured invalidation bus, if any (for wiring up a listener
+    /// that applies remote invalidations to this instance's backend).
+    pub fn invalidation_bus(&self) -> Option<&Arc<dyn InvalidationBus>> {
+        self.invalidation_bus.as_ref()
+    }
+
+    /// Report every `Evicted`/`Replaced` write-back event to `tx` (see
+    /// [`WriteBackCause`]), e.g. to flush changed values to a downstream
+    /// store or to propagate them to sibling instances alongside
+    /// [`Self::with_invalidation_bus`].
+    ///
+    /// Sends are best-effort via `try_send` - a full or closed receiver
+    /// drops the notification and logs a warning rather than blocking the
+    /// cache operation that triggered it, the same trade-off
+    /// `maybe_slide_ttl` makes for a failed TTL re-arm.
+    pub fn with_eviction_listener(
+        mut self,
+        tx: mpsc::Sender<(String, Vec<u8>, WriteBackCause)>,
+    ) -> Self {
+        self.eviction_tx = Some(tx);
+        self
+    }
+
+    /// Cap retries across every call sharing this expander with a
+    /// [`RetryBudget`] of `capacity` tokens, `retry_cost` charged per retry
+    /// attempt, and `return_fraction * capacity` refunded per successful
+    /// fetch. See [`RetryBudget`] for the full rationale.
+    pub fn with_retry_budget(mut self, capacity: u32, retry_cost: u32, return_fraction: f64) -> Self {
+        self.retry_budget = Some(Arc::new(RetryBudget::new(capacity, retry_cost, return_fraction)));
+        self
+    }
+
+    /// Serialize repository loads for the same key across *processes*, not
+    /// just within this one.
+    ///
+    /// `singleflight_fetch` already coalesces concurrent callers in-process
+    /// via `inflight`; several replicas racing on the same cold key would
+    /// otherwise still all reach the database at once. With this configured,
+    /// a miss additionally tries to acquire `lock` before fetching - failing
+    /// to acquire it (contention, or a transient Redis error) falls through
+    /// to fetching anyway rather than blocking, so this only ever sharpens
+    /// the existing guarantee, never weakens or risks deadlocking it.
+    ///
+    /// The lock is only taken inside `singleflight_fetch` with coalescing
+    /// on (`Refresh`/`Invalidate`); `Bypass`, `with_coalescing(false)` and
+    /// stale-while-revalidate fetches do not take it.
+    #[cfg(feature = "redis")]
+    pub fn with_locked_refresh(mut self, lock: Arc<crate::backend::redis::DistributedLock>) -> Self {
+        self.locked_refresh = Some(lock);
+        self
+    }
+
+    /// Gate repository refreshes through `limiter`, so a key configured with
+    /// `OperationConfig::with_rate_limit` can't re-hit the database more
+    /// often than its cap allows, even across a fleet of processes sharing
+    /// `limiter`'s Redis backend.
+    ///
+    /// Operations that don't set `OperationConfig::rate_limit` are
+    /// unaffected even with this configured. The limit is only checked
+    /// inside `singleflight_fetch` with coalescing on (`Refresh`/
+    /// `Invalidate`); `Bypass`, `with_coalescing(false)` and
+    /// stale-while-revalidate fetches are not paced.
+    #[cfg(feature = "redis")]
+    pub fn with_rate_limiter(mut self, limiter: Arc<crate::backend::redis::RateLimiter>) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
     /// Generic cache operation with strategy.
     ///
+    /// Concurrent misses for the same key under `Refresh`/`Invalidate`/
+    /// `Bypass` are coalesced into a single repository fetch by default -
+    /// see `singleflight_fetch` and [`OperationConfig::coalesce`] to opt a
+    /// call out.
+    ///
     /// This is the primary method used in 80% of cases.
     ///
     /// # Arguments
@@ -237,7 +1139,10 @@ impl<B: CacheBackend> CacheExpander<B> {
     /// - `Error::Timeout`: Operation exceeds timeout threshold
     /// - `Error::SerializationError`: Entity serialization for caching fails
     ///
-    /// Failed operations are retried up to `config.retry_count` times with exponential backoff.
+    /// A retryable failure (see [`OperationConfig::is_retryable`]) is retried
+    /// up to `config.retry_count` times with full-jitter exponential backoff;
+    /// a non-retryable one (e.g. `Error::ValidationError`) returns immediately
+    /// without consuming a retry, since it can't succeed on a later attempt.
     pub async fn with_config<T, F, R>(
         &self,
         feeder: &mut F,
@@ -263,475 +1168,4639 @@ impl<B: CacheBackend> CacheExpander<B> {
                 .await;
 
             match result {
-                Ok(()) => return Ok(()),
+                Ok(()) => {
+                    if let Some(budget) = &self.retry_budget {
+                        budget.refill();
+                    }
+                    return Ok(());
+                }
                 Err(e) => {
+                    if !config.is_retryable(&e) {
+                        debug!("Cache operation failed with a non-retryable error: {}", e);
+                        return Err(e);
+                    }
+
                     if attempts >= max_attempts {
                         return Err(e);
                     }
 
+                    if let Some(budget) = &self.retry_budget {
+                        if !budget.try_acquire() {
+                            debug!(
+                                "Retry budget exhausted, giving up after attempt {}/{}",
+                                attempts, max_attempts
+                            );
+                            return Err(e);
+                        }
+                    }
+
                     debug!(
                         "Cache operation failed (attempt {}/{}), retrying...",
                         attempts, max_attempts
                     );
 
-                    // Exponential backoff
-                    if config.retry_count > 0 {
-                        let delay =
-                            tokio::time::Duration::from_millis(100 * 2_u64.pow(attempts - 1));
-                        tokio::time::sleep(delay).await;
-                    }
+                    // Full-jitter exponential backoff: on zero-indexed retry
+                    // `k`, sleep a random duration in [0, min(backoff_cap,
+                    // backoff_base * backoff_factor^k)).
+                    let k = (attempts - 1).min(1000) as i32;
+                    let exp_delay =
+                        config.backoff_base.as_secs_f64() * config.backoff_factor.powi(k);
+                    let max_delay = Duration::from_secs_f64(
+                        exp_delay.min(config.backoff_cap.as_secs_f64()).max(0.0),
+                    );
+                    let delay = if config.backoff_jitter {
+                        full_jitter(max_delay)
+                    } else {
+                        max_delay
+                    };
+                    tokio::time::sleep(delay).await;
                 }
             }
         }
     }
 
-    /// Internal method to execute a single cache operation (without retry).
-    async fn execute_operation<T, F, R>(
+    /// Like [`CacheExpander::with`], but returns the served value wrapped in
+    /// a [`CacheOutcome`] instead of feeding it to `feeder` and discarding
+    /// whether it came from the cache or the repository.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` in the same cases as [`CacheExpander::with`].
+    pub async fn with_outcome<T, F, R>(
         &self,
         feeder: &mut F,
         repository: &R,
         strategy: CacheStrategy,
-        config: &OperationConfig,
-    ) -> Result<()>
+    ) -> Result<Option<CacheOutcome<T>>>
     where
         T: CacheEntity,
         F: CacheFeed<T>,
         R: DataRepository<T>,
         T::Key: FromStr,
     {
-        let timer = Instant::now();
+        self.with_config_outcome::<T, F, R>(feeder, repository, strategy, OperationConfig::default())
+            .await
+    }
 
-        // Step 1: Validate feeder
+    /// Like [`CacheExpander::with_config`], but returns the served value
+    /// wrapped in a [`CacheOutcome`] instead of feeding it to `feeder` and
+    /// discarding whether it came from the cache or the repository.
+    ///
+    /// Retried exactly like `with_config` - see that method for the
+    /// retry/backoff behavior.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` in the same cases as [`CacheExpander::with_config`].
+    pub async fn with_config_outcome<T, F, R>(
+        &self,
+        feeder: &mut F,
+        repository: &R,
+        strategy: CacheStrategy,
+        config: OperationConfig,
+    ) -> Result<Option<CacheOutcome<T>>>
+    where
+        T: CacheEntity,
+        F: CacheFeed<T>,
+        R: DataRepository<T>,
+        T::Key: FromStr,
+    {
+        let mut attempts = 0;
+        let max_attempts = config.retry_count + 1;
+
+        loop {
+            attempts += 1;
+
+            let result = self
+                .execute_operation_outcome::<T, F, R>(feeder, repository, strategy.clone(), &config)
+                .await;
+
+            match result {
+                Ok(outcome) => {
+                    if let Some(budget) = &self.retry_budget {
+                        budget.refill();
+                    }
+                    return Ok(outcome);
+                }
+                Err(e) => {
+                    if !config.is_retryable(&e) {
+                        debug!("Cache operation failed with a non-retryable error: {}", e);
+                        return Err(e);
+                    }
+
+                    if attempts >= max_attempts {
+                        return Err(e);
+                    }
+
+                    if let Some(budget) = &self.retry_budget {
+                        if !budget.try_acquire() {
+                            debug!(
+                                "Retry budget exhausted, giving up after attempt {}/{}",
+                                attempts, max_attempts
+                            );
+                            return Err(e);
+                        }
+                    }
+
+                    debug!(
+                        "Cache operation failed (attempt {}/{}), retrying...",
+                        attempts, max_attempts
+                    );
+
+                    let k = (attempts - 1).min(1000) as i32;
+                    let exp_delay =
+                        config.backoff_base.as_secs_f64() * config.backoff_factor.powi(k);
+                    let max_delay = Duration::from_secs_f64(
+                        exp_delay.min(config.backoff_cap.as_secs_f64()).max(0.0),
+                    );
+                    let delay = if config.backoff_jitter {
+                        full_jitter(max_delay)
+                    } else {
+                        max_delay
+                    };
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        }
+    }
+
+    /// Stale-while-revalidate: return a cached value immediately even if it's
+    /// past `config.stale_after`, while refreshing it from `repository` on a
+    /// detached background task - so a hot key's callers never wait on the
+    /// database, at the cost of serving up to one refresh cycle of staleness.
+    ///
+    /// Unlike [`CacheExpander::with`]/[`CacheExpander::with_config`], `repository`
+    /// is taken as an `Arc` rather than a reference: the background refresh
+    /// outlives this call, so it needs an owned handle it can move into the
+    /// spawned task. `config.stale_after` must be `Some`, either set directly
+    /// or falling back to [`crate::observability::TtlPolicy::SoftHard`] on
+    /// the expander's `ttl_policy`; without one of those there's no way to
+    /// decide when a hit should trigger a refresh.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let repo = Arc::new(EmploymentRepository::new(pool));
+    /// let config = OperationConfig::default().with_stale_after(Duration::from_secs(30));
+    /// expander.with_stale_while_revalidate(&mut feeder, repo, config).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` in the same cases as [`CacheExpander::with`], plus
+    /// `Error::ValidationError` if `config.stale_after` is `None` and the
+    /// expander's `ttl_policy` isn't `TtlPolicy::SoftHard`.
+    pub async fn with_stale_while_revalidate<T, F, R>(
+        &self,
+        feeder: &mut F,
+        repository: Arc<R>,
+        config: OperationConfig,
+    ) -> Result<()>
+    where
+        T: CacheEntity,
+        F: CacheFeed<T>,
+        R: DataRepository<T> + Send + Sync + 'static,
+        T::Key: FromStr,
+    {
+        let timer = Instant::now();
         feeder.validate()?;
 
-        // Step 2: Get entity ID and build cache key
+        let Some(stale_after) = config.stale_after.or_else(|| self.ttl_policy.soft_ttl()) else {
+            return Err(Error::ValidationError(
+                "CacheStrategy::StaleWhileRevalidate requires OperationConfig::with_stale_after \
+                 or a TtlPolicy::SoftHard ttl_policy"
+                    .to_string(),
+            ));
+        };
+
         let entity_id = feeder.entity_id();
-        let cache_key = CacheKeyBuilder::build::<T>(&entity_id);
+        let cache_key = self.cache_key_for::<T>(&entity_id);
 
         debug!(
-            "» Cache operation for key: {} (strategy: {})",
-            cache_key, strategy
+            "» Cache operation for key: {} (strategy: StaleWhileRevalidate)",
+            cache_key
         );
 
-        // Step 3: Execute strategy
-        let result = match strategy {
-            CacheStrategy::Fresh => {
-                self.strategy_fresh::<T, R>(&cache_key, repository, config)
-                    .await
-            }
-            CacheStrategy::Refresh => {
-                self.strategy_refresh::<T, R>(&cache_key, repository, config)
-                    .await
+        let result = self
+            .strategy_stale_while_revalidate::<T, R>(&cache_key, repository, stale_after, &config)
+            .await;
+
+        match result {
+            Ok(Some((entity, is_stale))) => {
+                entity.validate()?;
+                feeder.on_hit(&cache_key)?;
+                feeder.on_loaded(&entity)?;
+                feeder.feed(Some(entity));
+                if is_stale {
+                    self.metrics.record_stale_hit(&cache_key, timer.elapsed());
+                } else {
+                    self.metrics.record_hit(&cache_key, timer.elapsed());
+                }
             }
-            CacheStrategy::Invalidate => {
-                self.strategy_invalidate::<T, R>(&cache_key, repository, config)
-                    .await
+            Ok(None) => {
+                feeder.on_miss(&cache_key)?;
+                feeder.feed(None);
+                self.metrics.record_miss(&cache_key, timer.elapsed());
             }
-            CacheStrategy::Bypass => {
-                self.strategy_bypass::<T, R>(&cache_key, repository, config)
-                    .await
+            Err(e) => {
+                self.metrics.record_error(&cache_key, &e.to_string());
+                return Err(e);
             }
+        }
+
+        Ok(())
+    }
+
+    /// Like [`CacheExpander::with_stale_while_revalidate`], but returns the
+    /// served value wrapped in a [`CacheOutcome`] instead of feeding it to
+    /// `feeder` and discarding whether it was fresh, stale, or newly fetched.
+    /// A stale hit (background refresh triggered) comes back as
+    /// `CacheOutcome::Refreshed`; a fresh hit as `CacheOutcome::Cached`; a
+    /// miss that was fetched inline as `CacheOutcome::Fetched`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` in the same cases as [`CacheExpander::with_stale_while_revalidate`].
+    pub async fn with_stale_while_revalidate_outcome<T, F, R>(
+        &self,
+        feeder: &mut F,
+        repository: Arc<R>,
+        config: OperationConfig,
+    ) -> Result<Option<CacheOutcome<T>>>
+    where
+        T: CacheEntity,
+        F: CacheFeed<T>,
+        R: DataRepository<T> + Send + Sync + 'static,
+        T::Key: FromStr,
+    {
+        let timer = Instant::now();
+        feeder.validate()?;
+
+        let Some(stale_after) = config.stale_after.or_else(|| self.ttl_policy.soft_ttl()) else {
+            return Err(Error::ValidationError(
+                "CacheStrategy::StaleWhileRevalidate requires OperationConfig::with_stale_after \
+                 or a TtlPolicy::SoftHard ttl_policy"
+                    .to_string(),
+            ));
         };
 
-        // Step 4: Handle result
+        let entity_id = feeder.entity_id();
+        let cache_key = self.cache_key_for::<T>(&entity_id);
+
+        debug!(
+            "» Cache operation for key: {} (strategy: StaleWhileRevalidate)",
+            cache_key
+        );
+
+        let result = self
+            .strategy_stale_while_revalidate::<T, R>(&cache_key, repository, stale_after, &config)
+            .await;
+
         match result {
-            Ok(Some(entity)) => {
+            Ok(Some((entity, is_stale))) => {
                 entity.validate()?;
                 feeder.on_hit(&cache_key)?;
                 feeder.on_loaded(&entity)?;
-                feeder.feed(Some(entity));
-                self.metrics.record_hit(&cache_key, timer.elapsed());
-                info!("✓ Cache operation succeeded in {:?}", timer.elapsed());
+                feeder.feed(Some(entity.clone()));
+                if is_stale {
+                    self.metrics.record_stale_hit(&cache_key, timer.elapsed());
+                    Ok(Some(CacheOutcome::Refreshed(entity)))
+                } else {
+                    self.metrics.record_hit(&cache_key, timer.elapsed());
+                    Ok(Some(CacheOutcome::Cached(entity)))
+                }
             }
             Ok(None) => {
                 feeder.on_miss(&cache_key)?;
                 feeder.feed(None);
                 self.metrics.record_miss(&cache_key, timer.elapsed());
-                debug!("Entity not found after cache operation for {}", cache_key);
+                Ok(None)
             }
             Err(e) => {
                 self.metrics.record_error(&cache_key, &e.to_string());
-                return Err(e);
+                Err(e)
             }
         }
+    }
 
-        Ok(())
+    /// Read path for [`CacheExpander::with_stale_while_revalidate`]: serve a
+    /// cache hit immediately regardless of staleness, spawning a background
+    /// refresh if it's past `stale_after`; on a miss, fetch inline (there's
+    /// nothing stale to serve yet) and write the result back wrapped in
+    /// [`StaleAware`] so future hits can be judged for staleness.
+    ///
+    /// The returned `bool` is `true` when the hit was past `stale_after` (so
+    /// the caller can distinguish a stale hit from a fresh one for metrics -
+    /// see [`CacheMetrics::record_stale_hit`]); it's always `false` for a
+    /// freshly-populated miss.
+    async fn strategy_stale_while_revalidate<T, R>(
+        &self,
+        cache_key: &str,
+        repository: Arc<R>,
+        stale_after: Duration,
+        config: &OperationConfig,
+    ) -> Result<Option<(T, bool)>>
+    where
+        T: CacheEntity,
+        R: DataRepository<T> + Send + Sync + 'static,
+        T::Key: FromStr,
+    {
+        match self.backend.get(cache_key).await? {
+            Some(bytes) if is_tombstone(&bytes) => {
+                debug!(
+                    "✓ Cache hit (StaleWhileRevalidate) - negative-cached miss for {}",
+                    cache_key
+                );
+                Ok(None)
+            }
+            Some(bytes) => {
+                // Backward compatible with an entry that has no soft-expiry
+                // envelope - written under `Fresh`/`Refresh`/etc before this
+                // key was ever read under `StaleWhileRevalidate`, or from
+                // before this field existed. Treated as always-fresh: never
+                // triggers a background refresh, same as the request's
+                // "no soft-expiry field" case.
+                let Ok(stale) = deserialize_from_cache::<StaleAware<T>>(&bytes) else {
+                    debug!(
+                        "✓ Cache hit (StaleWhileRevalidate) - no soft-expiry envelope for {}, treating as fresh",
+                        cache_key
+                    );
+                    let value: T = deserialize_from_cache(&bytes)?;
+                    return Ok(Some((value, false)));
+                };
+                let is_stale = now_unix() >= stale.soft_expires_at;
+                if is_stale {
+                    debug!(
+                        "✓ Cache hit (StaleWhileRevalidate) - stale, refreshing {} in background",
+                        cache_key
+                    );
+                    let id = self.extract_id_from_key::<T>(cache_key)?;
+                    self.spawn_background_refresh(
+                        cache_key.to_string(),
+                        id,
+                        repository,
+                        stale_after,
+                        config.clone(),
+                    );
+                } else {
+                    debug!("✓ Cache hit (StaleWhileRevalidate) - fresh for {}", cache_key);
+                }
+                Ok(Some((stale.value, is_stale)))
+            }
+            None => {
+                debug!(
+                    "✗ Cache miss (StaleWhileRevalidate) - fetching inline for {}",
+                    cache_key
+                );
+                let id = self.extract_id_from_key::<T>(cache_key)?;
+                match repository.fetch_by_id(&id).await? {
+                    Some(entity) => {
+                        if repository.is_cacheable(&entity) {
+                            self.write_stale_aware(cache_key, &entity, stale_after, config)
+                                .await?;
+                        }
+                        Ok(Some((entity, false)))
+                    }
+                    None => {
+                        if let Some(negative_ttl) = config.negative_ttl {
+                            let _ = self
+                                .backend
+                                .set(cache_key, TOMBSTONE_MARKER.to_vec(), Some(negative_ttl))
+                                .await;
+                        }
+                        Ok(None)
+                    }
+                }
+            }
+        }
     }
 
-    /// Fresh strategy: Cache only, no database fallback.
-    async fn strategy_fresh<T: CacheEntity, R: DataRepository<T>>(
+    /// Serialize `entity` as a [`StaleAware`] payload (soft-expiring at
+    /// `stale_after` from now) and write it to the backend under the entry's
+    /// resolved hard TTL.
+    async fn write_stale_aware<T: CacheEntity>(
         &self,
         cache_key: &str,
-        _repository: &R,
-        _config: &OperationConfig,
-    ) -> Result<Option<T>> {
-        debug!("Executing Fresh strategy for {}", cache_key);
+        entity: &T,
+        stale_after: Duration,
+        config: &OperationConfig,
+    ) -> Result<()> {
+        let hard_ttl = config
+            .ttl_override
+            .or_else(|| entity.cache_ttl())
+            .or_else(|| self.ttl_policy.get_ttl(T::cache_prefix()));
+        let wrapped = StaleAware {
+            soft_expires_at: now_unix() + stale_after.as_secs(),
+            value: entity.clone(),
+        };
+        let bytes = serialize_for_cache(&wrapped)?;
+        let _ = self.backend.set(cache_key, bytes, hard_ttl).await;
+        Ok(())
+    }
+
+    /// Fetch `id` from `repository` on a detached task and rewrite the cache
+    /// entry as a fresh [`StaleAware`] payload, coalescing concurrent refresh
+    /// requests for the same `cache_key` into a single repository call via
+    /// `self.refreshing`. A successful rewrite records through
+    /// [`CacheMetrics::record_refresh`]; a failed fetch or serialize records
+    /// through [`CacheMetrics::record_refresh_error`] in addition to the
+    /// `warn!` log, since this runs detached and has no caller to propagate
+    /// the error to. On a refresh error, the stale entry is left in place
+    /// unless `config.evict_on_refresh_error` is set, in which case it's
+    /// deleted instead so the next hit falls back to a synchronous fetch
+    /// rather than keep serving a value that failed to refresh.
+    fn spawn_background_refresh<T, R>(
+        &self,
+        cache_key: String,
+        id: T::Key,
+        repository: Arc<R>,
+        stale_after: Duration,
+        config: OperationConfig,
+    ) where
+        T: CacheEntity,
+        R: DataRepository<T> + Send + Sync + 'static,
+    {
+        if self.refreshing.insert(cache_key.clone(), ()).is_some() {
+            debug!(
+                "Background refresh for {} already in flight, skipping",
+                cache_key
+            );
+            return;
+        }
+
+        let backend = self.backend.clone();
+        let ttl_policy = self.ttl_policy.clone();
+        let refreshing = self.refreshing.clone();
+        let eviction_tx = self.eviction_tx.clone();
+        let metrics = self.metrics.clone();
+
+        tokio::spawn(async move {
+            let timer = Instant::now();
+            match repository.fetch_by_id(&id).await {
+                Ok(Some(entity)) if repository.is_cacheable(&entity) => {
+                    let hard_ttl = config
+                        .ttl_override
+                        .or_else(|| entity.cache_ttl())
+                        .or_else(|| ttl_policy.get_ttl(T::cache_prefix()));
+                    let wrapped = StaleAware {
+                        soft_expires_at: now_unix() + stale_after.as_secs(),
+                        value: entity,
+                    };
+                    match serialize_for_cache(&wrapped) {
+                        Ok(bytes) => {
+                            let _ = backend.set(&cache_key, bytes.clone(), hard_ttl).await;
+                            if let Some(tx) = &eviction_tx {
+                                if let Err(e) =
+                                    tx.try_send((cache_key.clone(), bytes, WriteBackCause::Replaced))
+                                {
+                                    warn!("⚠ Write-back notify dropped for {}: {}", cache_key, e);
+                                }
+                            }
+                            metrics.record_refresh(&cache_key, timer.elapsed());
+                        }
+                        Err(e) => {
+                            warn!("Background refresh for {} failed to serialize: {}", cache_key, e);
+                            metrics.record_refresh_error(&cache_key, &e.to_string());
+                            if config.evict_on_refresh_error {
+                                let _ = backend.delete(&cache_key).await;
+                            }
+                        }
+                    }
+                }
+                Ok(Some(_)) => {
+                    debug!(
+                        "Background refresh for {} fetched an uncacheable entity, leaving stale entry in place",
+                        cache_key
+                    );
+                }
+                Ok(None) => {
+                    if let Some(negative_ttl) = config.negative_ttl {
+                        let _ = backend
+                            .set(&cache_key, TOMBSTONE_MARKER.to_vec(), Some(negative_ttl))
+                            .await;
+                    }
+                }
+                Err(e) => {
+                    warn!("Background refresh for {} failed: {}", cache_key, e);
+                    metrics.record_refresh_error(&cache_key, &e.to_string());
+                    if config.evict_on_refresh_error {
+                        let _ = backend.delete(&cache_key).await;
+                    }
+                }
+            }
+
+            refreshing.remove(&cache_key);
+        });
+    }
+
+    /// Resolve a batch of keys in a single round trip per side.
+    ///
+    /// Equivalent to calling [`CacheExpander::with`] with `CacheStrategy::Refresh`
+    /// once per id, except the cache lookup is one `CacheBackend::mget`, the
+    /// fallback is one `DataRepository::fetch_by_ids`, and newly-fetched
+    /// cacheable entities are written back with one `CacheBackend::mset` -
+    /// instead of N sequential round trips on every side.
+    /// `feeder.on_hit`/`on_miss`/`on_loaded` still fire once per key,
+    /// so existing per-key observability keeps working unchanged.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut feeder = GenericBatchFeeder::new(vec!["1".into(), "2".into(), "3".into()]);
+    /// expander.with_batch::<Employment, _, _>(&mut feeder, &repo).await?;
+    /// let results = feeder.data; // Vec<(String, Option<Employment>)>
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` in the same cases as [`CacheExpander::with`].
+    pub async fn with_batch<T, F, R>(&self, feeder: &mut F, repository: &R) -> Result<()>
+    where
+        T: CacheEntity,
+        F: BatchCacheFeed<T>,
+        R: DataRepository<T>,
+    {
+        self.with_batch_config::<T, F, R>(feeder, repository, OperationConfig::default())
+            .await
+    }
+
+    /// [`CacheExpander::with_batch`] with a per-operation [`OperationConfig`]
+    /// (currently only `ttl_override` applies; batch writes are not retried).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` in the same cases as [`CacheExpander::with`].
+    pub async fn with_batch_config<T, F, R>(
+        &self,
+        feeder: &mut F,
+        repository: &R,
+        config: OperationConfig,
+    ) -> Result<()>
+    where
+        T: CacheEntity,
+        F: BatchCacheFeed<T>,
+        R: DataRepository<T>,
+    {
+        let timer = Instant::now();
+        feeder.validate()?;
+
+        let ids = feeder.entity_ids();
+        if ids.is_empty() {
+            feeder.feed_batch(Vec::new());
+            return Ok(());
+        }
+
+        let cache_keys: Vec<String> = ids.iter().map(|id| self.cache_key_for::<T>(id)).collect();
+        let key_refs: Vec<&str> = cache_keys.iter().map(String::as_str).collect();
+
+        debug!("» Batch cache operation for {} keys", cache_keys.len());
+
+        let cached = self.backend.mget(&key_refs).await?;
+        let mut entities: Vec<Option<T>> = Vec::with_capacity(ids.len());
+        let mut miss_indices = Vec::new();
+
+        for (i, bytes) in cached.into_iter().enumerate() {
+            match bytes {
+                Some(bytes) => {
+                    entities.push(Some(T::deserialize_from_cache(&bytes)?));
+                    feeder.on_hit(&cache_keys[i])?;
+                    self.metrics.record_hit(&cache_keys[i], timer.elapsed());
+                }
+                None => {
+                    entities.push(None);
+                    miss_indices.push(i);
+                }
+            }
+        }
+
+        if !miss_indices.is_empty() {
+            let miss_ids: Vec<T::Key> = miss_indices.iter().map(|&i| ids[i].clone()).collect();
+            let fetched = repository.fetch_by_ids(&miss_ids).await?;
+
+            // Collect cacheable misses and write them back in one pipelined
+            // `mset` instead of one `set` round trip per entity.
+            let mut to_cache = Vec::with_capacity(miss_indices.len());
+
+            for (&i, entity) in miss_indices.iter().zip(fetched.into_iter()) {
+                match entity {
+                    Some(entity) => {
+                        if repository.is_cacheable(&entity) {
+                            let ttl = config
+                                .ttl_override
+                                .or_else(|| entity.cache_ttl())
+                                .or_else(|| self.ttl_policy.get_ttl(T::cache_prefix()));
+                            let bytes = entity.serialize_for_cache()?;
+                            to_cache.push((cache_keys[i].as_str(), bytes, ttl));
+                        }
+                        feeder.on_hit(&cache_keys[i])?;
+                        self.metrics.record_hit(&cache_keys[i], timer.elapsed());
+                        entities[i] = Some(entity);
+                    }
+                    None => {
+                        feeder.on_miss(&cache_keys[i])?;
+                        self.metrics.record_miss(&cache_keys[i], timer.elapsed());
+                    }
+                }
+            }
+
+            if !to_cache.is_empty() {
+                let _ = self.backend.mset(&to_cache).await;
+            }
+        }
+
+        for entity in entities.iter().flatten() {
+            entity.validate()?;
+            feeder.on_loaded(entity)?;
+        }
+
+        let results: Vec<(T::Key, Option<T>)> = ids.into_iter().zip(entities).collect();
+        info!(
+            "✓ Batch cache operation resolved {} keys in {:?}",
+            results.len(),
+            timer.elapsed()
+        );
+        feeder.feed_batch(results);
+
+        Ok(())
+    }
+
+    /// Alias for [`CacheExpander::with_batch`], for callers reaching for the
+    /// more familiar "multi-get" name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` in the same cases as [`CacheExpander::with_batch`].
+    pub async fn with_many<T, F, R>(&self, feeder: &mut F, repository: &R) -> Result<()>
+    where
+        T: CacheEntity,
+        F: BatchCacheFeed<T>,
+        R: DataRepository<T>,
+    {
+        self.with_batch::<T, F, R>(feeder, repository).await
+    }
+
+    /// [`CacheExpander::with_batch_config`] for callers that just want a
+    /// `Vec` of results back instead of defining a [`BatchCacheFeed`] - the
+    /// feederless read counterpart to [`CacheExpander::populate_many`], built
+    /// on a disposable [`crate::feed::GenericBatchFeeder`] so it shares the
+    /// exact same single `mget`/`fetch_by_ids`/`mset` round trip and
+    /// per-key `metrics.record_hit`/`record_miss` accounting.
+    ///
+    /// There's no `CacheStrategy` parameter here, matching `with_batch`/
+    /// `with_batch_config`: batch reads only support the
+    /// `CacheStrategy::Refresh`-equivalent read-through path, since the other
+    /// strategies (`Fresh`, `Invalidate`, `Bypass`, `StaleWhileRevalidate`)
+    /// don't have a meaningful batched form yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` in the same cases as [`CacheExpander::with_batch`].
+    pub async fn fetch_many<T, R>(
+        &self,
+        ids: &[T::Key],
+        repository: &R,
+        config: OperationConfig,
+    ) -> Result<Vec<(T::Key, Option<T>)>>
+    where
+        T: CacheEntity,
+        R: DataRepository<T>,
+    {
+        let mut feeder = crate::feed::GenericBatchFeeder::new(ids.to_vec());
+        self.with_batch_config::<T, _, R>(&mut feeder, repository, config)
+            .await?;
+        Ok(feeder.data)
+    }
+
+    /// Like [`CacheExpander::with_batch`], but returns each resolved entity
+    /// wrapped in a [`CacheOutcome`] instead of only feeding it to `feeder` -
+    /// so a list endpoint backed by a single `mget`/`fetch_by_ids`/`mset` pass
+    /// can still tell which rows served from cache and which came from the
+    /// repository, the batched equivalent of [`CacheExpander::with_outcome`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` in the same cases as [`CacheExpander::with_batch`].
+    pub async fn with_batch_outcome<T, F, R>(
+        &self,
+        feeder: &mut F,
+        repository: &R,
+    ) -> Result<Vec<(T::Key, Option<CacheOutcome<T>>)>>
+    where
+        T: CacheEntity,
+        F: BatchCacheFeed<T>,
+        R: DataRepository<T>,
+    {
+        self.with_batch_config_outcome::<T, F, R>(feeder, repository, OperationConfig::default())
+            .await
+    }
+
+    /// [`CacheExpander::with_batch_outcome`] with a per-operation
+    /// [`OperationConfig`].
+    ///
+    /// `feeder.feed_batch` still receives the plain `Option<T>` results (so
+    /// existing `BatchCacheFeed` impls keep working unchanged); the returned
+    /// `Vec` is the only place the per-row cached/fetched provenance shows
+    /// up, aligned with `feeder.entity_ids()`'s order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` in the same cases as [`CacheExpander::with_batch`].
+    pub async fn with_batch_config_outcome<T, F, R>(
+        &self,
+        feeder: &mut F,
+        repository: &R,
+        config: OperationConfig,
+    ) -> Result<Vec<(T::Key, Option<CacheOutcome<T>>)>>
+    where
+        T: CacheEntity,
+        F: BatchCacheFeed<T>,
+        R: DataRepository<T>,
+    {
+        let timer = Instant::now();
+        feeder.validate()?;
+
+        let ids = feeder.entity_ids();
+        if ids.is_empty() {
+            feeder.feed_batch(Vec::new());
+            return Ok(Vec::new());
+        }
+
+        let cache_keys: Vec<String> = ids.iter().map(|id| self.cache_key_for::<T>(id)).collect();
+        let key_refs: Vec<&str> = cache_keys.iter().map(String::as_str).collect();
+
+        debug!(
+            "» Batch cache operation (outcome) for {} keys",
+            cache_keys.len()
+        );
+
+        let cached = self.backend.mget(&key_refs).await?;
+        let mut entities: Vec<Option<T>> = Vec::with_capacity(ids.len());
+        let mut is_cached: Vec<bool> = Vec::with_capacity(ids.len());
+        let mut miss_indices = Vec::new();
+
+        for (i, bytes) in cached.into_iter().enumerate() {
+            match bytes {
+                Some(bytes) => {
+                    entities.push(Some(T::deserialize_from_cache(&bytes)?));
+                    is_cached.push(true);
+                    feeder.on_hit(&cache_keys[i])?;
+                    self.metrics.record_hit(&cache_keys[i], timer.elapsed());
+                }
+                None => {
+                    entities.push(None);
+                    is_cached.push(false);
+                    miss_indices.push(i);
+                }
+            }
+        }
+
+        if !miss_indices.is_empty() {
+            let miss_ids: Vec<T::Key> = miss_indices.iter().map(|&i| ids[i].clone()).collect();
+            let fetched = repository.fetch_by_ids(&miss_ids).await?;
+
+            let mut to_cache = Vec::with_capacity(miss_indices.len());
+
+            for (&i, entity) in miss_indices.iter().zip(fetched.into_iter()) {
+                match entity {
+                    Some(entity) => {
+                        if repository.is_cacheable(&entity) {
+                            let ttl = config
+                                .ttl_override
+                                .or_else(|| entity.cache_ttl())
+                                .or_else(|| self.ttl_policy.get_ttl(T::cache_prefix()));
+                            let bytes = entity.serialize_for_cache()?;
+                            to_cache.push((cache_keys[i].as_str(), bytes, ttl));
+                        }
+                        feeder.on_hit(&cache_keys[i])?;
+                        self.metrics.record_hit(&cache_keys[i], timer.elapsed());
+                        entities[i] = Some(entity);
+                    }
+                    None => {
+                        feeder.on_miss(&cache_keys[i])?;
+                        self.metrics.record_miss(&cache_keys[i], timer.elapsed());
+                    }
+                }
+            }
+
+            if !to_cache.is_empty() {
+                let _ = self.backend.mset(&to_cache).await;
+            }
+        }
+
+        for entity in entities.iter().flatten() {
+            entity.validate()?;
+            feeder.on_loaded(entity)?;
+        }
+
+        let plain_results: Vec<(T::Key, Option<T>)> =
+            ids.iter().cloned().zip(entities.iter().cloned()).collect();
+        info!(
+            "✓ Batch cache operation (outcome) resolved {} keys in {:?}",
+            plain_results.len(),
+            timer.elapsed()
+        );
+        feeder.feed_batch(plain_results);
+
+        let outcomes = ids
+            .into_iter()
+            .zip(entities)
+            .zip(is_cached)
+            .map(|((id, entity), cached)| {
+                let outcome = entity.map(|e| {
+                    if cached {
+                        CacheOutcome::Cached(e)
+                    } else {
+                        CacheOutcome::Fetched(e)
+                    }
+                });
+                (id, outcome)
+            })
+            .collect();
+
+        Ok(outcomes)
+    }
+
+    /// Bulk-populate the cache from already-loaded entities in one backend
+    /// round-trip, via [`CacheBackend::mset`], instead of one `set` per
+    /// entity.
+    ///
+    /// Unlike [`CacheExpander::with_batch`], this doesn't consult a
+    /// `DataRepository` or `BatchCacheFeed` - it's for warming the cache with
+    /// data the caller already has (e.g. after a bulk import), skipping
+    /// entities `DataRepository::is_cacheable`-style logic would exclude is
+    /// the caller's responsibility before calling this. Entries are written
+    /// in the same order as `entities`; a key repeated later in the slice
+    /// wins, matching `mset`'s positional semantics.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if any entity fails to serialize, or if the backend
+    /// write fails.
+    pub async fn populate_many<T: CacheEntity>(&self, entities: &[T]) -> Result<()> {
+        if entities.is_empty() {
+            return Ok(());
+        }
+
+        let mut entries = Vec::with_capacity(entities.len());
+        let cache_keys: Vec<String> = entities
+            .iter()
+            .map(|entity| self.cache_key_for::<T>(&entity.cache_key()))
+            .collect();
+
+        for (entity, cache_key) in entities.iter().zip(cache_keys.iter()) {
+            let ttl = entity
+                .cache_ttl()
+                .or_else(|| self.ttl_policy.get_ttl(T::cache_prefix()));
+            let bytes = entity.serialize_for_cache()?;
+            entries.push((cache_key.as_str(), bytes, ttl));
+        }
+
+        self.backend.mset(&entries).await?;
+        debug!("✓ Bulk-populated {} cache entries", entries.len());
+        Ok(())
+    }
+
+    /// [`CacheExpander::with_batch_config`] for callers that already have a
+    /// slice of independent per-entity [`CacheFeed`]s - e.g. assembled one per
+    /// item in an incoming request batch - instead of a single
+    /// [`BatchCacheFeed`] spanning one key list.
+    ///
+    /// Gathers every feeder's `entity_id()`, resolves them all through one
+    /// `CacheBackend::mget`, falls the misses through a single
+    /// `DataRepository::fetch_by_ids`, and writes newly-fetched cacheable
+    /// entities back with one `CacheBackend::mset` - exactly the round-trip
+    /// shape `with_batch_config` uses, just addressed by feeder index instead
+    /// of a `BatchCacheFeed`'s own id list. Each feeder's `validate`/`on_hit`/
+    /// `on_miss`/`on_loaded`/`feed` still fires individually, in the same
+    /// order as `feeders`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` in the same cases as [`CacheExpander::with_batch`].
+    pub async fn batch_refresh<T, F, R>(
+        &self,
+        feeders: &mut [F],
+        repository: &R,
+        config: OperationConfig,
+    ) -> Result<()>
+    where
+        T: CacheEntity,
+        F: CacheFeed<T>,
+        R: DataRepository<T>,
+    {
+        let timer = Instant::now();
+        if feeders.is_empty() {
+            return Ok(());
+        }
+
+        for feeder in feeders.iter() {
+            feeder.validate()?;
+        }
+
+        let ids: Vec<T::Key> = feeders.iter_mut().map(|f| f.entity_id()).collect();
+        let cache_keys: Vec<String> = ids.iter().map(|id| self.cache_key_for::<T>(id)).collect();
+        let key_refs: Vec<&str> = cache_keys.iter().map(String::as_str).collect();
+
+        debug!("» Batch refresh for {} feeders", feeders.len());
+
+        let cached = self.backend.mget(&key_refs).await?;
+        let mut entities: Vec<Option<T>> = Vec::with_capacity(ids.len());
+        let mut miss_indices = Vec::new();
+
+        for (i, bytes) in cached.into_iter().enumerate() {
+            match bytes {
+                Some(bytes) => {
+                    entities.push(Some(T::deserialize_from_cache(&bytes)?));
+                    feeders[i].on_hit(&cache_keys[i])?;
+                    self.metrics.record_hit(&cache_keys[i], timer.elapsed());
+                }
+                None => {
+                    entities.push(None);
+                    miss_indices.push(i);
+                }
+            }
+        }
+
+        if !miss_indices.is_empty() {
+            let miss_ids: Vec<T::Key> = miss_indices.iter().map(|&i| ids[i].clone()).collect();
+            let fetched = repository.fetch_by_ids(&miss_ids).await?;
+
+            // Collect cacheable misses and write them back in one pipelined
+            // `mset` instead of one `set` round trip per entity.
+            let mut to_cache = Vec::with_capacity(miss_indices.len());
+
+            for (&i, entity) in miss_indices.iter().zip(fetched.into_iter()) {
+                match entity {
+                    Some(entity) => {
+                        if repository.is_cacheable(&entity) {
+                            let ttl = config
+                                .ttl_override
+                                .or_else(|| entity.cache_ttl())
+                                .or_else(|| self.ttl_policy.get_ttl(T::cache_prefix()));
+                            let bytes = entity.serialize_for_cache()?;
+                            to_cache.push((cache_keys[i].as_str(), bytes, ttl));
+                        }
+                        feeders[i].on_hit(&cache_keys[i])?;
+                        self.metrics.record_hit(&cache_keys[i], timer.elapsed());
+                        entities[i] = Some(entity);
+                    }
+                    None => {
+                        feeders[i].on_miss(&cache_keys[i])?;
+                        self.metrics.record_miss(&cache_keys[i], timer.elapsed());
+                    }
+                }
+            }
+
+            if !to_cache.is_empty() {
+                let _ = self.backend.mset(&to_cache).await;
+            }
+        }
+
+        for (feeder, entity) in feeders.iter_mut().zip(entities.into_iter()) {
+            if let Some(entity) = &entity {
+                entity.validate()?;
+                feeder.on_loaded(entity)?;
+            }
+            feeder.feed(entity);
+        }
+
+        info!(
+            "✓ Batch refresh resolved {} feeders in {:?}",
+            cache_keys.len(),
+            timer.elapsed()
+        );
+
+        Ok(())
+    }
+
+    /// Invalidate every cache entry whose composite key starts with
+    /// `T::cache_prefix()` followed by `sub_prefix` - e.g.
+    /// `invalidate_prefix::<UserProfile>("123")` drops every
+    /// `user:123:*` sub-entry built via [`CacheKeyBuilder::build_composite`],
+    /// not just the single `user:123` key [`CacheExpander::with`] addresses.
+    ///
+    /// Delegates to [`CacheBackend::invalidate_prefix`], so it inherits that
+    /// method's cost and support: `InMemoryBackend` filters its map directly,
+    /// while a backend without a native prefix scan (the default
+    /// implementation) returns `Error::NotImplemented`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the backend doesn't support `invalidate_prefix`, or
+    /// the invalidation itself fails.
+    pub async fn invalidate_prefix<T: CacheEntity>(&self, sub_prefix: &str) -> Result<()> {
+        let prefix = CacheKeyBuilder::build_composite(&[T::cache_prefix(), sub_prefix]);
+        self.backend.invalidate_prefix(&prefix).await
+    }
+
+    /// Evict every cache entry previously written under `tag` via
+    /// [`CacheEntity::cache_tags`] - e.g. `invalidate_tag("customer:42")`
+    /// drops every invoice tagged with that customer, not just whichever
+    /// single `invoice:{id}` key a caller happens to know about.
+    ///
+    /// Unlike [`CacheExpander::invalidate_prefix`], `tag` is taken verbatim:
+    /// tags aren't namespaced by entity prefix, since the same tag can cover
+    /// entries of different prefixes (e.g. both cached invoices and cached
+    /// list pages for one customer). Delegates to
+    /// [`CacheBackend::invalidate_tag`], so it inherits that method's
+    /// support: `InMemoryBackend` and `RedisBackend` both implement it, while
+    /// a backend without tagging (the default implementation) returns
+    /// `Error::NotImplemented`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the backend doesn't support `invalidate_tag`, or the
+    /// invalidation itself fails.
+    pub async fn invalidate_tag(&self, tag: &str) -> Result<()> {
+        self.backend.invalidate_tag(tag).await
+    }
+
+    /// Read-modify-write `cache_key` through the backend's CAS primitive
+    /// ([`CacheBackend::gets`]/[`CacheBackend::cas`]) so two callers racing
+    /// through the same expander can't silently clobber one another: `modify`
+    /// receives the current value (`None` on a miss) and returns the value to
+    /// store, and the write only lands if nothing else wrote to `cache_key`
+    /// between the read and the write. On a lost race the whole read-modify
+    /// cycle retries, up to `max_attempts` times.
+    ///
+    /// A miss (`modify` is called with `None`) is written back with a plain
+    /// [`CacheBackend::set`] rather than a CAS, since a backend's `cas` has
+    /// nothing to compare against when the key doesn't exist yet - so this
+    /// doesn't protect the very first write into an empty key against a
+    /// concurrent first write (use a backend-specific `add`, e.g.
+    /// [`crate::backend::memcached::MemcachedBackend::add`], for that
+    /// narrower guarantee). Every subsequent update is fully protected.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(Error::NotImplemented)` if the backend has no native CAS
+    /// (the [`CacheBackend::gets`]/[`CacheBackend::cas`] default), propagates
+    /// any other backend error, and returns the last CAS mismatch as
+    /// `Err(Error::BackendError)` once `max_attempts` is exhausted.
+    pub async fn cas_update<F>(
+        &self,
+        cache_key: &str,
+        ttl: Option<Duration>,
+        max_attempts: u32,
+        mut modify: F,
+    ) -> Result<()>
+    where
+        F: FnMut(Option<Vec<u8>>) -> Vec<u8>,
+    {
+        let max_attempts = max_attempts.max(1);
+
+        for attempt in 1..=max_attempts {
+            match self.backend.gets(cache_key).await? {
+                Some((current, cas_token)) => {
+                    let next = modify(Some(current));
+                    if self.backend.cas(cache_key, next, ttl, cas_token).await? {
+                        return Ok(());
+                    }
+                    debug!(
+                        "CAS update for {} lost the race (attempt {}/{}), retrying...",
+                        cache_key, attempt, max_attempts
+                    );
+                }
+                None => {
+                    let next = modify(None);
+                    self.backend.set(cache_key, next, ttl).await?;
+                    return Ok(());
+                }
+            }
+        }
+
+        Err(Error::BackendError(format!(
+            "CAS update for {} did not converge after {} attempt(s)",
+            cache_key, max_attempts
+        )))
+    }
+
+    /// Refresh-strategy cache operation over a raw [`crate::streaming::CacheData`]
+    /// payload instead of a typed [`CacheEntity`], for large opaque blobs
+    /// (file bodies, rendered documents) that don't need `CacheEntity`'s
+    /// serialize/deserialize round trip and shouldn't be forced fully into
+    /// memory on backends that chunk natively (see
+    /// [`CacheBackend::set_stream`]/[`CacheBackend::get_stream`]).
+    ///
+    /// On a cache hit, the payload is fed straight from `backend.get_stream`,
+    /// so a backend with native chunked reads (see `RedisBackend`) never
+    /// materializes the whole value. On a miss, `source`'s payload is
+    /// buffered in memory once so it can be both written back and fed to the
+    /// caller; tee-ing a single stream into "cache it" and "return it"
+    /// without buffering is a natural follow-up, not implemented here.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the backend read/write fails or `source.fetch` fails.
+    pub async fn with_stream<F, S>(&self, feeder: &mut F, source: &S, ttl: Option<Duration>) -> Result<()>
+    where
+        F: crate::streaming::StreamingCacheFeed,
+        S: crate::streaming::StreamingDataSource,
+    {
+        use crate::streaming::DEFAULT_CHUNK_SIZE;
+
+        let timer = Instant::now();
+        let cache_key = feeder.cache_key();
+
+        if let Some(data) = self.backend.get_stream(&cache_key, DEFAULT_CHUNK_SIZE).await? {
+            debug!("✓ Cache hit (stream) for {}", cache_key);
+            self.metrics.record_hit(&cache_key, timer.elapsed());
+            feeder.feed(Some(data));
+            return Ok(());
+        }
+
+        debug!("✗ Cache miss (stream) for {}, fetching from source", cache_key);
+        match source.fetch(&cache_key).await {
+            Ok(Some(data)) => {
+                let bytes = bytes::Bytes::from(data.into_bytes().await?);
+                self.backend
+                    .set_stream(
+                        &cache_key,
+                        crate::streaming::CacheData::from_shared(bytes.clone()),
+                        ttl,
+                    )
+                    .await?;
+                self.metrics.record_hit(&cache_key, timer.elapsed());
+                feeder.feed(Some(crate::streaming::CacheData::from_shared(bytes)));
+                Ok(())
+            }
+            Ok(None) => {
+                self.metrics.record_miss(&cache_key, timer.elapsed());
+                feeder.feed(None);
+                Ok(())
+            }
+            Err(e) => {
+                self.metrics.record_error(&cache_key, &e.to_string());
+                Err(e)
+            }
+        }
+    }
+
+    /// Internal method to execute a single cache operation (without retry).
+    async fn execute_operation<T, F, R>(
+        &self,
+        feeder: &mut F,
+        repository: &R,
+        strategy: CacheStrategy,
+        config: &OperationConfig,
+    ) -> Result<()>
+    where
+        T: CacheEntity,
+        F: CacheFeed<T>,
+        R: DataRepository<T>,
+        T::Key: FromStr,
+    {
+        let timer = Instant::now();
+
+        // Step 1: Validate feeder
+        feeder.validate()?;
+
+        // Step 2: Get entity ID and build cache key
+        let entity_id = feeder.entity_id();
+        let cache_key = self.cache_key_for::<T>(&entity_id);
+
+        debug!(
+            "» Cache operation for key: {} (strategy: {})",
+            cache_key, strategy
+        );
+
+        // Step 3: Execute strategy
+        let result = match strategy {
+            CacheStrategy::Fresh => {
+                self.strategy_fresh::<T, R>(&cache_key, repository, config)
+                    .await
+            }
+            CacheStrategy::Refresh => {
+                self.strategy_refresh::<T, R>(&cache_key, repository, config)
+                    .await
+            }
+            CacheStrategy::Invalidate => {
+                self.strategy_invalidate::<T, R>(&cache_key, repository, config)
+                    .await
+            }
+            CacheStrategy::Bypass => {
+                self.strategy_bypass::<T, R>(&cache_key, repository, config)
+                    .await
+            }
+            CacheStrategy::StaleWhileRevalidate => Err(Error::NotImplemented(
+                "CacheStrategy::StaleWhileRevalidate needs an owned repository handle to \
+                 refresh in the background; use CacheExpander::with_stale_while_revalidate \
+                 instead of with()/with_config()"
+                    .to_string(),
+            )),
+        };
+
+        // Step 4: Handle result
+        match result {
+            Ok(Some((entity, is_cached))) => {
+                entity.validate()?;
+                feeder.on_hit(&cache_key)?;
+                feeder.on_loaded(&entity)?;
+                feeder.feed(Some(entity));
+                self.metrics.record_hit(&cache_key, timer.elapsed());
+                if !is_cached {
+                    self.metrics.record_repository_populate(&cache_key, timer.elapsed());
+                }
+                info!("✓ Cache operation succeeded in {:?}", timer.elapsed());
+            }
+            Ok(None) => {
+                feeder.on_miss(&cache_key)?;
+                feeder.feed(None);
+                self.metrics.record_miss(&cache_key, timer.elapsed());
+                debug!("Entity not found after cache operation for {}", cache_key);
+            }
+            Err(e) => {
+                self.metrics.record_error(&cache_key, &e.to_string());
+                return Err(e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Identical to [`Self::execute_operation`], except it returns the
+    /// served value wrapped in a [`CacheOutcome`] rather than feeding it to
+    /// `feeder` and discarding whether it was cached or fetched - see
+    /// [`CacheExpander::with_outcome`].
+    async fn execute_operation_outcome<T, F, R>(
+        &self,
+        feeder: &mut F,
+        repository: &R,
+        strategy: CacheStrategy,
+        config: &OperationConfig,
+    ) -> Result<Option<CacheOutcome<T>>>
+    where
+        T: CacheEntity,
+        F: CacheFeed<T>,
+        R: DataRepository<T>,
+        T::Key: FromStr,
+    {
+        let timer = Instant::now();
+
+        feeder.validate()?;
+
+        let entity_id = feeder.entity_id();
+        let cache_key = self.cache_key_for::<T>(&entity_id);
+
+        debug!(
+            "» Cache operation for key: {} (strategy: {})",
+            cache_key, strategy
+        );
+
+        let result = match strategy {
+            CacheStrategy::Fresh => {
+                self.strategy_fresh::<T, R>(&cache_key, repository, config)
+                    .await
+            }
+            CacheStrategy::Refresh => {
+                self.strategy_refresh::<T, R>(&cache_key, repository, config)
+                    .await
+            }
+            CacheStrategy::Invalidate => {
+                self.strategy_invalidate::<T, R>(&cache_key, repository, config)
+                    .await
+            }
+            CacheStrategy::Bypass => {
+                self.strategy_bypass::<T, R>(&cache_key, repository, config)
+                    .await
+            }
+            CacheStrategy::StaleWhileRevalidate => Err(Error::NotImplemented(
+                "CacheStrategy::StaleWhileRevalidate needs an owned repository handle to \
+                 refresh in the background; use CacheExpander::with_stale_while_revalidate \
+                 instead of with_outcome()/with_config_outcome()"
+                    .to_string(),
+            )),
+        };
+
+        match result {
+            Ok(Some((entity, is_cached))) => {
+                entity.validate()?;
+                feeder.on_hit(&cache_key)?;
+                feeder.on_loaded(&entity)?;
+                feeder.feed(Some(entity.clone()));
+                self.metrics.record_hit(&cache_key, timer.elapsed());
+                if !is_cached {
+                    self.metrics.record_repository_populate(&cache_key, timer.elapsed());
+                }
+                info!("✓ Cache operation succeeded in {:?}", timer.elapsed());
+                Ok(Some(if is_cached {
+                    CacheOutcome::Cached(entity)
+                } else {
+                    CacheOutcome::Fetched(entity)
+                }))
+            }
+            Ok(None) => {
+                feeder.on_miss(&cache_key)?;
+                feeder.feed(None);
+                self.metrics.record_miss(&cache_key, timer.elapsed());
+                debug!("Entity not found after cache operation for {}", cache_key);
+                Ok(None)
+            }
+            Err(e) => {
+                self.metrics.record_error(&cache_key, &e.to_string());
+                Err(e)
+            }
+        }
+    }
+
+    /// Resolve the [`CacheRecoveryPolicy`] in effect for `config`: its own
+    /// override if set, otherwise this expander's default.
+    fn recovery_policy_for(&self, config: &OperationConfig) -> CacheRecoveryPolicy {
+        config.recovery_policy.unwrap_or(self.recovery_policy)
+    }
+
+    /// Record a `CacheRecoveryPolicy`-driven fallback as a distinctly-tagged
+    /// error, so a `FallThrough`/`BlackHole` degradation shows up in metrics
+    /// instead of vanishing the way a bare `let _ = ...` swallow would.
+    fn record_degraded(&self, cache_key: &str, mode: &str, msg: &str) {
+        self.metrics
+            .record_error(cache_key, &format!("[degraded:{}] {}", mode, msg));
+    }
+
+    /// Trip `backend_blackholed` (if not already) and record it, so the rest
+    /// of this process stops calling a backend under `CacheRecoveryPolicy::BlackHole`.
+    fn trip_blackhole(&self, cache_key: &str, msg: &str) {
+        if !self.backend_blackholed.swap(true, std::sync::atomic::Ordering::Relaxed) {
+            self.record_degraded(cache_key, "blackhole", msg);
+        }
+    }
+
+    /// Recovery-policy-aware wrapper around `self.backend.get`, used by every
+    /// `strategy_*` read in place of a bare `self.backend.get(...).await?`.
+    ///
+    /// A `CacheRecoveryPolicy::BlackHole` trip (this call's or an earlier
+    /// one's) short-circuits straight to a miss without calling the backend.
+    /// Otherwise a backend error ([`Error::is_backend_error`] - this covers
+    /// both `Error::BackendError` and the source-preserving `Error::Backend`
+    /// produced by the `redis`/`sqlx`/io `From` conversions) is handled per
+    /// the resolved policy; any other error variant (e.g. a deserialization
+    /// bug surfaced through the backend) still always propagates.
+    async fn backend_get_recovering(
+        &self,
+        cache_key: &str,
+        config: &OperationConfig,
+    ) -> Result<Option<Vec<u8>>> {
+        if self.backend_blackholed.load(std::sync::atomic::Ordering::Relaxed) {
+            return Ok(None);
+        }
+
+        match self.backend.get(cache_key).await {
+            Ok(value) => Ok(value),
+            Err(e) if e.is_backend_error() => {
+                let msg = e.to_string();
+                match self.recovery_policy_for(config) {
+                    CacheRecoveryPolicy::Fail => Err(e),
+                    CacheRecoveryPolicy::FallThrough => {
+                        self.record_degraded(cache_key, "fallthrough", &msg);
+                        Ok(None)
+                    }
+                    CacheRecoveryPolicy::BlackHole => {
+                        self.trip_blackhole(cache_key, &msg);
+                        Ok(None)
+                    }
+                }
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Recovery-policy-aware wrapper around `self.backend.set_with_tags`,
+    /// used by every `strategy_*` write in place of the historical
+    /// `let _ = self.backend.set(...).await;`. `tags` is usually empty (in
+    /// which case this behaves exactly like a plain `set`) - see
+    /// [`CacheEntity::cache_tags`].
+    ///
+    /// `Fail` preserves that historical fail-open-on-write behavior exactly
+    /// (a write failure never aborted the read that triggered it); the only
+    /// change under `Fail` is that nothing is recorded, same as before.
+    /// `FallThrough` and `BlackHole` additionally surface the failure via
+    /// [`Self::record_degraded`] so it's observable instead of silent.
+    async fn backend_set_recovering(
+        &self,
+        cache_key: &str,
+        bytes: Vec<u8>,
+        ttl: Option<Duration>,
+        tags: &[&str],
+        config: &OperationConfig,
+    ) {
+        if self.backend_blackholed.load(std::sync::atomic::Ordering::Relaxed) {
+            return;
+        }
+
+        if let Err(e) = self.backend.set_with_tags(cache_key, bytes, ttl, tags).await {
+            if e.is_backend_error() {
+                let msg = e.to_string();
+                match self.recovery_policy_for(config) {
+                    CacheRecoveryPolicy::Fail => {}
+                    CacheRecoveryPolicy::FallThrough => {
+                        self.record_degraded(cache_key, "fallthrough", &msg)
+                    }
+                    CacheRecoveryPolicy::BlackHole => self.trip_blackhole(cache_key, &msg),
+                }
+            } else {
+                warn!("⚠ Unexpected cache write error for {}: {}", cache_key, e);
+            }
+        }
+    }
+
+    /// Recovery-policy-aware wrapper around `self.backend.delete`, used by
+    /// `strategy_invalidate` in place of a bare `self.backend.delete(...).await?`.
+    async fn backend_delete_recovering(&self, cache_key: &str, config: &OperationConfig) -> Result<()> {
+        if self.backend_blackholed.load(std::sync::atomic::Ordering::Relaxed) {
+            return Ok(());
+        }
+
+        match self.backend.delete(cache_key).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.is_backend_error() => {
+                let msg = e.to_string();
+                match self.recovery_policy_for(config) {
+                    CacheRecoveryPolicy::Fail => Err(e),
+                    CacheRecoveryPolicy::FallThrough => {
+                        self.record_degraded(cache_key, "fallthrough", &msg);
+                        Ok(())
+                    }
+                    CacheRecoveryPolicy::BlackHole => {
+                        self.trip_blackhole(cache_key, &msg);
+                        Ok(())
+                    }
+                }
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Best-effort notify on `self.eviction_tx`, if one is configured.
+    ///
+    /// Uses `try_send` rather than `send().await` so a slow or full consumer
+    /// never blocks the cache operation that triggered this - the same
+    /// trade-off `maybe_slide_ttl` makes for a failed TTL re-arm.
+    fn notify_write_back(&self, cache_key: &str, value: Vec<u8>, cause: WriteBackCause) {
+        if let Some(tx) = &self.eviction_tx {
+            if let Err(e) = tx.try_send((cache_key.to_string(), value, cause)) {
+                warn!("⚠ Write-back notify dropped for {}: {}", cache_key, e);
+            }
+        }
+    }
+
+    /// Re-arm `cache_key`'s TTL on a hit under [`TtlPolicy::Sliding`].
+    ///
+    /// Best-effort: a failed re-arm shouldn't fail the read that triggered
+    /// it, so errors are logged and swallowed, matching the promotion
+    /// best-effort pattern in `HotColdBackend::get`.
+    async fn maybe_slide_ttl(&self, cache_key: &str, entity_type: &str) {
+        if !self.ttl_policy.is_sliding() {
+            return;
+        }
+        if let Some(ttl) = self.ttl_policy.get_ttl(entity_type) {
+            if let Err(e) = self.backend.expire(cache_key, ttl).await {
+                warn!("⚠ Sliding TTL re-arm failed for {}: {}", cache_key, e);
+            }
+        }
+    }
+
+    /// Check `cache_key`'s remaining backend TTL against
+    /// [`OperationConfig::min_remaining_ttl`], treating a hit about to expire
+    /// as a miss. Returns `false` (never treat as a miss) when
+    /// `min_remaining_ttl` is unset, or when the backend doesn't support
+    /// [`crate::backend::CacheBackend::ttl`] - this is a best-effort guard,
+    /// not a correctness requirement.
+    async fn is_below_min_remaining_ttl(&self, cache_key: &str, config: &OperationConfig) -> bool {
+        let Some(min) = config.min_remaining_ttl else {
+            return false;
+        };
+        match self.backend.ttl(cache_key).await {
+            Ok(Some(remaining)) => remaining < min,
+            Ok(None) => false,
+            Err(e) => {
+                debug!("TTL read for {} unsupported or failed, skipping min-remaining-ttl guard: {}", cache_key, e);
+                false
+            }
+        }
+    }
+
+    /// Check a cache hit's [`CacheEntity::cache_expires_at`] against the
+    /// current time, treating it as logically expired once that instant has
+    /// passed - independent of however long the entry still has left on its
+    /// TTL. Returns `true` when the hit should be treated as a miss.
+    ///
+    /// When `config.evict_on_logical_expiry` is set, the entry is also
+    /// deleted outright rather than left for the caller's eventual
+    /// write-back to overwrite - see that field's docs for the trade-off.
+    async fn evict_if_logically_expired<T: CacheEntity>(
+        &self,
+        cache_key: &str,
+        entity: &T,
+        config: &OperationConfig,
+    ) -> bool {
+        match entity.cache_expires_at() {
+            Some(expires_at) if now_unix() >= expires_at => {
+                debug!(
+                    "Cache hit for {} is logically expired (expired at {})",
+                    cache_key, expires_at
+                );
+                if config.evict_on_logical_expiry {
+                    if let Err(e) = self.backend_delete_recovering(cache_key, config).await {
+                        warn!("⚠ Failed to evict logically expired entry {}: {}", cache_key, e);
+                    }
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Fresh strategy: Cache only, no database fallback.
+    ///
+    /// The returned `bool` is always `true` on a hit - Fresh never falls
+    /// through to the repository, so there's nothing to distinguish a
+    /// cache hit from - see [`Self::strategy_refresh`] for a strategy where
+    /// it matters.
+    async fn strategy_fresh<T: CacheEntity, R: DataRepository<T>>(
+        &self,
+        cache_key: &str,
+        _repository: &R,
+        config: &OperationConfig,
+    ) -> Result<Option<(T, bool)>> {
+        debug!("Executing Fresh strategy for {}", cache_key);
+
+        match self.backend_get_recovering(cache_key, config).await? {
+            Some(bytes) if is_tombstone(&bytes) => {
+                debug!("✓ Cache hit (Fresh strategy) - negative-cached miss");
+                Ok(None)
+            }
+            Some(bytes) => {
+                debug!("✓ Cache hit (Fresh strategy)");
+                if self.is_below_min_remaining_ttl(cache_key, config).await {
+                    debug!("✗ Cache hit (Fresh strategy) below min_remaining_ttl, treating as miss");
+                    return Ok(None);
+                }
+                self.maybe_slide_ttl(cache_key, T::cache_prefix()).await;
+                let entity = T::deserialize_from_cache(&bytes)?;
+                if self.evict_if_logically_expired(cache_key, &entity, config).await {
+                    // Fresh never falls back to the repository by contract,
+                    // so a logically expired hit is just a miss.
+                    return Ok(None);
+                }
+                Ok(Some((entity, true)))
+            }
+            None => {
+                debug!("✗ Cache miss (Fresh strategy) - no fallback");
+                Ok(None)
+            }
+        }
+    }
+
+    /// Refresh strategy: Try cache, fallback to database on miss.
+    ///
+    /// Concurrent misses for the same `cache_key` are coalesced into a single
+    /// repository fetch via `singleflight_fetch` to avoid a stampede on the
+    /// database when many callers race on a cold key at once.
+    ///
+    /// The returned `bool` is `true` when the value was already sitting in
+    /// the cache, `false` when it had to be fetched (directly or via
+    /// `singleflight_fetch`) - see [`CacheOutcome`].
+    async fn strategy_refresh<T: CacheEntity, R: DataRepository<T>>(
+        &self,
+        cache_key: &str,
+        repository: &R,
+        config: &OperationConfig,
+    ) -> Result<Option<(T, bool)>>
+    where
+        T::Key: FromStr,
+    {
+        debug!("Executing Refresh strategy for {}", cache_key);
+
+        // Try cache first
+        if let Some(bytes) = self.backend_get_recovering(cache_key, config).await? {
+            if is_tombstone(&bytes) {
+                debug!("✓ Cache hit (Refresh strategy) - negative-cached miss");
+                return Ok(None);
+            }
+            if self.is_below_min_remaining_ttl(cache_key, config).await {
+                debug!("Cache hit (Refresh strategy) below min_remaining_ttl, falling back to database");
+                return self
+                    .singleflight_fetch::<T, R>(cache_key, repository, config)
+                    .await;
+            }
+            debug!("✓ Cache hit (Refresh strategy)");
+            self.maybe_slide_ttl(cache_key, T::cache_prefix()).await;
+            let entity = T::deserialize_from_cache(&bytes)?;
+            if !self.evict_if_logically_expired(cache_key, &entity, config).await {
+                return Ok(Some((entity, true)));
+            }
+            debug!("Cache hit logically expired, falling back to database");
+            return self
+                .singleflight_fetch::<T, R>(cache_key, repository, config)
+                .await;
+        }
+
+        debug!("Cache miss, falling back to database");
+        self.singleflight_fetch::<T, R>(cache_key, repository, config)
+            .await
+    }
+
+    /// Fetch `cache_key` from `repository` and populate the cache - no
+    /// coalescing, no locking, just the fetch-and-write-back steps shared by
+    /// every strategy that falls through to the repository. Always reports
+    /// its `bool` as `false` (fetched, not cached) - see [`CacheOutcome`].
+    async fn fetch_and_populate<T: CacheEntity, R: DataRepository<T>>(
+        &self,
+        cache_key: &str,
+        repository: &R,
+        config: &OperationConfig,
+    ) -> Result<Option<(T, bool)>>
+    where
+        T::Key: FromStr,
+    {
+        let id = self.extract_id_from_key::<T>(cache_key)?;
+        match repository.fetch_by_id(&id).await? {
+            Some(entity) => {
+                if repository.is_cacheable(&entity) {
+                    let ttl = config
+                        .ttl_override
+                        .or_else(|| entity.cache_ttl())
+                        .or_else(|| self.ttl_policy.get_ttl(T::cache_prefix()));
+                    let bytes = entity.serialize_for_cache()?;
+                    let tags = entity.cache_tags();
+                    let tag_refs: Vec<&str> = tags.iter().map(String::as_str).collect();
+                    self.backend_set_recovering(cache_key, bytes, ttl, &tag_refs, config).await;
+                }
+                Ok(Some((entity, false)))
+            }
+            None => {
+                if let Some(negative_ttl) = config.negative_ttl {
+                    debug!("Negative-caching miss for {} ({:?})", cache_key, negative_ttl);
+                    self.backend_set_recovering(
+                        cache_key,
+                        TOMBSTONE_MARKER.to_vec(),
+                        Some(negative_ttl),
+                        &[],
+                        config,
+                    )
+                    .await;
+                }
+                Ok(None)
+            }
+        }
+    }
+
+    /// Fetch `cache_key` from `repository` and populate the cache, ensuring
+    /// only one concurrent caller per key actually reaches the repository
+    /// when `config.coalesce` is `true` (the default).
+    ///
+    /// Other callers for the same key block on the per-key lock, then
+    /// re-check the cache (which the winner just populated) before falling
+    /// through to their own fetch - so a thundering herd on a cold key turns
+    /// into a single database round-trip. With `config.coalesce` disabled,
+    /// every caller fetches independently via [`Self::fetch_and_populate`],
+    /// same as before coalescing existed.
+    ///
+    /// The returned `bool` is `true` for a caller that joined the cache entry
+    /// the lock-holder just wrote (a coalesced wait, not a fetch of its own),
+    /// `false` otherwise - see [`CacheOutcome`].
+    ///
+    /// Coalescing is built on a per-key `tokio::sync::Mutex` in `inflight`
+    /// rather than a `watch` channel each waiter subscribes to: waiters here
+    /// block on the lock itself, then re-read the cache the leader just
+    /// populated, instead of receiving the fetched value broadcast directly.
+    /// This bounds a cache-miss stampede to one repository fetch on the
+    /// happy path the same way a `watch`-based design would; it differs on
+    /// the leader's fetch failing, since nothing was written to the cache
+    /// for a waiter to find - the next waiter to acquire the lock becomes
+    /// the new leader and retries the fetch itself, rather than every
+    /// waiter observing the same error a `watch` channel would broadcast.
+    async fn singleflight_fetch<T: CacheEntity, R: DataRepository<T>>(
+        &self,
+        cache_key: &str,
+        repository: &R,
+        config: &OperationConfig,
+    ) -> Result<Option<(T, bool)>>
+    where
+        T::Key: FromStr,
+    {
+        if !config.coalesce {
+            return self.fetch_and_populate::<T, R>(cache_key, repository, config).await;
+        }
+
+        let lock = self
+            .inflight
+            .entry(cache_key.to_string())
+            .or_insert_with(|| Arc::new(AsyncMutex::new(())))
+            .clone();
+        let _guard = lock.lock().await;
+
+        // Another caller may have already populated the cache while we waited.
+        if let Some(bytes) = self.backend_get_recovering(cache_key, config).await? {
+            debug!("✓ Cache hit after single-flight wait for {}", cache_key);
+            self.metrics.record_coalesced_wait(cache_key);
+            self.inflight.remove_if(cache_key, |_, l| Arc::strong_count(l) == 1);
+            if is_tombstone(&bytes) {
+                return Ok(None);
+            }
+            self.maybe_slide_ttl(cache_key, T::cache_prefix()).await;
+            let entity = T::deserialize_from_cache(&bytes)?;
+            if !self.evict_if_logically_expired(cache_key, &entity, config).await {
+                return Ok(Some((entity, true)));
+            }
+            debug!("Cache hit after single-flight wait logically expired for {}", cache_key);
+        }
+
+        #[cfg(feature = "redis")]
+        let _distributed_guard = self.acquire_locked_refresh(cache_key).await;
+
+        #[cfg(feature = "redis")]
+        if let Some(retry_after) = self.check_rate_limit(cache_key, config).await? {
+            self.inflight.remove_if(cache_key, |_, l| Arc::strong_count(l) == 1);
+            return Err(Error::RateLimited(retry_after));
+        }
+
+        let result = self.fetch_and_populate::<T, R>(cache_key, repository, config).await;
+
+        self.inflight.remove_if(cache_key, |_, l| Arc::strong_count(l) == 1);
+        result
+    }
+
+    /// [`Self::fetch_and_populate`], coalesced for `CacheStrategy::Bypass`.
+    ///
+    /// Bypass's contract is "always hit the database", so unlike
+    /// [`Self::singleflight_fetch`] this never serves a caller from
+    /// whatever's already sitting in cache - only the caller that actually
+    /// starts a fetch (the "leader", i.e. whoever's `entry()` call creates
+    /// the in-flight slot) does so. A caller that instead joins an
+    /// already-in-flight fetch (the "follower") waits for it to finish, then
+    /// reads the leader's just-written cache entry - that's the leader's own
+    /// fresh database answer, not a stale pre-existing value, so Bypass's
+    /// guarantee holds. If the leader's entity wasn't cacheable (or its
+    /// write failed), there's nothing for a follower to read, so it falls
+    /// back to fetching on its own.
+    ///
+    /// The returned `bool` is `true` for a follower that read the leader's
+    /// just-written entry rather than fetching itself - see [`CacheOutcome`].
+    /// Note that this is still "a fresh database answer" per the doc above,
+    /// just one this caller didn't fetch directly.
+    async fn coalesced_bypass_fetch<T: CacheEntity, R: DataRepository<T>>(
+        &self,
+        cache_key: &str,
+        repository: &R,
+        config: &OperationConfig,
+    ) -> Result<Option<(T, bool)>>
+    where
+        T::Key: FromStr,
+    {
+        if !config.coalesce {
+            return self.fetch_and_populate::<T, R>(cache_key, repository, config).await;
+        }
+
+        let (lock, is_leader) = match self.inflight.entry(cache_key.to_string()) {
+            dashmap::mapref::entry::Entry::Occupied(entry) => (entry.get().clone(), false),
+            dashmap::mapref::entry::Entry::Vacant(entry) => {
+                let lock = Arc::new(AsyncMutex::new(()));
+                entry.insert(lock.clone());
+                (lock, true)
+            }
+        };
+        let _guard = lock.lock().await;
+
+        let result = if is_leader {
+            self.fetch_and_populate::<T, R>(cache_key, repository, config).await
+        } else {
+            self.metrics.record_coalesced_wait(cache_key);
+            match self.backend_get_recovering(cache_key, config).await? {
+                Some(bytes) if is_tombstone(&bytes) => Ok(None),
+                Some(bytes) => {
+                    let entity = T::deserialize_from_cache(&bytes)?;
+                    if self.evict_if_logically_expired(cache_key, &entity, config).await {
+                        self.fetch_and_populate::<T, R>(cache_key, repository, config).await
+                    } else {
+                        Ok(Some((entity, true)))
+                    }
+                }
+                None => self.fetch_and_populate::<T, R>(cache_key, repository, config).await,
+            }
+        };
+
+        self.inflight.remove_if(cache_key, |_, l| Arc::strong_count(l) == 1);
+        result
+    }
+
+    /// Try to acquire the configured `DistributedLock` (if any) around the
+    /// repository fetch below. See `with_locked_refresh` for the fail-open
+    /// rationale: any outcome other than "acquired" just proceeds without it.
+    #[cfg(feature = "redis")]
+    async fn acquire_locked_refresh(
+        &self,
+        cache_key: &str,
+    ) -> Option<crate::backend::redis::LockGuard> {
+        let lock = self.locked_refresh.as_ref()?;
+        match lock.acquire(cache_key, Duration::from_secs(10)).await {
+            Ok(guard) => guard,
+            Err(e) => {
+                warn!("Distributed lock acquisition failed for {}: {}", cache_key, e);
+                None
+            }
+        }
+    }
+
+    /// Check the configured `RateLimiter` (if any) against `config.rate_limit`
+    /// (if any) before the repository fetch below.
+    ///
+    /// Returns `Ok(Some(retry_after))` if the call should be denied,
+    /// `Ok(None)` if it's allowed or no limiter/limit is configured. Unlike
+    /// `acquire_locked_refresh`, a backend error here is propagated rather
+    /// than failed open: a rate limiter that silently stops limiting on
+    /// Redis hiccups defeats its own purpose.
+    #[cfg(feature = "redis")]
+    async fn check_rate_limit(
+        &self,
+        cache_key: &str,
+        config: &OperationConfig,
+    ) -> Result<Option<Duration>> {
+        let Some(limiter) = self.rate_limiter.as_ref() else {
+            return Ok(None);
+        };
+        let Some((limit, period)) = config.rate_limit else {
+            return Ok(None);
+        };
+        let decision = limiter.check(cache_key, limit, period).await?;
+        if decision.allowed {
+            Ok(None)
+        } else {
+            Ok(Some(decision.retry_after.unwrap_or(period)))
+        }
+    }
+
+    /// Invalidate strategy: Clear cache and refresh from database.
+    ///
+    /// The unconditional delete below also clears a previously-written
+    /// tombstone, so a row that was negative-cached as missing is re-checked
+    /// against the database on the next call instead of staying "missing"
+    /// forever.
+    ///
+    /// The refresh fetch is coalesced the same way as `strategy_refresh`
+    /// (see `singleflight_fetch`): a burst of concurrent `Invalidate` calls
+    /// for the same key still produces one delete each, but only one
+    /// repository fetch.
+    async fn strategy_invalidate<T: CacheEntity, R: DataRepository<T>>(
+        &self,
+        cache_key: &str,
+        repository: &R,
+        config: &OperationConfig,
+    ) -> Result<Option<(T, bool)>>
+    where
+        T::Key: FromStr,
+    {
+        debug!("Executing Invalidate strategy for {}", cache_key);
+        self.metrics.record_invalidation(cache_key);
+
+        // Grab the outgoing value before it's gone, but only if someone's
+        // listening - an extra read on every invalidate would be wasted work
+        // otherwise.
+        let outgoing = if self.eviction_tx.is_some() {
+            self.backend_get_recovering(cache_key, config).await?
+        } else {
+            None
+        };
+
+        // Delete from cache
+        self.backend_delete_recovering(cache_key, config).await?;
+        debug!("✓ Cache invalidated for {}", cache_key);
+
+        if let Some(bytes) = outgoing {
+            self.notify_write_back(cache_key, bytes, WriteBackCause::Evicted);
+        }
+
+        if let Some(bus) = &self.invalidation_bus {
+            bus.publish(cache_key);
+        }
+
+        // Fetch fresh from database, coalescing concurrent callers
+        self.singleflight_fetch::<T, R>(cache_key, repository, config)
+            .await
+    }
+
+    /// Bypass strategy: Skip cache, always hit database.
+    ///
+    /// The repository fetch is coalesced across concurrent `Bypass` callers
+    /// for the same key via `coalesced_bypass_fetch`, so a burst of bypass
+    /// reads doesn't multiply into one database round trip per caller - see
+    /// that method for why this doesn't weaken "always hit database".
+    async fn strategy_bypass<T: CacheEntity, R: DataRepository<T>>(
+        &self,
+        cache_key: &str,
+        repository: &R,
+        config: &OperationConfig,
+    ) -> Result<Option<(T, bool)>>
+    where
+        T::Key: FromStr,
+    {
+        debug!("Executing Bypass strategy for {}", cache_key);
+        debug!("Bypassing cache entirely for {}", cache_key);
+        self.metrics.record_bypass(cache_key);
+
+        self.coalesced_bypass_fetch::<T, R>(cache_key, repository, config)
+            .await
+    }
+
+    /// Extract the ID portion from a cache key.
+    /// Format: "prefix:id" → "id"
+    ///
+    /// `pub(crate)` rather than private so `CacheService::spawn_rehydrate`
+    /// can recover a repository id from a raw cache key in its tracked hot
+    /// set without duplicating this parsing.
+    pub(crate) fn extract_id_from_key<T: CacheEntity>(&self, cache_key: &str) -> Result<T::Key>
+    where
+        T::Key: FromStr,
+    {
+        let parts: Vec<&str> = cache_key.split(':').collect();
+        if parts.len() > 1 {
+            let id_str = parts[1..].join(":");
+            id_str.parse().ok().ok_or_else(|| {
+                Error::ValidationError(format!("Failed to parse ID from cache key: {}", cache_key))
+            })
+        } else {
+            Err(Error::ValidationError(format!(
+                "Invalid cache key format: {}",
+                cache_key
+            )))
+        }
+    }
+
+    /// Get backend reference (for advanced use).
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Get mutable backend reference (for advanced use).
+    pub fn backend_mut(&mut self) -> &mut B {
+        &mut self.backend
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::InMemoryBackend;
+    use crate::feed::GenericFeeder;
+    use crate::repository::InMemoryRepository;
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+
+    #[derive(Clone, Serialize, Deserialize)]
+    struct TestEntity {
+        id: String,
+        value: String,
+    }
+
+    impl CacheEntity for TestEntity {
+        type Key = String;
+
+        fn cache_key(&self) -> Self::Key {
+            self.id.clone()
+        }
+
+        fn cache_prefix() -> &'static str {
+            "test"
+        }
+    }
+
+    #[derive(Clone, Serialize, Deserialize)]
+    struct InvoiceEntity {
+        id: String,
+        customer_id: String,
+    }
+
+    impl CacheEntity for InvoiceEntity {
+        type Key = String;
+
+        fn cache_key(&self) -> Self::Key {
+            self.id.clone()
+        }
+
+        fn cache_prefix() -> &'static str {
+            "invoice"
+        }
+
+        fn cache_tags(&self) -> Vec<String> {
+            vec![format!("customer:{}", self.customer_id)]
+        }
+    }
+
+    #[tokio::test]
+    async fn test_key_registry_override_routes_through_with() {
+        let backend = InMemoryBackend::new();
+
+        let mut registry = KeyRegistry::new();
+        registry.register(std::any::type_name::<TestEntity>().to_string(), |id| {
+            format!("tenant42:test:{id}")
+        });
+        let expander = CacheExpander::new_with_registry(backend.clone(), Arc::new(registry));
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "db_data".to_string(),
+            },
+        );
+        let mut feeder = GenericFeeder::new("1".to_string());
+
+        expander
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute");
+
+        assert_eq!(feeder.data.expect("Data not found").value, "db_data");
+        assert_eq!(
+            backend.get("tenant42:test:1").await.expect("Failed to get"),
+            Some(
+                TestEntity {
+                    id: "1".to_string(),
+                    value: "db_data".to_string(),
+                }
+                .serialize_for_cache()
+                .expect("Failed to serialize")
+            ),
+            "the registered generator's key, not the default prefix:id scheme, should hold the cached entry"
+        );
+        assert_eq!(backend.get("test:1").await.expect("Failed to get"), None);
+    }
+
+    #[tokio::test]
+    async fn test_key_registry_falls_back_to_default_scheme_for_unregistered_types() {
+        let backend = InMemoryBackend::new();
+        let registry = KeyRegistry::new();
+        let expander = CacheExpander::new_with_registry(backend.clone(), Arc::new(registry));
+
+        let entity = TestEntity {
+            id: "1".to_string(),
+            value: "data".to_string(),
+        };
+        let bytes = entity.serialize_for_cache().expect("Failed to serialize");
+        backend.set("test:1", bytes, None).await.expect("Failed to set");
+
+        let mut feeder = GenericFeeder::new("1".to_string());
+        let repo = InMemoryRepository::new();
+
+        expander
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Fresh)
+            .await
+            .expect("Failed to execute");
+
+        assert_eq!(feeder.data.expect("Data not found").value, "data");
+    }
+
+    #[tokio::test]
+    async fn test_expander_with_fresh_strategy_hit() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+
+        // Pre-populate cache
+        let entity = TestEntity {
+            id: "1".to_string(),
+            value: "data".to_string(),
+        };
+        let bytes = entity.serialize_for_cache().expect("Failed to serialize");
+        backend
+            .clone()
+            .set("test:1", bytes, None)
+            .await
+            .expect("Failed to set");
+
+        // Create feeder
+        let mut feeder = GenericFeeder::new("1".to_string());
+        let repo = InMemoryRepository::new();
+
+        // Execute
+        expander
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Fresh)
+            .await
+            .expect("Failed to execute");
+
+        assert!(feeder.data.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_expander_with_fresh_strategy_miss() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+
+        let mut feeder = GenericFeeder::new("1".to_string());
+        let repo = InMemoryRepository::new();
+
+        expander
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Fresh)
+            .await
+            .expect("Failed to execute");
+
+        assert!(feeder.data.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_expander_refresh_strategy_cache_hit() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+
+        // Pre-populate cache
+        let entity = TestEntity {
+            id: "1".to_string(),
+            value: "cached_data".to_string(),
+        };
+        let bytes = entity.serialize_for_cache().expect("Failed to serialize");
+        backend
+            .clone()
+            .set("test:1", bytes, None)
+            .await
+            .expect("Failed to set");
+
+        let mut feeder = GenericFeeder::new("1".to_string());
+        let repo = InMemoryRepository::new();
+
+        expander
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute");
+
+        assert!(feeder.data.is_some());
+        assert_eq!(feeder.data.expect("Data not found").value, "cached_data");
+    }
+
+    #[tokio::test]
+    async fn test_expander_refresh_strategy_cache_miss_db_hit() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+
+        // Populate repository
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "db_data".to_string(),
+            },
+        );
+
+        let mut feeder = GenericFeeder::new("1".to_string());
+
+        expander
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute");
+
+        assert!(feeder.data.is_some());
+        assert_eq!(feeder.data.expect("Data not found").value, "db_data");
+
+        // Verify it was cached
+        let cached = backend
+            .clone()
+            .get("test:1")
+            .await
+            .expect("Failed to get from cache");
+        assert!(cached.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_expander_refresh_strategy_complete_miss() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+
+        let mut feeder = GenericFeeder::new("nonexistent".to_string());
+        let repo = InMemoryRepository::new();
+
+        expander
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute");
+
+        assert!(feeder.data.is_none());
+    }
+
+    #[test]
+    fn test_cache_outcome_is_cached_and_into_inner() {
+        assert!(CacheOutcome::Cached(1).is_cached());
+        assert!(CacheOutcome::Refreshed(1).is_cached());
+        assert!(!CacheOutcome::Fetched(1).is_cached());
+
+        assert_eq!(CacheOutcome::Cached(1).into_inner(), 1);
+        assert_eq!(CacheOutcome::Fetched(2).into_inner(), 2);
+        assert_eq!(CacheOutcome::Refreshed(3).into_inner(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_with_outcome_reports_cached_on_a_pre_populated_hit() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+
+        let entity = TestEntity {
+            id: "1".to_string(),
+            value: "cached_data".to_string(),
+        };
+        let bytes = entity.serialize_for_cache().expect("Failed to serialize");
+        backend.clone().set("test:1", bytes, None).await.expect("Failed to set");
+
+        let mut feeder = GenericFeeder::new("1".to_string());
+        let repo = InMemoryRepository::new();
+
+        let outcome = expander
+            .with_outcome::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute")
+            .expect("Expected a cache outcome");
+
+        assert!(outcome.is_cached());
+        assert_eq!(outcome.into_inner().value, "cached_data");
+    }
+
+    #[tokio::test]
+    async fn test_with_outcome_reports_fetched_on_a_cold_miss() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "db_data".to_string(),
+            },
+        );
+
+        let mut feeder = GenericFeeder::new("1".to_string());
+
+        let outcome = expander
+            .with_outcome::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute")
+            .expect("Expected a cache outcome");
+
+        assert!(!outcome.is_cached());
+        assert_eq!(outcome.into_inner().value, "db_data");
+    }
+
+    #[tokio::test]
+    async fn test_with_outcome_returns_none_on_a_complete_miss() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+
+        let mut feeder = GenericFeeder::new("nonexistent".to_string());
+        let repo = InMemoryRepository::new();
+
+        let outcome = expander
+            .with_outcome::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute");
+
+        assert!(outcome.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_expander_fetch_writes_entity_cache_tags_and_invalidate_tag_evicts_it() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            InvoiceEntity {
+                id: "1".to_string(),
+                customer_id: "42".to_string(),
+            },
+        );
+        repo.insert(
+            "2".to_string(),
+            InvoiceEntity {
+                id: "2".to_string(),
+                customer_id: "99".to_string(),
+            },
+        );
+
+        let mut first = GenericFeeder::new("1".to_string());
+        expander
+            .with::<InvoiceEntity, _, _>(&mut first, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute");
+        let mut second = GenericFeeder::new("2".to_string());
+        expander
+            .with::<InvoiceEntity, _, _>(&mut second, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute");
+
+        assert!(backend.exists("invoice:1").await.expect("Failed to check exists"));
+        assert!(backend.exists("invoice:2").await.expect("Failed to check exists"));
+
+        expander
+            .invalidate_tag("customer:42")
+            .await
+            .expect("Failed to invalidate tag");
+
+        assert!(!backend.exists("invoice:1").await.expect("Failed to check exists"));
+        assert!(
+            backend.exists("invoice:2").await.expect("Failed to check exists"),
+            "invalidating customer:42 must not evict a different customer's invoice"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expander_invalidate_strategy() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+
+        // Pre-populate cache with stale data
+        let stale_entity = TestEntity {
+            id: "1".to_string(),
+            value: "stale_data".to_string(),
+        };
+        let bytes = stale_entity
+            .serialize_for_cache()
+            .expect("Failed to serialize");
+        backend
+            .clone()
+            .set("test:1", bytes, None)
+            .await
+            .expect("Failed to set");
+
+        // Populate repository with fresh data
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "fresh_data".to_string(),
+            },
+        );
+
+        let mut feeder = GenericFeeder::new("1".to_string());
+
+        expander
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Invalidate)
+            .await
+            .expect("Failed to execute");
+
+        assert!(feeder.data.is_some());
+        assert_eq!(feeder.data.expect("Data not found").value, "fresh_data");
+
+        // Verify cache was updated
+        let cached_bytes = backend
+            .clone()
+            .get("test:1")
+            .await
+            .expect("Failed to get")
+            .expect("Cache is empty");
+        let cached_entity =
+            TestEntity::deserialize_from_cache(&cached_bytes).expect("Failed to deserialize");
+        assert_eq!(cached_entity.value, "fresh_data");
+    }
+
+    #[tokio::test]
+    async fn test_expander_bypass_strategy() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+
+        // Pre-populate cache
+        let cached_entity = TestEntity {
+            id: "1".to_string(),
+            value: "cached_data".to_string(),
+        };
+        let bytes = cached_entity
+            .serialize_for_cache()
+            .expect("Failed to serialize");
+        backend
+            .clone()
+            .set("test:1", bytes, None)
+            .await
+            .expect("Failed to set");
+
+        // Populate repository with different data
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "db_data".to_string(),
+            },
+        );
+
+        let mut feeder = GenericFeeder::new("1".to_string());
+
+        expander
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Bypass)
+            .await
+            .expect("Failed to execute");
+
+        // Should get database data, not cached data
+        assert!(feeder.data.is_some());
+        assert_eq!(feeder.data.expect("Data not found").value, "db_data");
+    }
+
+    #[tokio::test]
+    async fn test_expander_invalidate_and_bypass_strategies_record_metrics() {
+        let backend = InMemoryBackend::new();
+        let metrics = Arc::new(crate::observability::AtomicMetrics::new());
+        let expander = CacheExpander::new(backend).with_metrics(Box::new(Arc::clone(&metrics)));
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "db_data".to_string(),
+            },
+        );
+
+        let mut feeder = GenericFeeder::new("1".to_string());
+        expander
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Invalidate)
+            .await
+            .expect("Failed to execute");
+        assert_eq!(metrics.snapshot().invalidations, 1);
+        assert_eq!(metrics.snapshot().bypasses, 0);
+
+        let mut feeder = GenericFeeder::new("1".to_string());
+        expander
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Bypass)
+            .await
+            .expect("Failed to execute");
+        assert_eq!(metrics.snapshot().invalidations, 1);
+        assert_eq!(metrics.snapshot().bypasses, 1);
+    }
+
+    #[tokio::test]
+    async fn test_expander_with_ttl_policy() {
+        use crate::observability::TtlPolicy;
+        use std::time::Duration;
+
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone())
+            .with_ttl_policy(TtlPolicy::Fixed(Duration::from_secs(300)));
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "data".to_string(),
+            },
+        );
+
+        let mut feeder = GenericFeeder::new("1".to_string());
+
+        expander
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute");
+
+        assert!(feeder.data.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_expander_with_custom_metrics() {
+        use crate::observability::CacheMetrics;
+        use std::sync::{Arc, Mutex};
+        use std::time::Duration;
+
+        #[derive(Clone)]
+        struct TestMetrics {
+            hits: Arc<Mutex<usize>>,
+            misses: Arc<Mutex<usize>>,
+        }
+
+        impl CacheMetrics for TestMetrics {
+            fn record_hit(&self, _key: &str, _duration: Duration) {
+                *self.hits.lock().expect("Failed to lock hits") += 1;
+            }
+
+            fn record_miss(&self, _key: &str, _duration: Duration) {
+                *self.misses.lock().expect("Failed to lock misses") += 1;
+            }
+        }
+
+        let metrics = TestMetrics {
+            hits: Arc::new(Mutex::new(0)),
+            misses: Arc::new(Mutex::new(0)),
+        };
+
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone()).with_metrics(Box::new(metrics.clone()));
+
+        // Populate repository
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "data".to_string(),
+            },
+        );
+
+        let mut feeder = GenericFeeder::new("1".to_string());
+
+        // First call: cache miss, database hit
+        expander
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute");
+
+        assert_eq!(*metrics.hits.lock().expect("Failed to lock hits"), 1); // Counted as hit after DB fetch
+
+        // Second call: cache hit
+        let mut feeder2 = GenericFeeder::new("1".to_string());
+        expander
+            .with::<TestEntity, _, _>(&mut feeder2, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute");
+
+        assert_eq!(*metrics.hits.lock().expect("Failed to lock hits"), 2);
+    }
+
+    #[tokio::test]
+    async fn test_expander_with_records_repository_populate_only_on_backend_miss() {
+        let metrics = Arc::new(crate::observability::AtomicMetrics::new());
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend).with_metrics(Box::new(Arc::clone(&metrics)));
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "data".to_string(),
+            },
+        );
+
+        // First call: backend miss, populated from the repository.
+        let mut feeder = GenericFeeder::new("1".to_string());
+        expander
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute");
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.hits, 1);
+        assert_eq!(snapshot.repository_populates, 1);
+
+        // Second call: served from the backend, no repository fetch needed.
+        let mut feeder2 = GenericFeeder::new("1".to_string());
+        expander
+            .with::<TestEntity, _, _>(&mut feeder2, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute");
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.hits, 2);
+        assert_eq!(snapshot.repository_populates, 1, "second call was a backend hit, not a repository populate");
+    }
+
+    #[tokio::test]
+    async fn test_expander_error_on_missing_data() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+
+        let mut feeder = GenericFeeder::new("nonexistent".to_string());
+        let repo = InMemoryRepository::new();
+
+        // Fresh strategy with miss should return None (not error)
+        let result = expander
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Fresh)
+            .await;
+        assert!(result.is_ok());
+        assert!(feeder.data.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_expander_backend_reference() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+
+        // Test backend() method
+        let _backend_ref = expander.backend();
+
+        // Verify we can access the backend
+        assert_eq!(backend.len().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_expander_singleflight_coalesces_concurrent_misses() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        struct CountingRepository {
+            calls: Arc<AtomicUsize>,
+        }
+
+        impl DataRepository<TestEntity> for CountingRepository {
+            async fn fetch_by_id(&self, id: &String) -> Result<Option<TestEntity>> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                Ok(Some(TestEntity {
+                    id: id.clone(),
+                    value: "db_data".to_string(),
+                }))
+            }
+        }
+
+        let backend = InMemoryBackend::new();
+        let expander = Arc::new(CacheExpander::new(backend));
+        let calls = Arc::new(AtomicUsize::new(0));
+        let repo = Arc::new(CountingRepository {
+            calls: calls.clone(),
+        });
+
+        let mut handles = Vec::new();
+        for _ in 0..5 {
+            let expander = expander.clone();
+            let repo = repo.clone();
+            handles.push(tokio::spawn(async move {
+                let mut feeder = GenericFeeder::<TestEntity>::new("1".to_string());
+                expander
+                    .with::<TestEntity, _, _>(&mut feeder, &*repo, CacheStrategy::Refresh)
+                    .await
+                    .expect("Failed to execute");
+                feeder.data
+            }));
+        }
+
+        for handle in handles {
+            let data = handle.await.expect("Task panicked");
+            assert_eq!(data.expect("Data not found").value, "db_data");
+        }
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_expander_singleflight_records_coalesced_waits_for_followers() {
+        struct SlowRepository;
+
+        impl DataRepository<TestEntity> for SlowRepository {
+            async fn fetch_by_id(&self, id: &String) -> Result<Option<TestEntity>> {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                Ok(Some(TestEntity {
+                    id: id.clone(),
+                    value: "db_data".to_string(),
+                }))
+            }
+        }
+
+        let backend = InMemoryBackend::new();
+        let metrics = Arc::new(crate::observability::AtomicMetrics::new());
+        let expander = Arc::new(CacheExpander::new(backend).with_metrics(Box::new(Arc::clone(&metrics))));
+        let repo = Arc::new(SlowRepository);
+
+        let mut handles = Vec::new();
+        for _ in 0..5 {
+            let expander = expander.clone();
+            let repo = repo.clone();
+            handles.push(tokio::spawn(async move {
+                let mut feeder = GenericFeeder::<TestEntity>::new("1".to_string());
+                expander
+                    .with::<TestEntity, _, _>(&mut feeder, &*repo, CacheStrategy::Refresh)
+                    .await
+                    .expect("Failed to execute");
+            }));
+        }
+        for handle in handles {
+            handle.await.expect("Task panicked");
+        }
+
+        // One task runs the fetch; the other four join it and get recorded
+        // as coalesced waiters.
+        assert_eq!(metrics.snapshot().coalesced_waits, 4);
+    }
+
+    #[tokio::test]
+    async fn test_expander_singleflight_does_not_cache_errors() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        struct FlakyRepository {
+            calls: Arc<AtomicUsize>,
+        }
+
+        impl DataRepository<TestEntity> for FlakyRepository {
+            async fn fetch_by_id(&self, id: &String) -> Result<Option<TestEntity>> {
+                if self.calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                    return Err(Error::BackendError("database unavailable".to_string()));
+                }
+                Ok(Some(TestEntity {
+                    id: id.clone(),
+                    value: "db_data".to_string(),
+                }))
+            }
+        }
+
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+        let calls = Arc::new(AtomicUsize::new(0));
+        let repo = FlakyRepository {
+            calls: calls.clone(),
+        };
+
+        let mut feeder = GenericFeeder::<TestEntity>::new("1".to_string());
+        let first = expander
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await;
+        assert!(first.is_err(), "first fetch should surface the repository error");
+
+        // The failed attempt must not leave a stale single-flight entry
+        // behind (and must not have cached anything), or every later caller
+        // for this key would hang or spuriously miss.
+        let mut feeder = GenericFeeder::<TestEntity>::new("1".to_string());
+        expander
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Retry after single-flight error should succeed");
+        assert_eq!(feeder.data.expect("Data not found").value, "db_data");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_with_config_short_circuits_non_retryable_error() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        struct AlwaysInvalid {
+            calls: Arc<AtomicUsize>,
+        }
+
+        impl DataRepository<TestEntity> for AlwaysInvalid {
+            async fn fetch_by_id(&self, _id: &String) -> Result<Option<TestEntity>> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                Err(Error::ValidationError("not allowed".to_string()))
+            }
+        }
+
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+        let calls = Arc::new(AtomicUsize::new(0));
+        let repo = AlwaysInvalid {
+            calls: calls.clone(),
+        };
+
+        let mut feeder = GenericFeeder::<TestEntity>::new("1".to_string());
+        let config = OperationConfig::default().with_retry(5);
+        let result = expander
+            .with_config::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh, config)
+            .await;
+
+        assert!(matches!(result, Err(Error::ValidationError(_))));
+        assert_eq!(
+            calls.load(Ordering::SeqCst),
+            1,
+            "a non-retryable error must not be retried"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_with_config_retries_retryable_error_up_to_limit() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        struct AlwaysDown {
+            calls: Arc<AtomicUsize>,
+        }
+
+        impl DataRepository<TestEntity> for AlwaysDown {
+            async fn fetch_by_id(&self, _id: &String) -> Result<Option<TestEntity>> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                Err(Error::RepositoryError("db down".to_string()))
+            }
+        }
+
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+        let calls = Arc::new(AtomicUsize::new(0));
+        let repo = AlwaysDown {
+            calls: calls.clone(),
+        };
+
+        let mut feeder = GenericFeeder::<TestEntity>::new("1".to_string());
+        let config = OperationConfig::default()
+            .with_retry(2)
+            .with_backoff(Duration::from_millis(1), 2.0, Duration::from_millis(5));
+        let result = expander
+            .with_config::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh, config)
+            .await;
+
+        assert!(matches!(result, Err(Error::RepositoryError(_))));
+        assert_eq!(
+            calls.load(Ordering::SeqCst),
+            3,
+            "initial attempt plus 2 retries"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_with_config_retry_budget_caps_retries_across_calls() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        struct AlwaysDown {
+            calls: Arc<AtomicUsize>,
+        }
+
+        impl DataRepository<TestEntity> for AlwaysDown {
+            async fn fetch_by_id(&self, _id: &String) -> Result<Option<TestEntity>> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                Err(Error::RepositoryError("db down".to_string()))
+            }
+        }
+
+        let backend = InMemoryBackend::new();
+        // Budget for exactly 1 retry attempt (cost 10 out of 10 tokens), so
+        // the first call's own retry exhausts it and a second call's retries
+        // are refused immediately, regardless of its own `retry_count`.
+        let expander = CacheExpander::new(backend).with_retry_budget(10, 10, 0.0);
+        let calls = Arc::new(AtomicUsize::new(0));
+        let repo = AlwaysDown {
+            calls: calls.clone(),
+        };
+
+        let config = OperationConfig::default()
+            .with_retry(5)
+            .with_backoff(Duration::from_millis(1), 1.0, Duration::from_millis(1));
+
+        let mut feeder = GenericFeeder::<TestEntity>::new("1".to_string());
+        let first = expander
+            .with_config::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh, config.clone())
+            .await;
+        assert!(matches!(first, Err(Error::RepositoryError(_))));
+        assert_eq!(
+            calls.load(Ordering::SeqCst),
+            2,
+            "budget allows only the initial attempt plus one retry before it's empty"
+        );
+
+        calls.store(0, Ordering::SeqCst);
+        let mut feeder = GenericFeeder::<TestEntity>::new("1".to_string());
+        let second = expander
+            .with_config::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh, config)
+            .await;
+        assert!(matches!(second, Err(Error::RepositoryError(_))));
+        assert_eq!(
+            calls.load(Ordering::SeqCst),
+            1,
+            "exhausted budget means the second call's first failure isn't retried at all"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fresh_strategy_cache_miss_is_not_retried() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        struct CountingRepository {
+            calls: Arc<AtomicUsize>,
+        }
+
+        impl DataRepository<TestEntity> for CountingRepository {
+            async fn fetch_by_id(&self, _id: &String) -> Result<Option<TestEntity>> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                Ok(None)
+            }
+        }
+
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+        let calls = Arc::new(AtomicUsize::new(0));
+        let repo = CountingRepository {
+            calls: calls.clone(),
+        };
+
+        let mut feeder = GenericFeeder::<TestEntity>::new("missing".to_string());
+        // Fresh never falls through to the repository on a miss, so even a
+        // generous retry budget must never be spent chasing it.
+        let config = OperationConfig::default()
+            .with_retry(5)
+            .with_backoff(Duration::from_millis(1), 1.0, Duration::from_millis(1));
+
+        let result = expander
+            .with_config::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Fresh, config)
+            .await;
+
+        assert!(result.is_ok());
+        assert!(feeder.data.is_none());
+        assert_eq!(
+            calls.load(Ordering::SeqCst),
+            0,
+            "Fresh must never touch the repository, retry budget or not"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_with_retry_if_overrides_default_retryable_classification() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        struct AlwaysValidationError {
+            calls: Arc<AtomicUsize>,
+        }
+
+        impl DataRepository<TestEntity> for AlwaysValidationError {
+            async fn fetch_by_id(&self, _id: &String) -> Result<Option<TestEntity>> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                Err(Error::ValidationError("rejected".to_string()))
+            }
+        }
+
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+        let calls = Arc::new(AtomicUsize::new(0));
+        let repo = AlwaysValidationError {
+            calls: calls.clone(),
+        };
+
+        let mut feeder = GenericFeeder::<TestEntity>::new("1".to_string());
+        // Error::is_retryable would never retry ValidationError - override it
+        // so this operation retries it anyway.
+        let config = OperationConfig::default()
+            .with_retry(2)
+            .with_backoff(Duration::from_millis(1), 2.0, Duration::from_millis(5))
+            .with_retry_if(|e| matches!(e, Error::ValidationError(_)));
+        let result = expander
+            .with_config::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh, config)
+            .await;
+
+        assert!(matches!(result, Err(Error::ValidationError(_))));
+        assert_eq!(
+            calls.load(Ordering::SeqCst),
+            3,
+            "with_retry_if should force retries on an otherwise non-retryable error"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expander_with_config() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone())
+            .with_ttl_policy(TtlPolicy::Fixed(Duration::from_secs(60)));
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "test_value".to_string(),
+            },
+        );
+
+        let mut feeder = GenericFeeder::new("1".to_string());
+
+        // Test with_config() with TTL override and retry
+        let config = OperationConfig::default()
+            .with_ttl(Duration::from_secs(300))
+            .with_retry(2);
+
+        expander
+            .with_config::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh, config)
+            .await
+            .expect("Failed to execute with config");
+
+        assert!(feeder.data.is_some());
+        assert_eq!(feeder.data.expect("Data not found").value, "test_value");
+
+        // Verify that the original TTL policy wasn't mutated
+        match &expander.ttl_policy {
+            TtlPolicy::Fixed(duration) => assert_eq!(*duration, Duration::from_secs(60)),
+            _ => panic!("Expected Fixed TTL policy"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_expander_entity_cache_ttl_expires_entry() {
+        #[derive(Clone, Serialize, Deserialize)]
+        struct ShortLivedEntity {
+            id: String,
+        }
+
+        impl CacheEntity for ShortLivedEntity {
+            type Key = String;
+
+            fn cache_key(&self) -> Self::Key {
+                self.id.clone()
+            }
+
+            fn cache_prefix() -> &'static str {
+                "short_lived"
+            }
+
+            fn cache_ttl(&self) -> Option<Duration> {
+                Some(Duration::from_millis(20))
+            }
+        }
+
+        let backend = InMemoryBackend::new();
+        // No ttl_policy set, so without `cache_ttl()` this entry would never expire.
+        let expander = CacheExpander::new(backend);
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            ShortLivedEntity {
+                id: "1".to_string(),
+            },
+        );
+
+        let mut feeder = GenericFeeder::<ShortLivedEntity>::new("1".to_string());
+        expander
+            .with::<ShortLivedEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute");
+        assert!(feeder.data.is_some());
+
+        tokio::time::sleep(Duration::from_millis(40)).await;
+
+        let mut feeder2 = GenericFeeder::<ShortLivedEntity>::new("1".to_string());
+        expander
+            .with::<ShortLivedEntity, _, _>(&mut feeder2, &InMemoryRepository::new(), CacheStrategy::Fresh)
+            .await
+            .expect("Failed to execute");
+        assert!(
+            feeder2.data.is_none(),
+            "entry should have expired per cache_ttl()"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expander_entity_logical_expiry_forces_miss_on_fresh_strategy() {
+        #[derive(Clone, Serialize, Deserialize)]
+        struct ExpiringEntity {
+            id: String,
+            expires_at: u64,
+        }
+
+        impl CacheEntity for ExpiringEntity {
+            type Key = String;
+
+            fn cache_key(&self) -> Self::Key {
+                self.id.clone()
+            }
+
+            fn cache_prefix() -> &'static str {
+                "expiring"
+            }
+
+            fn cache_expires_at(&self) -> Option<u64> {
+                Some(self.expires_at)
+            }
+        }
+
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            ExpiringEntity {
+                id: "1".to_string(),
+                expires_at: now_unix() - 1,
+            },
+        );
+
+        let mut feeder = GenericFeeder::<ExpiringEntity>::new("1".to_string());
+        expander
+            .with::<ExpiringEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute");
+        assert!(feeder.data.is_some());
+
+        let mut feeder2 = GenericFeeder::<ExpiringEntity>::new("1".to_string());
+        expander
+            .with::<ExpiringEntity, _, _>(&mut feeder2, &InMemoryRepository::new(), CacheStrategy::Fresh)
+            .await
+            .expect("Failed to execute");
+        assert!(
+            feeder2.data.is_none(),
+            "logically expired entry should be a miss under Fresh, which never falls back to the database"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expander_min_remaining_ttl_forces_miss_on_fresh_strategy() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "cached".to_string(),
+            },
+        );
+
+        let config = OperationConfig::default().with_ttl(Duration::from_millis(50));
+        let mut feeder = GenericFeeder::<TestEntity>::new("1".to_string());
+        expander
+            .with_config::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh, config)
+            .await
+            .expect("Failed to execute");
+        assert!(feeder.data.is_some());
+
+        let guard_config =
+            OperationConfig::default().with_min_remaining_ttl(Duration::from_secs(1));
+        let mut feeder2 = GenericFeeder::<TestEntity>::new("1".to_string());
+        expander
+            .with_config::<TestEntity, _, _>(
+                &mut feeder2,
+                &InMemoryRepository::new(),
+                CacheStrategy::Fresh,
+                guard_config,
+            )
+            .await
+            .expect("Failed to execute");
+        assert!(
+            feeder2.data.is_none(),
+            "a hit with less than min_remaining_ttl left should be treated as a miss"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expander_min_remaining_ttl_refetches_under_refresh_strategy() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "stale".to_string(),
+            },
+        );
+
+        let config = OperationConfig::default().with_ttl(Duration::from_millis(50));
+        let mut feeder = GenericFeeder::<TestEntity>::new("1".to_string());
+        expander
+            .with_config::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh, config)
+            .await
+            .expect("Failed to execute");
+
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "fresh".to_string(),
+            },
+        );
+
+        let guard_config =
+            OperationConfig::default().with_min_remaining_ttl(Duration::from_secs(1));
+        let mut feeder2 = GenericFeeder::<TestEntity>::new("1".to_string());
+        expander
+            .with_config::<TestEntity, _, _>(&mut feeder2, &repo, CacheStrategy::Refresh, guard_config)
+            .await
+            .expect("Failed to execute");
+        assert_eq!(
+            feeder2.data.map(|e| e.value),
+            Some("fresh".to_string()),
+            "a hit below min_remaining_ttl under Refresh should fall through to the repository"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expander_entity_logical_expiry_refetches_under_refresh_strategy() {
+        #[derive(Clone, Serialize, Deserialize)]
+        struct ExpiringEntity {
+            id: String,
+            value: String,
+            expires_at: u64,
+        }
+
+        impl CacheEntity for ExpiringEntity {
+            type Key = String;
+
+            fn cache_key(&self) -> Self::Key {
+                self.id.clone()
+            }
+
+            fn cache_prefix() -> &'static str {
+                "expiring"
+            }
+
+            fn cache_expires_at(&self) -> Option<u64> {
+                Some(self.expires_at)
+            }
+        }
+
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            ExpiringEntity {
+                id: "1".to_string(),
+                value: "stale".to_string(),
+                expires_at: now_unix() - 1,
+            },
+        );
+
+        let mut feeder = GenericFeeder::<ExpiringEntity>::new("1".to_string());
+        expander
+            .with::<ExpiringEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute");
+        assert_eq!(feeder.data.expect("Data not found").value, "stale");
+
+        // The repository's view has since changed; a logically expired hit
+        // under Refresh should fall through and pick it up rather than
+        // serving the stale cached copy, even though the TTL hasn't lapsed.
+        repo.insert(
+            "1".to_string(),
+            ExpiringEntity {
+                id: "1".to_string(),
+                value: "updated".to_string(),
+                expires_at: now_unix() + 3600,
+            },
+        );
+
+        let mut feeder2 = GenericFeeder::<ExpiringEntity>::new("1".to_string());
+        expander
+            .with::<ExpiringEntity, _, _>(&mut feeder2, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute");
+        assert_eq!(
+            feeder2.data.expect("Data not found").value,
+            "updated",
+            "logically expired entry should force a database re-fetch under Refresh"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expander_entity_cache_ttl_varies_with_value() {
+        // cache_ttl() has full access to `self`, so a single impl can give a
+        // negative/empty result a short TTL and a populated one a long TTL -
+        // the value-dependent TTL a `TtlPolicy::PerEntry` closure would need
+        // to reach into the value for, without adding one.
+        #[derive(Clone, Serialize, Deserialize)]
+        struct Lookup {
+            id: String,
+            found: bool,
+        }
+
+        impl CacheEntity for Lookup {
+            type Key = String;
+
+            fn cache_key(&self) -> Self::Key {
+                self.id.clone()
+            }
+
+            fn cache_prefix() -> &'static str {
+                "lookup"
+            }
+
+            fn cache_ttl(&self) -> Option<Duration> {
+                Some(if self.found {
+                    Duration::from_secs(3600)
+                } else {
+                    Duration::from_millis(20)
+                })
+            }
+        }
+
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "miss".to_string(),
+            Lookup {
+                id: "miss".to_string(),
+                found: false,
+            },
+        );
+
+        let mut feeder = GenericFeeder::<Lookup>::new("miss".to_string());
+        expander
+            .with::<Lookup, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute");
+        assert!(feeder.data.is_some());
+
+        tokio::time::sleep(Duration::from_millis(40)).await;
+
+        let mut feeder2 = GenericFeeder::<Lookup>::new("miss".to_string());
+        expander
+            .with::<Lookup, _, _>(&mut feeder2, &InMemoryRepository::new(), CacheStrategy::Fresh)
+            .await
+            .expect("Failed to execute");
+        assert!(
+            feeder2.data.is_none(),
+            "negative result's short cache_ttl() should have expired"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expander_eviction_listener_notifies_on_invalidate() {
+        let backend = InMemoryBackend::new();
+        let (tx, mut rx) = mpsc::channel(8);
+        let expander = CacheExpander::new(backend.clone()).with_eviction_listener(tx);
+
+        let entity = TestEntity {
+            id: "1".to_string(),
+            value: "cached_data".to_string(),
+        };
+        let bytes = entity.serialize_for_cache().expect("Failed to serialize");
+        backend
+            .clone()
+            .set("test:1", bytes, None)
+            .await
+            .expect("Failed to set");
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "fresh_data".to_string(),
+            },
+        );
+
+        let mut feeder = GenericFeeder::new("1".to_string());
+        expander
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Invalidate)
+            .await
+            .expect("Failed to execute");
+
+        let (key, bytes, cause) = rx.try_recv().expect("expected an eviction notification");
+        assert_eq!(key, "test:1");
+        assert_eq!(cause, WriteBackCause::Evicted);
+        let evicted = TestEntity::deserialize_from_cache(&bytes).expect("Failed to deserialize");
+        assert_eq!(evicted.value, "cached_data");
+    }
+
+    #[tokio::test]
+    async fn test_expander_with_batch_mixed_hits_and_misses() {
+        use crate::feed::GenericBatchFeeder;
+
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+
+        // "1" is already cached.
+        let cached = TestEntity {
+            id: "1".to_string(),
+            value: "cached".to_string(),
+        };
+        let bytes = cached.serialize_for_cache().expect("Failed to serialize");
+        backend
+            .clone()
+            .set("test:1", bytes, None)
+            .await
+            .expect("Failed to set");
+
+        // "2" only exists in the repository; "3" exists nowhere.
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "2".to_string(),
+            TestEntity {
+                id: "2".to_string(),
+                value: "from_db".to_string(),
+            },
+        );
+
+        let mut feeder = GenericBatchFeeder::<TestEntity>::new(vec![
+            "1".to_string(),
+            "2".to_string(),
+            "3".to_string(),
+        ]);
+
+        expander
+            .with_batch::<TestEntity, _, _>(&mut feeder, &repo)
+            .await
+            .expect("Failed to execute batch");
+
+        assert_eq!(feeder.data.len(), 3);
+        assert_eq!(feeder.data[0].0, "1");
+        assert_eq!(feeder.data[0].1.as_ref().unwrap().value, "cached");
+        assert_eq!(feeder.data[1].0, "2");
+        assert_eq!(feeder.data[1].1.as_ref().unwrap().value, "from_db");
+        assert_eq!(feeder.data[2].0, "3");
+        assert!(feeder.data[2].1.is_none());
+
+        // The repository-sourced entity should now be cached too.
+        let cached_now = backend
+            .clone()
+            .get("test:2")
+            .await
+            .expect("Failed to get")
+            .expect("Entity 2 should have been written back to cache");
+        let entity = TestEntity::deserialize_from_cache(&cached_now).expect("Failed to deserialize");
+        assert_eq!(entity.value, "from_db");
+    }
+
+    #[tokio::test]
+    async fn test_expander_with_batch_outcome_distinguishes_cached_from_fetched_rows() {
+        use crate::feed::GenericBatchFeeder;
+
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+
+        // "1" is already cached; "2" only exists in the repository; "3" exists nowhere.
+        let cached = TestEntity {
+            id: "1".to_string(),
+            value: "cached".to_string(),
+        };
+        let bytes = cached.serialize_for_cache().expect("Failed to serialize");
+        expander
+            .backend
+            .clone()
+            .set("test:1", bytes, None)
+            .await
+            .expect("Failed to set");
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "2".to_string(),
+            TestEntity {
+                id: "2".to_string(),
+                value: "from_db".to_string(),
+            },
+        );
+
+        let mut feeder = GenericBatchFeeder::<TestEntity>::new(vec![
+            "1".to_string(),
+            "2".to_string(),
+            "3".to_string(),
+        ]);
+
+        let results = expander
+            .with_batch_outcome::<TestEntity, _, _>(&mut feeder, &repo)
+            .await
+            .expect("Failed to execute batch outcome");
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].0, "1");
+        assert!(matches!(
+            results[0].1,
+            Some(CacheOutcome::Cached(ref v)) if v.value == "cached"
+        ));
+        assert_eq!(results[1].0, "2");
+        assert!(matches!(
+            results[1].1,
+            Some(CacheOutcome::Fetched(ref v)) if v.value == "from_db"
+        ));
+        assert_eq!(results[2].0, "3");
+        assert!(results[2].1.is_none());
+
+        // feeder.feed_batch still received the plain results.
+        assert_eq!(feeder.data.len(), 3);
+        assert_eq!(feeder.data[0].1.as_ref().unwrap().value, "cached");
+    }
+
+    #[tokio::test]
+    async fn test_expander_with_batch_preserves_requested_order_out_of_order_ids() {
+        use crate::feed::GenericBatchFeeder;
+
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+
+        // "2" is already cached; "3" and "1" only exist in the repository.
+        let cached = TestEntity {
+            id: "2".to_string(),
+            value: "cached".to_string(),
+        };
+        let bytes = cached.serialize_for_cache().expect("Failed to serialize");
+        backend
+            .clone()
+            .set("test:2", bytes, None)
+            .await
+            .expect("Failed to set");
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "3".to_string(),
+            TestEntity {
+                id: "3".to_string(),
+                value: "three".to_string(),
+            },
+        );
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "one".to_string(),
+            },
+        );
+
+        // Request ids out of sort order and interleaved with the hit, to
+        // exercise that the miss-index -> fetched-entity zip in
+        // with_batch_config doesn't silently depend on ids being sorted.
+        let mut feeder = GenericBatchFeeder::<TestEntity>::new(vec![
+            "3".to_string(),
+            "2".to_string(),
+            "1".to_string(),
+        ]);
+
+        expander
+            .with_batch::<TestEntity, _, _>(&mut feeder, &repo)
+            .await
+            .expect("Failed to execute batch");
+
+        assert_eq!(feeder.data.len(), 3);
+        assert_eq!(feeder.data[0].0, "3");
+        assert_eq!(feeder.data[0].1.as_ref().unwrap().value, "three");
+        assert_eq!(feeder.data[1].0, "2");
+        assert_eq!(feeder.data[1].1.as_ref().unwrap().value, "cached");
+        assert_eq!(feeder.data[2].0, "1");
+        assert_eq!(feeder.data[2].1.as_ref().unwrap().value, "one");
+    }
+
+    #[tokio::test]
+    async fn test_expander_with_batch_empty_ids_is_noop() {
+        use crate::feed::GenericBatchFeeder;
+
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+        let repo = InMemoryRepository::new();
+
+        let mut feeder = GenericBatchFeeder::<TestEntity>::new(vec![]);
+        expander
+            .with_batch::<TestEntity, _, _>(&mut feeder, &repo)
+            .await
+            .expect("Failed to execute batch");
+
+        assert!(feeder.data.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_expander_with_batch_config_ttl_override() {
+        use crate::feed::GenericBatchFeeder;
+
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "data".to_string(),
+            },
+        );
+
+        let mut feeder = GenericBatchFeeder::<TestEntity>::new(vec!["1".to_string()]);
+        let config = OperationConfig::default().with_ttl(Duration::from_millis(20));
+
+        expander
+            .with_batch_config::<TestEntity, _, _>(&mut feeder, &repo, config)
+            .await
+            .expect("Failed to execute batch");
+        assert!(feeder.data[0].1.is_some());
+
+        tokio::time::sleep(Duration::from_millis(40)).await;
+
+        assert!(
+            backend.clone().get("test:1").await.expect("Failed to get").is_none(),
+            "entry should have expired per the batch ttl_override"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expander_with_batch_writes_misses_back_in_one_mset() {
+        use crate::backend::MockBackend;
+        use crate::feed::GenericBatchFeeder;
+
+        let backend = MockBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "one".to_string(),
+            },
+        );
+        repo.insert(
+            "2".to_string(),
+            TestEntity {
+                id: "2".to_string(),
+                value: "two".to_string(),
+            },
+        );
+
+        let mut feeder =
+            GenericBatchFeeder::<TestEntity>::new(vec!["1".to_string(), "2".to_string()]);
+
+        expander
+            .with_batch::<TestEntity, _, _>(&mut feeder, &repo)
+            .await
+            .expect("Failed to execute batch");
+
+        // Both misses are written back through a single `mset` call, not
+        // one `set` round trip per entity.
+        let stats = backend.stats();
+        assert_eq!(stats.set_calls, 0);
+        assert_eq!(stats.mset_calls, 1);
+        assert!(backend.get("test:1").await.expect("Failed to get").is_some());
+        assert!(backend.get("test:2").await.expect("Failed to get").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_expander_fetch_many_returns_results_without_a_feeder() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "one".to_string(),
+            },
+        );
+        repo.insert(
+            "2".to_string(),
+            TestEntity {
+                id: "2".to_string(),
+                value: "two".to_string(),
+            },
+        );
+
+        let ids = vec!["1".to_string(), "2".to_string(), "missing".to_string()];
+        let results = expander
+            .fetch_many::<TestEntity, _>(&ids, &repo, OperationConfig::default())
+            .await
+            .expect("Failed to fetch many");
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].0, "1");
+        assert_eq!(results[0].1.as_ref().expect("Missing entity").value, "one");
+        assert_eq!(results[1].0, "2");
+        assert_eq!(results[1].1.as_ref().expect("Missing entity").value, "two");
+        assert_eq!(results[2].0, "missing");
+        assert!(results[2].1.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_expander_populate_many_writes_every_entity() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+
+        let entities = vec![
+            TestEntity { id: "1".to_string(), value: "a".to_string() },
+            TestEntity { id: "2".to_string(), value: "b".to_string() },
+            TestEntity { id: "3".to_string(), value: "c".to_string() },
+        ];
+
+        expander
+            .populate_many(&entities)
+            .await
+            .expect("Failed to populate");
+
+        for (id, value) in [("1", "a"), ("2", "b"), ("3", "c")] {
+            let bytes = backend
+                .get(&format!("test:{}", id))
+                .await
+                .expect("Failed to get")
+                .expect("entity should be cached");
+            let entity: TestEntity =
+                TestEntity::deserialize_from_cache(&bytes).expect("Failed to deserialize");
+            assert_eq!(entity.value, value);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_expander_populate_many_empty_is_noop() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+
+        expander
+            .populate_many::<TestEntity>(&[])
+            .await
+            .expect("Failed to populate");
+
+        assert_eq!(backend.len().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_expander_batch_refresh_resolves_each_feeder_from_cache_or_repository() {
+        use crate::feed::GenericFeeder;
+
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+
+        let cached = TestEntity {
+            id: "1".to_string(),
+            value: "cached".to_string(),
+        };
+        let bytes = cached.serialize_for_cache().expect("Failed to serialize");
+        backend
+            .clone()
+            .set("test:1", bytes, None)
+            .await
+            .expect("Failed to set");
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "2".to_string(),
+            TestEntity {
+                id: "2".to_string(),
+                value: "two".to_string(),
+            },
+        );
+
+        let mut feeders = vec![
+            GenericFeeder::<TestEntity>::new("1".to_string()),
+            GenericFeeder::<TestEntity>::new("2".to_string()),
+            GenericFeeder::<TestEntity>::new("missing".to_string()),
+        ];
+
+        expander
+            .batch_refresh::<TestEntity, _, _>(&mut feeders, &repo, OperationConfig::default())
+            .await
+            .expect("Failed to batch refresh");
+
+        assert_eq!(feeders[0].data.as_ref().expect("Missing entity").value, "cached");
+        assert_eq!(feeders[1].data.as_ref().expect("Missing entity").value, "two");
+        assert!(feeders[2].data.is_none());
+
+        // The repository fetch for "2" should have been written back through
+        // the batch's single `mset`, so it's now a cache hit too.
+        assert!(backend.get("test:2").await.expect("Failed to get").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_expander_batch_refresh_empty_feeders_is_noop() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+        let repo = InMemoryRepository::new();
+
+        let mut feeders: Vec<crate::feed::GenericFeeder<TestEntity>> = Vec::new();
+        expander
+            .batch_refresh::<TestEntity, _, _>(&mut feeders, &repo, OperationConfig::default())
+            .await
+            .expect("Failed to batch refresh");
+    }
+
+    #[tokio::test]
+    async fn test_expander_invalidate_prefix_drops_only_matching_sub_entries() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+
+        for key in ["test:123:profile", "test:123:settings", "test:456:profile"] {
+            backend
+                .clone()
+                .set(key, b"value".to_vec(), None)
+                .await
+                .expect("Failed to set");
+        }
+
+        expander
+            .invalidate_prefix::<TestEntity>("123")
+            .await
+            .expect("Failed to invalidate prefix");
+
+        assert!(backend.get("test:123:profile").await.expect("Failed to get").is_none());
+        assert!(backend.get("test:123:settings").await.expect("Failed to get").is_none());
+        assert!(backend.get("test:456:profile").await.expect("Failed to get").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_expander_ttl_override_wins_over_entity_cache_ttl() {
+        #[derive(Clone, Serialize, Deserialize)]
+        struct LongLivedEntity {
+            id: String,
+        }
+
+        impl CacheEntity for LongLivedEntity {
+            type Key = String;
+
+            fn cache_key(&self) -> Self::Key {
+                self.id.clone()
+            }
+
+            fn cache_prefix() -> &'static str {
+                "long_lived"
+            }
+
+            fn cache_ttl(&self) -> Option<Duration> {
+                Some(Duration::from_millis(20))
+            }
+        }
+
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            LongLivedEntity {
+                id: "1".to_string(),
+            },
+        );
+
+        let mut feeder = GenericFeeder::<LongLivedEntity>::new("1".to_string());
+        let config = OperationConfig::default().with_ttl(Duration::from_secs(300));
+        expander
+            .with_config::<LongLivedEntity, _, _>(
+                &mut feeder,
+                &repo,
+                CacheStrategy::Refresh,
+                config,
+            )
+            .await
+            .expect("Failed to execute");
+        assert!(feeder.data.is_some());
+
+        tokio::time::sleep(Duration::from_millis(40)).await;
+
+        let mut feeder2 = GenericFeeder::<LongLivedEntity>::new("1".to_string());
+        expander
+            .with::<LongLivedEntity, _, _>(&mut feeder2, &InMemoryRepository::new(), CacheStrategy::Fresh)
+            .await
+            .expect("Failed to execute");
+        assert!(
+            feeder2.data.is_some(),
+            "ttl_override should keep the entry alive past the entity's own cache_ttl()"
+        );
+    }
+
+    struct UncacheableRepository(InMemoryRepository<TestEntity>);
+
+    impl DataRepository<TestEntity> for UncacheableRepository {
+        async fn fetch_by_id(&self, id: &String) -> Result<Option<TestEntity>> {
+            self.0.fetch_by_id(id).await
+        }
+
+        fn is_cacheable(&self, _entity: &TestEntity) -> bool {
+            false
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refresh_strategy_skips_cache_write_for_uncacheable_entity() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+
+        let mut inner = InMemoryRepository::new();
+        inner.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "volatile".to_string(),
+            },
+        );
+        let repo = UncacheableRepository(inner);
+
+        let mut feeder = GenericFeeder::new("1".to_string());
+        expander
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute");
+
+        assert_eq!(feeder.data.expect("Data not found").value, "volatile");
+        assert!(!backend
+            .exists("test:1")
+            .await
+            .expect("Failed to check exists"));
+    }
+
+    #[tokio::test]
+    async fn test_negative_caching_avoids_repeated_repository_calls() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+        let repo = InMemoryRepository::<TestEntity>::new();
+
+        let config = OperationConfig::default().with_negative_ttl(Duration::from_secs(60));
+
+        let mut feeder = GenericFeeder::new("missing".to_string());
+        expander
+            .with_config::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh, config.clone())
+            .await
+            .expect("Failed to execute");
+        assert!(feeder.data.is_none());
+        assert_eq!(repo.fetch_by_id_calls(), 1);
+
+        let mut feeder2 = GenericFeeder::new("missing".to_string());
+        expander
+            .with_config::<TestEntity, _, _>(&mut feeder2, &repo, CacheStrategy::Refresh, config)
+            .await
+            .expect("Failed to execute");
+        assert!(feeder2.data.is_none());
+        assert_eq!(
+            repo.fetch_by_id_calls(),
+            1,
+            "second lookup should resolve from the tombstone without hitting the repository"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_strategy_clears_tombstone() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+        let mut repo = InMemoryRepository::<TestEntity>::new();
+
+        let config = OperationConfig::default().with_negative_ttl(Duration::from_secs(60));
+
+        let mut feeder = GenericFeeder::new("1".to_string());
+        expander
+            .with_config::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh, config)
+            .await
+            .expect("Failed to execute");
+        assert!(feeder.data.is_none());
+
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "now_exists".to_string(),
+            },
+        );
+
+        let mut feeder2 = GenericFeeder::new("1".to_string());
+        expander
+            .with::<TestEntity, _, _>(&mut feeder2, &repo, CacheStrategy::Invalidate)
+            .await
+            .expect("Failed to execute");
+        assert_eq!(feeder2.data.expect("Data not found").value, "now_exists");
+    }
+
+    #[tokio::test]
+    async fn test_without_negative_caching_disables_tombstone_writes() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+        let repo = InMemoryRepository::<TestEntity>::new();
+
+        let config = OperationConfig::default()
+            .with_negative_ttl(Duration::from_secs(60))
+            .without_negative_caching();
+
+        let mut feeder = GenericFeeder::new("missing".to_string());
+        expander
+            .with_config::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh, config)
+            .await
+            .expect("Failed to execute");
+
+        assert!(!backend
+            .exists("test:missing")
+            .await
+            .expect("Failed to check exists"));
+    }
+
+    /// Minimal repository whose entries can be updated after it's wrapped in
+    /// `Arc` - `with_stale_while_revalidate` takes `Arc<R>` (not `&R`) so a
+    /// background refresh can outlive the call, and `InMemoryRepository`'s
+    /// `insert` takes `&mut self`, which an `Arc` can no longer offer once a
+    /// test needs to change what the repository returns mid-test.
+    struct SharedRepo<T: CacheEntity> {
+        data: std::sync::Mutex<HashMap<String, T>>,
+    }
+
+    impl<T: CacheEntity> SharedRepo<T> {
+        fn new() -> Self {
+            SharedRepo {
+                data: std::sync::Mutex::new(HashMap::new()),
+            }
+        }
+
+        fn insert(&self, id: T::Key, value: T) {
+            self.data.lock().expect("lock poisoned").insert(id.to_string(), value);
+        }
+    }
+
+    impl<T: CacheEntity> DataRepository<T> for SharedRepo<T> {
+        async fn fetch_by_id(&self, id: &T::Key) -> Result<Option<T>> {
+            Ok(self.data.lock().expect("lock poisoned").get(&id.to_string()).cloned())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stale_while_revalidate_requires_stale_after_configured() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+        let repo = Arc::new(SharedRepo::<TestEntity>::new());
+
+        let mut feeder = GenericFeeder::new("1".to_string());
+        let result = expander
+            .with_stale_while_revalidate::<TestEntity, _, _>(
+                &mut feeder,
+                repo,
+                OperationConfig::default(),
+            )
+            .await;
+
+        match result.unwrap_err() {
+            Error::ValidationError(_) => {}
+            e => panic!("Expected ValidationError, got {:?}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stale_while_revalidate_falls_back_to_soft_hard_ttl_policy() {
+        use crate::observability::TtlPolicy;
+
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend).with_ttl_policy(TtlPolicy::SoftHard {
+            soft: Duration::from_secs(300),
+            hard: Duration::from_secs(3600),
+        });
+        let repo = Arc::new(SharedRepo::<TestEntity>::new());
+
+        // No `with_stale_after` on the config - the SoftHard policy alone
+        // should be enough to satisfy the stale_after requirement.
+        let mut feeder = GenericFeeder::new("1".to_string());
+        let result = expander
+            .with_stale_while_revalidate::<TestEntity, _, _>(
+                &mut feeder,
+                repo,
+                OperationConfig::default(),
+            )
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_stale_while_revalidate_miss_fetches_and_caches() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+        let repo = Arc::new(SharedRepo::new());
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "fresh".to_string(),
+            },
+        );
+
+        let config = OperationConfig::default().with_stale_after(Duration::from_secs(300));
+        let mut feeder = GenericFeeder::new("1".to_string());
+        expander
+            .with_stale_while_revalidate::<TestEntity, _, _>(&mut feeder, repo, config)
+            .await
+            .expect("Failed to execute");
+
+        assert_eq!(feeder.data.expect("Data not found").value, "fresh");
+        assert!(backend
+            .exists("test:1")
+            .await
+            .expect("Failed to check exists"));
+    }
+
+    #[tokio::test]
+    async fn test_stale_while_revalidate_treats_a_foreign_envelope_as_fresh() {
+        // A key written by a plain `Refresh` call - no soft-expiry envelope
+        // at all - must still be readable under `StaleWhileRevalidate`
+        // instead of erroring, and must never trigger a background refresh
+        // since there's no soft-expiry to have lapsed.
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+        let repo = Arc::new(SharedRepo::new());
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "refresh_strategy_value".to_string(),
+            },
+        );
+
+        let mut setup_feeder = GenericFeeder::new("1".to_string());
+        expander
+            .with::<TestEntity, _, _>(&mut setup_feeder, repo.as_ref(), CacheStrategy::Refresh)
+            .await
+            .expect("Failed to seed via Refresh");
+        let seeded = backend.get("test:1").await.expect("Failed to get");
+
+        let config = OperationConfig::default().with_stale_after(Duration::from_secs(300));
+        let mut feeder = GenericFeeder::new("1".to_string());
+        let outcome = expander
+            .with_stale_while_revalidate_outcome::<TestEntity, _, _>(&mut feeder, repo, config)
+            .await
+            .expect("Failed to execute")
+            .expect("Expected a value");
+
+        assert_eq!(outcome.into_inner().value, "refresh_strategy_value");
+
+        // No background refresh was spawned - give one a moment to run if it
+        // was (it shouldn't have been) and confirm the cached bytes didn't
+        // change.
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        let still_bare = backend.get("test:1").await.expect("Failed to get");
+        assert!(still_bare.is_some());
+        assert_eq!(still_bare, seeded);
+    }
+
+    #[tokio::test]
+    async fn test_stale_while_revalidate_serves_fresh_hit_without_background_refresh() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+        let repo = Arc::new(SharedRepo::new());
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "original".to_string(),
+            },
+        );
+
+        let config = OperationConfig::default().with_stale_after(Duration::from_secs(300));
+        let mut first = GenericFeeder::new("1".to_string());
+        expander
+            .with_stale_while_revalidate::<TestEntity, _, _>(&mut first, repo.clone(), config.clone())
+            .await
+            .expect("Failed to execute");
+
+        // Change what the repository would return, to prove a fresh hit never
+        // triggers a refresh and so never picks up this new value.
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "changed".to_string(),
+            },
+        );
+
+        let mut second = GenericFeeder::new("1".to_string());
+        expander
+            .with_stale_while_revalidate::<TestEntity, _, _>(&mut second, repo, config)
+            .await
+            .expect("Failed to execute");
+
+        assert_eq!(second.data.expect("Data not found").value, "original");
+    }
+
+    #[tokio::test]
+    async fn test_stale_while_revalidate_serves_stale_hit_and_refreshes_in_background() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+        let repo = Arc::new(SharedRepo::new());
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "original".to_string(),
+            },
+        );
+
+        // A zero stale_after means every hit is already past its soft TTL.
+        let config = OperationConfig::default().with_stale_after(Duration::from_secs(0));
+        let mut first = GenericFeeder::new("1".to_string());
+        expander
+            .with_stale_while_revalidate::<TestEntity, _, _>(&mut first, repo.clone(), config.clone())
+            .await
+            .expect("Failed to execute");
+        assert_eq!(first.data.expect("Data not found").value, "original");
+
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "refreshed".to_string(),
+            },
+        );
+
+        let mut second = GenericFeeder::new("1".to_string());
+        expander
+            .with_stale_while_revalidate::<TestEntity, _, _>(&mut second, repo, config)
+            .await
+            .expect("Failed to execute");
+        // The stale value is still served immediately...
+        assert_eq!(second.data.expect("Data not found").value, "original");
+
+        // ...while the background refresh catches up shortly after.
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let bytes = backend
+            .get("test:1")
+            .await
+            .expect("Failed to get")
+            .expect("Entry missing");
+        let refreshed: StaleAware<TestEntity> =
+            deserialize_from_cache(&bytes).expect("Failed to deserialize");
+        assert_eq!(refreshed.value.value, "refreshed");
+    }
+
+    #[tokio::test]
+    async fn test_stale_while_revalidate_outcome_distinguishes_fresh_stale_and_fetched() {
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend);
+        let repo = Arc::new(SharedRepo::new());
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "original".to_string(),
+            },
+        );
+
+        // Cold miss: fetched inline.
+        let fresh_config = OperationConfig::default().with_stale_after(Duration::from_secs(300));
+        let mut first = GenericFeeder::new("1".to_string());
+        let outcome = expander
+            .with_stale_while_revalidate_outcome::<TestEntity, _, _>(&mut first, repo.clone(), fresh_config.clone())
+            .await
+            .expect("Failed to execute")
+            .expect("Expected a cache outcome");
+        assert!(matches!(outcome, CacheOutcome::Fetched(ref v) if v.value == "original"));
+
+        // Fresh hit.
+        let mut second = GenericFeeder::new("1".to_string());
+        let outcome = expander
+            .with_stale_while_revalidate_outcome::<TestEntity, _, _>(&mut second, repo.clone(), fresh_config)
+            .await
+            .expect("Failed to execute")
+            .expect("Expected a cache outcome");
+        assert!(matches!(outcome, CacheOutcome::Cached(_)));
+
+        // Stale hit, background refresh triggered.
+        let stale_config = OperationConfig::default().with_stale_after(Duration::from_secs(0));
+        let mut third = GenericFeeder::new("1".to_string());
+        let outcome = expander
+            .with_stale_while_revalidate_outcome::<TestEntity, _, _>(&mut third, repo, stale_config)
+            .await
+            .expect("Failed to execute")
+            .expect("Expected a cache outcome");
+        assert!(matches!(outcome, CacheOutcome::Refreshed(_)));
+    }
+
+    #[tokio::test]
+    async fn test_stale_while_revalidate_records_stale_hit_distinct_from_fresh_hit() {
+        let backend = InMemoryBackend::new();
+        let metrics = Arc::new(crate::observability::AtomicMetrics::new());
+        let expander = CacheExpander::new(backend).with_metrics(Box::new(Arc::clone(&metrics)));
+        let repo = Arc::new(SharedRepo::new());
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "original".to_string(),
+            },
+        );
+
+        // A zero stale_after means every hit is already past its soft TTL.
+        let config = OperationConfig::default().with_stale_after(Duration::from_secs(0));
+        let mut first = GenericFeeder::new("1".to_string());
+        expander
+            .with_stale_while_revalidate::<TestEntity, _, _>(&mut first, repo.clone(), config.clone())
+            .await
+            .expect("Failed to execute");
+        // Miss, fetched inline - not yet stale.
+        assert_eq!(metrics.snapshot().stale_hits, 0);
 
-        match self.backend.get(cache_key).await? {
-            Some(bytes) => {
-                debug!("✓ Cache hit (Fresh strategy)");
-                T::deserialize_from_cache(&bytes).map(Some)
-            }
-            None => {
-                debug!("✗ Cache miss (Fresh strategy) - no fallback");
-                Ok(None)
-            }
-        }
+        let mut second = GenericFeeder::new("1".to_string());
+        expander
+            .with_stale_while_revalidate::<TestEntity, _, _>(&mut second, repo, config)
+            .await
+            .expect("Failed to execute");
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.stale_hits, 1);
+        assert_eq!(snapshot.hits, 0);
     }
 
-    /// Refresh strategy: Try cache, fallback to database on miss.
-    async fn strategy_refresh<T: CacheEntity, R: DataRepository<T>>(
-        &self,
-        cache_key: &str,
-        repository: &R,
-        config: &OperationConfig,
-    ) -> Result<Option<T>>
-    where
-        T::Key: FromStr,
-    {
-        debug!("Executing Refresh strategy for {}", cache_key);
+    #[tokio::test]
+    async fn test_stale_while_revalidate_background_refresh_failure_records_refresh_error() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
 
-        // Try cache first
-        if let Some(bytes) = self.backend.get(cache_key).await? {
-            debug!("✓ Cache hit (Refresh strategy)");
-            return T::deserialize_from_cache(&bytes).map(Some);
+        struct FailsOnRefresh {
+            calls: AtomicUsize,
         }
 
-        debug!("Cache miss, falling back to database");
-
-        // Cache miss - fetch from database
-        let id = self.extract_id_from_key::<T>(cache_key)?;
-        match repository.fetch_by_id(&id).await? {
-            Some(entity) => {
-                // Store in cache for future use
-                // Use config override if provided, otherwise use default TTL policy
-                let ttl = config
-                    .ttl_override
-                    .or_else(|| self.ttl_policy.get_ttl(T::cache_prefix()));
-                let bytes = entity.serialize_for_cache()?;
-                let _ = self.backend.set(cache_key, bytes, ttl).await;
-                Ok(Some(entity))
+        impl DataRepository<TestEntity> for FailsOnRefresh {
+            async fn fetch_by_id(&self, id: &String) -> Result<Option<TestEntity>> {
+                if self.calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                    return Ok(Some(TestEntity {
+                        id: id.clone(),
+                        value: "original".to_string(),
+                    }));
+                }
+                Err(Error::RepositoryError("db down".to_string()))
             }
-            None => Ok(None),
         }
-    }
 
-    /// Invalidate strategy: Clear cache and refresh from database.
-    async fn strategy_invalidate<T: CacheEntity, R: DataRepository<T>>(
-        &self,
-        cache_key: &str,
-        repository: &R,
-        config: &OperationConfig,
-    ) -> Result<Option<T>>
-    where
-        T::Key: FromStr,
-    {
-        debug!("Executing Invalidate strategy for {}", cache_key);
+        let backend = InMemoryBackend::new();
+        let metrics = Arc::new(crate::observability::AtomicMetrics::new());
+        let expander = CacheExpander::new(backend).with_metrics(Box::new(Arc::clone(&metrics)));
+        let repo = Arc::new(FailsOnRefresh {
+            calls: AtomicUsize::new(0),
+        });
 
-        // Delete from cache
-        self.backend.delete(cache_key).await?;
-        debug!("✓ Cache invalidated for {}", cache_key);
+        // Zero stale_after: the miss-fill fetch populates the cache, and
+        // the very next call is already past soft TTL, triggering a
+        // background refresh that hits the repository's failing second call.
+        let config = OperationConfig::default().with_stale_after(Duration::from_secs(0));
+        let mut first = GenericFeeder::new("1".to_string());
+        expander
+            .with_stale_while_revalidate::<TestEntity, _, _>(&mut first, repo.clone(), config.clone())
+            .await
+            .expect("Failed to execute");
 
-        // Fetch fresh from database
-        let id = self.extract_id_from_key::<T>(cache_key)?;
-        match repository.fetch_by_id(&id).await? {
-            Some(entity) => {
-                // Re-populate cache
-                // Use config override if provided, otherwise use default TTL policy
-                let ttl = config
-                    .ttl_override
-                    .or_else(|| self.ttl_policy.get_ttl(T::cache_prefix()));
-                let bytes = entity.serialize_for_cache()?;
-                let _ = self.backend.set(cache_key, bytes, ttl).await;
-                Ok(Some(entity))
-            }
-            None => Ok(None),
-        }
-    }
+        let mut second = GenericFeeder::new("1".to_string());
+        expander
+            .with_stale_while_revalidate::<TestEntity, _, _>(&mut second, repo, config)
+            .await
+            .expect("Failed to execute");
+        // The stale value is still served to the caller even though the
+        // background refresh that it kicked off is about to fail.
+        assert_eq!(second.data.expect("Data not found").value, "original");
 
-    /// Bypass strategy: Skip cache, always hit database.
-    async fn strategy_bypass<T: CacheEntity, R: DataRepository<T>>(
-        &self,
-        cache_key: &str,
-        repository: &R,
-        config: &OperationConfig,
-    ) -> Result<Option<T>>
-    where
-        T::Key: FromStr,
-    {
-        debug!("Executing Bypass strategy for {}", cache_key);
-        debug!("Bypassing cache entirely for {}", cache_key);
+        // Give the detached background refresh task a chance to run and fail.
+        tokio::time::sleep(Duration::from_millis(50)).await;
 
-        // Fetch from database without checking cache
-        let id = self.extract_id_from_key::<T>(cache_key)?;
-        match repository.fetch_by_id(&id).await? {
-            Some(entity) => {
-                // Still populate cache for others
-                // Use config override if provided, otherwise use default TTL policy
-                let ttl = config
-                    .ttl_override
-                    .or_else(|| self.ttl_policy.get_ttl(T::cache_prefix()));
-                let bytes = entity.serialize_for_cache()?;
-                let _ = self.backend.set(cache_key, bytes, ttl).await;
-                Ok(Some(entity))
-            }
-            None => Ok(None),
-        }
+        let snapshot = metrics.snapshot();
+        assert_eq!(
+            snapshot.refresh_errors, 1,
+            "a failed background refresh should record through CacheMetrics::record_refresh_error"
+        );
+        assert_eq!(
+            snapshot.errors, 0,
+            "record_refresh_error is distinct from record_error - it's not the foreground operation that failed"
+        );
     }
 
-    /// Extract the ID portion from a cache key.
-    /// Format: "prefix:id" → "id"
-    fn extract_id_from_key<T: CacheEntity>(&self, cache_key: &str) -> Result<T::Key>
-    where
-        T::Key: FromStr,
-    {
-        let parts: Vec<&str> = cache_key.split(':').collect();
-        if parts.len() > 1 {
-            let id_str = parts[1..].join(":");
-            id_str.parse().ok().ok_or_else(|| {
-                Error::ValidationError(format!("Failed to parse ID from cache key: {}", cache_key))
-            })
-        } else {
-            Err(Error::ValidationError(format!(
-                "Invalid cache key format: {}",
-                cache_key
-            )))
-        }
-    }
+    #[tokio::test]
+    async fn test_stale_while_revalidate_background_refresh_success_records_refresh() {
+        let backend = InMemoryBackend::new();
+        let metrics = Arc::new(crate::observability::AtomicMetrics::new());
+        let expander = CacheExpander::new(backend).with_metrics(Box::new(Arc::clone(&metrics)));
+        let repo = Arc::new(SharedRepo::new());
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "original".to_string(),
+            },
+        );
 
-    /// Get backend reference (for advanced use).
-    pub fn backend(&self) -> &B {
-        &self.backend
-    }
+        let config = OperationConfig::default().with_stale_after(Duration::from_secs(0));
+        let mut first = GenericFeeder::new("1".to_string());
+        expander
+            .with_stale_while_revalidate::<TestEntity, _, _>(&mut first, repo.clone(), config.clone())
+            .await
+            .expect("Failed to execute");
 
-    /// Get mutable backend reference (for advanced use).
-    pub fn backend_mut(&mut self) -> &mut B {
-        &mut self.backend
-    }
-}
+        let mut second = GenericFeeder::new("1".to_string());
+        expander
+            .with_stale_while_revalidate::<TestEntity, _, _>(&mut second, repo, config)
+            .await
+            .expect("Failed to execute");
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::backend::InMemoryBackend;
-    use crate::feed::GenericFeeder;
-    use crate::repository::InMemoryRepository;
-    use serde::{Deserialize, Serialize};
+        tokio::time::sleep(Duration::from_millis(50)).await;
 
-    #[derive(Clone, Serialize, Deserialize)]
-    struct TestEntity {
-        id: String,
-        value: String,
+        assert_eq!(
+            metrics.snapshot().refreshes,
+            1,
+            "a successful background refresh should record through CacheMetrics::record_refresh"
+        );
     }
 
-    impl CacheEntity for TestEntity {
-        type Key = String;
+    #[tokio::test]
+    async fn test_stale_while_revalidate_evicts_on_refresh_error_when_configured() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
 
-        fn cache_key(&self) -> Self::Key {
-            self.id.clone()
+        struct FailsOnRefresh {
+            calls: AtomicUsize,
         }
 
-        fn cache_prefix() -> &'static str {
-            "test"
+        impl DataRepository<TestEntity> for FailsOnRefresh {
+            async fn fetch_by_id(&self, id: &String) -> Result<Option<TestEntity>> {
+                if self.calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                    return Ok(Some(TestEntity {
+                        id: id.clone(),
+                        value: "original".to_string(),
+                    }));
+                }
+                Err(Error::RepositoryError("db down".to_string()))
+            }
         }
-    }
 
-    #[tokio::test]
-    async fn test_expander_with_fresh_strategy_hit() {
         let backend = InMemoryBackend::new();
         let expander = CacheExpander::new(backend.clone());
+        let repo = Arc::new(FailsOnRefresh {
+            calls: AtomicUsize::new(0),
+        });
 
-        // Pre-populate cache
-        let entity = TestEntity {
-            id: "1".to_string(),
-            value: "data".to_string(),
-        };
-        let bytes = entity.serialize_for_cache().expect("Failed to serialize");
-        backend
-            .clone()
-            .set("test:1", bytes, None)
+        let config = OperationConfig::default()
+            .with_stale_after(Duration::from_secs(0))
+            .with_evict_on_refresh_error(true);
+        let mut first = GenericFeeder::new("1".to_string());
+        expander
+            .with_stale_while_revalidate::<TestEntity, _, _>(&mut first, repo.clone(), config.clone())
             .await
-            .expect("Failed to set");
-
-        // Create feeder
-        let mut feeder = GenericFeeder::new("1".to_string());
-        let repo = InMemoryRepository::new();
+            .expect("Failed to execute");
 
-        // Execute
+        let mut second = GenericFeeder::new("1".to_string());
         expander
-            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Fresh)
+            .with_stale_while_revalidate::<TestEntity, _, _>(&mut second, repo, config)
             .await
             .expect("Failed to execute");
+        assert_eq!(second.data.expect("Data not found").value, "original");
 
-        assert!(feeder.data.is_some());
+        // Give the detached background refresh task a chance to run, fail,
+        // and evict the now-untrustworthy stale entry.
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        assert!(
+            backend
+                .get("test:1")
+                .await
+                .expect("Failed to get")
+                .is_none(),
+            "evict_on_refresh_error should delete the stale entry after a failed refresh"
+        );
+    }
+
+    struct StaticSource {
+        data: HashMap<String, Vec<u8>>,
+    }
+
+    impl crate::streaming::StreamingDataSource for StaticSource {
+        async fn fetch(&self, key: &str) -> Result<Option<crate::streaming::CacheData>> {
+            Ok(self
+                .data
+                .get(key)
+                .map(|bytes| crate::streaming::CacheData::from_bytes(bytes.clone())))
+        }
     }
 
     #[tokio::test]
-    async fn test_expander_with_fresh_strategy_miss() {
-        let backend = InMemoryBackend::new();
-        let expander = CacheExpander::new(backend);
+    async fn test_expander_with_stream_miss_fetches_and_caches() {
+        use crate::streaming::GenericStreamingFeeder;
 
-        let mut feeder = GenericFeeder::new("1".to_string());
-        let repo = InMemoryRepository::new();
+        let backend = InMemoryBackend::new();
+        let expander = CacheExpander::new(backend.clone());
+        let source = StaticSource {
+            data: HashMap::from([("doc:1".to_string(), b"large payload".to_vec())]),
+        };
 
+        let mut feeder = GenericStreamingFeeder::new("doc:1".to_string());
         expander
-            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Fresh)
+            .with_stream(&mut feeder, &source, None)
             .await
             .expect("Failed to execute");
 
-        assert!(feeder.data.is_none());
+        let data = feeder.data.expect("Expected a payload");
+        assert_eq!(
+            data.into_bytes().await.expect("Failed to collect"),
+            b"large payload".to_vec()
+        );
+        assert!(backend
+            .get("doc:1:chunks")
+            .await
+            .expect("Failed to get")
+            .is_none());
+        // Default set_stream buffers into one `set()` call, so the manifest
+        // key is only used by backends that chunk natively.
+        assert_eq!(
+            backend.get("doc:1").await.expect("Failed to get"),
+            Some(b"large payload".to_vec())
+        );
     }
 
     #[tokio::test]
-    async fn test_expander_refresh_strategy_cache_hit() {
-        let backend = InMemoryBackend::new();
-        let expander = CacheExpander::new(backend.clone());
+    async fn test_expander_with_stream_hit_skips_source() {
+        use crate::streaming::GenericStreamingFeeder;
 
-        // Pre-populate cache
-        let entity = TestEntity {
-            id: "1".to_string(),
-            value: "cached_data".to_string(),
-        };
-        let bytes = entity.serialize_for_cache().expect("Failed to serialize");
+        let backend = InMemoryBackend::new();
         backend
-            .clone()
-            .set("test:1", bytes, None)
+            .set("doc:1", b"cached already".to_vec(), None)
             .await
             .expect("Failed to set");
+        let expander = CacheExpander::new(backend);
+        let source = StaticSource {
+            data: HashMap::from([("doc:1".to_string(), b"should not be fetched".to_vec())]),
+        };
 
-        let mut feeder = GenericFeeder::new("1".to_string());
-        let repo = InMemoryRepository::new();
-
+        let mut feeder = GenericStreamingFeeder::new("doc:1".to_string());
         expander
-            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .with_stream(&mut feeder, &source, None)
             .await
             .expect("Failed to execute");
 
-        assert!(feeder.data.is_some());
-        assert_eq!(feeder.data.expect("Data not found").value, "cached_data");
+        let data = feeder.data.expect("Expected a payload");
+        assert_eq!(
+            data.into_bytes().await.expect("Failed to collect"),
+            b"cached already".to_vec()
+        );
     }
 
     #[tokio::test]
-    async fn test_expander_refresh_strategy_cache_miss_db_hit() {
+    async fn test_expander_with_stream_miss_in_source_feeds_none() {
         let backend = InMemoryBackend::new();
-        let expander = CacheExpander::new(backend.clone());
-
-        // Populate repository
-        let mut repo = InMemoryRepository::new();
-        repo.insert(
-            "1".to_string(),
-            TestEntity {
-                id: "1".to_string(),
-                value: "db_data".to_string(),
-            },
-        );
-
-        let mut feeder = GenericFeeder::new("1".to_string());
+        let expander = CacheExpander::new(backend);
+        let source = StaticSource {
+            data: HashMap::new(),
+        };
 
+        let mut feeder = crate::streaming::GenericStreamingFeeder::new("missing".to_string());
         expander
-            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .with_stream(&mut feeder, &source, None)
             .await
             .expect("Failed to execute");
 
-        assert!(feeder.data.is_some());
-        assert_eq!(feeder.data.expect("Data not found").value, "db_data");
+        assert!(feeder.data.is_none());
+    }
 
-        // Verify it was cached
-        let cached = backend
-            .clone()
-            .get("test:1")
-            .await
-            .expect("Failed to get from cache");
-        assert!(cached.is_some());
+    /// Backend whose `get`/`set`/`delete` fail on demand, for exercising
+    /// `CacheRecoveryPolicy`. Mirrors `RecoveringBackend`'s own `FlakyBackend`
+    /// test helper in `backend::recovering`.
+    #[derive(Clone)]
+    struct FlakyBackend {
+        inner: InMemoryBackend,
+        failure: std::sync::Arc<std::sync::Mutex<crate::repository::FailurePolicy>>,
+    }
+
+    impl FlakyBackend {
+        fn new(failure: crate::repository::FailurePolicy) -> Self {
+            FlakyBackend {
+                inner: InMemoryBackend::new(),
+                failure: std::sync::Arc::new(std::sync::Mutex::new(failure)),
+            }
+        }
+
+        fn should_fail(&self) -> bool {
+            !matches!(
+                *self.failure.lock().expect("Lock poisoned"),
+                crate::repository::FailurePolicy::None
+            )
+        }
+    }
+
+    impl CacheBackend for FlakyBackend {
+        async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+            if self.should_fail() {
+                return Err(Error::BackendError("simulated outage".to_string()));
+            }
+            self.inner.get(key).await
+        }
+
+        async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<()> {
+            if self.should_fail() {
+                return Err(Error::BackendError("simulated outage".to_string()));
+            }
+            self.inner.set(key, value, ttl).await
+        }
+
+        async fn delete(&self, key: &str) -> Result<()> {
+            if self.should_fail() {
+                return Err(Error::BackendError("simulated outage".to_string()));
+            }
+            self.inner.delete(key).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recovery_policy_default_fails_propagates_backend_error() {
+        let backend = FlakyBackend::new(crate::repository::FailurePolicy::Always(
+            Error::BackendError("down".to_string()),
+        ));
+        let expander = CacheExpander::new(backend);
+
+        let mut feeder = GenericFeeder::new("1".to_string());
+        let repo = InMemoryRepository::new();
+
+        let result = expander
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Fresh)
+            .await;
+
+        assert!(matches!(result, Err(Error::BackendError(_))));
     }
 
     #[tokio::test]
-    async fn test_expander_refresh_strategy_complete_miss() {
-        let backend = InMemoryBackend::new();
-        let expander = CacheExpander::new(backend);
+    async fn test_recovery_policy_fallthrough_treats_get_error_as_miss() {
+        let backend = FlakyBackend::new(crate::repository::FailurePolicy::Always(
+            Error::BackendError("down".to_string()),
+        ));
+        let expander =
+            CacheExpander::new(backend).with_recovery_policy(CacheRecoveryPolicy::FallThrough);
 
-        let mut feeder = GenericFeeder::new("nonexistent".to_string());
+        let mut feeder = GenericFeeder::new("1".to_string());
         let repo = InMemoryRepository::new();
 
         expander
-            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Fresh)
             .await
-            .expect("Failed to execute");
+            .expect("FallThrough should treat a backend error as a miss, not fail");
 
         assert!(feeder.data.is_none());
     }
 
     #[tokio::test]
-    async fn test_expander_invalidate_strategy() {
-        let backend = InMemoryBackend::new();
-        let expander = CacheExpander::new(backend.clone());
-
-        // Pre-populate cache with stale data
-        let stale_entity = TestEntity {
-            id: "1".to_string(),
-            value: "stale_data".to_string(),
-        };
-        let bytes = stale_entity
-            .serialize_for_cache()
-            .expect("Failed to serialize");
-        backend
-            .clone()
-            .set("test:1", bytes, None)
-            .await
-            .expect("Failed to set");
+    async fn test_recovery_policy_fallthrough_falls_back_to_repository_on_refresh() {
+        let backend = FlakyBackend::new(crate::repository::FailurePolicy::Always(
+            Error::BackendError("down".to_string()),
+        ));
+        let expander =
+            CacheExpander::new(backend).with_recovery_policy(CacheRecoveryPolicy::FallThrough);
 
-        // Populate repository with fresh data
         let mut repo = InMemoryRepository::new();
         repo.insert(
             "1".to_string(),
             TestEntity {
                 id: "1".to_string(),
-                value: "fresh_data".to_string(),
+                value: "db_data".to_string(),
             },
         );
-
         let mut feeder = GenericFeeder::new("1".to_string());
 
         expander
-            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Invalidate)
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
             .await
-            .expect("Failed to execute");
-
-        assert!(feeder.data.is_some());
-        assert_eq!(feeder.data.expect("Data not found").value, "fresh_data");
+            .expect("FallThrough should still serve from the repository on a backend error");
 
-        // Verify cache was updated
-        let cached_bytes = backend
-            .clone()
-            .get("test:1")
-            .await
-            .expect("Failed to get")
-            .expect("Cache is empty");
-        let cached_entity =
-            TestEntity::deserialize_from_cache(&cached_bytes).expect("Failed to deserialize");
-        assert_eq!(cached_entity.value, "fresh_data");
+        assert_eq!(feeder.data.expect("Expected a value").value, "db_data");
     }
 
     #[tokio::test]
-    async fn test_expander_bypass_strategy() {
-        let backend = InMemoryBackend::new();
-        let expander = CacheExpander::new(backend.clone());
-
-        // Pre-populate cache
-        let cached_entity = TestEntity {
-            id: "1".to_string(),
-            value: "cached_data".to_string(),
-        };
-        let bytes = cached_entity
-            .serialize_for_cache()
-            .expect("Failed to serialize");
-        backend
-            .clone()
-            .set("test:1", bytes, None)
-            .await
-            .expect("Failed to set");
+    async fn test_recovery_policy_blackhole_trips_and_stays_tripped() {
+        let backend = FlakyBackend::new(crate::repository::FailurePolicy::Always(
+            Error::BackendError("down".to_string()),
+        ));
+        let expander =
+            CacheExpander::new(backend).with_recovery_policy(CacheRecoveryPolicy::BlackHole);
 
-        // Populate repository with different data
         let mut repo = InMemoryRepository::new();
         repo.insert(
             "1".to_string(),
@@ -741,168 +5810,390 @@ mod tests {
             },
         );
 
+        // First operation trips the backend on its failed get.
         let mut feeder = GenericFeeder::new("1".to_string());
-
         expander
-            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Bypass)
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
             .await
-            .expect("Failed to execute");
+            .expect("BlackHole should fall through instead of failing");
+        assert_eq!(feeder.data.expect("Expected a value").value, "db_data");
 
-        // Should get database data, not cached data
-        assert!(feeder.data.is_some());
-        assert_eq!(feeder.data.expect("Data not found").value, "db_data");
+        // A second operation never touches the backend again, even though
+        // nothing about the backend's own failure state changed.
+        let mut feeder = GenericFeeder::new("1".to_string());
+        expander
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Blackholed backend should keep falling through");
+        assert_eq!(feeder.data.expect("Expected a value").value, "db_data");
     }
 
     #[tokio::test]
-    async fn test_expander_with_ttl_policy() {
-        use crate::observability::TtlPolicy;
-        use std::time::Duration;
+    async fn test_operation_config_recovery_policy_overrides_expander_default() {
+        let backend = FlakyBackend::new(crate::repository::FailurePolicy::Always(
+            Error::BackendError("down".to_string()),
+        ));
+        // Expander default is `Fail`, but this one call opts into `FallThrough`.
+        let expander = CacheExpander::new(backend);
 
-        let backend = InMemoryBackend::new();
-        let expander = CacheExpander::new(backend.clone())
-            .with_ttl_policy(TtlPolicy::Fixed(Duration::from_secs(300)));
+        let mut feeder = GenericFeeder::new("1".to_string());
+        let repo = InMemoryRepository::new();
+        let config = OperationConfig::default().with_recovery_policy(CacheRecoveryPolicy::FallThrough);
+
+        expander
+            .with_config::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Fresh, config)
+            .await
+            .expect("Per-operation override should take precedence over the expander default");
+
+        assert!(feeder.data.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_recovery_policy_fallthrough_records_degraded_error_on_failed_set() {
+        let backend = FlakyBackend::new(crate::repository::FailurePolicy::Always(
+            Error::BackendError("down".to_string()),
+        ));
+        let metrics = Arc::new(crate::observability::AtomicMetrics::new());
+        let expander = CacheExpander::new(backend)
+            .with_recovery_policy(CacheRecoveryPolicy::FallThrough)
+            .with_metrics(Box::new(Arc::clone(&metrics)));
 
         let mut repo = InMemoryRepository::new();
         repo.insert(
             "1".to_string(),
             TestEntity {
                 id: "1".to_string(),
-                value: "data".to_string(),
+                value: "db_data".to_string(),
             },
         );
-
         let mut feeder = GenericFeeder::new("1".to_string());
 
         expander
             .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
             .await
-            .expect("Failed to execute");
+            .expect("FallThrough should still succeed overall");
 
-        assert!(feeder.data.is_some());
+        // One degraded read (the initial `get`) plus one degraded write (the
+        // repository-fetched entity failing to cache back).
+        assert!(metrics.snapshot().errors >= 2);
     }
 
     #[tokio::test]
-    async fn test_expander_with_custom_metrics() {
-        use crate::observability::CacheMetrics;
-        use std::sync::{Arc, Mutex};
-        use std::time::Duration;
+    async fn test_expander_coalescing_disabled_allows_independent_fetches() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
 
-        #[derive(Clone)]
-        struct TestMetrics {
-            hits: Arc<Mutex<usize>>,
-            misses: Arc<Mutex<usize>>,
+        struct CountingRepository {
+            calls: Arc<AtomicUsize>,
         }
 
-        impl CacheMetrics for TestMetrics {
-            fn record_hit(&self, _key: &str, _duration: Duration) {
-                *self.hits.lock().expect("Failed to lock hits") += 1;
+        impl DataRepository<TestEntity> for CountingRepository {
+            async fn fetch_by_id(&self, id: &String) -> Result<Option<TestEntity>> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                Ok(Some(TestEntity {
+                    id: id.clone(),
+                    value: "db_data".to_string(),
+                }))
             }
+        }
 
-            fn record_miss(&self, _key: &str, _duration: Duration) {
-                *self.misses.lock().expect("Failed to lock misses") += 1;
+        let backend = InMemoryBackend::new();
+        let expander = Arc::new(CacheExpander::new(backend));
+        let calls = Arc::new(AtomicUsize::new(0));
+        let repo = Arc::new(CountingRepository {
+            calls: calls.clone(),
+        });
+
+        let mut handles = Vec::new();
+        for _ in 0..5 {
+            let expander = expander.clone();
+            let repo = repo.clone();
+            handles.push(tokio::spawn(async move {
+                let mut feeder = GenericFeeder::<TestEntity>::new("1".to_string());
+                let config = OperationConfig::default().with_coalescing(false);
+                expander
+                    .with_config::<TestEntity, _, _>(
+                        &mut feeder,
+                        &*repo,
+                        CacheStrategy::Refresh,
+                        config,
+                    )
+                    .await
+                    .expect("Failed to execute");
+            }));
+        }
+
+        for handle in handles {
+            handle.await.expect("Task panicked");
+        }
+
+        assert_eq!(calls.load(Ordering::SeqCst), 5);
+    }
+
+    #[tokio::test]
+    async fn test_expander_invalidate_coalesces_concurrent_refetches() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        struct CountingRepository {
+            calls: Arc<AtomicUsize>,
+        }
+
+        impl DataRepository<TestEntity> for CountingRepository {
+            async fn fetch_by_id(&self, id: &String) -> Result<Option<TestEntity>> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                Ok(Some(TestEntity {
+                    id: id.clone(),
+                    value: "db_data".to_string(),
+                }))
             }
         }
 
-        let metrics = TestMetrics {
-            hits: Arc::new(Mutex::new(0)),
-            misses: Arc::new(Mutex::new(0)),
-        };
+        let backend = InMemoryBackend::new();
+        backend
+            .set("test:1", b"stale".to_vec(), None)
+            .await
+            .expect("Failed to pre-populate");
+        let expander = Arc::new(CacheExpander::new(backend));
+        let calls = Arc::new(AtomicUsize::new(0));
+        let repo = Arc::new(CountingRepository {
+            calls: calls.clone(),
+        });
+
+        let mut handles = Vec::new();
+        for _ in 0..5 {
+            let expander = expander.clone();
+            let repo = repo.clone();
+            handles.push(tokio::spawn(async move {
+                let mut feeder = GenericFeeder::<TestEntity>::new("1".to_string());
+                expander
+                    .with::<TestEntity, _, _>(&mut feeder, &*repo, CacheStrategy::Invalidate)
+                    .await
+                    .expect("Failed to execute");
+                feeder.data
+            }));
+        }
+
+        for handle in handles {
+            let data = handle.await.expect("Task panicked");
+            assert_eq!(data.expect("Data not found").value, "db_data");
+        }
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_expander_bypass_coalesces_concurrent_fetches() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        struct CountingRepository {
+            calls: Arc<AtomicUsize>,
+        }
+
+        impl DataRepository<TestEntity> for CountingRepository {
+            async fn fetch_by_id(&self, id: &String) -> Result<Option<TestEntity>> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                Ok(Some(TestEntity {
+                    id: id.clone(),
+                    value: "db_data".to_string(),
+                }))
+            }
+        }
 
         let backend = InMemoryBackend::new();
-        let expander = CacheExpander::new(backend.clone()).with_metrics(Box::new(metrics.clone()));
+        let expander = Arc::new(CacheExpander::new(backend));
+        let calls = Arc::new(AtomicUsize::new(0));
+        let repo = Arc::new(CountingRepository {
+            calls: calls.clone(),
+        });
+
+        let mut handles = Vec::new();
+        for _ in 0..5 {
+            let expander = expander.clone();
+            let repo = repo.clone();
+            handles.push(tokio::spawn(async move {
+                let mut feeder = GenericFeeder::<TestEntity>::new("1".to_string());
+                expander
+                    .with::<TestEntity, _, _>(&mut feeder, &*repo, CacheStrategy::Bypass)
+                    .await
+                    .expect("Failed to execute");
+                feeder.data
+            }));
+        }
+
+        for handle in handles {
+            let data = handle.await.expect("Task panicked");
+            assert_eq!(data.expect("Data not found").value, "db_data");
+        }
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_expander_bypass_always_hits_repository_when_uncontended() {
+        // Even with coalescing on, an uncontended Bypass call must never be
+        // served from a pre-existing cache entry - only a concurrently
+        // in-flight fetch's result is ever reused.
+        let backend = InMemoryBackend::new();
+        backend
+            .set("test:1", b"stale cached bytes that aren't valid cbor".to_vec(), None)
+            .await
+            .expect("Failed to pre-populate");
+        let expander = CacheExpander::new(backend);
 
-        // Populate repository
         let mut repo = InMemoryRepository::new();
         repo.insert(
             "1".to_string(),
             TestEntity {
                 id: "1".to_string(),
-                value: "data".to_string(),
+                value: "fresh_from_db".to_string(),
             },
         );
-
         let mut feeder = GenericFeeder::new("1".to_string());
 
-        // First call: cache miss, database hit
         expander
-            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Bypass)
             .await
             .expect("Failed to execute");
 
-        assert_eq!(*metrics.hits.lock().expect("Failed to lock hits"), 1); // Counted as hit after DB fetch
+        assert_eq!(feeder.data.expect("Expected a value").value, "fresh_from_db");
+    }
 
-        // Second call: cache hit
-        let mut feeder2 = GenericFeeder::new("1".to_string());
-        expander
-            .with::<TestEntity, _, _>(&mut feeder2, &repo, CacheStrategy::Refresh)
-            .await
-            .expect("Failed to execute");
+    /// Minimal token-versioned backend for exercising
+    /// [`CacheExpander::cas_update`] without a real Memcached server -
+    /// `cas_token` is just a per-key write counter, not a real memcached
+    /// unique id, but it has the same "changes on every write, mismatch means
+    /// someone else wrote first" contract.
+    #[derive(Clone, Default)]
+    struct CasBackend {
+        entries: std::sync::Arc<std::sync::Mutex<HashMap<String, (Vec<u8>, u64)>>>,
+    }
 
-        assert_eq!(*metrics.hits.lock().expect("Failed to lock hits"), 2);
+    impl CasBackend {
+        /// Write `value` directly, bypassing `cas_update`'s loop entirely -
+        /// stands in for some other process writing to the key mid-race.
+        fn steal_write(&self, key: &str, value: Vec<u8>) {
+            let mut entries = self.entries.lock().expect("Lock poisoned");
+            let token = entries.get(key).map_or(0, |(_, token)| token + 1);
+            entries.insert(key.to_string(), (value, token));
+        }
     }
 
-    #[tokio::test]
-    async fn test_expander_error_on_missing_data() {
-        let backend = InMemoryBackend::new();
-        let expander = CacheExpander::new(backend);
+    impl CacheBackend for CasBackend {
+        async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+            Ok(self
+                .entries
+                .lock()
+                .expect("Lock poisoned")
+                .get(key)
+                .map(|(data, _)| data.clone()))
+        }
 
-        let mut feeder = GenericFeeder::new("nonexistent".to_string());
-        let repo = InMemoryRepository::new();
+        async fn set(&self, key: &str, value: Vec<u8>, _ttl: Option<Duration>) -> Result<()> {
+            let mut entries = self.entries.lock().expect("Lock poisoned");
+            let token = entries.get(key).map_or(0, |(_, token)| token + 1);
+            entries.insert(key.to_string(), (value, token));
+            Ok(())
+        }
 
-        // Fresh strategy with miss should return None (not error)
-        let result = expander
-            .with::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Fresh)
-            .await;
-        assert!(result.is_ok());
-        assert!(feeder.data.is_none());
+        async fn delete(&self, key: &str) -> Result<()> {
+            self.entries.lock().expect("Lock poisoned").remove(key);
+            Ok(())
+        }
+
+        async fn gets(&self, key: &str) -> Result<Option<(Vec<u8>, u64)>> {
+            Ok(self.entries.lock().expect("Lock poisoned").get(key).cloned())
+        }
+
+        async fn cas(&self, key: &str, value: Vec<u8>, _ttl: Option<Duration>, cas_token: u64) -> Result<bool> {
+            let mut entries = self.entries.lock().expect("Lock poisoned");
+            match entries.get(key) {
+                Some((_, current_token)) if *current_token != cas_token => Ok(false),
+                _ => {
+                    entries.insert(key.to_string(), (value, cas_token + 1));
+                    Ok(true)
+                }
+            }
+        }
     }
 
     #[tokio::test]
-    async fn test_expander_backend_reference() {
-        let backend = InMemoryBackend::new();
+    async fn test_cas_update_retries_past_a_concurrent_writer() {
+        let backend = CasBackend::default();
+        backend
+            .set("counter", b"1".to_vec(), None)
+            .await
+            .expect("Failed to seed");
         let expander = CacheExpander::new(backend.clone());
 
-        // Test backend() method
-        let _backend_ref = expander.backend();
+        // Simulate a concurrent writer racing the first attempt: steal the
+        // token by writing once right after `cas_update`'s first `gets`.
+        let mut first_attempt = true;
+        expander
+            .cas_update("counter", None, 5, move |current| {
+                if first_attempt {
+                    first_attempt = false;
+                    backend.steal_write("counter", b"10".to_vec());
+                }
+                let n: u32 = String::from_utf8(current.expect("Expected a value"))
+                    .expect("Expected utf8")
+                    .parse()
+                    .expect("Expected a number");
+                (n + 1).to_string().into_bytes()
+            })
+            .await
+            .expect("cas_update should retry past the lost race");
 
-        // Verify we can access the backend
-        assert_eq!(backend.len().await, 0);
+        let stored = expander.backend().get("counter").await.expect("Failed to get");
+        assert_eq!(stored, Some(b"11".to_vec()));
     }
 
     #[tokio::test]
-    async fn test_expander_with_config() {
-        let backend = InMemoryBackend::new();
-        let expander = CacheExpander::new(backend.clone())
-            .with_ttl_policy(TtlPolicy::Fixed(Duration::from_secs(60)));
-
-        let mut repo = InMemoryRepository::new();
-        repo.insert(
-            "1".to_string(),
-            TestEntity {
-                id: "1".to_string(),
-                value: "test_value".to_string(),
-            },
-        );
+    async fn test_cas_update_writes_directly_on_a_miss() {
+        let expander = CacheExpander::new(CasBackend::default());
 
-        let mut feeder = GenericFeeder::new("1".to_string());
+        expander
+            .cas_update("new_key", None, 3, |current| {
+                assert!(current.is_none(), "key doesn't exist yet");
+                b"seeded".to_vec()
+            })
+            .await
+            .expect("Failed to cas_update a missing key");
 
-        // Test with_config() with TTL override and retry
-        let config = OperationConfig::default()
-            .with_ttl(Duration::from_secs(300))
-            .with_retry(2);
+        let stored = expander.backend().get("new_key").await.expect("Failed to get");
+        assert_eq!(stored, Some(b"seeded".to_vec()));
+    }
 
-        expander
-            .with_config::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh, config)
+    #[tokio::test]
+    async fn test_cas_update_gives_up_after_max_attempts() {
+        let backend = CasBackend::default();
+        backend
+            .set("hot_key", b"0".to_vec(), None)
             .await
-            .expect("Failed to execute with config");
+            .expect("Failed to seed");
+        let expander = CacheExpander::new(backend.clone());
 
-        assert!(feeder.data.is_some());
-        assert_eq!(feeder.data.expect("Data not found").value, "test_value");
+        // A modify closure that always loses: every call steals the token
+        // out from under the pending `cas` before it runs.
+        let result = expander
+            .cas_update("hot_key", None, 3, move |_current| {
+                backend.steal_write("hot_key", b"someone else wrote".to_vec());
+                b"never stored".to_vec()
+            })
+            .await;
 
-        // Verify that the original TTL policy wasn't mutated
-        match &expander.ttl_policy {
-            TtlPolicy::Fixed(duration) => assert_eq!(*duration, Duration::from_secs(60)),
-            _ => panic!("Expected Fixed TTL policy"),
-        }
+        assert!(matches!(result, Err(Error::BackendError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_cas_update_on_an_uncas_backend_returns_not_implemented() {
+        let expander = CacheExpander::new(InMemoryBackend::new());
+
+        let result = expander
+            .cas_update("key", None, 3, |_current| b"value".to_vec())
+            .await;
+
+        assert!(matches!(result, Err(Error::NotImplemented(_))));
     }
 }
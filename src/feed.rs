@@ -80,6 +80,101 @@ pub trait CacheFeed<T: CacheEntity>: Send {
     fn on_hit(&mut self, _key: &str) -> Result<()> {
         Ok(())
     }
+
+    /// Optional: Called when `key` is invalidated by a remote write seen
+    /// through `invalidation::CacheInvalidator`.
+    ///
+    /// A `CacheFeed` is normally a short-lived, per-operation object, so this
+    /// only fires for a feeder kept alive across operations (e.g. one owned
+    /// by a long-running subscriber) and registered with
+    /// `CacheInvalidator::with_callback` to receive it. Most feeders can
+    /// safely ignore this hook.
+    fn on_invalidated(&mut self, _key: &str) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Feeder trait for fetching many entities in a single round trip.
+///
+/// Where [`CacheFeed`] resolves one key through `CacheExpander::with`,
+/// `BatchCacheFeed` resolves a whole list of keys through
+/// `CacheExpander::with_batch` using a single backend `mget` and a single
+/// `DataRepository::fetch_by_ids` call for whatever misses remain, instead of
+/// one round trip per id.
+///
+/// # Example
+///
+/// ```no_run
+/// use cache_kit::{BatchCacheFeed, CacheEntity};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Clone, Serialize, Deserialize)]
+/// struct Employment {
+///     id: String,
+///     employer_name: String,
+/// }
+///
+/// impl CacheEntity for Employment {
+///     type Key = String;
+///     fn cache_key(&self) -> Self::Key { self.id.clone() }
+///     fn cache_prefix() -> &'static str { "employment" }
+/// }
+///
+/// struct EmploymentListFeeder {
+///     ids: Vec<String>,
+///     employments: Vec<(String, Option<Employment>)>,
+/// }
+///
+/// impl BatchCacheFeed<Employment> for EmploymentListFeeder {
+///     fn entity_ids(&mut self) -> Vec<String> {
+///         self.ids.clone()
+///     }
+///
+///     fn feed_batch(&mut self, results: Vec<(String, Option<Employment>)>) {
+///         self.employments = results;
+///     }
+/// }
+/// ```
+pub trait BatchCacheFeed<T: CacheEntity>: Send {
+    /// Return the entity IDs to fetch cache for, in the order results should
+    /// be returned in.
+    ///
+    /// Called first by the expander to determine which cache entries to fetch.
+    fn entity_ids(&mut self) -> Vec<T::Key>;
+
+    /// Feed the loaded entities into this feeder.
+    ///
+    /// Called by the expander once every id has been resolved, either from
+    /// cache or from the repository fallback. Order matches `entity_ids()`.
+    fn feed_batch(&mut self, results: Vec<(T::Key, Option<T>)>);
+
+    /// Optional: Validate the feeder before processing.
+    ///
+    /// Called before attempting the batch cache fetch. Use to validate state.
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Optional: Called after an entity is loaded but before returning.
+    ///
+    /// Invoked once per resolved entity, in the same way [`CacheFeed::on_loaded`] is.
+    fn on_loaded(&mut self, _entity: &T) -> Result<()> {
+        Ok(())
+    }
+
+    /// Optional: Called once per key that misses the cache.
+    ///
+    /// Useful for metrics or custom behavior.
+    fn on_miss(&mut self, _key: &str) -> Result<()> {
+        Ok(())
+    }
+
+    /// Optional: Called once per key that hits the cache.
+    ///
+    /// Useful for metrics or logging.
+    fn on_hit(&mut self, _key: &str) -> Result<()> {
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -108,6 +203,31 @@ impl<T: CacheEntity> CacheFeed<T> for GenericFeeder<T> {
     }
 }
 
+/// Generic feeder for a batch of entities.
+pub struct GenericBatchFeeder<T: CacheEntity> {
+    pub ids: Vec<T::Key>,
+    pub data: Vec<(T::Key, Option<T>)>,
+}
+
+impl<T: CacheEntity> GenericBatchFeeder<T> {
+    pub fn new(ids: Vec<T::Key>) -> Self {
+        GenericBatchFeeder {
+            ids,
+            data: Vec::new(),
+        }
+    }
+}
+
+impl<T: CacheEntity> BatchCacheFeed<T> for GenericBatchFeeder<T> {
+    fn entity_ids(&mut self) -> Vec<T::Key> {
+        self.ids.clone()
+    }
+
+    fn feed_batch(&mut self, results: Vec<(T::Key, Option<T>)>) {
+        self.data = results;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -276,4 +396,28 @@ mod tests {
         feeder.feed(None);
         assert!(feeder.data.is_none());
     }
+
+    #[test]
+    fn test_generic_batch_feeder() {
+        let mut feeder =
+            GenericBatchFeeder::<TestEntity>::new(vec!["1".to_string(), "2".to_string()]);
+
+        assert_eq!(feeder.entity_ids(), vec!["1".to_string(), "2".to_string()]);
+
+        let entity = TestEntity {
+            id: "1".to_string(),
+            value: "data".to_string(),
+        };
+        feeder.feed_batch(vec![("1".to_string(), Some(entity)), ("2".to_string(), None)]);
+
+        assert_eq!(feeder.data.len(), 2);
+        assert!(feeder.data[0].1.is_some());
+        assert!(feeder.data[1].1.is_none());
+    }
+
+    #[test]
+    fn test_batch_feeder_validation() {
+        let feeder: GenericBatchFeeder<TestEntity> = GenericBatchFeeder::new(vec![]);
+        assert!(feeder.validate().is_ok());
+    }
 }
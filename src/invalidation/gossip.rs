@@ -0,0 +1,509 @@
+//! UDP gossip-based cross-node cache invalidation.
+//!
+//! [`super::BroadcastInvalidationBus`] only reaches subscribers in the same
+//! process. [`GossipInvalidationBus`] is the cross-process answer for a
+//! cluster of peers that each own their own local-tier backend (e.g. one
+//! `InMemoryBackend` per pod) with no shared broker in front of them: a
+//! `CacheStrategy::Invalidate` on one node broadcasts a small UDP message to
+//! every configured peer, and each peer's receiver evicts the named key from
+//! its own backend - turning the single-node `Invalidate` strategy into a
+//! cluster-wide (best-effort) coherence mechanism.
+//!
+//! Delivery is unordered and unacknowledged, like any UDP-based protocol, so
+//! [`GossipMessage`] carries a per-node monotonic `logical_timestamp`:
+//! receivers drop a message that's no newer than the last one they've
+//! already applied for that key, so a reordered or duplicated packet can't
+//! resurrect a stale invalidation over a fresher one. Recently seen message
+//! ids are also tracked so a retransmitted or looping packet doesn't get
+//! applied (and re-broadcast) twice.
+
+use crate::error::{Error, Result};
+use crate::invalidation::{InvalidationBus, InvalidationEvent, InvalidationListenerHandle};
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::net::UdpSocket;
+use tokio::sync::{broadcast, watch};
+
+/// Largest UDP datagram this module expects a peer to send. Generously sized
+/// for a `GossipMessage` (namespace, key, a few integers); a message too
+/// large to fit would indicate a misbehaving peer, not a real invalidation.
+const MAX_DATAGRAM_SIZE: usize = 65536;
+
+/// Wire format broadcast to every configured peer on [`GossipInvalidationBus::publish`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GossipMessage {
+    /// Logical cache namespace this message belongs to - lets several
+    /// independent gossip clusters share the same peer addresses without
+    /// cross-applying each other's invalidations.
+    pub namespace: String,
+    /// Cache key to invalidate.
+    pub key: String,
+    /// Sender's per-node monotonic clock value at the time of this publish.
+    /// Receivers ignore a message whose timestamp is no newer than the last
+    /// one they've applied for `key`, so re-ordered or duplicate delivery
+    /// can't undo a fresher invalidation.
+    pub logical_timestamp: u64,
+    /// Identifies the sending node; paired with `sequence` for dedup. The
+    /// `logical_timestamp` staleness check does not use it: it compares
+    /// against the last timestamp applied for `key`, whichever node sent it.
+    pub node_id: u64,
+    /// Per-node sequence number, paired with `node_id` to dedupe a
+    /// retransmitted or looping packet.
+    pub sequence: u64,
+}
+
+/// Derive a node id from the bound address and process id. Not
+/// cryptographically unique, just enough to keep two nodes on the same host
+/// (or the same node across a quick restart) from colliding on `(node_id,
+/// sequence)` in practice.
+fn derive_node_id(bind_addr: SocketAddr) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    bind_addr.hash(&mut hasher);
+    std::process::id().hash(&mut hasher);
+    hasher.finish()
+}
+
+/// UDP gossip transport implementing [`InvalidationBus`], so
+/// `CacheExpander::with_invalidation_bus` and the `Invalidate` strategy work
+/// against it exactly as they do against [`super::BroadcastInvalidationBus`];
+/// the difference is purely in how `publish` fans invalidations out.
+///
+/// # Example
+///
+/// ```no_run
+/// use cache_kit::invalidation::gossip::GossipInvalidationBus;
+/// use cache_kit::invalidation::InvalidationBus;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let peers = vec!["10.0.0.2:9000".parse().unwrap(), "10.0.0.3:9000".parse().unwrap()];
+/// let bus = GossipInvalidationBus::bind("0.0.0.0:9000".parse().unwrap(), peers, "my-cache")
+///     .await
+///     .expect("Failed to bind gossip socket");
+/// let _receiver = bus.spawn_receiver();
+///
+/// bus.publish("user:42"); // fans out to every configured peer
+/// # }
+/// ```
+pub struct GossipInvalidationBus {
+    socket: Arc<UdpSocket>,
+    peers: Vec<SocketAddr>,
+    namespace: String,
+    node_id: u64,
+    logical_clock: AtomicU64,
+    sequence: AtomicU64,
+    /// Highest `logical_timestamp` applied per key, local or remote, so a
+    /// late or duplicate message for that key can be told apart from a
+    /// fresher one.
+    last_seen: Arc<Mutex<HashMap<String, u64>>>,
+    /// Recently seen `(node_id, sequence)` pairs, bounded FIFO, to damp
+    /// retransmitted or looping packets.
+    seen_message_ids: Arc<Mutex<VecDeque<(u64, u64)>>>,
+    dedup_capacity: usize,
+    sender: broadcast::Sender<InvalidationEvent>,
+    history: Arc<Mutex<VecDeque<InvalidationEvent>>>,
+    history_capacity: usize,
+}
+
+impl GossipInvalidationBus {
+    /// Bind a UDP socket at `bind_addr` and gossip invalidations to `peers`
+    /// under `namespace`. Retains the last 64 events for `replay()` and the
+    /// last 1024 message ids for dedup; see [`Self::with_dedup_capacity`].
+    ///
+    /// # Errors
+    /// Returns `Err` if the socket fails to bind (e.g. `bind_addr` already in use).
+    pub async fn bind(
+        bind_addr: SocketAddr,
+        peers: Vec<SocketAddr>,
+        namespace: impl Into<String>,
+    ) -> Result<Self> {
+        let socket = UdpSocket::bind(bind_addr)
+            .await
+            .map_err(|e| Error::BackendError(format!("Gossip invalidation bus failed to bind {}: {}", bind_addr, e)))?;
+        let (sender, _) = broadcast::channel(64);
+
+        Ok(GossipInvalidationBus {
+            socket: Arc::new(socket),
+            peers,
+            namespace: namespace.into(),
+            node_id: derive_node_id(bind_addr),
+            logical_clock: AtomicU64::new(0),
+            sequence: AtomicU64::new(0),
+            last_seen: Arc::new(Mutex::new(HashMap::new())),
+            seen_message_ids: Arc::new(Mutex::new(VecDeque::new())),
+            dedup_capacity: 1024,
+            sender,
+            history: Arc::new(Mutex::new(VecDeque::new())),
+            history_capacity: 64,
+        })
+    }
+
+    /// Retain up to `capacity` recently seen message ids for dedup, instead
+    /// of the default 1024.
+    pub fn with_dedup_capacity(mut self, capacity: usize) -> Self {
+        self.dedup_capacity = capacity.max(1);
+        self
+    }
+
+    /// Spawn a background task that applies incoming gossip messages from
+    /// peers to this bus's subscribers (and `replay()` history), the same
+    /// as a locally published invalidation.
+    ///
+    /// Returns a handle that can be shut down cleanly or awaited; see
+    /// [`InvalidationListenerHandle`]. Wire the same bus into
+    /// `CacheExpander::with_invalidation_bus` and a
+    /// `crate::invalidation::CacheInvalidator` subscribed to it to actually
+    /// evict a local backend on receipt.
+    pub fn spawn_receiver(&self) -> InvalidationListenerHandle {
+        let socket = self.socket.clone();
+        let namespace = self.namespace.clone();
+        let last_seen = self.last_seen.clone();
+        let seen_message_ids = self.seen_message_ids.clone();
+        let dedup_capacity = self.dedup_capacity;
+        let sender = self.sender.clone();
+        let history = self.history.clone();
+        let history_capacity = self.history_capacity;
+        let (shutdown_tx, mut shutdown_rx) = watch::channel(false);
+
+        let task = tokio::spawn(async move {
+            let mut buf = vec![0u8; MAX_DATAGRAM_SIZE];
+            loop {
+                tokio::select! {
+                    _ = shutdown_rx.changed() => {
+                        if *shutdown_rx.borrow() {
+                            debug!("Gossip invalidation receiver shutting down");
+                            break;
+                        }
+                    }
+                    result = socket.recv_from(&mut buf) => {
+                        match result {
+                            Ok((len, from)) => {
+                                apply_gossip_datagram(
+                                    &buf[..len],
+                                    from,
+                                    &namespace,
+                                    &last_seen,
+                                    &seen_message_ids,
+                                    dedup_capacity,
+                                    &sender,
+                                    &history,
+                                    history_capacity,
+                                );
+                            }
+                            Err(e) => {
+                                warn!("Gossip invalidation receive failed: {}", e);
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        InvalidationListenerHandle::new(task, shutdown_tx)
+    }
+}
+
+impl InvalidationBus for GossipInvalidationBus {
+    fn publish(&self, key: &str) {
+        let logical_timestamp = self.logical_clock.fetch_add(1, Ordering::SeqCst) + 1;
+        let sequence = self.sequence.fetch_add(1, Ordering::SeqCst);
+
+        record_applied(
+            key,
+            logical_timestamp,
+            &self.last_seen,
+            &self.sender,
+            &self.history,
+            self.history_capacity,
+        );
+
+        let message = GossipMessage {
+            namespace: self.namespace.clone(),
+            key: key.to_string(),
+            logical_timestamp,
+            node_id: self.node_id,
+            sequence,
+        };
+        let Ok(payload) = serde_json::to_vec(&message) else {
+            warn!("Failed to serialize gossip invalidation for {}", key);
+            return;
+        };
+
+        for peer in &self.peers {
+            let socket = self.socket.clone();
+            let payload = payload.clone();
+            let peer = *peer;
+            let key = key.to_string();
+            tokio::spawn(async move {
+                if let Err(e) = socket.send_to(&payload, peer).await {
+                    warn!("Failed to gossip invalidation for {} to {}: {}", key, peer, e);
+                }
+            });
+        }
+        debug!("✓ Gossiped invalidation for {} to {} peer(s)", key, self.peers.len());
+    }
+
+    fn subscribe(&self) -> broadcast::Receiver<InvalidationEvent> {
+        self.sender.subscribe()
+    }
+
+    fn replay(&self) -> Vec<InvalidationEvent> {
+        self.history.lock().expect("lock poisoned").iter().cloned().collect()
+    }
+}
+
+/// Record `key` as invalidated as of `logical_timestamp` - updates
+/// `last_seen`, then surfaces it on `sender`/`history` the same way a local
+/// [`super::BroadcastInvalidationBus`] publish does, so any
+/// `crate::invalidation::CacheInvalidator` subscribed to this bus evicts it
+/// regardless of whether the invalidation originated locally or over gossip.
+#[allow(clippy::too_many_arguments)]
+fn record_applied(
+    key: &str,
+    logical_timestamp: u64,
+    last_seen: &Arc<Mutex<HashMap<String, u64>>>,
+    sender: &broadcast::Sender<InvalidationEvent>,
+    history: &Arc<Mutex<VecDeque<InvalidationEvent>>>,
+    history_capacity: usize,
+) {
+    last_seen
+        .lock()
+        .expect("lock poisoned")
+        .insert(key.to_string(), logical_timestamp);
+
+    let event = InvalidationEvent {
+        key: key.to_string(),
+        sequence: logical_timestamp,
+    };
+
+    {
+        let mut history = history.lock().expect("lock poisoned");
+        history.push_back(event.clone());
+        while history.len() > history_capacity {
+            history.pop_front();
+        }
+    }
+
+    // Err here just means there are currently no subscribers - not a failure.
+    let _ = sender.send(event);
+}
+
+/// Parse and apply one incoming UDP datagram: drop it silently if it's
+/// malformed, for another namespace, already seen, or no newer than the
+/// last invalidation applied for its key - otherwise record it the same as
+/// a local publish.
+#[allow(clippy::too_many_arguments)]
+fn apply_gossip_datagram(
+    datagram: &[u8],
+    from: SocketAddr,
+    namespace: &str,
+    last_seen: &Arc<Mutex<HashMap<String, u64>>>,
+    seen_message_ids: &Arc<Mutex<VecDeque<(u64, u64)>>>,
+    dedup_capacity: usize,
+    sender: &broadcast::Sender<InvalidationEvent>,
+    history: &Arc<Mutex<VecDeque<InvalidationEvent>>>,
+    history_capacity: usize,
+) {
+    let message: GossipMessage = match serde_json::from_slice(datagram) {
+        Ok(message) => message,
+        Err(e) => {
+            warn!("Discarding malformed gossip datagram from {}: {}", from, e);
+            return;
+        }
+    };
+
+    if message.namespace != namespace {
+        debug!("Ignoring gossip message for namespace {} (from {})", message.namespace, from);
+        return;
+    }
+
+    {
+        let mut seen = seen_message_ids.lock().expect("lock poisoned");
+        let id = (message.node_id, message.sequence);
+        if seen.contains(&id) {
+            debug!("⏭ Gossip message {:?} from {} already seen, dropping", id, from);
+            return;
+        }
+        seen.push_back(id);
+        while seen.len() > dedup_capacity {
+            seen.pop_front();
+        }
+    }
+
+    {
+        let mut last_seen = last_seen.lock().expect("lock poisoned");
+        if let Some(&last) = last_seen.get(&message.key) {
+            if message.logical_timestamp <= last {
+                debug!(
+                    "⏭ Gossip invalidation for {} from {} is stale ({} <= {}), dropping",
+                    message.key, from, message.logical_timestamp, last
+                );
+                return;
+            }
+        }
+        last_seen.insert(message.key.clone(), message.logical_timestamp);
+    }
+
+    let event = InvalidationEvent {
+        key: message.key.clone(),
+        sequence: message.logical_timestamp,
+    };
+
+    {
+        let mut history = history.lock().expect("lock poisoned");
+        history.push_back(event.clone());
+        while history.len() > history_capacity {
+            history.pop_front();
+        }
+    }
+
+    let _ = sender.send(event);
+    debug!("✓ Applied gossip invalidation for {} from {}", message.key, from);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{CacheBackend, InMemoryBackend};
+    use crate::invalidation::CacheInvalidator;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_gossip_invalidation_reaches_peer_backend() {
+        let addr_a: SocketAddr = "127.0.0.1:0".parse().unwrap();
+        let addr_b: SocketAddr = "127.0.0.1:0".parse().unwrap();
+
+        // Bind the peer first so we know the port the OS assigned it.
+        let bus_b = GossipInvalidationBus::bind(addr_b, vec![], "test")
+            .await
+            .expect("Failed to bind bus_b");
+        let peer_b_addr = bus_b.socket.local_addr().expect("Failed to read local addr");
+
+        let bus_a = GossipInvalidationBus::bind(addr_a, vec![peer_b_addr], "test")
+            .await
+            .expect("Failed to bind bus_a");
+
+        let backend_b = InMemoryBackend::new();
+        backend_b.set("user:42", vec![1, 2, 3], None).await.expect("Failed to set");
+
+        let receiver_b = bus_b.spawn_receiver();
+        let bus_b: Arc<dyn InvalidationBus> = Arc::new(bus_b);
+        let invalidator_b = CacheInvalidator::new(backend_b.clone(), bus_b).spawn();
+
+        bus_a.publish("user:42");
+
+        for _ in 0..50 {
+            if backend_b.get("user:42").await.expect("Failed to get").is_none() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        assert!(
+            backend_b.get("user:42").await.expect("Failed to get").is_none(),
+            "peer's backend should have been invalidated via gossip"
+        );
+
+        invalidator_b.shutdown().await.expect("Invalidator should shut down cleanly");
+        receiver_b.shutdown().await.expect("Receiver should shut down cleanly");
+    }
+
+    #[tokio::test]
+    async fn test_stale_logical_timestamp_is_dropped() {
+        let addr: SocketAddr = "127.0.0.1:0".parse().unwrap();
+        let bus = GossipInvalidationBus::bind(addr, vec![], "test")
+            .await
+            .expect("Failed to bind bus");
+        let mut rx = bus.subscribe();
+
+        apply_gossip_datagram(
+            &serde_json::to_vec(&GossipMessage {
+                namespace: "test".to_string(),
+                key: "k".to_string(),
+                logical_timestamp: 5,
+                node_id: 1,
+                sequence: 0,
+            })
+            .unwrap(),
+            addr,
+            "test",
+            &bus.last_seen,
+            &bus.seen_message_ids,
+            bus.dedup_capacity,
+            &bus.sender,
+            &bus.history,
+            bus.history_capacity,
+        );
+        assert_eq!(rx.try_recv().unwrap().key, "k");
+
+        // Older timestamp for the same key should be dropped.
+        apply_gossip_datagram(
+            &serde_json::to_vec(&GossipMessage {
+                namespace: "test".to_string(),
+                key: "k".to_string(),
+                logical_timestamp: 3,
+                node_id: 1,
+                sequence: 1,
+            })
+            .unwrap(),
+            addr,
+            "test",
+            &bus.last_seen,
+            &bus.seen_message_ids,
+            bus.dedup_capacity,
+            &bus.sender,
+            &bus.history,
+            bus.history_capacity,
+        );
+        assert!(rx.try_recv().is_err(), "stale timestamp should not be applied");
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_message_id_is_dropped() {
+        let addr: SocketAddr = "127.0.0.1:0".parse().unwrap();
+        let bus = GossipInvalidationBus::bind(addr, vec![], "test")
+            .await
+            .expect("Failed to bind bus");
+        let mut rx = bus.subscribe();
+
+        let message = GossipMessage {
+            namespace: "test".to_string(),
+            key: "k".to_string(),
+            logical_timestamp: 1,
+            node_id: 7,
+            sequence: 0,
+        };
+        let payload = serde_json::to_vec(&message).unwrap();
+
+        apply_gossip_datagram(
+            &payload,
+            addr,
+            "test",
+            &bus.last_seen,
+            &bus.seen_message_ids,
+            bus.dedup_capacity,
+            &bus.sender,
+            &bus.history,
+            bus.history_capacity,
+        );
+        apply_gossip_datagram(
+            &payload,
+            addr,
+            "test",
+            &bus.last_seen,
+            &bus.seen_message_ids,
+            bus.dedup_capacity,
+            &bus.sender,
+            &bus.history,
+            bus.history_capacity,
+        );
+
+        assert_eq!(rx.try_recv().unwrap().key, "k");
+        assert!(rx.try_recv().is_err(), "duplicate message id should not be applied twice");
+    }
+}
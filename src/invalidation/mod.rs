@@ -0,0 +1,523 @@
+//! Cross-instance cache invalidation broadcast.
+//!
+//! Each process normally only invalidates its own cache backend. When
+//! multiple instances share a logical cache (e.g. one `InMemoryBackend` per
+//! pod), an invalidation in one process needs to reach the others too,
+//! instead of leaving them to serve stale data until TTL expiry.
+//! `InvalidationBus` is the pluggable extension point for that; wire an
+//! implementation into `CacheExpander::with_invalidation_bus` and the
+//! `Invalidate` strategy publishes to it automatically.
+//!
+//! [`BroadcastInvalidationBus`] is the in-process default, built on
+//! `tokio::sync::broadcast`. A Redis pub/sub or NATS-backed implementation
+//! would implement the same trait to fan invalidations out across processes;
+//! [`gossip::GossipInvalidationBus`] is one such implementation, over plain
+//! UDP.
+
+use crate::backend::CacheBackend;
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, watch};
+
+#[cfg(feature = "postgres")]
+pub mod postgres;
+#[cfg(feature = "postgres")]
+pub use postgres::{migration_sql, PgInvalidationBus, PgInvalidator};
+
+pub mod gossip;
+
+/// A single cache-key invalidation, as seen on the bus.
+///
+/// `Serialize`/`Deserialize` so a cross-process bus (e.g. a Redis pub/sub
+/// implementation) can use this as its wire format directly.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InvalidationEvent {
+    /// Cache key to invalidate.
+    pub key: String,
+    /// Monotonically increasing sequence number, for ordering and replay.
+    pub sequence: u64,
+}
+
+/// Trait for broadcasting and receiving cache invalidations across instances.
+///
+/// Implement this to plug in a different transport (Redis pub/sub, NATS,
+/// etc); [`BroadcastInvalidationBus`] is the in-process default.
+pub trait InvalidationBus: Send + Sync {
+    /// Announce that `key` should be invalidated everywhere.
+    ///
+    /// Implementations should suppress redundant re-broadcasts of the same
+    /// key in quick succession, since callers may invalidate the same hot
+    /// key repeatedly without coordinating.
+    fn publish(&self, key: &str);
+
+    /// Subscribe to future invalidations.
+    fn subscribe(&self) -> broadcast::Receiver<InvalidationEvent>;
+
+    /// Return invalidations published before this subscriber joined, so a
+    /// late-starting instance can catch up instead of trusting cache entries
+    /// that were invalidated elsewhere while it was down.
+    fn replay(&self) -> Vec<InvalidationEvent>;
+}
+
+/// In-process `tokio::sync::broadcast`-backed invalidation bus.
+///
+/// # Example
+///
+/// ```
+/// use cache_kit::invalidation::{BroadcastInvalidationBus, InvalidationBus};
+///
+/// let bus = BroadcastInvalidationBus::new(16);
+/// let mut rx = bus.subscribe();
+/// bus.publish("user:42");
+///
+/// let event = rx.try_recv().expect("event should be queued");
+/// assert_eq!(event.key, "user:42");
+/// ```
+pub struct BroadcastInvalidationBus {
+    sender: broadcast::Sender<InvalidationEvent>,
+    sequence: AtomicU64,
+    /// Recent events kept around so late subscribers can `replay()` them.
+    history: Mutex<VecDeque<InvalidationEvent>>,
+    history_capacity: usize,
+    /// Last time each key was published, to suppress redundant re-broadcasts.
+    last_published: Mutex<HashMap<String, Instant>>,
+    suppress_window: Duration,
+}
+
+impl BroadcastInvalidationBus {
+    /// Create a bus that retains the last `history_capacity` events for
+    /// `replay()`, suppressing re-broadcasts of the same key within 50ms.
+    pub fn new(history_capacity: usize) -> Self {
+        Self::with_suppress_window(history_capacity, Duration::from_millis(50))
+    }
+
+    /// Like `new`, with an explicit redundant-broadcast suppression window.
+    pub fn with_suppress_window(history_capacity: usize, suppress_window: Duration) -> Self {
+        let (sender, _) = broadcast::channel(history_capacity.max(1));
+        BroadcastInvalidationBus {
+            sender,
+            sequence: AtomicU64::new(0),
+            history: Mutex::new(VecDeque::with_capacity(history_capacity)),
+            history_capacity,
+            last_published: Mutex::new(HashMap::new()),
+            suppress_window,
+        }
+    }
+}
+
+impl InvalidationBus for BroadcastInvalidationBus {
+    fn publish(&self, key: &str) {
+        {
+            let mut last_published = self.last_published.lock().expect("lock poisoned");
+            if let Some(last) = last_published.get(key) {
+                if last.elapsed() < self.suppress_window {
+                    debug!("⏭ Invalidation for {} suppressed (recently broadcast)", key);
+                    return;
+                }
+            }
+            last_published.insert(key.to_string(), Instant::now());
+        }
+
+        let sequence = self.sequence.fetch_add(1, Ordering::SeqCst);
+        let event = InvalidationEvent {
+            key: key.to_string(),
+            sequence,
+        };
+
+        {
+            let mut history = self.history.lock().expect("lock poisoned");
+            history.push_back(event.clone());
+            while history.len() > self.history_capacity {
+                history.pop_front();
+            }
+        }
+
+        // Err here just means there are currently no subscribers - not a failure.
+        let _ = self.sender.send(event);
+        debug!("✓ Invalidation broadcast for {}", key);
+    }
+
+    fn subscribe(&self) -> broadcast::Receiver<InvalidationEvent> {
+        self.sender.subscribe()
+    }
+
+    fn replay(&self) -> Vec<InvalidationEvent> {
+        self.history
+            .lock()
+            .expect("lock poisoned")
+            .iter()
+            .cloned()
+            .collect()
+    }
+}
+
+/// Handle to a listener task spawned by [`CacheInvalidator::spawn`].
+///
+/// Drop it and the listener keeps running in the background (same as any
+/// other detached `tokio::spawn`); call [`Self::shutdown`] to stop it
+/// deliberately, or [`Self::join`] to wait for it to exit on its own (e.g.
+/// because the bus was dropped).
+pub struct InvalidationListenerHandle {
+    task: tokio::task::JoinHandle<()>,
+    shutdown: watch::Sender<bool>,
+}
+
+impl InvalidationListenerHandle {
+    /// Build a handle around an already-spawned listener task, for other
+    /// transports (e.g. the Postgres `LISTEN/NOTIFY`-backed invalidator
+    /// behind the `postgres` feature) that want the same shutdown/join API
+    /// as [`CacheInvalidator`].
+    pub(crate) fn new(task: tokio::task::JoinHandle<()>, shutdown: watch::Sender<bool>) -> Self {
+        InvalidationListenerHandle { task, shutdown }
+    }
+
+    /// Signal the listener to stop and wait for it to exit.
+    ///
+    /// # Errors
+    /// Returns `Err` if the listener task panicked.
+    pub async fn shutdown(self) -> Result<()> {
+        let _ = self.shutdown.send(true);
+        self.task
+            .await
+            .map_err(|e| Error::BackendError(format!("invalidation listener panicked: {e}")))
+    }
+
+    /// Wait for the listener task to exit without requesting shutdown.
+    ///
+    /// # Errors
+    /// Returns `Err` if the listener task panicked.
+    pub async fn join(self) -> Result<()> {
+        self.task
+            .await
+            .map_err(|e| Error::BackendError(format!("invalidation listener panicked: {e}")))
+    }
+}
+
+/// Drives a local backend from a remote [`InvalidationBus`], so a write on
+/// one instance evicts the entry on every other instance sharing the same
+/// logical cache instead of leaving them to serve it until TTL expiry.
+///
+/// This is the consumer side of the bus `CacheExpander::with_invalidation_bus`
+/// publishes to; wire one of these up per instance that owns its own backend
+/// (e.g. one `InMemoryBackend` per pod behind a shared `BroadcastInvalidationBus`,
+/// or a Redis-backed bus shared across processes).
+///
+/// # Example
+///
+/// ```no_run
+/// use cache_kit::backend::InMemoryBackend;
+/// use cache_kit::invalidation::{BroadcastInvalidationBus, CacheInvalidator, InvalidationBus};
+/// use std::sync::Arc;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let bus: Arc<dyn InvalidationBus> = Arc::new(BroadcastInvalidationBus::new(16));
+/// let backend = InMemoryBackend::new();
+///
+/// let listener = CacheInvalidator::new(backend, bus.clone()).spawn();
+/// bus.publish("user:42");
+///
+/// listener.shutdown().await.expect("listener should shut down cleanly");
+/// # }
+/// ```
+pub struct CacheInvalidator<B: CacheBackend> {
+    backend: B,
+    bus: Arc<dyn InvalidationBus>,
+    callback: Option<Arc<dyn Fn(&str) + Send + Sync>>,
+    /// If set, only events whose key starts with `"{prefix}:"` are applied;
+    /// others are dropped. See `with_prefix_filter`.
+    prefix_filter: Option<String>,
+}
+
+impl<B: CacheBackend + 'static> CacheInvalidator<B> {
+    /// Create an invalidator that applies events from `bus` to `backend`.
+    pub fn new(backend: B, bus: Arc<dyn InvalidationBus>) -> Self {
+        CacheInvalidator {
+            backend,
+            bus,
+            callback: None,
+            prefix_filter: None,
+        }
+    }
+
+    /// Only apply events for keys under `prefix` (i.e. `T::cache_prefix()`
+    /// for some entity `T`), dropping everything else.
+    ///
+    /// Use this when an instance only caches a subset of entity types and
+    /// shares a bus with instances caching others, so it doesn't pay to
+    /// delete keys it never holds.
+    pub fn with_prefix_filter(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix_filter = Some(prefix.into());
+        self
+    }
+
+    /// Register a callback invoked with the invalidated key after the entry
+    /// is removed from the backend.
+    ///
+    /// Use this to drive a long-lived `CacheFeed::on_invalidated` (or any
+    /// other reactive consumer) from remote invalidations.
+    pub fn with_callback<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.callback = Some(Arc::new(callback));
+        self
+    }
+
+    /// Spawn a background task that deletes the invalidated key from this
+    /// instance's backend on every event seen on `bus`.
+    ///
+    /// Returns a handle that can be shut down cleanly or awaited; see
+    /// [`InvalidationListenerHandle`].
+    pub fn spawn(self) -> InvalidationListenerHandle {
+        let mut rx = self.bus.subscribe();
+        let (shutdown_tx, mut shutdown_rx) = watch::channel(false);
+        let backend = self.backend;
+        let callback = self.callback;
+        let prefix_filter = self.prefix_filter.map(|p| format!("{}:", p));
+
+        let task = tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = shutdown_rx.changed() => {
+                        if *shutdown_rx.borrow() {
+                            debug!("Cache invalidation listener shutting down");
+                            break;
+                        }
+                    }
+                    event = rx.recv() => {
+                        match event {
+                            Ok(event) => {
+                                if let Some(prefix) = &prefix_filter {
+                                    if !event.key.starts_with(prefix.as_str()) {
+                                        continue;
+                                    }
+                                }
+                                if let Err(e) = backend.delete(&event.key).await {
+                                    warn!("Failed to apply remote invalidation for {}: {}", event.key, e);
+                                    continue;
+                                }
+                                debug!("✓ Applied remote invalidation for {}", event.key);
+                                if let Some(cb) = &callback {
+                                    cb(&event.key);
+                                }
+                            }
+                            Err(broadcast::error::RecvError::Closed) => break,
+                            Err(broadcast::error::RecvError::Lagged(n)) => {
+                                warn!("Invalidation listener lagged by {} events, some invalidations were missed", n);
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        InvalidationListenerHandle {
+            task,
+            shutdown: shutdown_tx,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_publish_subscribe_roundtrip() {
+        let bus = BroadcastInvalidationBus::new(16);
+        let mut rx = bus.subscribe();
+
+        bus.publish("user:1");
+
+        let event = rx.try_recv().expect("event should be queued");
+        assert_eq!(event.key, "user:1");
+        assert_eq!(event.sequence, 0);
+    }
+
+    #[test]
+    fn test_sequence_increments_across_publishes() {
+        let bus = BroadcastInvalidationBus::new(16);
+        let mut rx = bus.subscribe();
+
+        bus.publish("a");
+        bus.publish("b");
+
+        assert_eq!(rx.try_recv().unwrap().sequence, 0);
+        assert_eq!(rx.try_recv().unwrap().sequence, 1);
+    }
+
+    #[test]
+    fn test_replay_returns_history_for_late_subscriber() {
+        let bus = BroadcastInvalidationBus::new(16);
+
+        bus.publish("a");
+        bus.publish("b");
+
+        // Subscribing after the fact misses the live broadcast...
+        let mut rx = bus.subscribe();
+        assert!(rx.try_recv().is_err());
+
+        // ...but replay() still has it.
+        let replayed = bus.replay();
+        assert_eq!(replayed.len(), 2);
+        assert_eq!(replayed[0].key, "a");
+        assert_eq!(replayed[1].key, "b");
+    }
+
+    #[test]
+    fn test_replay_is_capped_at_history_capacity() {
+        let bus = BroadcastInvalidationBus::new(2);
+
+        bus.publish("a");
+        bus.publish("b");
+        bus.publish("c");
+
+        let replayed = bus.replay();
+        assert_eq!(replayed.len(), 2);
+        assert_eq!(replayed[0].key, "b");
+        assert_eq!(replayed[1].key, "c");
+    }
+
+    #[test]
+    fn test_redundant_broadcast_suppressed_within_window() {
+        let bus = BroadcastInvalidationBus::with_suppress_window(16, Duration::from_secs(60));
+        let mut rx = bus.subscribe();
+
+        bus.publish("hot_key");
+        bus.publish("hot_key"); // Should be suppressed - too soon after the first.
+
+        assert_eq!(rx.try_recv().unwrap().key, "hot_key");
+        assert!(rx.try_recv().is_err(), "second publish should be suppressed");
+    }
+
+    #[test]
+    fn test_broadcast_allowed_again_after_suppress_window_elapses() {
+        let bus = BroadcastInvalidationBus::with_suppress_window(16, Duration::from_millis(10));
+        let mut rx = bus.subscribe();
+
+        bus.publish("key");
+        std::thread::sleep(Duration::from_millis(20));
+        bus.publish("key");
+
+        assert_eq!(rx.try_recv().unwrap().key, "key");
+        assert_eq!(rx.try_recv().unwrap().key, "key");
+    }
+
+    #[tokio::test]
+    async fn test_cache_invalidator_deletes_on_remote_event() {
+        use crate::backend::InMemoryBackend;
+
+        let backend = InMemoryBackend::new();
+        backend
+            .set("user:42", vec![1, 2, 3], None)
+            .await
+            .expect("Failed to set");
+
+        let bus: Arc<dyn InvalidationBus> = Arc::new(BroadcastInvalidationBus::new(16));
+        let listener = CacheInvalidator::new(backend.clone(), bus.clone()).spawn();
+
+        bus.publish("user:42");
+
+        // Give the spawned task a moment to process the event.
+        for _ in 0..50 {
+            if backend.get("user:42").await.expect("Failed to get").is_none() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        assert!(
+            backend.get("user:42").await.expect("Failed to get").is_none(),
+            "entry should have been invalidated by the listener"
+        );
+
+        listener.shutdown().await.expect("Listener should shut down cleanly");
+    }
+
+    #[tokio::test]
+    async fn test_cache_invalidator_callback_fires_with_key() {
+        use crate::backend::InMemoryBackend;
+        use std::sync::Mutex as StdMutex;
+
+        let backend = InMemoryBackend::new();
+        let bus: Arc<dyn InvalidationBus> = Arc::new(BroadcastInvalidationBus::new(16));
+
+        let seen = Arc::new(StdMutex::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let listener = CacheInvalidator::new(backend, bus.clone())
+            .with_callback(move |key| {
+                seen_clone.lock().expect("lock poisoned").push(key.to_string());
+            })
+            .spawn();
+
+        bus.publish("order:7");
+
+        for _ in 0..50 {
+            if !seen.lock().expect("lock poisoned").is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        assert_eq!(seen.lock().expect("lock poisoned").as_slice(), ["order:7"]);
+
+        listener.shutdown().await.expect("Listener should shut down cleanly");
+    }
+
+    #[tokio::test]
+    async fn test_cache_invalidator_prefix_filter_drops_other_prefixes() {
+        use crate::backend::InMemoryBackend;
+
+        let backend = InMemoryBackend::new();
+        backend
+            .set("user:1", vec![1], None)
+            .await
+            .expect("Failed to set");
+        backend
+            .set("order:1", vec![2], None)
+            .await
+            .expect("Failed to set");
+
+        let bus: Arc<dyn InvalidationBus> = Arc::new(BroadcastInvalidationBus::new(16));
+        let listener = CacheInvalidator::new(backend.clone(), bus.clone())
+            .with_prefix_filter("user")
+            .spawn();
+
+        bus.publish("order:1");
+        bus.publish("user:1");
+
+        for _ in 0..50 {
+            if backend.get("user:1").await.expect("Failed to get").is_none() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        assert!(
+            backend.get("user:1").await.expect("Failed to get").is_none(),
+            "matching-prefix key should have been invalidated"
+        );
+        assert!(
+            backend.get("order:1").await.expect("Failed to get").is_some(),
+            "other-prefix key should NOT have been invalidated"
+        );
+
+        listener.shutdown().await.expect("Listener should shut down cleanly");
+    }
+
+    #[tokio::test]
+    async fn test_cache_invalidator_shutdown_stops_the_task() {
+        use crate::backend::InMemoryBackend;
+
+        let backend = InMemoryBackend::new();
+        let bus: Arc<dyn InvalidationBus> = Arc::new(BroadcastInvalidationBus::new(16));
+        let listener = CacheInvalidator::new(backend, bus).spawn();
+
+        listener.shutdown().await.expect("Listener should shut down cleanly");
+    }
+}
@@ -0,0 +1,591 @@
+//! Postgres LISTEN/NOTIFY-driven cache invalidation.
+//!
+//! Where [`super::CacheInvalidator`] drives a backend from an in-process
+//! [`super::InvalidationBus`], [`PgInvalidator`] drives one straight from the
+//! database: a trigger installed by [`migration_sql`] calls `pg_notify` on
+//! every INSERT/UPDATE/DELETE, and a background task `LISTEN`s for those
+//! notifications and evicts the matching cache key - so a repository that
+//! writes through `sqlx` directly (bypassing `CacheExpander::execute`
+//! entirely) still keeps the cache coherent.
+//!
+//! [`PgInvalidationBus`] covers the other direction: a caller-originated
+//! [`InvalidationBus`] for deployments that already mutate through
+//! `CacheExpander`/`CacheService` and just need `Invalidate` to fan out over
+//! Postgres `NOTIFY` instead of (or on top of) a local `BroadcastInvalidationBus`,
+//! without standing up a separate Redis or gossip cluster.
+
+use crate::backend::CacheBackend;
+use crate::invalidation::{InvalidationBus, InvalidationEvent, InvalidationListenerHandle};
+use serde::{Deserialize, Serialize};
+use sqlx::postgres::{PgListener, PgPool};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, watch};
+
+/// How long to wait before retrying a dropped `LISTEN` connection.
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// Payload `pg_notify` is expected to carry: the table the trigger fired on
+/// and the affected row's id, as JSON (`{"table": "users", "id": "42"}`).
+#[derive(Deserialize)]
+struct NotifyPayload {
+    table: String,
+    id: String,
+}
+
+/// Build the SQL that installs a `pg_notify`-on-write trigger for `table`.
+///
+/// Run this once per table as a migration (e.g. via `sqlx migrate` or a
+/// plain `pool.execute(&migration_sql(...))`). The trigger fires
+/// `AFTER INSERT OR UPDATE OR DELETE`, notifying `channel` with a JSON
+/// payload identifying `table` and the row's `id_column` so a single
+/// [`PgInvalidator`] can listen on one channel for many tables.
+///
+/// The function/trigger names are derived from `table` so installing this
+/// on multiple tables against the same `channel` doesn't collide.
+pub fn migration_sql(table: &str, id_column: &str, channel: &str) -> String {
+    format!(
+        r#"
+CREATE OR REPLACE FUNCTION cache_kit_notify_{table}() RETURNS trigger AS $$
+DECLARE
+    row_id text;
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        row_id := OLD.{id_column}::text;
+    ELSE
+        row_id := NEW.{id_column}::text;
+    END IF;
+
+    PERFORM pg_notify('{channel}', json_build_object('table', '{table}', 'id', row_id)::text);
+
+    IF TG_OP = 'DELETE' THEN
+        RETURN OLD;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS cache_kit_notify_{table}_trigger ON {table};
+CREATE TRIGGER cache_kit_notify_{table}_trigger
+    AFTER INSERT OR UPDATE OR DELETE ON {table}
+    FOR EACH ROW EXECUTE FUNCTION cache_kit_notify_{table}();
+"#
+    )
+}
+
+/// Drives a local backend from Postgres `LISTEN/NOTIFY`, evicting
+/// `"{prefix}:{id}"` for every row a [`migration_sql`] trigger reports
+/// changed.
+///
+/// Tables are mapped to their `CacheEntity::cache_prefix()` via
+/// [`Self::register_table`] - the listener only knows how to build a cache
+/// key once a table has been registered, so unregistered tables'
+/// notifications are dropped.
+pub struct PgInvalidator<B: CacheBackend> {
+    pool: PgPool,
+    backend: B,
+    channel: String,
+    table_prefixes: HashMap<String, String>,
+    suppress_window: Duration,
+}
+
+impl<B: CacheBackend + 'static> PgInvalidator<B> {
+    /// Listen on `channel` (the same channel a [`migration_sql`] trigger
+    /// notifies) and evict matching keys from `backend`.
+    pub fn new(pool: PgPool, backend: B, channel: impl Into<String>) -> Self {
+        PgInvalidator {
+            pool,
+            backend,
+            channel: channel.into(),
+            table_prefixes: HashMap::new(),
+            suppress_window: Duration::from_millis(50),
+        }
+    }
+
+    /// Map `table` to `prefix` (i.e. `T::cache_prefix()` for some entity
+    /// `T`), so a notification for that table evicts `"{prefix}:{id}"`.
+    pub fn register_table(mut self, table: impl Into<String>, prefix: impl Into<String>) -> Self {
+        self.table_prefixes.insert(table.into(), prefix.into());
+        self
+    }
+
+    /// Suppress re-deleting the same key within `window` of a prior delete,
+    /// for tables where one write fires several near-simultaneous
+    /// notifications (e.g. a trigger per column group). Default 50ms,
+    /// matching [`super::BroadcastInvalidationBus`]'s default.
+    pub fn with_suppress_window(mut self, window: Duration) -> Self {
+        self.suppress_window = window;
+        self
+    }
+
+    /// Spawn the background `LISTEN` task.
+    ///
+    /// Returns the same [`InvalidationListenerHandle`] the in-process bus
+    /// listener uses, for a consistent shutdown/join API regardless of
+    /// transport. If the connection drops, the task reconnects and
+    /// re-`LISTEN`s after [`RECONNECT_DELAY`] rather than exiting.
+    pub fn spawn(self) -> InvalidationListenerHandle {
+        let (shutdown_tx, mut shutdown_rx) = watch::channel(false);
+        let pool = self.pool;
+        let backend = self.backend;
+        let channel = self.channel;
+        let table_prefixes = self.table_prefixes;
+        let suppress_window = self.suppress_window;
+        let last_deleted: Arc<Mutex<HashMap<String, Instant>>> = Arc::new(Mutex::new(HashMap::new()));
+
+        let task = tokio::spawn(async move {
+            loop {
+                if *shutdown_rx.borrow() {
+                    break;
+                }
+
+                let mut listener = match PgListener::connect_with(&pool).await {
+                    Ok(listener) => listener,
+                    Err(e) => {
+                        warn!("Failed to connect Postgres invalidation listener: {}, retrying", e);
+                        tokio::select! {
+                            _ = tokio::time::sleep(RECONNECT_DELAY) => continue,
+                            _ = shutdown_rx.changed() => break,
+                        }
+                    }
+                };
+
+                if let Err(e) = listener.listen(&channel).await {
+                    warn!("Failed to LISTEN on {}: {}, retrying", channel, e);
+                    tokio::select! {
+                        _ = tokio::time::sleep(RECONNECT_DELAY) => continue,
+                        _ = shutdown_rx.changed() => break,
+                    }
+                }
+
+                debug!("✓ Postgres invalidation listener subscribed to {}", channel);
+
+                loop {
+                    tokio::select! {
+                        _ = shutdown_rx.changed() => {
+                            if *shutdown_rx.borrow() {
+                                debug!("Postgres invalidation listener shutting down");
+                                return;
+                            }
+                        }
+                        notification = listener.recv() => {
+                            match notification {
+                                Ok(notification) => {
+                                    apply_notification(
+                                        notification.payload(),
+                                        &backend,
+                                        &table_prefixes,
+                                        &last_deleted,
+                                        suppress_window,
+                                    )
+                                    .await;
+                                }
+                                Err(e) => {
+                                    warn!("Postgres invalidation listener connection lost: {}, reconnecting", e);
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        InvalidationListenerHandle::new(task, shutdown_tx)
+    }
+}
+
+async fn apply_notification<B: CacheBackend>(
+    payload: &str,
+    backend: &B,
+    table_prefixes: &HashMap<String, String>,
+    last_deleted: &Arc<Mutex<HashMap<String, Instant>>>,
+    suppress_window: Duration,
+) {
+    let payload: NotifyPayload = match serde_json::from_str(payload) {
+        Ok(payload) => payload,
+        Err(e) => {
+            warn!("Discarding malformed invalidation payload: {}", e);
+            return;
+        }
+    };
+
+    let Some(prefix) = table_prefixes.get(&payload.table) else {
+        debug!("Ignoring notification for unregistered table {}", payload.table);
+        return;
+    };
+    let key = format!("{}:{}", prefix, payload.id);
+
+    {
+        let mut last_deleted = last_deleted.lock().expect("lock poisoned");
+        if let Some(last) = last_deleted.get(&key) {
+            if last.elapsed() < suppress_window {
+                debug!("⏭ Invalidation for {} suppressed (recently applied)", key);
+                return;
+            }
+        }
+        last_deleted.insert(key.clone(), Instant::now());
+    }
+
+    if let Err(e) = backend.delete(&key).await {
+        warn!("Failed to apply Postgres invalidation for {}: {}", key, e);
+        return;
+    }
+    debug!("✓ Applied Postgres invalidation for {}", key);
+}
+
+/// Wire payload published via `pg_notify`/received via `LISTEN`, identifying
+/// which [`PgInvalidationBus`] instance it came from.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct PgNotifyBusPayload {
+    key: String,
+    sequence: u64,
+    origin: u64,
+}
+
+static ORIGIN_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Derive a per-process-unique id for a [`PgInvalidationBus`] instance, so
+/// its own `spawn_listener` can recognize and skip a notification it just
+/// published itself - Postgres delivers `NOTIFY` back to the publishing
+/// session too when that session is also `LISTEN`ing on the same channel.
+/// Not cryptographically unique, just enough to keep two instances (or the
+/// same one across a quick restart) from colliding in practice, the same
+/// tradeoff `gossip::derive_node_id` makes.
+fn generate_origin_id() -> u64 {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0);
+    nanos ^ ORIGIN_COUNTER.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Record `event` in `history`/`sender` the same way regardless of whether it
+/// originated from a local [`PgInvalidationBus::publish`] call or a `LISTEN`
+/// notification from another instance.
+fn record_locally(
+    event: InvalidationEvent,
+    sender: &broadcast::Sender<InvalidationEvent>,
+    history: &Arc<Mutex<VecDeque<InvalidationEvent>>>,
+    history_capacity: usize,
+) {
+    {
+        let mut history = history.lock().expect("lock poisoned");
+        history.push_back(event.clone());
+        while history.len() > history_capacity {
+            history.pop_front();
+        }
+    }
+    let _ = sender.send(event);
+}
+
+/// Postgres `NOTIFY`/`LISTEN`-backed [`InvalidationBus`], for fanning
+/// `CacheExpander`/`CacheService`-originated invalidations out across
+/// instances that already run against Postgres, without a separate Redis or
+/// gossip cluster.
+///
+/// Unlike [`PgInvalidator`], which is driven by a database trigger on a
+/// specific table, `publish` here is called directly by
+/// `CacheExpander`'s `Invalidate` strategy - the same role
+/// `super::BroadcastInvalidationBus` and `super::gossip::GossipInvalidationBus`
+/// play, just over Postgres.
+///
+/// # Example
+///
+/// ```no_run
+/// use cache_kit::invalidation::postgres::PgInvalidationBus;
+/// use cache_kit::invalidation::InvalidationBus;
+/// use sqlx::postgres::PgPool;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let pool = PgPool::connect("postgres://localhost/myapp").await.expect("Failed to connect");
+/// let bus = PgInvalidationBus::new(pool, "cache_invalidate", 16);
+/// let _listener = bus.spawn_listener();
+///
+/// bus.publish("user:42");
+/// # }
+/// ```
+pub struct PgInvalidationBus {
+    pool: PgPool,
+    channel: String,
+    origin: u64,
+    sender: broadcast::Sender<InvalidationEvent>,
+    sequence: AtomicU64,
+    history: Arc<Mutex<VecDeque<InvalidationEvent>>>,
+    history_capacity: usize,
+}
+
+impl PgInvalidationBus {
+    /// Create a bus that publishes `NOTIFY` on `channel`, retaining the last
+    /// `history_capacity` events for `replay()`.
+    pub fn new(pool: PgPool, channel: impl Into<String>, history_capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(history_capacity.max(1));
+        PgInvalidationBus {
+            pool,
+            channel: channel.into(),
+            origin: generate_origin_id(),
+            sender,
+            sequence: AtomicU64::new(0),
+            history: Arc::new(Mutex::new(VecDeque::with_capacity(history_capacity))),
+            history_capacity,
+        }
+    }
+
+    /// Spawn a background task that `LISTEN`s on `channel` and forwards
+    /// notifications from other instances to this bus's subscribers (and
+    /// `replay()` history) - the same reconnect-on-drop behavior as
+    /// [`PgInvalidator::spawn`].
+    ///
+    /// Notifications carrying this instance's own origin id are dropped,
+    /// since [`Self::publish`] already delivered them to local subscribers
+    /// directly; without that check, a publisher that's also listening on
+    /// its own channel would apply every invalidation twice.
+    pub fn spawn_listener(&self) -> InvalidationListenerHandle {
+        let pool = self.pool.clone();
+        let channel = self.channel.clone();
+        let origin = self.origin;
+        let sender = self.sender.clone();
+        let history = self.history.clone();
+        let history_capacity = self.history_capacity;
+        let (shutdown_tx, mut shutdown_rx) = watch::channel(false);
+
+        let task = tokio::spawn(async move {
+            loop {
+                if *shutdown_rx.borrow() {
+                    break;
+                }
+
+                let mut listener = match PgListener::connect_with(&pool).await {
+                    Ok(listener) => listener,
+                    Err(e) => {
+                        warn!("Failed to connect Postgres invalidation bus listener: {}, retrying", e);
+                        tokio::select! {
+                            _ = tokio::time::sleep(RECONNECT_DELAY) => continue,
+                            _ = shutdown_rx.changed() => break,
+                        }
+                    }
+                };
+
+                if let Err(e) = listener.listen(&channel).await {
+                    warn!("Failed to LISTEN on {}: {}, retrying", channel, e);
+                    tokio::select! {
+                        _ = tokio::time::sleep(RECONNECT_DELAY) => continue,
+                        _ = shutdown_rx.changed() => break,
+                    }
+                }
+
+                debug!("✓ Postgres invalidation bus listener subscribed to {}", channel);
+
+                loop {
+                    tokio::select! {
+                        _ = shutdown_rx.changed() => {
+                            if *shutdown_rx.borrow() {
+                                debug!("Postgres invalidation bus listener shutting down");
+                                return;
+                            }
+                        }
+                        notification = listener.recv() => {
+                            match notification {
+                                Ok(notification) => {
+                                    apply_pg_notify_bus_payload(
+                                        notification.payload(),
+                                        origin,
+                                        &sender,
+                                        &history,
+                                        history_capacity,
+                                    );
+                                }
+                                Err(e) => {
+                                    warn!("Postgres invalidation bus connection lost: {}, reconnecting", e);
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        InvalidationListenerHandle::new(task, shutdown_tx)
+    }
+}
+
+impl InvalidationBus for PgInvalidationBus {
+    fn publish(&self, key: &str) {
+        let sequence = self.sequence.fetch_add(1, Ordering::SeqCst);
+        let event = InvalidationEvent {
+            key: key.to_string(),
+            sequence,
+        };
+
+        // Local subscribers get it immediately; remote ones get it once the
+        // spawned NOTIFY below completes (and reaches their `spawn_listener`).
+        record_locally(event, &self.sender, &self.history, self.history_capacity);
+
+        let payload = PgNotifyBusPayload {
+            key: key.to_string(),
+            sequence,
+            origin: self.origin,
+        };
+        let payload = match serde_json::to_string(&payload) {
+            Ok(payload) => payload,
+            Err(e) => {
+                warn!("Failed to serialize invalidation bus event for {}: {}", key, e);
+                return;
+            }
+        };
+        let pool = self.pool.clone();
+        let channel = self.channel.clone();
+        let key = key.to_string();
+        tokio::spawn(async move {
+            if let Err(e) = sqlx::query("SELECT pg_notify($1, $2)")
+                .bind(&channel)
+                .bind(&payload)
+                .execute(&pool)
+                .await
+            {
+                warn!("Failed to publish invalidation bus event for {} on {}: {}", key, channel, e);
+            }
+        });
+    }
+
+    fn subscribe(&self) -> broadcast::Receiver<InvalidationEvent> {
+        self.sender.subscribe()
+    }
+
+    fn replay(&self) -> Vec<InvalidationEvent> {
+        self.history.lock().expect("lock poisoned").iter().cloned().collect()
+    }
+}
+
+/// Parse and apply one incoming `LISTEN` notification: drop it silently if
+/// it's malformed or carries this instance's own origin id, otherwise record
+/// it the same as a local publish.
+fn apply_pg_notify_bus_payload(
+    payload: &str,
+    origin: u64,
+    sender: &broadcast::Sender<InvalidationEvent>,
+    history: &Arc<Mutex<VecDeque<InvalidationEvent>>>,
+    history_capacity: usize,
+) {
+    let payload: PgNotifyBusPayload = match serde_json::from_str(payload) {
+        Ok(payload) => payload,
+        Err(e) => {
+            warn!("Discarding malformed invalidation bus payload: {}", e);
+            return;
+        }
+    };
+
+    if payload.origin == origin {
+        debug!("⏭ Ignoring invalidation bus notification for {} (published by this instance)", payload.key);
+        return;
+    }
+
+    let event = InvalidationEvent {
+        key: payload.key.clone(),
+        sequence: payload.sequence,
+    };
+    record_locally(event, sender, history, history_capacity);
+    debug!("✓ Applied Postgres invalidation bus notification for {}", payload.key);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migration_sql_embeds_table_channel_and_id_column() {
+        let sql = migration_sql("users", "id", "cache_invalidate");
+        assert!(sql.contains("cache_kit_notify_users"));
+        assert!(sql.contains("ON users"));
+        assert!(sql.contains("NEW.id::text"));
+        assert!(sql.contains("OLD.id::text"));
+        assert!(sql.contains("pg_notify('cache_invalidate'"));
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn test_pg_invalidator_deletes_on_notification() {
+        use crate::backend::InMemoryBackend;
+
+        let pool = PgPool::connect("postgres://localhost/cache_kit_test")
+            .await
+            .expect("Failed to connect");
+        sqlx::query(&migration_sql("users", "id", "cache-kit:test-invalidations"))
+            .execute(&pool)
+            .await
+            .expect("Failed to install trigger");
+
+        let backend = InMemoryBackend::new();
+        backend
+            .set("user:42", vec![1, 2, 3], None)
+            .await
+            .expect("Failed to set");
+
+        let listener = PgInvalidator::new(pool.clone(), backend.clone(), "cache-kit:test-invalidations")
+            .register_table("users", "user")
+            .spawn();
+
+        sqlx::query("UPDATE users SET updated_at = now() WHERE id = 42")
+            .execute(&pool)
+            .await
+            .expect("Failed to update row");
+
+        for _ in 0..50 {
+            if backend.get("user:42").await.expect("Failed to get").is_none() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        assert!(
+            backend.get("user:42").await.expect("Failed to get").is_none(),
+            "entry should have been invalidated by the trigger notification"
+        );
+
+        listener.shutdown().await.expect("Listener should shut down cleanly");
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn test_pg_invalidation_bus_listener_applies_remote_notification() {
+        use crate::backend::InMemoryBackend;
+        use crate::invalidation::CacheInvalidator;
+
+        let pool = PgPool::connect("postgres://localhost/cache_kit_test")
+            .await
+            .expect("Failed to connect");
+
+        let bus = PgInvalidationBus::new(pool, "cache-kit:test-bus-invalidations", 16);
+        let bus_listener = bus.spawn_listener();
+        let bus: Arc<dyn InvalidationBus> = Arc::new(bus);
+
+        let backend = InMemoryBackend::new();
+        backend
+            .set("user:42", vec![1, 2, 3], None)
+            .await
+            .expect("Failed to set");
+
+        let invalidator = CacheInvalidator::new(backend.clone(), bus.clone()).spawn();
+
+        bus.publish("user:42");
+
+        for _ in 0..50 {
+            if backend.get("user:42").await.expect("Failed to get").is_none() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        assert!(
+            backend.get("user:42").await.expect("Failed to get").is_none(),
+            "entry should have been invalidated via the Postgres-backed invalidation bus"
+        );
+
+        invalidator.shutdown().await.expect("Invalidator should shut down cleanly");
+        bus_listener.shutdown().await.expect("Listener should shut down cleanly");
+    }
+}
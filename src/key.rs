@@ -2,6 +2,104 @@
 
 use crate::entity::CacheEntity;
 
+/// A cache key assembled from ordered, length-prefixed segments, as an
+/// alternative to [`CacheKeyBuilder`]'s `":"`-joined keys for callers who
+/// can't guarantee a segment (a user-supplied search term, a free-text tag)
+/// never contains `:` itself.
+///
+/// Each segment is encoded as `"{byte_length}:{segment}"`, so a reader
+/// always knows exactly how many bytes to consume for one segment before the
+/// next one starts - unlike joining with `:`, a segment's own content can
+/// never be mistaken for a boundary, and one prefix being a substring of
+/// another (`"user"` vs `"user_session"`) can't cause one key's segments to
+/// be parsed as a prefix of the other's, since `encode()` lengths differ at
+/// the very first segment.
+///
+/// # Scope
+///
+/// This doesn't change [`crate::backend::CacheBackend`]'s `&str` key type or
+/// how [`CacheEntity`] builds its default key ([`CacheKeyBuilder`]/
+/// `format!("{}:{}", ..)` remain the default for every existing entity) -
+/// `CacheKey` is an opt-in alternative for composite/namespaced keys where
+/// segment content can't be trusted not to contain `:`. Adopting it
+/// crate-wide would touch every backend and `CacheEntity` impl, which is too
+/// large a change to land in one pass without a build to verify it against
+/// (see [`crate::serialization::codec`]'s module doc for the same tradeoff).
+///
+/// **Not a drop-in replacement for `scan_prefix`/`invalidate_prefix`/
+/// `delete_prefix`:** every backend's existing prefix-matching (e.g.
+/// `InMemoryBackend::matching_prefix_keys`) appends its own `":"` to the
+/// argument before matching, a convention built around `CacheKeyBuilder`'s
+/// keys. Passing a `CacheKey::encode()`ed string straight into those methods
+/// does *not* correctly enumerate or bulk-invalidate a namespace, since the
+/// extra appended `:` doesn't land on a length-prefix boundary. Using
+/// `CacheKey` safely for prefix operations needs backend-level support for
+/// this encoding, which is out of scope here - see the scope note above.
+///
+/// # Example
+///
+/// ```rust
+/// use cache_kit::key::CacheKey;
+///
+/// let a = CacheKey::new().segment("user").segment("session");
+/// let b = CacheKey::new().segment("user_session");
+/// assert_ne!(a.encode(), b.encode());
+///
+/// // A parent key's encoding is always a literal prefix of any key built by
+/// // appending further segments to it.
+/// let parent = CacheKey::namespaced("tenant-1").segment("user");
+/// let child = parent.clone().segment("42");
+/// assert!(child.encode().starts_with(&parent.encode()));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CacheKey {
+    segments: Vec<String>,
+}
+
+impl CacheKey {
+    /// Start building a key with no segments yet.
+    pub fn new() -> Self {
+        CacheKey::default()
+    }
+
+    /// Start building a key scoped under a global application namespace
+    /// (e.g. a tenant id), so multiple tenants sharing one backend can't
+    /// collide even if their own segments happen to match.
+    pub fn namespaced(namespace: impl Into<String>) -> Self {
+        CacheKey {
+            segments: vec![namespace.into()],
+        }
+    }
+
+    /// Append a segment. Segment content is unrestricted - it may contain
+    /// `:` or anything else - since segments are length-prefixed rather than
+    /// delimiter-separated.
+    pub fn segment(mut self, segment: impl Into<String>) -> Self {
+        self.segments.push(segment.into());
+        self
+    }
+
+    /// Render this key as a single backend-compatible string: each segment
+    /// is written as `"{byte_length}:{segment}"` in order, with no separator
+    /// between segments - the next segment's own length prefix is all a
+    /// reader needs to find the following boundary.
+    pub fn encode(&self) -> String {
+        let mut out = String::new();
+        for segment in &self.segments {
+            out.push_str(&segment.len().to_string());
+            out.push(':');
+            out.push_str(segment);
+        }
+        out
+    }
+}
+
+impl std::fmt::Display for CacheKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.encode())
+    }
+}
+
 /// Type alias for key generator function.
 type KeyGeneratorFn = dyn Fn(&dyn std::fmt::Display) -> String + Send + Sync;
 
@@ -125,4 +223,36 @@ mod tests {
 
         assert!(registry.generate("unknown", &"123").is_none());
     }
+
+    #[test]
+    fn test_cache_key_prevents_substring_prefix_collision() {
+        // "user" vs "user_session" would otherwise overlap under a naive
+        // `:`-joined prefix scheme.
+        let a = CacheKey::new().segment("user").segment("session");
+        let b = CacheKey::new().segment("user_session");
+        assert_ne!(a.encode(), b.encode());
+        assert!(!b.encode().starts_with(&a.encode()));
+        assert!(!a.encode().starts_with(&b.encode()));
+    }
+
+    #[test]
+    fn test_cache_key_segment_containing_separator_stays_distinct() {
+        let a = CacheKey::new().segment("user:admin").segment("123");
+        let b = CacheKey::new().segment("user").segment("admin").segment("123");
+        assert_ne!(a.encode(), b.encode());
+    }
+
+    #[test]
+    fn test_cache_key_namespace_is_a_prefix_of_its_children() {
+        let parent = CacheKey::namespaced("tenant-1").segment("user");
+        let child = parent.clone().segment("42");
+        assert!(child.encode().starts_with(&parent.encode()));
+    }
+
+    #[test]
+    fn test_cache_key_display_matches_encode() {
+        let key = CacheKey::new().segment("user").segment("42");
+        assert_eq!(key.to_string(), key.encode());
+        assert_eq!(key.encode(), "4:user2:42");
+    }
 }
@@ -5,11 +5,12 @@
 //! ## Features
 //!
 //! - **Fully Generic:** Cache any type `T` that implements `CacheEntity`
-//! - **Backend Agnostic:** Support for in-memory, Redis, Memcached, and custom backends
+//! - **Backend Agnostic:** Support for in-memory, Redis, Memcached, RocksDB, and custom backends
 //! - **Database Agnostic:** Works with SQLx, tokio-postgres, Diesel, or custom repositories
 //! - **Framework Independent:** Zero dependencies on web frameworks (Axum, Actix, Rocket, etc.)
 //! - **Production Ready:** Built-in logging, metrics support, and error handling
 //! - **Type Safe:** Compile-time verified, no magic strings
+//! - **Derive Macro:** `#[derive(CacheEntity)]` (feature `derive`) generates `cache_key()`/`cache_prefix()` from attributes
 //!
 //! ## Quick Start
 //!
@@ -76,25 +77,42 @@
 #[macro_use]
 extern crate log;
 
+#[cfg(feature = "admin")]
+pub mod admin;
 pub mod backend;
+pub mod coherence;
+pub mod crdt;
 pub mod entity;
 pub mod error;
 pub mod expander;
 pub mod feed;
+pub mod invalidation;
 pub mod key;
 pub mod observability;
 pub mod repository;
+pub mod resilience;
 pub mod serialization;
 pub mod service;
 pub mod strategy;
+pub mod streaming;
 
 // Re-exports for convenience
-pub use backend::CacheBackend;
+pub use backend::{Backend, CacheBackend};
 pub use entity::CacheEntity;
+/// `#[derive(CacheEntity)]` - generates `cache_key()`/`cache_prefix()` from
+/// `#[cache(prefix = "...")]` and `#[cache(key)]`. See `cache_kit_derive` docs.
+#[cfg(feature = "derive")]
+pub use cache_kit_derive::CacheEntity;
+/// `#[cache_kit(backend = ..., prefix = "...")]` - memoizes an `async fn`
+/// through a `CacheBackend`. See `cache_kit_derive` docs.
+#[cfg(feature = "derive")]
+pub use cache_kit_derive::cache_kit;
 pub use error::{Error, Result};
-pub use expander::{CacheExpander, OperationConfig};
-pub use feed::CacheFeed;
-pub use repository::DataRepository;
+pub use expander::{
+    CacheExpander, CacheOutcome, CacheRecoveryPolicy, OperationConfig, RetryBudget, WriteBackCause,
+};
+pub use feed::{BatchCacheFeed, CacheFeed};
+pub use repository::{DataRepository, Page};
 pub use service::CacheService;
 pub use strategy::CacheStrategy;
 
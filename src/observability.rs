@@ -79,6 +79,8 @@
 //!
 //! All methods receive the cache key and relevant timing/error information.
 
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Trait for cache metrics collection.
@@ -107,6 +109,132 @@ pub trait CacheMetrics: Send + Sync {
     fn record_error(&self, key: &str, error: &str) {
         warn!("Cache ERROR for {}: {}", key, error);
     }
+
+    /// Record a stale hit under `CacheStrategy::StaleWhileRevalidate`: the
+    /// entry was past its soft TTL, so the caller got it immediately while a
+    /// background refresh was kicked off. Distinct from `record_hit` so a
+    /// dashboard can tell "fast and fresh" apart from "fast but stale" -
+    /// a rising stale-hit rate usually means `stale_after` is too short for
+    /// how long a refresh actually takes.
+    fn record_stale_hit(&self, key: &str, duration: Duration) {
+        debug!("Cache STALE HIT: {} took {:?}", key, duration);
+    }
+
+    /// Record a capacity- or weight-driven eviction, e.g. from
+    /// [`crate::backend::InMemoryBackend::with_capacity`]/`with_eviction`.
+    /// Distinct from `record_delete`, which covers an explicit caller-driven
+    /// removal - a rising eviction rate usually means the configured
+    /// capacity is too small for the working set, not a usage bug.
+    fn record_eviction(&self, key: &str) {
+        debug!("Cache EVICT: {}", key);
+    }
+
+    /// Record a follower joining an already-in-flight single-flight fetch
+    /// for `key` instead of starting its own (see
+    /// [`crate::expander::OperationConfig::with_coalescing`]). A rising
+    /// coalesced-wait rate under a cold cache means single-flight is doing
+    /// its job collapsing a thundering herd into one backing fetch, rather
+    /// than one fetch per waiting caller.
+    fn record_coalesced_wait(&self, key: &str) {
+        debug!("Cache COALESCE: {} joined an in-flight fetch", key);
+    }
+
+    /// Record a successful background refresh under `TtlPolicy::RefreshAhead`
+    /// (or `CacheStrategy::StaleWhileRevalidate`'s equivalent background
+    /// refresh): the entry was reloaded from the source and the cache entry
+    /// rewritten before a caller ever saw a miss.
+    fn record_refresh(&self, key: &str, duration: Duration) {
+        debug!("Cache REFRESH: {} took {:?}", key, duration);
+    }
+
+    /// Record a failed background refresh. Distinct from `record_error`
+    /// since this runs detached with no caller to propagate the error to -
+    /// the stale entry is left in place (or evicted, depending on
+    /// `OperationConfig::with_evict_on_refresh_error`) rather than the
+    /// operation itself failing.
+    fn record_refresh_error(&self, key: &str, error: &str) {
+        warn!("Cache REFRESH ERROR for {}: {}", key, error);
+    }
+
+    /// Record that a [`crate::expander::CacheExpander::with`]-family call
+    /// found nothing in the cache backend and had to fetch the entity from
+    /// the repository (and write it back). A backend miss that's then
+    /// populated from the repository still reports `record_hit` to the
+    /// caller (the feeder got its entity), so this is the signal to watch
+    /// instead of `record_hit`/`record_miss` for the backend's own,
+    /// repository-excluded hit ratio.
+    fn record_repository_populate(&self, key: &str, duration: Duration) {
+        debug!("Cache REPOSITORY POPULATE: {} took {:?}", key, duration);
+    }
+
+    /// Record a [`crate::strategy::CacheStrategy::Invalidate`] call.
+    fn record_invalidation(&self, key: &str) {
+        debug!("Cache INVALIDATE: {}", key);
+    }
+
+    /// Record a [`crate::strategy::CacheStrategy::Bypass`] call.
+    fn record_bypass(&self, key: &str) {
+        debug!("Cache BYPASS: {}", key);
+    }
+}
+
+/// Lets a shared `Arc<T>` stand in for `T` wherever `CacheMetrics` is
+/// expected, so a concrete metrics handle (e.g. [`HistogramMetrics`]) can be
+/// kept around for direct reads (`render_prometheus`, `avg_latency_us`) while
+/// the same `Arc` is also boxed up via [`crate::service::CacheService::with_metrics`]
+/// to actually receive the recordings.
+impl<T: CacheMetrics> CacheMetrics for Arc<T> {
+    fn record_hit(&self, key: &str, duration: Duration) {
+        self.as_ref().record_hit(key, duration)
+    }
+
+    fn record_miss(&self, key: &str, duration: Duration) {
+        self.as_ref().record_miss(key, duration)
+    }
+
+    fn record_set(&self, key: &str, duration: Duration) {
+        self.as_ref().record_set(key, duration)
+    }
+
+    fn record_delete(&self, key: &str, duration: Duration) {
+        self.as_ref().record_delete(key, duration)
+    }
+
+    fn record_error(&self, key: &str, error: &str) {
+        self.as_ref().record_error(key, error)
+    }
+
+    fn record_stale_hit(&self, key: &str, duration: Duration) {
+        self.as_ref().record_stale_hit(key, duration)
+    }
+
+    fn record_eviction(&self, key: &str) {
+        self.as_ref().record_eviction(key)
+    }
+
+    fn record_coalesced_wait(&self, key: &str) {
+        self.as_ref().record_coalesced_wait(key)
+    }
+
+    fn record_refresh(&self, key: &str, duration: Duration) {
+        self.as_ref().record_refresh(key, duration)
+    }
+
+    fn record_refresh_error(&self, key: &str, error: &str) {
+        self.as_ref().record_refresh_error(key, error)
+    }
+
+    fn record_repository_populate(&self, key: &str, duration: Duration) {
+        self.as_ref().record_repository_populate(key, duration)
+    }
+
+    fn record_invalidation(&self, key: &str) {
+        self.as_ref().record_invalidation(key)
+    }
+
+    fn record_bypass(&self, key: &str) {
+        self.as_ref().record_bypass(key)
+    }
 }
 
 /// Default metrics implementation (no-op).
@@ -119,6 +247,1256 @@ impl CacheMetrics for NoOpMetrics {
     fn record_set(&self, _key: &str, _duration: Duration) {}
     fn record_delete(&self, _key: &str, _duration: Duration) {}
     fn record_error(&self, _key: &str, _error: &str) {}
+    fn record_stale_hit(&self, _key: &str, _duration: Duration) {}
+    fn record_eviction(&self, _key: &str) {}
+    fn record_coalesced_wait(&self, _key: &str) {}
+    fn record_refresh(&self, _key: &str, _duration: Duration) {}
+    fn record_refresh_error(&self, _key: &str, _error: &str) {}
+    fn record_repository_populate(&self, _key: &str, _duration: Duration) {}
+    fn record_invalidation(&self, _key: &str) {}
+    fn record_bypass(&self, _key: &str) {}
+}
+
+/// Fans every `record_*` call out to a list of registered sinks, in
+/// registration order, so a deployment can install
+/// [`PrometheusMetrics`], [`TracingMetrics`], and a local [`StatsCollector`]
+/// on the same [`CacheExpander`](crate::expander::CacheExpander) at once
+/// instead of hand-writing a wrapper type per combination.
+///
+/// # Example
+///
+/// ```
+/// use cache_kit::observability::{CacheMetrics, CompositeMetrics, NoOpMetrics, StatsCollector};
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// let stats = Arc::new(StatsCollector::new());
+/// let metrics = CompositeMetrics::new()
+///     .with_sink(Box::new(NoOpMetrics))
+///     .with_sink(Box::new(Arc::clone(&stats)));
+///
+/// metrics.record_hit("user:1", Duration::from_micros(50));
+/// assert_eq!(stats.snapshot().total_ops, 1);
+/// ```
+#[derive(Default)]
+pub struct CompositeMetrics {
+    sinks: Vec<Box<dyn CacheMetrics>>,
+}
+
+impl CompositeMetrics {
+    /// Create an empty fan-out with no sinks registered yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a fan-out from an already-assembled list of sinks.
+    pub fn from_sinks(sinks: Vec<Box<dyn CacheMetrics>>) -> Self {
+        CompositeMetrics { sinks }
+    }
+
+    /// Register another sink to receive every subsequent `record_*` call,
+    /// after every sink already registered.
+    pub fn with_sink(mut self, sink: Box<dyn CacheMetrics>) -> Self {
+        self.sinks.push(sink);
+        self
+    }
+}
+
+impl CacheMetrics for CompositeMetrics {
+    fn record_hit(&self, key: &str, duration: Duration) {
+        for sink in &self.sinks {
+            sink.record_hit(key, duration);
+        }
+    }
+
+    fn record_miss(&self, key: &str, duration: Duration) {
+        for sink in &self.sinks {
+            sink.record_miss(key, duration);
+        }
+    }
+
+    fn record_set(&self, key: &str, duration: Duration) {
+        for sink in &self.sinks {
+            sink.record_set(key, duration);
+        }
+    }
+
+    fn record_delete(&self, key: &str, duration: Duration) {
+        for sink in &self.sinks {
+            sink.record_delete(key, duration);
+        }
+    }
+
+    fn record_error(&self, key: &str, error: &str) {
+        for sink in &self.sinks {
+            sink.record_error(key, error);
+        }
+    }
+
+    fn record_stale_hit(&self, key: &str, duration: Duration) {
+        for sink in &self.sinks {
+            sink.record_stale_hit(key, duration);
+        }
+    }
+
+    fn record_eviction(&self, key: &str) {
+        for sink in &self.sinks {
+            sink.record_eviction(key);
+        }
+    }
+
+    fn record_coalesced_wait(&self, key: &str) {
+        for sink in &self.sinks {
+            sink.record_coalesced_wait(key);
+        }
+    }
+
+    fn record_refresh(&self, key: &str, duration: Duration) {
+        for sink in &self.sinks {
+            sink.record_refresh(key, duration);
+        }
+    }
+
+    fn record_refresh_error(&self, key: &str, error: &str) {
+        for sink in &self.sinks {
+            sink.record_refresh_error(key, error);
+        }
+    }
+
+    fn record_repository_populate(&self, key: &str, duration: Duration) {
+        for sink in &self.sinks {
+            sink.record_repository_populate(key, duration);
+        }
+    }
+
+    fn record_invalidation(&self, key: &str) {
+        for sink in &self.sinks {
+            sink.record_invalidation(key);
+        }
+    }
+
+    fn record_bypass(&self, key: &str) {
+        for sink in &self.sinks {
+            sink.record_bypass(key);
+        }
+    }
+}
+
+/// Point-in-time snapshot of an [`AtomicMetrics`]'s counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MetricsSnapshot {
+    /// Number of `record_hit` calls.
+    pub hits: u64,
+    /// Number of `record_miss` calls.
+    pub misses: u64,
+    /// Number of `record_set` calls.
+    pub sets: u64,
+    /// Number of `record_delete` calls.
+    pub deletes: u64,
+    /// Number of `record_error` calls.
+    pub errors: u64,
+    /// Number of `record_stale_hit` calls.
+    pub stale_hits: u64,
+    /// Number of `record_eviction` calls.
+    pub evictions: u64,
+    /// Number of `record_coalesced_wait` calls.
+    pub coalesced_waits: u64,
+    /// Number of `record_refresh` calls.
+    pub refreshes: u64,
+    /// Number of `record_refresh_error` calls.
+    pub refresh_errors: u64,
+    /// Number of `record_repository_populate` calls.
+    pub repository_populates: u64,
+    /// Number of `record_invalidation` calls.
+    pub invalidations: u64,
+    /// Number of `record_bypass` calls.
+    pub bypasses: u64,
+}
+
+impl MetricsSnapshot {
+    /// Hit ratio over `hits + misses`, as a value in `[0.0, 1.0]`.
+    ///
+    /// Returns `0.0` when no hits or misses have been recorded yet, rather
+    /// than dividing by zero.
+    pub fn hit_ratio(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            return 0.0;
+        }
+        self.hits as f64 / total as f64
+    }
+}
+
+/// In-process [`CacheMetrics`] implementation backed by atomic counters,
+/// with a [`MetricsSnapshot`] readout.
+///
+/// Use this in tests that want to assert a `Fresh` operation produced a hit
+/// (or `Invalidate`/`Bypass` produced a miss) instead of inferring it from
+/// log output, and in production as a lightweight default before wiring up
+/// [`PrometheusMetrics`] or an equivalent.
+///
+/// # Example
+///
+/// ```
+/// use cache_kit::observability::AtomicMetrics;
+/// use std::time::Duration;
+///
+/// let metrics = AtomicMetrics::new();
+/// metrics.record_hit("user:1", Duration::from_micros(50));
+/// metrics.record_miss("user:2", Duration::from_micros(80));
+///
+/// let snapshot = metrics.snapshot();
+/// assert_eq!(snapshot.hits, 1);
+/// assert_eq!(snapshot.misses, 1);
+/// assert_eq!(snapshot.hit_ratio(), 0.5);
+/// ```
+#[derive(Default)]
+pub struct AtomicMetrics {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    sets: AtomicU64,
+    deletes: AtomicU64,
+    errors: AtomicU64,
+    stale_hits: AtomicU64,
+    evictions: AtomicU64,
+    coalesced_waits: AtomicU64,
+    refreshes: AtomicU64,
+    refresh_errors: AtomicU64,
+    repository_populates: AtomicU64,
+    invalidations: AtomicU64,
+    bypasses: AtomicU64,
+}
+
+impl AtomicMetrics {
+    /// Create a new, zeroed counter set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read the current counters without resetting them.
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            sets: self.sets.load(Ordering::Relaxed),
+            deletes: self.deletes.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+            stale_hits: self.stale_hits.load(Ordering::Relaxed),
+            evictions: self.evictions.load(Ordering::Relaxed),
+            coalesced_waits: self.coalesced_waits.load(Ordering::Relaxed),
+            refreshes: self.refreshes.load(Ordering::Relaxed),
+            refresh_errors: self.refresh_errors.load(Ordering::Relaxed),
+            repository_populates: self.repository_populates.load(Ordering::Relaxed),
+            invalidations: self.invalidations.load(Ordering::Relaxed),
+            bypasses: self.bypasses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl CacheMetrics for AtomicMetrics {
+    fn record_hit(&self, _key: &str, _duration: Duration) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_miss(&self, _key: &str, _duration: Duration) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_set(&self, _key: &str, _duration: Duration) {
+        self.sets.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_delete(&self, _key: &str, _duration: Duration) {
+        self.deletes.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_error(&self, _key: &str, _error: &str) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_stale_hit(&self, _key: &str, _duration: Duration) {
+        self.stale_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_coalesced_wait(&self, _key: &str) {
+        self.coalesced_waits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_eviction(&self, _key: &str) {
+        self.evictions.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_refresh(&self, _key: &str, _duration: Duration) {
+        self.refreshes.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_refresh_error(&self, _key: &str, _error: &str) {
+        self.refresh_errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_repository_populate(&self, _key: &str, _duration: Duration) {
+        self.repository_populates.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_invalidation(&self, _key: &str) {
+        self.invalidations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_bypass(&self, _key: &str) {
+        self.bypasses.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Default bucket upper bounds (in microseconds) for [`HistogramMetrics`].
+pub const DEFAULT_LATENCY_BUCKETS_US: &[f64] =
+    &[50.0, 100.0, 250.0, 500.0, 1_000.0, 2_500.0, 5_000.0, 10_000.0];
+
+/// Cumulative latency histogram for one operation class (hit/miss/set/delete).
+///
+/// `bucket_counts[i]` counts every observation `<= buckets[i]`, so later
+/// buckets include everything earlier ones do - the shape a Prometheus
+/// histogram's `_bucket{le="..."}` series already assumes.
+#[derive(Default)]
+struct OpHistogram {
+    bucket_counts: Vec<AtomicU64>,
+    sum_us: AtomicU64,
+    count: AtomicU64,
+}
+
+impl OpHistogram {
+    fn new(num_buckets: usize) -> Self {
+        OpHistogram {
+            bucket_counts: (0..num_buckets).map(|_| AtomicU64::new(0)).collect(),
+            sum_us: AtomicU64::new(0),
+            count: AtomicU64::new(0),
+        }
+    }
+
+    fn observe(&self, buckets: &[f64], duration: Duration) {
+        let observed_us = duration.as_micros() as f64;
+        for (bound, counter) in buckets.iter().zip(self.bucket_counts.iter()) {
+            if observed_us <= *bound {
+                counter.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        self.sum_us
+            .fetch_add(duration.as_micros() as u64, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Estimate the `p`-th percentile (`p` in `[0.0, 1.0]`), in microseconds,
+    /// as the smallest bucket upper bound whose cumulative count covers at
+    /// least a `p` fraction of all observations. Returns `0.0` with no
+    /// observations yet, and the largest finite bucket bound if `p` falls
+    /// past it (there's no observed upper bound for the implicit `+Inf`
+    /// bucket to report).
+    fn percentile(&self, buckets: &[f64], p: f64) -> f64 {
+        let total = self.count.load(Ordering::Relaxed);
+        if total == 0 {
+            return 0.0;
+        }
+        let target = (p * total as f64).ceil() as u64;
+        for (bound, counter) in buckets.iter().zip(self.bucket_counts.iter()) {
+            if counter.load(Ordering::Relaxed) >= target {
+                return *bound;
+            }
+        }
+        buckets.last().copied().unwrap_or(0.0)
+    }
+}
+
+/// In-process [`CacheMetrics`] implementation that tracks per-operation-class
+/// latency histograms with caller-configured bucket boundaries, and renders
+/// them in Prometheus text exposition format.
+///
+/// Unlike [`PrometheusMetrics`], which forwards every observation through the
+/// `metrics` crate facade for an external recorder to bucket, `HistogramMetrics`
+/// keeps its own atomic bucket counters in-process - no facade, no external
+/// recorder - the same "we own the counters" approach [`AtomicMetrics`]
+/// already takes for hit/miss/set/delete totals, just extended to latency
+/// distributions. [`PrometheusMetrics`] also keeps its own per-entity-type
+/// counters and histograms for self-serve scraping, but labeled by entity
+/// type rather than bucketed the way `HistogramMetrics` is.
+///
+/// # Example
+///
+/// ```
+/// use cache_kit::observability::{CacheMetrics, HistogramMetrics};
+/// use std::time::Duration;
+///
+/// let metrics = HistogramMetrics::with_buckets(vec![100.0, 1_000.0]);
+/// metrics.record_hit("user:1", Duration::from_micros(50));
+/// metrics.record_hit("user:2", Duration::from_micros(500));
+///
+/// let rendered = metrics.render_prometheus();
+/// assert!(rendered.contains(r#"cache_op_latency_us_bucket{op="hit",le="100"}"#));
+/// ```
+pub struct HistogramMetrics {
+    buckets: Vec<f64>,
+    hit: OpHistogram,
+    miss: OpHistogram,
+    set: OpHistogram,
+    delete: OpHistogram,
+}
+
+impl HistogramMetrics {
+    /// Create a histogram recorder using [`DEFAULT_LATENCY_BUCKETS_US`].
+    pub fn new() -> Self {
+        Self::with_buckets(DEFAULT_LATENCY_BUCKETS_US.to_vec())
+    }
+
+    /// Create a histogram recorder with explicit bucket upper bounds, in
+    /// microseconds. Bounds should be sorted ascending; an implicit `+Inf`
+    /// bucket (equal to the total count) is always rendered last.
+    pub fn with_buckets(buckets: Vec<f64>) -> Self {
+        let num_buckets = buckets.len();
+        HistogramMetrics {
+            buckets,
+            hit: OpHistogram::new(num_buckets),
+            miss: OpHistogram::new(num_buckets),
+            set: OpHistogram::new(num_buckets),
+            delete: OpHistogram::new(num_buckets),
+        }
+    }
+
+    /// Mean latency in microseconds for one operation class
+    /// (`"hit"`, `"miss"`, `"set"`, or `"delete"`), or `0.0` if nothing has
+    /// been recorded yet for that class.
+    pub fn avg_latency_us(&self, op: &str) -> f64 {
+        let histogram = match op {
+            "hit" => &self.hit,
+            "miss" => &self.miss,
+            "set" => &self.set,
+            "delete" => &self.delete,
+            _ => return 0.0,
+        };
+        let count = histogram.count.load(Ordering::Relaxed);
+        if count == 0 {
+            return 0.0;
+        }
+        histogram.sum_us.load(Ordering::Relaxed) as f64 / count as f64
+    }
+
+    /// Render all four operation-class histograms in Prometheus text
+    /// exposition format (`_bucket`/`_sum`/`_count` lines per `op` label).
+    pub fn render_prometheus(&self) -> String {
+        let mut out = String::new();
+        out.push_str("# HELP cache_op_latency_us Cache operation latency in microseconds.\n");
+        out.push_str("# TYPE cache_op_latency_us histogram\n");
+        for (op, histogram) in [
+            ("hit", &self.hit),
+            ("miss", &self.miss),
+            ("set", &self.set),
+            ("delete", &self.delete),
+        ] {
+            for (bound, counter) in self.buckets.iter().zip(histogram.bucket_counts.iter()) {
+                out.push_str(&format!(
+                    "cache_op_latency_us_bucket{{op=\"{op}\",le=\"{bound}\"}} {}\n",
+                    counter.load(Ordering::Relaxed)
+                ));
+            }
+            let count = histogram.count.load(Ordering::Relaxed);
+            out.push_str(&format!(
+                "cache_op_latency_us_bucket{{op=\"{op}\",le=\"+Inf\"}} {count}\n"
+            ));
+            out.push_str(&format!(
+                "cache_op_latency_us_sum{{op=\"{op}\"}} {}\n",
+                histogram.sum_us.load(Ordering::Relaxed)
+            ));
+            out.push_str(&format!("cache_op_latency_us_count{{op=\"{op}\"}} {count}\n"));
+        }
+        out
+    }
+}
+
+impl Default for HistogramMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CacheMetrics for HistogramMetrics {
+    fn record_hit(&self, _key: &str, duration: Duration) {
+        self.hit.observe(&self.buckets, duration);
+    }
+
+    fn record_miss(&self, _key: &str, duration: Duration) {
+        self.miss.observe(&self.buckets, duration);
+    }
+
+    fn record_set(&self, _key: &str, duration: Duration) {
+        self.set.observe(&self.buckets, duration);
+    }
+
+    fn record_delete(&self, _key: &str, duration: Duration) {
+        self.delete.observe(&self.buckets, duration);
+    }
+
+    fn record_error(&self, _key: &str, _error: &str) {}
+
+    fn record_stale_hit(&self, _key: &str, duration: Duration) {
+        self.hit.observe(&self.buckets, duration);
+    }
+
+    fn record_eviction(&self, _key: &str) {}
+}
+
+/// Log-spaced latency histogram bucket upper bounds (in microseconds),
+/// 1-2-5 per decade from 1µs to 10s, used by [`StatsCollector`] by default -
+/// wide enough to bucket everything from an in-memory hit to a slow
+/// network-backed fetch.
+pub const DEFAULT_STATS_BUCKETS_US: &[f64] = &[
+    1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1_000.0, 2_000.0, 5_000.0, 10_000.0,
+    20_000.0, 50_000.0, 100_000.0, 200_000.0, 500_000.0, 1_000_000.0, 2_000_000.0, 5_000_000.0,
+    10_000_000.0,
+];
+
+/// p50/p95/p99 latency, in microseconds, for one operation class.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LatencyPercentiles {
+    /// Median latency.
+    pub p50_us: f64,
+    /// 95th percentile latency.
+    pub p95_us: f64,
+    /// 99th percentile latency.
+    pub p99_us: f64,
+}
+
+/// Point-in-time snapshot from a [`StatsCollector`]: hit ratio, total
+/// operations, and [`LatencyPercentiles`] per operation class.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct StatsSnapshot {
+    /// Hits over `hits + misses`, as a value in `[0.0, 1.0]`. `0.0` if
+    /// neither has been recorded yet.
+    pub hit_ratio: f64,
+    /// Total `record_hit` + `record_miss` + `record_set` + `record_delete`
+    /// calls.
+    pub total_ops: u64,
+    /// `record_hit` calls.
+    pub hits: u64,
+    /// `record_miss` calls.
+    pub misses: u64,
+    /// `record_set` calls.
+    pub sets: u64,
+    /// `record_delete` calls.
+    pub deletes: u64,
+    /// Hit latency percentiles.
+    pub hit: LatencyPercentiles,
+    /// Miss latency percentiles.
+    pub miss: LatencyPercentiles,
+    /// Set latency percentiles.
+    pub set: LatencyPercentiles,
+    /// Delete latency percentiles.
+    pub delete: LatencyPercentiles,
+}
+
+/// In-process [`CacheMetrics`] implementation that atomically accumulates
+/// per-operation counts and a streaming latency histogram per operation
+/// class, then exposes hit ratio, total ops, and p50/p95/p99 latencies via
+/// [`StatsCollector::snapshot`] - no external metrics backend required.
+///
+/// This is the crate-native equivalent of hand-rolling a `TimingStats`
+/// struct (min/max/avg over a `Vec<Duration>`) in a test or example:
+/// recording is lock-free (atomic counters plus a fixed bucket histogram,
+/// same approach as [`HistogramMetrics`]), so it's cheap enough to leave on
+/// in production, not just in a benchmark.
+///
+/// # Example
+///
+/// ```
+/// use cache_kit::observability::{CacheMetrics, StatsCollector};
+/// use std::time::Duration;
+///
+/// let stats = StatsCollector::new();
+/// stats.record_hit("user:1", Duration::from_micros(50));
+/// stats.record_miss("user:2", Duration::from_micros(80));
+///
+/// let snapshot = stats.snapshot();
+/// assert_eq!(snapshot.hit_ratio, 0.5);
+/// assert_eq!(snapshot.total_ops, 2);
+/// assert!(snapshot.hit.p50_us > 0.0);
+/// ```
+pub struct StatsCollector {
+    buckets: Vec<f64>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    sets: AtomicU64,
+    deletes: AtomicU64,
+    errors: AtomicU64,
+    stale_hits: AtomicU64,
+    evictions: AtomicU64,
+    coalesced_waits: AtomicU64,
+    refreshes: AtomicU64,
+    refresh_errors: AtomicU64,
+    repository_populates: AtomicU64,
+    invalidations: AtomicU64,
+    bypasses: AtomicU64,
+    hit_latency: OpHistogram,
+    miss_latency: OpHistogram,
+    set_latency: OpHistogram,
+    delete_latency: OpHistogram,
+}
+
+impl StatsCollector {
+    /// Create a new collector using [`DEFAULT_STATS_BUCKETS_US`].
+    pub fn new() -> Self {
+        Self::with_buckets(DEFAULT_STATS_BUCKETS_US.to_vec())
+    }
+
+    /// Create a new collector with explicit latency bucket upper bounds, in
+    /// microseconds. Bounds should be sorted ascending.
+    pub fn with_buckets(buckets: Vec<f64>) -> Self {
+        let num_buckets = buckets.len();
+        StatsCollector {
+            buckets,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            sets: AtomicU64::new(0),
+            deletes: AtomicU64::new(0),
+            errors: AtomicU64::new(0),
+            stale_hits: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
+            coalesced_waits: AtomicU64::new(0),
+            refreshes: AtomicU64::new(0),
+            refresh_errors: AtomicU64::new(0),
+            repository_populates: AtomicU64::new(0),
+            invalidations: AtomicU64::new(0),
+            bypasses: AtomicU64::new(0),
+            hit_latency: OpHistogram::new(num_buckets),
+            miss_latency: OpHistogram::new(num_buckets),
+            set_latency: OpHistogram::new(num_buckets),
+            delete_latency: OpHistogram::new(num_buckets),
+        }
+    }
+
+    /// Read the current counters and latency percentiles without resetting
+    /// them.
+    pub fn snapshot(&self) -> StatsSnapshot {
+        let hits = self.hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
+        let sets = self.sets.load(Ordering::Relaxed);
+        let deletes = self.deletes.load(Ordering::Relaxed);
+        let hit_ratio = if hits + misses == 0 {
+            0.0
+        } else {
+            hits as f64 / (hits + misses) as f64
+        };
+
+        StatsSnapshot {
+            hit_ratio,
+            total_ops: hits + misses + sets + deletes,
+            hits,
+            misses,
+            sets,
+            deletes,
+            hit: self.percentiles(&self.hit_latency),
+            miss: self.percentiles(&self.miss_latency),
+            set: self.percentiles(&self.set_latency),
+            delete: self.percentiles(&self.delete_latency),
+        }
+    }
+
+    fn percentiles(&self, histogram: &OpHistogram) -> LatencyPercentiles {
+        LatencyPercentiles {
+            p50_us: histogram.percentile(&self.buckets, 0.50),
+            p95_us: histogram.percentile(&self.buckets, 0.95),
+            p99_us: histogram.percentile(&self.buckets, 0.99),
+        }
+    }
+}
+
+impl Default for StatsCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CacheMetrics for StatsCollector {
+    fn record_hit(&self, _key: &str, duration: Duration) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        self.hit_latency.observe(&self.buckets, duration);
+    }
+
+    fn record_miss(&self, _key: &str, duration: Duration) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        self.miss_latency.observe(&self.buckets, duration);
+    }
+
+    fn record_set(&self, _key: &str, duration: Duration) {
+        self.sets.fetch_add(1, Ordering::Relaxed);
+        self.set_latency.observe(&self.buckets, duration);
+    }
+
+    fn record_delete(&self, _key: &str, duration: Duration) {
+        self.deletes.fetch_add(1, Ordering::Relaxed);
+        self.delete_latency.observe(&self.buckets, duration);
+    }
+
+    fn record_error(&self, _key: &str, _error: &str) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_stale_hit(&self, _key: &str, duration: Duration) {
+        self.stale_hits.fetch_add(1, Ordering::Relaxed);
+        self.hit_latency.observe(&self.buckets, duration);
+    }
+
+    fn record_eviction(&self, _key: &str) {
+        self.evictions.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_coalesced_wait(&self, _key: &str) {
+        self.coalesced_waits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_refresh(&self, _key: &str, _duration: Duration) {
+        self.refreshes.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_refresh_error(&self, _key: &str, _error: &str) {
+        self.refresh_errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_repository_populate(&self, _key: &str, _duration: Duration) {
+        self.repository_populates.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_invalidation(&self, _key: &str) {
+        self.invalidations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_bypass(&self, _key: &str) {
+        self.bypasses.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// The entity-type prefix of a `"prefix:id"` cache key (see
+/// [`crate::key::CacheKeyBuilder::build`]), or the whole key if it has no
+/// `:` separator. Used to label [`PrometheusMetrics`] and [`TracingMetrics`]
+/// output by entity type instead of by individual key, which would
+/// otherwise grow one time series (or one distinct field value) per cache
+/// key ever touched.
+#[cfg(any(feature = "metrics", feature = "tracing"))]
+fn entity_type_label(key: &str) -> &str {
+    key.split_once(':').map_or(key, |(prefix, _)| prefix)
+}
+
+/// Per-entity-type counters and latency histograms backing
+/// [`PrometheusMetrics::render`].
+#[cfg(feature = "metrics")]
+struct PrometheusEntityMetrics {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    sets: AtomicU64,
+    deletes: AtomicU64,
+    errors: AtomicU64,
+    stale_hits: AtomicU64,
+    evictions: AtomicU64,
+    coalesced_waits: AtomicU64,
+    refreshes: AtomicU64,
+    refresh_errors: AtomicU64,
+    repository_populates: AtomicU64,
+    invalidations: AtomicU64,
+    bypasses: AtomicU64,
+    hit_latency: OpHistogram,
+    miss_latency: OpHistogram,
+    set_latency: OpHistogram,
+    delete_latency: OpHistogram,
+}
+
+#[cfg(feature = "metrics")]
+impl PrometheusEntityMetrics {
+    fn new(num_buckets: usize) -> Self {
+        PrometheusEntityMetrics {
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            sets: AtomicU64::new(0),
+            deletes: AtomicU64::new(0),
+            errors: AtomicU64::new(0),
+            stale_hits: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
+            coalesced_waits: AtomicU64::new(0),
+            refreshes: AtomicU64::new(0),
+            refresh_errors: AtomicU64::new(0),
+            repository_populates: AtomicU64::new(0),
+            invalidations: AtomicU64::new(0),
+            bypasses: AtomicU64::new(0),
+            hit_latency: OpHistogram::new(num_buckets),
+            miss_latency: OpHistogram::new(num_buckets),
+            set_latency: OpHistogram::new(num_buckets),
+            delete_latency: OpHistogram::new(num_buckets),
+        }
+    }
+}
+
+#[cfg(feature = "metrics")]
+struct PrometheusState {
+    buckets: Vec<f64>,
+    entities: dashmap::DashMap<String, PrometheusEntityMetrics>,
+}
+
+/// [`CacheMetrics`] implementation that emits counters and histograms via the
+/// `metrics` crate facade (for scraping by whatever recorder the host
+/// process installs - Prometheus, StatsD, etc., see the `metrics-exporter-*`
+/// crates), while *also* keeping its own per-entity-type counters and
+/// latency histograms so this type can serve its own scrape endpoint via
+/// [`PrometheusMetrics::render`] without requiring a separate recorder.
+///
+/// Every series is labeled by `entity_type`, the prefix of the cache key
+/// before its first `:` (see [`entity_type_label`]) - that keeps
+/// cardinality bounded by the number of [`crate::entity::CacheEntity`]
+/// types in use, not by the number of distinct keys.
+///
+/// # Example
+///
+/// ```
+/// use cache_kit::observability::{CacheMetrics, PrometheusMetrics};
+/// use std::time::Duration;
+///
+/// let metrics = PrometheusMetrics::new();
+/// metrics.record_hit("user:1", Duration::from_micros(50));
+///
+/// let rendered = metrics.render();
+/// assert!(rendered.contains(r#"cache_kit_hits_total{entity_type="user"} 1"#));
+/// ```
+#[cfg(feature = "metrics")]
+#[derive(Clone)]
+pub struct PrometheusMetrics {
+    state: Arc<PrometheusState>,
+}
+
+#[cfg(feature = "metrics")]
+impl PrometheusMetrics {
+    /// Create a new instance using [`DEFAULT_LATENCY_BUCKETS_US`]. Installing
+    /// a recorder (e.g. `metrics_exporter_prometheus::PrometheusBuilder`) is
+    /// still the caller's responsibility if the global `metrics` facade
+    /// should also be fed - this type's own [`render`](Self::render) works
+    /// regardless.
+    pub fn new() -> Self {
+        Self::with_buckets(DEFAULT_LATENCY_BUCKETS_US.to_vec())
+    }
+
+    /// Create a new instance with explicit latency bucket upper bounds, in
+    /// microseconds.
+    pub fn with_buckets(buckets: Vec<f64>) -> Self {
+        PrometheusMetrics {
+            state: Arc::new(PrometheusState {
+                buckets,
+                entities: dashmap::DashMap::new(),
+            }),
+        }
+    }
+
+    fn entity(
+        &self,
+        key: &str,
+    ) -> dashmap::mapref::one::RefMut<'_, String, PrometheusEntityMetrics> {
+        let num_buckets = self.state.buckets.len();
+        self.state
+            .entities
+            .entry(entity_type_label(key).to_string())
+            .or_insert_with(|| PrometheusEntityMetrics::new(num_buckets))
+    }
+
+    /// Render every counter and latency histogram this collector has seen in
+    /// Prometheus text exposition format, suitable for returning directly
+    /// from a scrape handler (see [`prometheus_scrape_handler`]).
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for (name, help, extract) in [
+            (
+                "cache_kit_hits_total",
+                "Total cache hits.",
+                (|e: &PrometheusEntityMetrics| &e.hits) as fn(&PrometheusEntityMetrics) -> &AtomicU64,
+            ),
+            ("cache_kit_misses_total", "Total cache misses.", |e| &e.misses),
+            ("cache_kit_sets_total", "Total cache sets.", |e| &e.sets),
+            ("cache_kit_deletes_total", "Total cache deletes.", |e| &e.deletes),
+            ("cache_kit_errors_total", "Total cache errors.", |e| &e.errors),
+            (
+                "cache_kit_stale_hits_total",
+                "Total stale-while-revalidate hits.",
+                |e| &e.stale_hits,
+            ),
+            ("cache_kit_evictions_total", "Total cache evictions.", |e| &e.evictions),
+            (
+                "cache_kit_coalesced_waits_total",
+                "Total followers that joined an in-flight single-flight fetch.",
+                |e| &e.coalesced_waits,
+            ),
+            (
+                "cache_kit_refreshes_total",
+                "Total successful background refreshes.",
+                |e| &e.refreshes,
+            ),
+            (
+                "cache_kit_refresh_errors_total",
+                "Total failed background refreshes.",
+                |e| &e.refresh_errors,
+            ),
+            (
+                "cache_kit_repository_populates_total",
+                "Total backend misses resolved by fetching from the repository.",
+                |e| &e.repository_populates,
+            ),
+            (
+                "cache_kit_invalidations_total",
+                "Total CacheStrategy::Invalidate calls.",
+                |e| &e.invalidations,
+            ),
+            (
+                "cache_kit_bypasses_total",
+                "Total CacheStrategy::Bypass calls.",
+                |e| &e.bypasses,
+            ),
+        ] {
+            out.push_str(&format!("# HELP {name} {help}\n# TYPE {name} counter\n"));
+            for entry in self.state.entities.iter() {
+                let count = extract(entry.value()).load(Ordering::Relaxed);
+                out.push_str(&format!(
+                    "{name}{{entity_type=\"{}\"}} {count}\n",
+                    entry.key()
+                ));
+            }
+        }
+
+        out.push_str(
+            "# HELP cache_kit_operation_duration_seconds Cache operation latency, in seconds.\n\
+             # TYPE cache_kit_operation_duration_seconds histogram\n",
+        );
+        for entry in self.state.entities.iter() {
+            let entity_type = entry.key();
+            for (op, histogram) in [
+                ("hit", &entry.value().hit_latency),
+                ("miss", &entry.value().miss_latency),
+                ("set", &entry.value().set_latency),
+                ("delete", &entry.value().delete_latency),
+            ] {
+                for (bound_us, counter) in self.state.buckets.iter().zip(histogram.bucket_counts.iter()) {
+                    out.push_str(&format!(
+                        "cache_kit_operation_duration_seconds_bucket{{op=\"{op}\",entity_type=\"{entity_type}\",le=\"{}\"}} {}\n",
+                        bound_us / 1_000_000.0,
+                        counter.load(Ordering::Relaxed)
+                    ));
+                }
+                let count = histogram.count.load(Ordering::Relaxed);
+                out.push_str(&format!(
+                    "cache_kit_operation_duration_seconds_bucket{{op=\"{op}\",entity_type=\"{entity_type}\",le=\"+Inf\"}} {count}\n"
+                ));
+                out.push_str(&format!(
+                    "cache_kit_operation_duration_seconds_sum{{op=\"{op}\",entity_type=\"{entity_type}\"}} {}\n",
+                    histogram.sum_us.load(Ordering::Relaxed) as f64 / 1_000_000.0
+                ));
+                out.push_str(&format!(
+                    "cache_kit_operation_duration_seconds_count{{op=\"{op}\",entity_type=\"{entity_type}\"}} {count}\n"
+                ));
+            }
+        }
+        out
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl Default for PrometheusMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl CacheMetrics for PrometheusMetrics {
+    fn record_hit(&self, key: &str, duration: Duration) {
+        let entity_type = entity_type_label(key).to_string();
+        metrics::counter!("cache_kit_hits_total", "entity_type" => entity_type.clone()).increment(1);
+        metrics::histogram!("cache_kit_operation_duration_seconds", "op" => "hit", "entity_type" => entity_type)
+            .record(duration.as_secs_f64());
+        let entry = self.entity(key);
+        entry.hits.fetch_add(1, Ordering::Relaxed);
+        entry.hit_latency.observe(&self.state.buckets, duration);
+    }
+
+    fn record_miss(&self, key: &str, duration: Duration) {
+        let entity_type = entity_type_label(key).to_string();
+        metrics::counter!("cache_kit_misses_total", "entity_type" => entity_type.clone()).increment(1);
+        metrics::histogram!("cache_kit_operation_duration_seconds", "op" => "miss", "entity_type" => entity_type)
+            .record(duration.as_secs_f64());
+        let entry = self.entity(key);
+        entry.misses.fetch_add(1, Ordering::Relaxed);
+        entry.miss_latency.observe(&self.state.buckets, duration);
+    }
+
+    fn record_set(&self, key: &str, duration: Duration) {
+        let entity_type = entity_type_label(key).to_string();
+        metrics::counter!("cache_kit_sets_total", "entity_type" => entity_type.clone()).increment(1);
+        metrics::histogram!("cache_kit_operation_duration_seconds", "op" => "set", "entity_type" => entity_type)
+            .record(duration.as_secs_f64());
+        let entry = self.entity(key);
+        entry.sets.fetch_add(1, Ordering::Relaxed);
+        entry.set_latency.observe(&self.state.buckets, duration);
+    }
+
+    fn record_delete(&self, key: &str, duration: Duration) {
+        let entity_type = entity_type_label(key).to_string();
+        metrics::counter!("cache_kit_deletes_total", "entity_type" => entity_type.clone()).increment(1);
+        metrics::histogram!("cache_kit_operation_duration_seconds", "op" => "delete", "entity_type" => entity_type)
+            .record(duration.as_secs_f64());
+        let entry = self.entity(key);
+        entry.deletes.fetch_add(1, Ordering::Relaxed);
+        entry.delete_latency.observe(&self.state.buckets, duration);
+    }
+
+    fn record_error(&self, key: &str, error: &str) {
+        let entity_type = entity_type_label(key).to_string();
+        metrics::counter!("cache_kit_errors_total", "entity_type" => entity_type).increment(1);
+        self.entity(key).errors.fetch_add(1, Ordering::Relaxed);
+        warn!("Cache ERROR for {}: {}", key, error);
+    }
+
+    fn record_stale_hit(&self, key: &str, duration: Duration) {
+        let entity_type = entity_type_label(key).to_string();
+        metrics::counter!("cache_kit_stale_hits_total", "entity_type" => entity_type.clone()).increment(1);
+        metrics::histogram!("cache_kit_operation_duration_seconds", "op" => "stale_hit", "entity_type" => entity_type)
+            .record(duration.as_secs_f64());
+        self.entity(key).stale_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_eviction(&self, key: &str) {
+        let entity_type = entity_type_label(key).to_string();
+        metrics::counter!("cache_kit_evictions_total", "entity_type" => entity_type).increment(1);
+        self.entity(key).evictions.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_coalesced_wait(&self, key: &str) {
+        let entity_type = entity_type_label(key).to_string();
+        metrics::counter!("cache_kit_coalesced_waits_total", "entity_type" => entity_type).increment(1);
+        self.entity(key).coalesced_waits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_refresh(&self, key: &str, duration: Duration) {
+        let entity_type = entity_type_label(key).to_string();
+        metrics::counter!("cache_kit_refreshes_total", "entity_type" => entity_type.clone()).increment(1);
+        metrics::histogram!("cache_kit_operation_duration_seconds", "op" => "refresh", "entity_type" => entity_type)
+            .record(duration.as_secs_f64());
+        self.entity(key).refreshes.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_refresh_error(&self, key: &str, error: &str) {
+        let entity_type = entity_type_label(key).to_string();
+        metrics::counter!("cache_kit_refresh_errors_total", "entity_type" => entity_type).increment(1);
+        self.entity(key).refresh_errors.fetch_add(1, Ordering::Relaxed);
+        warn!("Cache REFRESH ERROR for {}: {}", key, error);
+    }
+
+    fn record_repository_populate(&self, key: &str, duration: Duration) {
+        let entity_type = entity_type_label(key).to_string();
+        metrics::counter!("cache_kit_repository_populates_total", "entity_type" => entity_type.clone()).increment(1);
+        metrics::histogram!("cache_kit_operation_duration_seconds", "op" => "repository_populate", "entity_type" => entity_type)
+            .record(duration.as_secs_f64());
+        self.entity(key).repository_populates.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_invalidation(&self, key: &str) {
+        let entity_type = entity_type_label(key).to_string();
+        metrics::counter!("cache_kit_invalidations_total", "entity_type" => entity_type).increment(1);
+        self.entity(key).invalidations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_bypass(&self, key: &str) {
+        let entity_type = entity_type_label(key).to_string();
+        metrics::counter!("cache_kit_bypasses_total", "entity_type" => entity_type).increment(1);
+        self.entity(key).bypasses.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Actix handler that renders a shared [`PrometheusMetrics`] collector in
+/// Prometheus text exposition format - mount it at whatever path your
+/// scrape config's `prometheus_url` points at, mirroring the
+/// usage-metering scrape endpoint the demeter fabric daemon exposes.
+///
+/// ```ignore
+/// use actix_web::{web, App};
+/// use cache_kit::observability::{prometheus_scrape_handler, PrometheusMetrics};
+///
+/// let metrics = PrometheusMetrics::new();
+/// let app = App::new()
+///     .app_data(web::Data::new(metrics))
+///     .route("/metrics", web::get().to(prometheus_scrape_handler));
+/// ```
+#[cfg(all(feature = "metrics", feature = "actix"))]
+pub async fn prometheus_scrape_handler(
+    metrics: actix_web::web::Data<PrometheusMetrics>,
+) -> impl actix_web::Responder {
+    actix_web::HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4")
+        .body(metrics.render())
+}
+
+/// [`CacheMetrics`] implementation that emits structured `tracing` events
+/// instead of formatted `log` strings, with `key`, `entity_type`,
+/// `duration_ms`, `outcome`, and (for errors) `error` as separate fields
+/// rather than interpolated into a message - so a JSON/bunyan subscriber can
+/// filter and aggregate on them without parsing text.
+///
+/// Each call also opens (and immediately closes) a `cache_op` span around
+/// the event. With no ambient span this is a no-op wrapper, but when called
+/// from inside a request span (e.g. `tracing-actix-web`'s
+/// `RootSpan`/`TracingLogger`), `cache_op` nests under it, so a cache hit or
+/// miss shows up correlated with the request that triggered it instead of
+/// as a free-floating log line.
+///
+/// # Example
+///
+/// ```
+/// use cache_kit::observability::{CacheMetrics, TracingMetrics};
+/// use std::time::Duration;
+///
+/// let metrics = TracingMetrics;
+/// metrics.record_hit("user:1", Duration::from_micros(50));
+/// ```
+#[cfg(feature = "tracing")]
+#[derive(Clone, Copy, Default)]
+pub struct TracingMetrics;
+
+#[cfg(feature = "tracing")]
+impl CacheMetrics for TracingMetrics {
+    fn record_hit(&self, key: &str, duration: Duration) {
+        let _span = tracing::info_span!("cache_op", key, entity_type = entity_type_label(key)).entered();
+        tracing::info!(
+            key,
+            entity_type = entity_type_label(key),
+            duration_ms = duration.as_secs_f64() * 1_000.0,
+            outcome = "hit",
+            "cache hit"
+        );
+    }
+
+    fn record_miss(&self, key: &str, duration: Duration) {
+        let _span = tracing::info_span!("cache_op", key, entity_type = entity_type_label(key)).entered();
+        tracing::info!(
+            key,
+            entity_type = entity_type_label(key),
+            duration_ms = duration.as_secs_f64() * 1_000.0,
+            outcome = "miss",
+            "cache miss"
+        );
+    }
+
+    fn record_set(&self, key: &str, duration: Duration) {
+        let _span = tracing::info_span!("cache_op", key, entity_type = entity_type_label(key)).entered();
+        tracing::info!(
+            key,
+            entity_type = entity_type_label(key),
+            duration_ms = duration.as_secs_f64() * 1_000.0,
+            outcome = "set",
+            "cache set"
+        );
+    }
+
+    fn record_delete(&self, key: &str, duration: Duration) {
+        let _span = tracing::info_span!("cache_op", key, entity_type = entity_type_label(key)).entered();
+        tracing::info!(
+            key,
+            entity_type = entity_type_label(key),
+            duration_ms = duration.as_secs_f64() * 1_000.0,
+            outcome = "delete",
+            "cache delete"
+        );
+    }
+
+    fn record_error(&self, key: &str, error: &str) {
+        let _span = tracing::info_span!("cache_op", key, entity_type = entity_type_label(key)).entered();
+        tracing::error!(
+            key,
+            entity_type = entity_type_label(key),
+            outcome = "error",
+            error,
+            "cache error"
+        );
+    }
+
+    fn record_stale_hit(&self, key: &str, duration: Duration) {
+        let _span = tracing::info_span!("cache_op", key, entity_type = entity_type_label(key)).entered();
+        tracing::info!(
+            key,
+            entity_type = entity_type_label(key),
+            duration_ms = duration.as_secs_f64() * 1_000.0,
+            outcome = "stale_hit",
+            "cache stale hit"
+        );
+    }
+
+    fn record_eviction(&self, key: &str) {
+        let _span = tracing::info_span!("cache_op", key, entity_type = entity_type_label(key)).entered();
+        tracing::info!(
+            key,
+            entity_type = entity_type_label(key),
+            outcome = "eviction",
+            "cache eviction"
+        );
+    }
+
+    fn record_refresh(&self, key: &str, duration: Duration) {
+        let _span = tracing::info_span!("cache_op", key, entity_type = entity_type_label(key)).entered();
+        tracing::info!(
+            key,
+            entity_type = entity_type_label(key),
+            duration_ms = duration.as_secs_f64() * 1_000.0,
+            outcome = "refresh",
+            "cache background refresh"
+        );
+    }
+
+    fn record_refresh_error(&self, key: &str, error: &str) {
+        let _span = tracing::info_span!("cache_op", key, entity_type = entity_type_label(key)).entered();
+        tracing::error!(
+            key,
+            entity_type = entity_type_label(key),
+            outcome = "refresh_error",
+            error,
+            "cache background refresh error"
+        );
+    }
+
+    fn record_coalesced_wait(&self, key: &str) {
+        let _span = tracing::info_span!("cache_op", key, entity_type = entity_type_label(key)).entered();
+        tracing::info!(
+            key,
+            entity_type = entity_type_label(key),
+            outcome = "coalesced_wait",
+            "cache coalesced wait"
+        );
+    }
+
+    fn record_repository_populate(&self, key: &str, duration: Duration) {
+        let _span = tracing::info_span!("cache_op", key, entity_type = entity_type_label(key)).entered();
+        tracing::info!(
+            key,
+            entity_type = entity_type_label(key),
+            duration_ms = duration.as_secs_f64() * 1_000.0,
+            outcome = "repository_populate",
+            "cache backend miss populated from repository"
+        );
+    }
+
+    fn record_invalidation(&self, key: &str) {
+        let _span = tracing::info_span!("cache_op", key, entity_type = entity_type_label(key)).entered();
+        tracing::info!(
+            key,
+            entity_type = entity_type_label(key),
+            outcome = "invalidation",
+            "cache invalidation"
+        );
+    }
+
+    fn record_bypass(&self, key: &str) {
+        let _span = tracing::info_span!("cache_op", key, entity_type = entity_type_label(key)).entered();
+        tracing::info!(
+            key,
+            entity_type = entity_type_label(key),
+            outcome = "bypass",
+            "cache bypass"
+        );
+    }
 }
 
 /// TTL (Time-to-Live) policy for cache entries.
@@ -136,9 +1514,115 @@ pub enum TtlPolicy {
 
     /// Custom per-type policy
     PerType(fn(&str) -> Duration),
+
+    /// Randomize each entry's TTL within `base ± spread`, so entries written
+    /// around the same time don't all expire in the same instant and stampede
+    /// the repository at once.
+    Jittered {
+        /// Center of the TTL range.
+        base: Duration,
+        /// Maximum deviation from `base` in either direction.
+        spread: Duration,
+    },
+
+    /// Re-arm the TTL on every cache hit (refresh-on-read, i.e. "expire
+    /// after idle time") instead of letting it count down from the last
+    /// write. `CacheExpander` does this by issuing
+    /// [`crate::backend::CacheBackend::expire`] on a hit - resetting the
+    /// backend's own expiry is sufficient to get idle-expiry semantics, so
+    /// there's no separate `last_accessed` timestamp to maintain.
+    Sliding(Duration),
+
+    /// A soft TTL to source [`crate::expander::OperationConfig::with_stale_after`]
+    /// from when a call doesn't set one explicitly, plus the entry's real
+    /// (hard) backend TTL - for `CacheStrategy::StaleWhileRevalidate` callers
+    /// that want one policy covering every key of a type instead of repeating
+    /// `with_stale_after` at every call site. See [`TtlPolicy::soft_ttl`].
+    SoftHard {
+        /// How long a hit is served fresh before it's eligible for a
+        /// background refresh; read via [`TtlPolicy::soft_ttl`].
+        soft: Duration,
+        /// The entry's real backend TTL; read via [`TtlPolicy::get_ttl`] like
+        /// every other variant. This doubles as the grace window past
+        /// `soft`: once `hard` lapses too, the backend entry is actually
+        /// gone, so the next call is a genuine miss and falls back to a
+        /// synchronous fetch rather than serving indefinitely-stale data.
+        hard: Duration,
+    },
 }
 
+// Note on "refresh before hard expiry" (the margin idea from limitador's
+// cache): `CacheStrategy::StaleWhileRevalidate` already implements this -
+// entries are wrapped in a soft-expiry marker and judged stale `stale_after`
+// before their backend TTL runs out, triggering a background refresh on a
+// still-served hit. `TtlPolicy::SoftHard` doesn't duplicate that mechanism;
+// it's just an alternate source for the same `stale_after` duration (via
+// `soft_ttl()`) for policies that want it set once per entity type instead
+// of passed to `OperationConfig::with_stale_after` on every call.
+// `TtlPolicy::refresh_ahead` is the same idea again, one level up: a
+// fraction-of-TTL constructor for `SoftHard` for callers that think in terms
+// of "refresh at 80% of TTL" rather than picking an absolute soft duration.
+
 impl TtlPolicy {
+    /// Build a [`TtlPolicy::Jittered`] from a jitter *ratio* instead of an
+    /// absolute spread - `base` scaled by a random factor in
+    /// `[1.0 - jitter, 1.0 + jitter]` each time [`TtlPolicy::get_ttl`] is
+    /// called, so a batch of entries written together don't all expire in
+    /// the same instant and stampede the repository at once.
+    ///
+    /// `jitter` is clamped to `[0.0, 1.0]`, and the resulting spread is
+    /// clamped to a minimum of one second so a small `base` with a small
+    /// `jitter` doesn't round down to "no jitter at all".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cache_kit::observability::TtlPolicy;
+    /// use std::time::Duration;
+    ///
+    /// let policy = TtlPolicy::jittered_ratio(Duration::from_secs(600), 0.1);
+    /// let ttl = policy.get_ttl("user").unwrap();
+    /// assert!(ttl >= Duration::from_secs(540) && ttl <= Duration::from_secs(660));
+    /// ```
+    pub fn jittered_ratio(base: Duration, jitter: f64) -> Self {
+        let jitter = jitter.clamp(0.0, 1.0);
+        let spread = (base.as_secs_f64() * jitter).max(1.0);
+        TtlPolicy::Jittered {
+            base,
+            spread: Duration::from_secs_f64(spread),
+        }
+    }
+
+    /// Build a [`TtlPolicy::SoftHard`] from a TTL and a refresh-ahead
+    /// *fraction* of it, instead of an absolute soft TTL - `refresh_at` (e.g.
+    /// `0.8`) is the fraction of `ttl` a hit must have aged past before it's
+    /// eligible for a background refresh (see
+    /// [`crate::expander::CacheExpander::with_stale_while_revalidate`], which
+    /// this soft TTL feeds via [`TtlPolicy::soft_ttl`]).
+    ///
+    /// `refresh_at` is clamped to `[0.0, 1.0]`; `0.0` makes every hit eligible
+    /// for an immediate background refresh, `1.0` disables refresh-ahead
+    /// entirely (a hit is never older than `ttl` before the backend entry
+    /// itself expires).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cache_kit::observability::TtlPolicy;
+    /// use std::time::Duration;
+    ///
+    /// let policy = TtlPolicy::refresh_ahead(Duration::from_secs(300), 0.8);
+    /// assert_eq!(policy.soft_ttl(), Some(Duration::from_secs(240)));
+    /// assert_eq!(policy.get_ttl("user"), Some(Duration::from_secs(300)));
+    /// ```
+    pub fn refresh_ahead(ttl: Duration, refresh_at: f64) -> Self {
+        let refresh_at = refresh_at.clamp(0.0, 1.0);
+        TtlPolicy::SoftHard {
+            soft: Duration::from_secs_f64(ttl.as_secs_f64() * refresh_at),
+            hard: ttl,
+        }
+    }
+
     /// Get TTL for an entity type.
     pub fn get_ttl(&self, entity_type: &str) -> Option<Duration> {
         match self {
@@ -146,10 +1630,57 @@ impl TtlPolicy {
             TtlPolicy::Fixed(d) => Some(*d),
             TtlPolicy::Infinite => None,
             TtlPolicy::PerType(f) => Some(f(entity_type)),
+            TtlPolicy::Jittered { base, spread } => Some(jittered_duration(*base, *spread)),
+            TtlPolicy::Sliding(d) => Some(*d),
+            TtlPolicy::SoftHard { hard, .. } => Some(*hard),
+        }
+    }
+
+    /// Whether a cache hit under this policy should re-arm the entry's TTL
+    /// (see [`TtlPolicy::Sliding`]).
+    pub fn is_sliding(&self) -> bool {
+        matches!(self, TtlPolicy::Sliding(_))
+    }
+
+    /// The soft TTL [`TtlPolicy::SoftHard`] carries, for
+    /// `CacheExpander::with_stale_while_revalidate` to fall back to when a
+    /// call omits `OperationConfig::with_stale_after`. Every other variant
+    /// has no notion of staleness, so this is `None` for them.
+    pub fn soft_ttl(&self) -> Option<Duration> {
+        match self {
+            TtlPolicy::SoftHard { soft, .. } => Some(*soft),
+            _ => None,
         }
     }
 }
 
+/// Deterministic xorshift64 PRNG state for [`jittered_duration`], advanced on
+/// every call. Not seeded per-`TtlPolicy` instance since `TtlPolicy` is
+/// `Clone` and cheaply copied around; a shared counter is enough for
+/// "spread expiry out," which doesn't need cryptographic randomness.
+static JITTER_STATE: AtomicU64 = AtomicU64::new(0x9E3779B97F4A7C15);
+
+/// Pick a duration in `[base - spread, base + spread]`, saturating at zero.
+fn jittered_duration(base: Duration, spread: Duration) -> Duration {
+    if spread.is_zero() {
+        return base;
+    }
+
+    let mut x = JITTER_STATE.fetch_add(1, Ordering::Relaxed);
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    JITTER_STATE.store(x, Ordering::Relaxed);
+
+    // Map to a unit value in [0.0, 1.0), then to [-1.0, 1.0).
+    let unit = (x >> 11) as f64 * (1.0 / (1u64 << 53) as f64);
+    let signed_unit = unit * 2.0 - 1.0;
+
+    let offset_nanos = (spread.as_nanos() as f64) * signed_unit;
+    let result_nanos = (base.as_nanos() as f64 + offset_nanos).max(0.0);
+    Duration::from_nanos(result_nanos as u64)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -161,6 +1692,252 @@ mod tests {
         metrics.record_miss("key", Duration::from_secs(2));
     }
 
+    #[test]
+    fn test_composite_metrics_forwards_to_every_sink() {
+        let a = Arc::new(AtomicMetrics::new());
+        let b = Arc::new(AtomicMetrics::new());
+        let metrics = CompositeMetrics::new()
+            .with_sink(Box::new(Arc::clone(&a)))
+            .with_sink(Box::new(Arc::clone(&b)));
+
+        metrics.record_hit("user:1", Duration::from_micros(50));
+        metrics.record_miss("user:2", Duration::from_micros(80));
+
+        assert_eq!(a.snapshot().hits, 1);
+        assert_eq!(a.snapshot().misses, 1);
+        assert_eq!(b.snapshot().hits, 1);
+        assert_eq!(b.snapshot().misses, 1);
+    }
+
+    #[test]
+    fn test_composite_metrics_from_sinks_matches_incremental_building() {
+        let sink = Arc::new(AtomicMetrics::new());
+        let metrics = CompositeMetrics::from_sinks(vec![Box::new(Arc::clone(&sink))]);
+
+        metrics.record_eviction("user:1");
+
+        assert_eq!(sink.snapshot().evictions, 1);
+    }
+
+    #[test]
+    fn test_composite_metrics_with_no_sinks_is_a_no_op() {
+        let metrics = CompositeMetrics::new();
+        metrics.record_hit("key", Duration::from_millis(1));
+        metrics.record_error("key", "boom");
+    }
+
+    #[test]
+    fn test_atomic_metrics_counts_each_event() {
+        let metrics = AtomicMetrics::new();
+        metrics.record_hit("key", Duration::from_millis(1));
+        metrics.record_hit("key", Duration::from_millis(1));
+        metrics.record_miss("key", Duration::from_millis(1));
+        metrics.record_set("key", Duration::from_millis(1));
+        metrics.record_delete("key", Duration::from_millis(1));
+        metrics.record_error("key", "boom");
+        metrics.record_eviction("key");
+        metrics.record_coalesced_wait("key");
+        metrics.record_refresh("key", Duration::from_millis(1));
+        metrics.record_refresh_error("key", "boom");
+        metrics.record_repository_populate("key", Duration::from_millis(1));
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.hits, 2);
+        assert_eq!(snapshot.misses, 1);
+        assert_eq!(snapshot.sets, 1);
+        assert_eq!(snapshot.deletes, 1);
+        assert_eq!(snapshot.errors, 1);
+        assert_eq!(snapshot.evictions, 1);
+        assert_eq!(snapshot.coalesced_waits, 1);
+        assert_eq!(snapshot.refreshes, 1);
+        assert_eq!(snapshot.refresh_errors, 1);
+        assert_eq!(snapshot.repository_populates, 1);
+    }
+
+    #[test]
+    fn test_metrics_snapshot_hit_ratio() {
+        let snapshot = MetricsSnapshot {
+            hits: 3,
+            misses: 1,
+            ..Default::default()
+        };
+        assert_eq!(snapshot.hit_ratio(), 0.75);
+        assert_eq!(MetricsSnapshot::default().hit_ratio(), 0.0);
+    }
+
+    #[test]
+    fn test_histogram_metrics_counts_and_sums_into_the_right_buckets() {
+        let metrics = HistogramMetrics::with_buckets(vec![100.0, 1_000.0]);
+        metrics.record_hit("key", Duration::from_micros(50));
+        metrics.record_hit("key", Duration::from_micros(500));
+        metrics.record_hit("key", Duration::from_micros(5_000));
+
+        let rendered = metrics.render_prometheus();
+        assert!(rendered.contains(r#"cache_op_latency_us_bucket{op="hit",le="100"} 1"#));
+        assert!(rendered.contains(r#"cache_op_latency_us_bucket{op="hit",le="1000"} 2"#));
+        assert!(rendered.contains(r#"cache_op_latency_us_bucket{op="hit",le="+Inf"} 3"#));
+        assert!(rendered.contains(r#"cache_op_latency_us_sum{op="hit"} 5550"#));
+        assert!(rendered.contains(r#"cache_op_latency_us_count{op="hit"} 3"#));
+    }
+
+    #[test]
+    fn test_histogram_metrics_avg_latency_us() {
+        let metrics = HistogramMetrics::new();
+        assert_eq!(metrics.avg_latency_us("hit"), 0.0);
+
+        metrics.record_set("key", Duration::from_micros(100));
+        metrics.record_set("key", Duration::from_micros(300));
+        assert_eq!(metrics.avg_latency_us("set"), 200.0);
+        assert_eq!(metrics.avg_latency_us("unknown-op"), 0.0);
+    }
+
+    #[test]
+    fn test_histogram_metrics_tracks_each_operation_class_independently() {
+        let metrics = HistogramMetrics::new();
+        metrics.record_hit("key", Duration::from_micros(10));
+        metrics.record_miss("key", Duration::from_micros(20));
+        metrics.record_set("key", Duration::from_micros(30));
+        metrics.record_delete("key", Duration::from_micros(40));
+
+        let rendered = metrics.render_prometheus();
+        assert!(rendered.contains(r#"cache_op_latency_us_count{op="hit"} 1"#));
+        assert!(rendered.contains(r#"cache_op_latency_us_count{op="miss"} 1"#));
+        assert!(rendered.contains(r#"cache_op_latency_us_count{op="set"} 1"#));
+        assert!(rendered.contains(r#"cache_op_latency_us_count{op="delete"} 1"#));
+    }
+
+    #[test]
+    fn test_stats_collector_hit_ratio_and_total_ops() {
+        let stats = StatsCollector::new();
+        stats.record_hit("key", Duration::from_micros(10));
+        stats.record_hit("key", Duration::from_micros(10));
+        stats.record_miss("key", Duration::from_micros(10));
+        stats.record_set("key", Duration::from_micros(10));
+
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.hit_ratio, 2.0 / 3.0);
+        assert_eq!(snapshot.total_ops, 4);
+    }
+
+    #[test]
+    fn test_stats_collector_empty_hit_ratio_is_zero() {
+        let snapshot = StatsCollector::new().snapshot();
+        assert_eq!(snapshot.hit_ratio, 0.0);
+        assert_eq!(snapshot.total_ops, 0);
+    }
+
+    #[test]
+    fn test_stats_collector_percentiles_track_the_right_operation_class() {
+        let stats = StatsCollector::with_buckets(vec![10.0, 100.0, 1_000.0, 10_000.0]);
+        for us in [5, 50, 500, 5_000] {
+            stats.record_hit("key", Duration::from_micros(us));
+        }
+        stats.record_miss("key", Duration::from_micros(1));
+
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.hit.p50_us, 100.0);
+        assert_eq!(snapshot.hit.p99_us, 10_000.0);
+        assert_eq!(snapshot.miss.p50_us, 10.0);
+        assert_eq!(snapshot.set.p50_us, 0.0, "no sets recorded yet");
+    }
+
+    #[test]
+    fn test_stats_collector_stale_hit_folds_into_hit_latency() {
+        let stats = StatsCollector::new();
+        stats.record_stale_hit("key", Duration::from_micros(10));
+
+        let snapshot = stats.snapshot();
+        assert!(snapshot.hit.p50_us > 0.0);
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn test_prometheus_metrics_labels_by_entity_type_not_full_key() {
+        let metrics = PrometheusMetrics::new();
+        metrics.record_hit("user:1", Duration::from_micros(10));
+        metrics.record_hit("user:2", Duration::from_micros(20));
+        metrics.record_miss("session:abc", Duration::from_micros(5));
+
+        let rendered = metrics.render();
+        assert!(rendered.contains(r#"cache_kit_hits_total{entity_type="user"} 2"#));
+        assert!(rendered.contains(r#"cache_kit_misses_total{entity_type="session"} 1"#));
+        assert!(!rendered.contains("user:1"));
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn test_prometheus_metrics_renders_latency_histogram_buckets() {
+        let metrics = PrometheusMetrics::with_buckets(vec![100.0, 1_000.0]);
+        metrics.record_set("user:1", Duration::from_micros(50));
+
+        let rendered = metrics.render();
+        assert!(rendered.contains(r#"cache_kit_operation_duration_seconds_bucket{op="set",entity_type="user",le="0.0001"}"#));
+        assert!(rendered.contains(r#"cache_kit_operation_duration_seconds_count{op="set",entity_type="user"} 1"#));
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn test_prometheus_metrics_key_without_prefix_labels_as_whole_key() {
+        let metrics = PrometheusMetrics::new();
+        metrics.record_eviction("no_prefix_key");
+
+        let rendered = metrics.render();
+        assert!(rendered.contains(r#"cache_kit_evictions_total{entity_type="no_prefix_key"} 1"#));
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn test_prometheus_metrics_counts_coalesced_waits() {
+        let metrics = PrometheusMetrics::new();
+        metrics.record_coalesced_wait("user:1");
+        metrics.record_coalesced_wait("user:2");
+
+        let rendered = metrics.render();
+        assert!(rendered.contains(r#"cache_kit_coalesced_waits_total{entity_type="user"} 2"#));
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn test_prometheus_metrics_counts_refreshes_and_refresh_errors() {
+        let metrics = PrometheusMetrics::new();
+        metrics.record_refresh("user:1", Duration::from_micros(10));
+        metrics.record_refresh_error("user:2", "db down");
+
+        let rendered = metrics.render();
+        assert!(rendered.contains(r#"cache_kit_refreshes_total{entity_type="user"} 1"#));
+        assert!(rendered.contains(r#"cache_kit_refresh_errors_total{entity_type="user"} 1"#));
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_tracing_metrics_record_methods_do_not_panic_without_a_subscriber() {
+        // With no subscriber installed, `tracing` events are no-ops - this
+        // just guards against a panic in field/span construction itself
+        // (e.g. a bad format string) rather than asserting on emitted output.
+        let metrics = TracingMetrics;
+        metrics.record_hit("user:1", Duration::from_micros(50));
+        metrics.record_miss("session:abc", Duration::from_micros(5));
+        metrics.record_set("user:1", Duration::from_micros(30));
+        metrics.record_delete("user:1", Duration::from_micros(10));
+        metrics.record_stale_hit("user:1", Duration::from_micros(60));
+        metrics.record_eviction("user:1");
+        metrics.record_coalesced_wait("user:1");
+        metrics.record_error("user:2", "backend unreachable");
+        metrics.record_refresh("user:1", Duration::from_micros(70));
+        metrics.record_refresh_error("user:2", "db down");
+    }
+
+    #[test]
+    fn test_arc_wrapped_metrics_delegates_to_the_inner_type() {
+        let metrics = Arc::new(AtomicMetrics::new());
+        CacheMetrics::record_hit(&metrics, "key", Duration::from_millis(1));
+        CacheMetrics::record_miss(&metrics, "key", Duration::from_millis(1));
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.hits, 1);
+        assert_eq!(snapshot.misses, 1);
+    }
+
     #[test]
     fn test_ttl_policy_default() {
         let policy = TtlPolicy::Default;
@@ -173,6 +1950,69 @@ mod tests {
         assert_eq!(policy.get_ttl("any"), Some(Duration::from_secs(300)));
     }
 
+    #[test]
+    fn test_ttl_policy_jittered_stays_within_spread() {
+        let policy = TtlPolicy::Jittered {
+            base: Duration::from_secs(300),
+            spread: Duration::from_secs(30),
+        };
+
+        for _ in 0..50 {
+            let ttl = policy.get_ttl("any").expect("Jittered should return a TTL");
+            assert!(ttl >= Duration::from_secs(270) && ttl <= Duration::from_secs(330));
+        }
+    }
+
+    #[test]
+    fn test_ttl_policy_jittered_zero_spread_is_exact() {
+        let policy = TtlPolicy::Jittered {
+            base: Duration::from_secs(60),
+            spread: Duration::ZERO,
+        };
+        assert_eq!(policy.get_ttl("any"), Some(Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn test_ttl_policy_jittered_ratio_stays_within_percentage_spread() {
+        let policy = TtlPolicy::jittered_ratio(Duration::from_secs(600), 0.1);
+
+        for _ in 0..50 {
+            let ttl = policy.get_ttl("any").expect("jittered_ratio should return a TTL");
+            assert!(ttl >= Duration::from_secs(540) && ttl <= Duration::from_secs(660));
+        }
+    }
+
+    #[test]
+    fn test_ttl_policy_jittered_ratio_clamps_spread_to_at_least_one_second() {
+        let policy = TtlPolicy::jittered_ratio(Duration::from_millis(500), 0.01);
+        match policy {
+            TtlPolicy::Jittered { spread, .. } => {
+                assert!(spread >= Duration::from_secs(1), "spread = {spread:?}")
+            }
+            _ => panic!("expected Jittered"),
+        }
+    }
+
+    #[test]
+    fn test_ttl_policy_jittered_ratio_clamps_out_of_range_jitter() {
+        let policy = TtlPolicy::jittered_ratio(Duration::from_secs(100), 5.0);
+        match policy {
+            TtlPolicy::Jittered { base, spread } => {
+                assert_eq!(base, Duration::from_secs(100));
+                assert_eq!(spread, Duration::from_secs(100));
+            }
+            _ => panic!("expected Jittered"),
+        }
+    }
+
+    #[test]
+    fn test_ttl_policy_sliding_returns_duration_and_is_sliding() {
+        let policy = TtlPolicy::Sliding(Duration::from_secs(120));
+        assert_eq!(policy.get_ttl("any"), Some(Duration::from_secs(120)));
+        assert!(policy.is_sliding());
+        assert!(!TtlPolicy::Fixed(Duration::from_secs(1)).is_sliding());
+    }
+
     #[test]
     fn test_ttl_policy_per_type() {
         let policy = TtlPolicy::PerType(|entity_type| match entity_type {
@@ -186,4 +2026,36 @@ mod tests {
         );
         assert_eq!(policy.get_ttl("other"), Some(Duration::from_secs(1800)));
     }
+
+    #[test]
+    fn test_ttl_policy_soft_hard_exposes_both_durations() {
+        let policy = TtlPolicy::SoftHard {
+            soft: Duration::from_secs(30),
+            hard: Duration::from_secs(300),
+        };
+        assert_eq!(policy.get_ttl("any"), Some(Duration::from_secs(300)));
+        assert_eq!(policy.soft_ttl(), Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn test_ttl_policy_soft_ttl_is_none_for_other_variants() {
+        assert_eq!(TtlPolicy::Fixed(Duration::from_secs(1)).soft_ttl(), None);
+        assert_eq!(TtlPolicy::Default.soft_ttl(), None);
+    }
+
+    #[test]
+    fn test_ttl_policy_refresh_ahead_builds_soft_hard_from_a_fraction() {
+        let policy = TtlPolicy::refresh_ahead(Duration::from_secs(300), 0.8);
+        assert_eq!(policy.get_ttl("any"), Some(Duration::from_secs(300)));
+        assert_eq!(policy.soft_ttl(), Some(Duration::from_secs(240)));
+    }
+
+    #[test]
+    fn test_ttl_policy_refresh_ahead_clamps_out_of_range_fraction() {
+        let below = TtlPolicy::refresh_ahead(Duration::from_secs(100), -1.0);
+        assert_eq!(below.soft_ttl(), Some(Duration::ZERO));
+
+        let above = TtlPolicy::refresh_ahead(Duration::from_secs(100), 2.0);
+        assert_eq!(above.soft_ttl(), Some(Duration::from_secs(100)));
+    }
 }
@@ -59,6 +59,22 @@
 use crate::entity::CacheEntity;
 use crate::error::Result;
 
+/// One page of a cursor-paginated scan over an entity set, returned by
+/// [`DataRepository::fetch_page`].
+///
+/// `next_cursor` is `None` once the scan has reached the end; otherwise pass
+/// it back as the `cursor` argument to fetch the next page.
+#[derive(Clone)]
+pub struct Page<T: CacheEntity> {
+    /// Entities in this page, in cursor order.
+    pub items: Vec<T>,
+    /// Cursor to pass to the next `fetch_page` call, or `None` if this was the last page.
+    pub next_cursor: Option<T::Key>,
+    /// Total entity count, if the repository can report it cheaply. `None`
+    /// when computing it would cost an extra full scan (e.g. keyset pagination).
+    pub total: Option<u64>,
+}
+
 /// Trait for data repository implementations.
 ///
 /// Abstracts database operations, decoupling cache from specific DB client.
@@ -117,6 +133,79 @@ pub trait DataRepository<T: CacheEntity>: Send + Sync {
             "fetch_all not implemented for this repository".to_string(),
         ))
     }
+
+    /// Optional: Scan the entity set in bounded chunks via keyset pagination,
+    /// instead of loading it all at once through `fetch_all`.
+    ///
+    /// Pass `cursor: None` for the first page, then feed back each page's
+    /// `Page::next_cursor` until it's `None`. Implementations should order by
+    /// `T::Key` and select `WHERE id > cursor` rather than `OFFSET`, so the
+    /// cost of fetching a page doesn't grow with how far into the scan it is.
+    ///
+    /// # Errors
+    /// Returns `Err` if not implemented or if data source operation fails
+    async fn fetch_page(&self, _cursor: Option<T::Key>, _limit: usize) -> Result<Page<T>> {
+        Err(crate::error::Error::NotImplemented(
+            "fetch_page not implemented for this repository".to_string(),
+        ))
+    }
+
+    /// Optional: Persist `entity` to the primary data source, inserting or
+    /// updating it as needed.
+    ///
+    /// Enables write-through/write-behind cache modes, where cache-kit writes
+    /// a dirty entry back to the repository instead of only reading through
+    /// it. Not required for read-through/refresh-only usage.
+    ///
+    /// # Errors
+    /// Returns `Err` if not implemented or if the write fails
+    async fn upsert(&self, _entity: &T) -> Result<()> {
+        Err(crate::error::Error::NotImplemented(
+            "upsert not implemented for this repository".to_string(),
+        ))
+    }
+
+    /// Optional: Batch upsert (optional optimization).
+    ///
+    /// Default implementation calls `upsert()` for each entity.
+    /// Override for efficiency (e.g., SQL bulk `INSERT ... ON CONFLICT`).
+    ///
+    /// # Errors
+    /// Returns `Err` if not implemented or if the write fails
+    async fn upsert_many(&self, entities: &[T]) -> Result<()> {
+        for entity in entities {
+            self.upsert(entity).await?;
+        }
+        Ok(())
+    }
+
+    /// Optional: Delete an entity from the primary data source by ID.
+    ///
+    /// # Returns
+    /// - `Ok(true)` - Entity existed and was deleted
+    /// - `Ok(false)` - Entity did not exist
+    ///
+    /// # Errors
+    /// Returns `Err` if not implemented or if the delete fails
+    async fn delete_by_id(&self, _id: &T::Key) -> Result<bool> {
+        Err(crate::error::Error::NotImplemented(
+            "delete_by_id not implemented for this repository".to_string(),
+        ))
+    }
+
+    /// Whether `entity`, just fetched from this repository, is safe to write
+    /// back into the cache.
+    ///
+    /// Defaults to `true`. Override to return `false` for results that are
+    /// unbounded or non-deterministic - a list query collapsed into a single
+    /// row, a row carrying a volatile computed column, or anything else that
+    /// shouldn't be replayed from cache on the next lookup. `CacheExpander`
+    /// still feeds such entities to the `CacheFeed` for this call, it just
+    /// skips the backend write, so the caller gets a correct answer every
+    /// time at the cost of always hitting this repository for that row.
+    fn is_cacheable(&self, _entity: &T) -> bool {
+        true
+    }
 }
 
 // ============================================================================
@@ -124,6 +213,37 @@ pub trait DataRepository<T: CacheEntity>: Send + Sync {
 // ============================================================================
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Failure injection policy for [`InMemoryRepository`], set via
+/// `InMemoryRepository::set_failure`.
+///
+/// Real repositories fail with timeouts, connectivity drops, and
+/// serialization errors; `InMemoryRepository` otherwise never returns `Err`,
+/// which leaves cache-kit's fallback/retry/stale-while-revalidate paths
+/// untested. Use this to make `fetch_by_id`/`fetch_by_ids` fail on demand.
+#[derive(Clone)]
+pub enum FailurePolicy {
+    /// Never fail (the default).
+    None,
+    /// Always fail with `error`.
+    Always(crate::error::Error),
+    /// Succeed for the first `after_calls` calls, then fail with `error` from
+    /// then on. Useful for simulating a connection that drops mid-test.
+    AfterCalls {
+        after_calls: usize,
+        error: crate::error::Error,
+    },
+    /// Fail with `error` with approximate probability `probability`
+    /// (`0.0..=1.0`), using a seeded PRNG so a given seed reproduces the same
+    /// sequence of failures across test runs. Seed via `set_seed`.
+    Probabilistic {
+        probability: f64,
+        error: crate::error::Error,
+    },
+}
 
 /// Simple in-memory repository for testing cache-kit implementations.
 ///
@@ -160,8 +280,20 @@ use std::collections::HashMap;
 /// - **Cache miss**: Keep repo empty, cache will fallback to repo (which has nothing)
 /// - **Invalidation**: Clear repo between operations to test refresh behavior
 /// - **Batch operations**: Use `fetch_by_ids()` to test multi-key scenarios
+/// - **Call assertions**: Use `fetch_by_id_calls()`/`fetch_by_ids_calls()`/`recorded_keys()`
+///   to assert a cache layer deduped reads or hit the repository exactly once after a miss
 pub struct InMemoryRepository<T: CacheEntity> {
-    data: HashMap<String, T>,
+    data: Mutex<HashMap<String, T>>,
+    fetch_by_id_calls: Arc<AtomicUsize>,
+    fetch_by_ids_calls: Arc<AtomicUsize>,
+    recorded_keys: Mutex<Vec<String>>,
+    failure_policy: Mutex<FailurePolicy>,
+    /// Successful calls seen under the current `failure_policy`, for `AfterCalls`.
+    calls_since_policy_set: AtomicUsize,
+    /// xorshift64 PRNG state for `FailurePolicy::Probabilistic`.
+    rng_state: AtomicU64,
+    per_key_failures: Mutex<HashMap<String, crate::error::Error>>,
+    artificial_delay: Mutex<Option<Duration>>,
 }
 
 impl<T: CacheEntity> InMemoryRepository<T> {
@@ -175,10 +307,44 @@ impl<T: CacheEntity> InMemoryRepository<T> {
     /// ```
     pub fn new() -> Self {
         InMemoryRepository {
-            data: HashMap::new(),
+            data: Mutex::new(HashMap::new()),
+            fetch_by_id_calls: Arc::new(AtomicUsize::new(0)),
+            fetch_by_ids_calls: Arc::new(AtomicUsize::new(0)),
+            recorded_keys: Mutex::new(Vec::new()),
+            failure_policy: Mutex::new(FailurePolicy::None),
+            calls_since_policy_set: AtomicUsize::new(0),
+            rng_state: AtomicU64::new(0x9E3779B97F4A7C15),
+            per_key_failures: Mutex::new(HashMap::new()),
+            artificial_delay: Mutex::new(None),
         }
     }
 
+    /// Number of times `fetch_by_id` has been called since creation or the
+    /// last `reset_stats()`.
+    pub fn fetch_by_id_calls(&self) -> usize {
+        self.fetch_by_id_calls.load(Ordering::SeqCst)
+    }
+
+    /// Number of times `fetch_by_ids` has been called since creation or the
+    /// last `reset_stats()`.
+    pub fn fetch_by_ids_calls(&self) -> usize {
+        self.fetch_by_ids_calls.load(Ordering::SeqCst)
+    }
+
+    /// Every key requested via `fetch_by_id` or `fetch_by_ids`, in call
+    /// order, since creation or the last `reset_stats()`.
+    pub fn recorded_keys(&self) -> Vec<String> {
+        self.recorded_keys.lock().expect("lock poisoned").clone()
+    }
+
+    /// Reset all call counters and the recorded key log. Stored entities are
+    /// left untouched; use `clear()` for that.
+    pub fn reset_stats(&self) {
+        self.fetch_by_id_calls.store(0, Ordering::SeqCst);
+        self.fetch_by_ids_calls.store(0, Ordering::SeqCst);
+        self.recorded_keys.lock().expect("lock poisoned").clear();
+    }
+
     /// Insert or update an entity by key.
     ///
     /// # Example
@@ -188,7 +354,7 @@ impl<T: CacheEntity> InMemoryRepository<T> {
     /// let found = repo.fetch_by_id(&"user:123".to_string()).await?;
     /// ```
     pub fn insert(&mut self, id: T::Key, value: T) {
-        self.data.insert(id.to_string(), value);
+        self.data.lock().expect("lock poisoned").insert(id.to_string(), value);
     }
 
     /// Remove all entities from the repository.
@@ -204,7 +370,275 @@ impl<T: CacheEntity> InMemoryRepository<T> {
     /// assert!(repo.is_empty());
     /// ```
     pub fn clear(&mut self) {
+        self.data.lock().expect("lock poisoned").clear();
+    }
+
+    /// Return the number of entities in the repository.
+    pub fn len(&self) -> usize {
+        self.data.lock().expect("lock poisoned").len()
+    }
+
+    /// Return true if the repository contains no entities.
+    pub fn is_empty(&self) -> bool {
+        self.data.lock().expect("lock poisoned").is_empty()
+    }
+
+    /// Install a failure policy used by `fetch_by_id`/`fetch_by_ids` to
+    /// simulate repository errors (e.g. a flaky upstream database).
+    ///
+    /// Replacing the policy resets the `AfterCalls` counter.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// repo.set_failure(FailurePolicy::Always(Error::BackendError("down".into())));
+    /// assert!(repo.fetch_by_id(&"user:1".to_string()).await.is_err());
+    /// ```
+    pub fn set_failure(&self, policy: FailurePolicy) {
+        *self.failure_policy.lock().expect("lock poisoned") = policy;
+        self.calls_since_policy_set.store(0, Ordering::SeqCst);
+    }
+
+    /// Clear any failure policy, per-key failures, and artificial delay,
+    /// returning the repository to normal operation. Stored entities and
+    /// call stats are left untouched.
+    pub fn clear_failures(&self) {
+        *self.failure_policy.lock().expect("lock poisoned") = FailurePolicy::None;
+        self.calls_since_policy_set.store(0, Ordering::SeqCst);
+        self.per_key_failures.lock().expect("lock poisoned").clear();
+        *self.artificial_delay.lock().expect("lock poisoned") = None;
+    }
+
+    /// Seed the PRNG used by `FailurePolicy::Probabilistic`, for
+    /// deterministic tests.
+    pub fn set_seed(&self, seed: u64) {
+        // xorshift64 is undefined at a zero state; nudge it off zero.
+        self.rng_state
+            .store(if seed == 0 { 0x9E3779B97F4A7C15 } else { seed }, Ordering::SeqCst);
+    }
+
+    /// Force a specific key to fail with `error` on every `fetch_by_id` or
+    /// `fetch_by_ids` call that requests it, regardless of the global
+    /// `FailurePolicy`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// repo.fail_key("user:1", Error::NotFound("user:1".into()));
+    /// ```
+    pub fn fail_key(&self, key: impl ToString, error: crate::error::Error) {
+        self.per_key_failures
+            .lock()
+            .expect("lock poisoned")
+            .insert(key.to_string(), error);
+    }
+
+    /// Make every subsequent `fetch_by_id`/`fetch_by_ids` call sleep for
+    /// `delay` before resolving, to simulate a slow upstream.
+    pub fn set_delay(&self, delay: Duration) {
+        *self.artificial_delay.lock().expect("lock poisoned") = Some(delay);
+    }
+
+    /// Apply any configured artificial delay, then decide whether `key`
+    /// should fail: an exact `fail_key` match takes precedence over the
+    /// global `FailurePolicy`.
+    async fn check_failure(&self, key: &str) -> Result<()> {
+        let delay = *self.artificial_delay.lock().expect("lock poisoned");
+        if let Some(delay) = delay {
+            tokio::time::sleep(delay).await;
+        }
+
+        if let Some(error) = self
+            .per_key_failures
+            .lock()
+            .expect("lock poisoned")
+            .get(key)
+            .cloned()
+        {
+            return Err(error);
+        }
+
+        let policy = self.failure_policy.lock().expect("lock poisoned").clone();
+        match policy {
+            FailurePolicy::None => Ok(()),
+            FailurePolicy::Always(error) => Err(error),
+            FailurePolicy::AfterCalls { after_calls, error } => {
+                let calls = self.calls_since_policy_set.fetch_add(1, Ordering::SeqCst);
+                if calls >= after_calls {
+                    Err(error)
+                } else {
+                    Ok(())
+                }
+            }
+            FailurePolicy::Probabilistic { probability, error } => {
+                if next_unit_f64(&self.rng_state) < probability {
+                    Err(error)
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+}
+
+/// Deterministic xorshift64 PRNG, advancing `state` and returning a value in
+/// `[0, 1)`. Used by `FailurePolicy::Probabilistic` instead of pulling in an
+/// external RNG crate for a single call site.
+fn next_unit_f64(state: &AtomicU64) -> f64 {
+    let mut x = state.load(Ordering::SeqCst);
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    state.store(x, Ordering::SeqCst);
+    (x >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+}
+
+impl<T: CacheEntity> Default for InMemoryRepository<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: CacheEntity> DataRepository<T> for InMemoryRepository<T> {
+    async fn fetch_by_id(&self, id: &T::Key) -> Result<Option<T>> {
+        self.fetch_by_id_calls.fetch_add(1, Ordering::SeqCst);
+        self.recorded_keys
+            .lock()
+            .expect("lock poisoned")
+            .push(id.to_string());
+
+        self.check_failure(&id.to_string()).await?;
+
+        Ok(self.data.lock().expect("lock poisoned").get(&id.to_string()).cloned())
+    }
+
+    async fn fetch_by_ids(&self, ids: &[T::Key]) -> Result<Vec<Option<T>>> {
+        self.fetch_by_ids_calls.fetch_add(1, Ordering::SeqCst);
+        self.recorded_keys
+            .lock()
+            .expect("lock poisoned")
+            .extend(ids.iter().map(|id| id.to_string()));
+
+        for id in ids {
+            self.check_failure(&id.to_string()).await?;
+        }
+
+        let data = self.data.lock().expect("lock poisoned");
+        Ok(ids
+            .iter()
+            .map(|id| data.get(&id.to_string()).cloned())
+            .collect())
+    }
+
+    async fn count(&self) -> Result<u64> {
+        Ok(self.data.lock().expect("lock poisoned").len() as u64)
+    }
+
+    async fn fetch_all(&self) -> Result<Vec<T>> {
+        Ok(self.data.lock().expect("lock poisoned").values().cloned().collect())
+    }
+
+    async fn upsert(&self, entity: &T) -> Result<()> {
+        self.data
+            .lock()
+            .expect("lock poisoned")
+            .insert(entity.cache_key().to_string(), entity.clone());
+        Ok(())
+    }
+
+    async fn upsert_many(&self, entities: &[T]) -> Result<()> {
+        let mut data = self.data.lock().expect("lock poisoned");
+        for entity in entities {
+            data.insert(entity.cache_key().to_string(), entity.clone());
+        }
+        Ok(())
+    }
+
+    async fn delete_by_id(&self, id: &T::Key) -> Result<bool> {
+        Ok(self
+            .data
+            .lock()
+            .expect("lock poisoned")
+            .remove(&id.to_string())
+            .is_some())
+    }
+
+    async fn fetch_page(&self, cursor: Option<T::Key>, limit: usize) -> Result<Page<T>> {
+        let mut entries: Vec<(T::Key, T)> = {
+            let data = self.data.lock().expect("lock poisoned");
+            data.values()
+                .map(|entity| (entity.cache_key(), entity.clone()))
+                .collect()
+        };
+
+        // No `Ord` bound on `T::Key`, so order (and compare against the
+        // cursor) by its `Display` string rather than the key itself.
+        entries.sort_by(|(a, _), (b, _)| a.to_string().cmp(&b.to_string()));
+
+        let total = entries.len();
+        let cursor_str = cursor.map(|c| c.to_string());
+        let start = match &cursor_str {
+            Some(cursor_str) => entries.partition_point(|(key, _)| &key.to_string() <= cursor_str),
+            None => 0,
+        };
+
+        let page: Vec<(T::Key, T)> = entries.into_iter().skip(start).take(limit).collect();
+        let next_cursor = if start + page.len() < total {
+            page.last().map(|(key, _)| key.clone())
+        } else {
+            None
+        };
+
+        Ok(Page {
+            items: page.into_iter().map(|(_, entity)| entity).collect(),
+            next_cursor,
+            total: Some(total as u64),
+        })
+    }
+}
+
+/// Concurrent, size-aware in-memory repository for high-throughput testing
+/// and benchmarks.
+///
+/// Unlike [`InMemoryRepository`], which guards a single `HashMap` behind one
+/// `Mutex` (fine for sequential tests, but a bottleneck once many tasks hit
+/// it at once), this backs storage with a `dashmap::DashMap`, so reads and
+/// writes to different keys don't block each other. It also tracks an
+/// approximate byte footprint via [`CacheEntity::heap_size`], so callers can
+/// make eviction or memory-pressure decisions. Requires the
+/// `high_parallelism` feature.
+#[cfg(feature = "high_parallelism")]
+pub struct ConcurrentInMemoryRepository<T: CacheEntity> {
+    data: dashmap::DashMap<String, T>,
+    approximate_size_bytes: std::sync::atomic::AtomicUsize,
+}
+
+#[cfg(feature = "high_parallelism")]
+impl<T: CacheEntity> ConcurrentInMemoryRepository<T> {
+    /// Create a new empty concurrent in-memory repository.
+    pub fn new() -> Self {
+        ConcurrentInMemoryRepository {
+            data: dashmap::DashMap::new(),
+            approximate_size_bytes: std::sync::atomic::AtomicUsize::new(0),
+        }
+    }
+
+    /// Insert or update an entity by key without going through the
+    /// `DataRepository::upsert` async method - handy for seeding state in
+    /// synchronous test setup.
+    pub fn insert(&self, id: T::Key, value: T) {
+        let size = value.heap_size();
+        if let Some(old) = self.data.insert(id.to_string(), value) {
+            self.approximate_size_bytes
+                .fetch_sub(old.heap_size(), Ordering::SeqCst);
+        }
+        self.approximate_size_bytes.fetch_add(size, Ordering::SeqCst);
+    }
+
+    /// Remove all entities from the repository.
+    pub fn clear(&self) {
         self.data.clear();
+        self.approximate_size_bytes.store(0, Ordering::SeqCst);
     }
 
     /// Return the number of entities in the repository.
@@ -216,23 +650,35 @@ impl<T: CacheEntity> InMemoryRepository<T> {
     pub fn is_empty(&self) -> bool {
         self.data.is_empty()
     }
+
+    /// Approximate total heap footprint of all stored entities, in bytes, as
+    /// measured by `CacheEntity::heap_size` at insert time.
+    ///
+    /// This is a running total updated incrementally on insert/update/delete,
+    /// not a live recomputation, so it stays O(1) regardless of repository
+    /// size.
+    pub fn approximate_size_bytes(&self) -> usize {
+        self.approximate_size_bytes.load(Ordering::SeqCst)
+    }
 }
 
-impl<T: CacheEntity> Default for InMemoryRepository<T> {
+#[cfg(feature = "high_parallelism")]
+impl<T: CacheEntity> Default for ConcurrentInMemoryRepository<T> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<T: CacheEntity> DataRepository<T> for InMemoryRepository<T> {
+#[cfg(feature = "high_parallelism")]
+impl<T: CacheEntity> DataRepository<T> for ConcurrentInMemoryRepository<T> {
     async fn fetch_by_id(&self, id: &T::Key) -> Result<Option<T>> {
-        Ok(self.data.get(&id.to_string()).cloned())
+        Ok(self.data.get(&id.to_string()).map(|entry| entry.clone()))
     }
 
     async fn fetch_by_ids(&self, ids: &[T::Key]) -> Result<Vec<Option<T>>> {
         Ok(ids
             .iter()
-            .map(|id| self.data.get(&id.to_string()).cloned())
+            .map(|id| self.data.get(&id.to_string()).map(|entry| entry.clone()))
             .collect())
     }
 
@@ -241,7 +687,62 @@ impl<T: CacheEntity> DataRepository<T> for InMemoryRepository<T> {
     }
 
     async fn fetch_all(&self) -> Result<Vec<T>> {
-        Ok(self.data.values().cloned().collect())
+        Ok(self.data.iter().map(|entry| entry.clone()).collect())
+    }
+
+    async fn upsert(&self, entity: &T) -> Result<()> {
+        self.insert(entity.cache_key(), entity.clone());
+        Ok(())
+    }
+
+    async fn upsert_many(&self, entities: &[T]) -> Result<()> {
+        for entity in entities {
+            self.insert(entity.cache_key(), entity.clone());
+        }
+        Ok(())
+    }
+
+    async fn delete_by_id(&self, id: &T::Key) -> Result<bool> {
+        match self.data.remove(&id.to_string()) {
+            Some((_, entity)) => {
+                self.approximate_size_bytes
+                    .fetch_sub(entity.heap_size(), Ordering::SeqCst);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn fetch_page(&self, cursor: Option<T::Key>, limit: usize) -> Result<Page<T>> {
+        let mut entries: Vec<(T::Key, T)> = self
+            .data
+            .iter()
+            .map(|entry| (entry.cache_key(), entry.clone()))
+            .collect();
+
+        // No `Ord` bound on `T::Key`, so order (and compare against the
+        // cursor) by its `Display` string rather than the key itself.
+        entries.sort_by(|(a, _), (b, _)| a.to_string().cmp(&b.to_string()));
+
+        let total = entries.len();
+        let cursor_str = cursor.map(|c| c.to_string());
+        let start = match &cursor_str {
+            Some(cursor_str) => entries.partition_point(|(key, _)| &key.to_string() <= cursor_str),
+            None => 0,
+        };
+
+        let page: Vec<(T::Key, T)> = entries.into_iter().skip(start).take(limit).collect();
+        let next_cursor = if start + page.len() < total {
+            page.last().map(|(key, _)| key.clone())
+        } else {
+            None
+        };
+
+        Ok(Page {
+            items: page.into_iter().map(|(_, entity)| entity).collect(),
+            next_cursor,
+            total: Some(total as u64),
+        })
     }
 }
 
@@ -344,4 +845,432 @@ mod tests {
 
         assert_eq!(repo.count().await.expect("Failed to count"), 1);
     }
+
+    #[tokio::test]
+    async fn test_in_memory_repository_upsert_inserts_and_updates() {
+        let repo: InMemoryRepository<TestEntity> = InMemoryRepository::new();
+
+        repo.upsert(&TestEntity {
+            id: "1".to_string(),
+            value: "first".to_string(),
+        })
+        .await
+        .expect("Failed to upsert");
+
+        assert_eq!(
+            repo.fetch_by_id(&"1".to_string())
+                .await
+                .expect("Failed to fetch")
+                .expect("Entity not found")
+                .value,
+            "first"
+        );
+
+        repo.upsert(&TestEntity {
+            id: "1".to_string(),
+            value: "updated".to_string(),
+        })
+        .await
+        .expect("Failed to upsert");
+
+        assert_eq!(
+            repo.fetch_by_id(&"1".to_string())
+                .await
+                .expect("Failed to fetch")
+                .expect("Entity not found")
+                .value,
+            "updated"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_repository_upsert_many() {
+        let repo: InMemoryRepository<TestEntity> = InMemoryRepository::new();
+
+        repo.upsert_many(&[
+            TestEntity {
+                id: "1".to_string(),
+                value: "a".to_string(),
+            },
+            TestEntity {
+                id: "2".to_string(),
+                value: "b".to_string(),
+            },
+        ])
+        .await
+        .expect("Failed to upsert_many");
+
+        assert_eq!(repo.count().await.expect("Failed to count"), 2);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_repository_delete_by_id() {
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "a".to_string(),
+            },
+        );
+
+        assert!(repo
+            .delete_by_id(&"1".to_string())
+            .await
+            .expect("Failed to delete"));
+        assert!(repo
+            .fetch_by_id(&"1".to_string())
+            .await
+            .expect("Failed to fetch")
+            .is_none());
+        assert!(!repo
+            .delete_by_id(&"1".to_string())
+            .await
+            .expect("Failed to delete"));
+    }
+
+    #[tokio::test]
+    async fn test_default_upsert_returns_not_implemented() {
+        struct ReadOnlyRepository;
+
+        impl DataRepository<TestEntity> for ReadOnlyRepository {
+            async fn fetch_by_id(&self, _id: &String) -> Result<Option<TestEntity>> {
+                Ok(None)
+            }
+        }
+
+        let repo = ReadOnlyRepository;
+        let result = repo
+            .upsert(&TestEntity {
+                id: "1".to_string(),
+                value: "a".to_string(),
+            })
+            .await;
+        assert!(matches!(result, Err(crate::error::Error::NotImplemented(_))));
+
+        let result = repo.delete_by_id(&"1".to_string()).await;
+        assert!(matches!(result, Err(crate::error::Error::NotImplemented(_))));
+    }
+
+    fn seeded_repo(n: usize) -> InMemoryRepository<TestEntity> {
+        let mut repo = InMemoryRepository::new();
+        for i in 0..n {
+            let id = format!("{:02}", i);
+            repo.insert(
+                id.clone(),
+                TestEntity {
+                    id,
+                    value: "v".to_string(),
+                },
+            );
+        }
+        repo
+    }
+
+    #[tokio::test]
+    async fn test_fetch_page_first_page_has_next_cursor() {
+        let repo = seeded_repo(5);
+
+        let page = repo
+            .fetch_page(None, 2)
+            .await
+            .expect("Failed to fetch page");
+
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.items[0].id, "00");
+        assert_eq!(page.items[1].id, "01");
+        assert_eq!(page.next_cursor, Some("01".to_string()));
+        assert_eq!(page.total, Some(5));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_page_walks_to_the_end() {
+        let repo = seeded_repo(5);
+
+        let mut seen = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = repo
+                .fetch_page(cursor.clone(), 2)
+                .await
+                .expect("Failed to fetch page");
+            seen.extend(page.items.iter().map(|e| e.id.clone()));
+            cursor = page.next_cursor.clone();
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        assert_eq!(seen, vec!["00", "01", "02", "03", "04"]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_page_last_page_has_no_next_cursor() {
+        let repo = seeded_repo(3);
+
+        let page = repo
+            .fetch_page(Some("01".to_string()), 10)
+            .await
+            .expect("Failed to fetch page");
+
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].id, "02");
+        assert_eq!(page.next_cursor, None);
+    }
+
+    #[tokio::test]
+    async fn test_default_fetch_page_returns_not_implemented() {
+        struct ReadOnlyRepository;
+
+        impl DataRepository<TestEntity> for ReadOnlyRepository {
+            async fn fetch_by_id(&self, _id: &String) -> Result<Option<TestEntity>> {
+                Ok(None)
+            }
+        }
+
+        let repo = ReadOnlyRepository;
+        let result = repo.fetch_page(None, 10).await;
+        assert!(matches!(result, Err(crate::error::Error::NotImplemented(_))));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_by_id_calls_are_counted_and_recorded() {
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "a".to_string(),
+            },
+        );
+
+        repo.fetch_by_id(&"1".to_string())
+            .await
+            .expect("Failed to fetch");
+        repo.fetch_by_id(&"missing".to_string())
+            .await
+            .expect("Failed to fetch");
+
+        assert_eq!(repo.fetch_by_id_calls(), 2);
+        assert_eq!(repo.fetch_by_ids_calls(), 0);
+        assert_eq!(repo.recorded_keys(), vec!["1".to_string(), "missing".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_by_ids_calls_are_counted_and_recorded() {
+        let repo: InMemoryRepository<TestEntity> = InMemoryRepository::new();
+
+        repo.fetch_by_ids(&["1".to_string(), "2".to_string()])
+            .await
+            .expect("Failed to fetch batch");
+
+        assert_eq!(repo.fetch_by_ids_calls(), 1);
+        assert_eq!(repo.fetch_by_id_calls(), 0);
+        assert_eq!(repo.recorded_keys(), vec!["1".to_string(), "2".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_reset_stats_clears_counters_and_keys_but_not_data() {
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "a".to_string(),
+            },
+        );
+
+        repo.fetch_by_id(&"1".to_string())
+            .await
+            .expect("Failed to fetch");
+        assert_eq!(repo.fetch_by_id_calls(), 1);
+
+        repo.reset_stats();
+
+        assert_eq!(repo.fetch_by_id_calls(), 0);
+        assert!(repo.recorded_keys().is_empty());
+        assert_eq!(repo.count().await.expect("Failed to count"), 1);
+    }
+
+    #[tokio::test]
+    async fn test_singleflight_dedupes_repository_calls() {
+        use crate::backend::InMemoryBackend;
+        use crate::expander::CacheExpander;
+        use crate::feed::GenericFeeder;
+        use crate::strategy::CacheStrategy;
+        use std::sync::Arc;
+
+        let backend = InMemoryBackend::new();
+        let expander = Arc::new(CacheExpander::new(backend));
+        let repo = Arc::new(InMemoryRepository::new());
+        repo.upsert(&TestEntity {
+            id: "1".to_string(),
+            value: "data".to_string(),
+        })
+        .await
+        .expect("Failed to upsert");
+
+        let mut handles = Vec::new();
+        for _ in 0..5 {
+            let expander = expander.clone();
+            let repo = repo.clone();
+            handles.push(tokio::spawn(async move {
+                let mut feeder = GenericFeeder::<TestEntity>::new("1".to_string());
+                expander
+                    .with::<TestEntity, _, _>(&mut feeder, &*repo, CacheStrategy::Refresh)
+                    .await
+                    .expect("Failed to execute");
+            }));
+        }
+
+        for handle in handles {
+            handle.await.expect("Task panicked");
+        }
+
+        assert_eq!(
+            repo.fetch_by_id_calls(),
+            1,
+            "singleflight coalescing should hit the repository exactly once"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failure_policy_always_fails_every_call() {
+        let repo: InMemoryRepository<TestEntity> = InMemoryRepository::new();
+        repo.set_failure(FailurePolicy::Always(crate::error::Error::BackendError(
+            "upstream down".to_string(),
+        )));
+
+        assert!(repo.fetch_by_id(&"1".to_string()).await.is_err());
+        assert!(repo.fetch_by_id(&"2".to_string()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_failure_policy_after_calls_fails_once_threshold_reached() {
+        let repo: InMemoryRepository<TestEntity> = InMemoryRepository::new();
+        repo.set_failure(FailurePolicy::AfterCalls {
+            after_calls: 2,
+            error: crate::error::Error::BackendError("exhausted".to_string()),
+        });
+
+        assert!(repo.fetch_by_id(&"1".to_string()).await.is_ok());
+        assert!(repo.fetch_by_id(&"1".to_string()).await.is_ok());
+        assert!(repo.fetch_by_id(&"1".to_string()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_failure_policy_probabilistic_is_deterministic_with_seed() {
+        let repo: InMemoryRepository<TestEntity> = InMemoryRepository::new();
+        repo.set_seed(42);
+        repo.set_failure(FailurePolicy::Probabilistic {
+            probability: 0.5,
+            error: crate::error::Error::BackendError("unlucky".to_string()),
+        });
+
+        let mut results = Vec::new();
+        for _ in 0..10 {
+            results.push(repo.fetch_by_id(&"1".to_string()).await.is_ok());
+        }
+
+        let repo_again: InMemoryRepository<TestEntity> = InMemoryRepository::new();
+        repo_again.set_seed(42);
+        repo_again.set_failure(FailurePolicy::Probabilistic {
+            probability: 0.5,
+            error: crate::error::Error::BackendError("unlucky".to_string()),
+        });
+
+        let mut results_again = Vec::new();
+        for _ in 0..10 {
+            results_again.push(repo_again.fetch_by_id(&"1".to_string()).await.is_ok());
+        }
+
+        assert_eq!(results, results_again);
+        assert!(
+            results.iter().any(|ok| !ok),
+            "a 50% failure rate over 10 calls should fail at least once"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fail_key_overrides_global_policy_for_that_key_only() {
+        let repo: InMemoryRepository<TestEntity> = InMemoryRepository::new();
+        repo.fail_key("1", crate::error::Error::BackendError("poisoned row".to_string()));
+
+        assert!(repo.fetch_by_id(&"1".to_string()).await.is_err());
+        assert!(repo.fetch_by_id(&"2".to_string()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_clear_failures_restores_normal_operation() {
+        let repo: InMemoryRepository<TestEntity> = InMemoryRepository::new();
+        repo.set_failure(FailurePolicy::Always(crate::error::Error::BackendError(
+            "down".to_string(),
+        )));
+        repo.fail_key("1", crate::error::Error::BackendError("bad row".to_string()));
+
+        repo.clear_failures();
+
+        assert!(repo.fetch_by_id(&"1".to_string()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_set_delay_sleeps_before_resolving() {
+        let repo: InMemoryRepository<TestEntity> = InMemoryRepository::new();
+        repo.set_delay(Duration::from_millis(20));
+
+        let start = tokio::time::Instant::now();
+        repo.fetch_by_id(&"1".to_string())
+            .await
+            .expect("Failed to fetch");
+
+        assert!(start.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[cfg(feature = "high_parallelism")]
+    #[tokio::test]
+    async fn test_concurrent_repository_tracks_approximate_size() {
+        let repo: ConcurrentInMemoryRepository<TestEntity> = ConcurrentInMemoryRepository::new();
+        assert_eq!(repo.approximate_size_bytes(), 0);
+
+        let entity = TestEntity {
+            id: "1".to_string(),
+            value: "data".to_string(),
+        };
+        let expected_size = entity.heap_size();
+        repo.upsert(&entity).await.expect("Failed to upsert");
+
+        assert_eq!(repo.approximate_size_bytes(), expected_size);
+
+        repo.delete_by_id(&"1".to_string())
+            .await
+            .expect("Failed to delete");
+        assert_eq!(repo.approximate_size_bytes(), 0);
+    }
+
+    #[cfg(feature = "high_parallelism")]
+    #[tokio::test]
+    async fn test_concurrent_repository_parallel_inserts_dont_block() {
+        use std::sync::Arc;
+
+        let repo = Arc::new(ConcurrentInMemoryRepository::<TestEntity>::new());
+
+        let mut handles = Vec::new();
+        for i in 0..50 {
+            let repo = repo.clone();
+            handles.push(tokio::spawn(async move {
+                repo.upsert(&TestEntity {
+                    id: i.to_string(),
+                    value: "data".to_string(),
+                })
+                .await
+                .expect("Failed to upsert");
+            }));
+        }
+
+        for handle in handles {
+            handle.await.expect("Task panicked");
+        }
+
+        assert_eq!(repo.count().await.expect("Failed to count"), 50);
+    }
 }
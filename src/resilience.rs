@@ -0,0 +1,373 @@
+//! Resilience decorator for [`DataRepository`] implementations.
+//!
+//! Wraps any repository with retry-with-backoff, per-operation timeouts, and
+//! a circuit breaker, so a flapping database doesn't get hammered by every
+//! cache miss and doesn't stall every caller while it recovers.
+//!
+//! # Composing with a pooled repository
+//!
+//! This wrapper only adds operational behavior around whatever
+//! `DataRepository` it's given - it doesn't manage connections itself. If
+//! your repository impl wraps a `sqlx::PgPool` (or any other connection
+//! pool), compose `ResilientRepository` around that impl so callers get
+//! pooled *and* resilient reads through one type:
+//!
+//! ```ignore
+//! let pooled_repo = PgUserRepository::new(pg_pool); // wraps sqlx::PgPool
+//! let repo = ResilientRepository::new(pooled_repo, ResilienceConfig::default());
+//! expander.with(&mut feeder, &repo, CacheStrategy::Refresh).await?;
+//! ```
+
+use crate::entity::CacheEntity;
+use crate::error::{Error, Result};
+use crate::repository::{DataRepository, Page};
+use std::sync::atomic::{AtomicU32, AtomicU8, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Circuit breaker state, surfaced via [`ResilientRepository::state`] for
+/// metrics/dashboards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakerState {
+    /// Operations pass through normally.
+    Closed,
+    /// Too many consecutive failures; operations are rejected immediately
+    /// with `Error::BackendError` until the cooldown elapses.
+    Open,
+    /// Cooldown elapsed; the next call is let through as a trial. Success
+    /// closes the breaker, failure reopens it.
+    HalfOpen,
+}
+
+/// Configuration for [`ResilientRepository`].
+#[derive(Debug, Clone)]
+pub struct ResilienceConfig {
+    /// Number of retry attempts per operation (0 = no retry).
+    pub retry_count: u32,
+    /// Base delay for exponential backoff between retries. Actual delay is
+    /// `base_delay * 2^attempt`, plus up to `base_delay` of jitter.
+    pub base_delay: Duration,
+    /// Per-attempt timeout; an attempt that exceeds this fails with
+    /// `Error::Timeout`.
+    pub timeout: Duration,
+    /// Consecutive failures before the breaker opens.
+    pub failure_threshold: u32,
+    /// How long the breaker stays open before allowing a half-open trial.
+    pub cooldown: Duration,
+}
+
+impl Default for ResilienceConfig {
+    fn default() -> Self {
+        ResilienceConfig {
+            retry_count: 2,
+            base_delay: Duration::from_millis(100),
+            timeout: Duration::from_secs(5),
+            failure_threshold: 5,
+            cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+const BREAKER_CLOSED: u8 = 0;
+const BREAKER_OPEN: u8 = 1;
+const BREAKER_HALF_OPEN: u8 = 2;
+
+/// Wraps a [`DataRepository`] with retry-with-backoff, per-operation
+/// timeouts, and a closed/open/half-open circuit breaker.
+///
+/// All four `DataRepository` methods delegate through the same
+/// retry/timeout/breaker pipeline. See the module docs for composing this
+/// around a connection-pooled repository.
+pub struct ResilientRepository<R> {
+    inner: R,
+    config: ResilienceConfig,
+    state: AtomicU8,
+    consecutive_failures: AtomicU32,
+    opened_at: Mutex<Option<Instant>>,
+}
+
+impl<R> ResilientRepository<R> {
+    /// Wrap `inner` with the given resilience configuration.
+    pub fn new(inner: R, config: ResilienceConfig) -> Self {
+        ResilientRepository {
+            inner,
+            config,
+            state: AtomicU8::new(BREAKER_CLOSED),
+            consecutive_failures: AtomicU32::new(0),
+            opened_at: Mutex::new(None),
+        }
+    }
+
+    /// Current circuit breaker state.
+    pub fn state(&self) -> BreakerState {
+        match self.state.load(Ordering::SeqCst) {
+            BREAKER_OPEN => BreakerState::Open,
+            BREAKER_HALF_OPEN => BreakerState::HalfOpen,
+            _ => BreakerState::Closed,
+        }
+    }
+
+    /// Reject the call if the breaker is open and the cooldown hasn't
+    /// elapsed yet; otherwise let it through (transitioning `Open` ->
+    /// `HalfOpen` once the cooldown has passed).
+    fn guard(&self) -> Result<()> {
+        if self.state.load(Ordering::SeqCst) == BREAKER_OPEN {
+            let elapsed = self
+                .opened_at
+                .lock()
+                .expect("lock poisoned")
+                .map(|t| t.elapsed());
+            match elapsed {
+                Some(elapsed) if elapsed >= self.config.cooldown => {
+                    self.state.store(BREAKER_HALF_OPEN, Ordering::SeqCst);
+                    Ok(())
+                }
+                _ => Err(Error::BackendError(
+                    "circuit breaker open: repository unavailable".to_string(),
+                )),
+            }
+        } else {
+            Ok(())
+        }
+    }
+
+    fn record_success(&self) {
+        self.consecutive_failures.store(0, Ordering::SeqCst);
+        self.state.store(BREAKER_CLOSED, Ordering::SeqCst);
+        *self.opened_at.lock().expect("lock poisoned") = None;
+    }
+
+    fn record_failure(&self) {
+        // A failed half-open trial reopens the breaker immediately, without
+        // waiting for the threshold again.
+        if self.state.load(Ordering::SeqCst) == BREAKER_HALF_OPEN {
+            self.open_breaker();
+            return;
+        }
+
+        let failures = self.consecutive_failures.fetch_add(1, Ordering::SeqCst) + 1;
+        if failures >= self.config.failure_threshold {
+            self.open_breaker();
+        }
+    }
+
+    fn open_breaker(&self) {
+        self.state.store(BREAKER_OPEN, Ordering::SeqCst);
+        *self.opened_at.lock().expect("lock poisoned") = Some(Instant::now());
+    }
+
+    /// Run `op` through the timeout/retry/breaker pipeline shared by every
+    /// `DataRepository` method.
+    async fn call<V, F, Fut>(&self, op: F) -> Result<V>
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = Result<V>>,
+    {
+        self.guard()?;
+
+        let max_attempts = self.config.retry_count + 1;
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+
+            let result = match tokio::time::timeout(self.config.timeout, op()).await {
+                Ok(result) => result,
+                Err(_) => Err(Error::Timeout(format!(
+                    "repository call exceeded {:?}",
+                    self.config.timeout
+                ))),
+            };
+
+            match result {
+                Ok(value) => {
+                    self.record_success();
+                    return Ok(value);
+                }
+                Err(e) => {
+                    if attempt >= max_attempts {
+                        self.record_failure();
+                        return Err(e);
+                    }
+
+                    let backoff = self.config.base_delay * 2_u32.pow(attempt - 1);
+                    let jitter = Duration::from_nanos(
+                        (next_jitter_seed(attempt) % self.config.base_delay.as_nanos().max(1) as u64)
+                            as u64,
+                    );
+                    tokio::time::sleep(backoff + jitter).await;
+                }
+            }
+        }
+    }
+}
+
+/// Cheap per-retry jitter source. Not cryptographic and not a general
+/// PRNG - just enough spread across attempts/backoff windows to avoid
+/// synchronized retry storms from multiple clients.
+fn next_jitter_seed(attempt: u32) -> u64 {
+    let nanos = Instant::now().elapsed().as_nanos() as u64;
+    nanos.wrapping_mul(0x9E3779B97F4A7C15).wrapping_add(attempt as u64)
+}
+
+impl<T, R> DataRepository<T> for ResilientRepository<R>
+where
+    T: CacheEntity,
+    R: DataRepository<T> + Send + Sync,
+{
+    async fn fetch_by_id(&self, id: &T::Key) -> Result<Option<T>> {
+        self.call(|| self.inner.fetch_by_id(id)).await
+    }
+
+    async fn fetch_by_ids(&self, ids: &[T::Key]) -> Result<Vec<Option<T>>> {
+        self.call(|| self.inner.fetch_by_ids(ids)).await
+    }
+
+    async fn count(&self) -> Result<u64> {
+        self.call(|| self.inner.count()).await
+    }
+
+    async fn fetch_all(&self) -> Result<Vec<T>> {
+        self.call(|| self.inner.fetch_all()).await
+    }
+
+    async fn upsert(&self, entity: &T) -> Result<()> {
+        self.call(|| self.inner.upsert(entity)).await
+    }
+
+    async fn upsert_many(&self, entities: &[T]) -> Result<()> {
+        self.call(|| self.inner.upsert_many(entities)).await
+    }
+
+    async fn delete_by_id(&self, id: &T::Key) -> Result<bool> {
+        self.call(|| self.inner.delete_by_id(id)).await
+    }
+
+    async fn fetch_page(&self, cursor: Option<T::Key>, limit: usize) -> Result<Page<T>> {
+        self.call(|| self.inner.fetch_page(cursor.clone(), limit))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::InMemoryRepository;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Serialize, Deserialize)]
+    struct TestEntity {
+        id: String,
+        value: String,
+    }
+
+    impl CacheEntity for TestEntity {
+        type Key = String;
+
+        fn cache_key(&self) -> Self::Key {
+            self.id.clone()
+        }
+
+        fn cache_prefix() -> &'static str {
+            "test"
+        }
+    }
+
+    fn fast_config(failure_threshold: u32) -> ResilienceConfig {
+        ResilienceConfig {
+            retry_count: 2,
+            base_delay: Duration::from_millis(1),
+            timeout: Duration::from_millis(50),
+            failure_threshold,
+            cooldown: Duration::from_millis(20),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_successful_call_passes_through() {
+        let mut inner = InMemoryRepository::new();
+        inner.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "a".to_string(),
+            },
+        );
+        let repo = ResilientRepository::new(inner, fast_config(5));
+
+        let found = repo
+            .fetch_by_id(&"1".to_string())
+            .await
+            .expect("Failed to fetch");
+        assert_eq!(found.map(|e| e.value), Some("a".to_string()));
+        assert_eq!(repo.state(), BreakerState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_exhausts_configured_retry_count_before_failing() {
+        use crate::repository::FailurePolicy;
+
+        let inner: InMemoryRepository<TestEntity> = InMemoryRepository::new();
+        inner.set_failure(FailurePolicy::Always(Error::BackendError(
+            "flaky".to_string(),
+        )));
+
+        // A high failure threshold keeps the breaker closed so every retry
+        // actually reaches the inner repository.
+        let repo = ResilientRepository::new(inner, fast_config(100));
+        let retry_count = repo.config.retry_count;
+
+        assert!(repo.fetch_by_id(&"1".to_string()).await.is_err());
+        assert_eq!(repo.inner.fetch_by_id_calls(), (retry_count + 1) as usize);
+    }
+
+    #[tokio::test]
+    async fn test_breaker_opens_after_consecutive_failures() {
+        use crate::repository::FailurePolicy;
+
+        let inner: InMemoryRepository<TestEntity> = InMemoryRepository::new();
+        inner.set_failure(FailurePolicy::Always(Error::BackendError(
+            "down".to_string(),
+        )));
+
+        let repo = ResilientRepository::new(inner, fast_config(1));
+
+        assert!(repo.fetch_by_id(&"1".to_string()).await.is_err());
+        assert_eq!(repo.state(), BreakerState::Open);
+
+        // While open, calls fail fast without reaching the inner repository.
+        let err = repo.fetch_by_id(&"1".to_string()).await.unwrap_err();
+        assert!(matches!(err, Error::BackendError(_)));
+    }
+
+    #[tokio::test]
+    async fn test_breaker_half_open_recovers_after_cooldown() {
+        use crate::repository::FailurePolicy;
+
+        let inner: InMemoryRepository<TestEntity> = InMemoryRepository::new();
+        inner.set_failure(FailurePolicy::Always(Error::BackendError(
+            "down".to_string(),
+        )));
+
+        let repo = ResilientRepository::new(inner, fast_config(1));
+        assert!(repo.fetch_by_id(&"1".to_string()).await.is_err());
+        assert_eq!(repo.state(), BreakerState::Open);
+
+        tokio::time::sleep(Duration::from_millis(25)).await;
+        repo.inner.clear_failures();
+
+        let result = repo.fetch_by_id(&"1".to_string()).await;
+        assert!(result.is_ok());
+        assert_eq!(repo.state(), BreakerState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_timeout_counts_as_failure() {
+        let inner: InMemoryRepository<TestEntity> = InMemoryRepository::new();
+        inner.set_delay(Duration::from_millis(200));
+
+        let repo = ResilientRepository::new(inner, fast_config(5));
+        let result = repo.fetch_by_id(&"1".to_string()).await;
+        assert!(matches!(result, Err(Error::Timeout(_))));
+    }
+}
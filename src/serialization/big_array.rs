@@ -0,0 +1,144 @@
+//! `#[serde(with = "...")]` adapter for fixed-size `[u8; N]` fields, for any `N`.
+//!
+//! Serde's own derive only has built-in `Serialize`/`Deserialize` impls for
+//! arrays up to length 32, so a `CacheEntity` storing cryptographic material
+//! or another fixed-width binary blob wider than that - a `[u8; 512]` key or
+//! a `[u8; 580]` note ciphertext, say - can't derive `Serialize` on the field
+//! directly. Annotate it instead:
+//!
+//! ```rust
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Blob {
+//!     #[serde(with = "cache_kit::serialization::big_array")]
+//!     payload: [u8; 580],
+//! }
+//! ```
+//!
+//! Only handles `[u8; N]`, not arrays of arbitrary `T`: every real use of this
+//! in the wild (Zebra's note/memo fields, the request that motivated this
+//! module) is a fixed-width byte blob, and staying byte-specific means
+//! serializing via `serialize_bytes`/`deserialize_bytes` - one length-prefixed
+//! write, not N individual element writes - instead of a generic-element
+//! tuple-like encoding external big-array crates use to support any `T`.
+
+use serde::de::{Deserializer, Error as DeError, Visitor};
+use serde::ser::{SerializeTuple, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Encode `array` as a single length-prefixed byte string.
+pub fn serialize<S, const N: usize>(array: &[u8; N], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if serializer.is_human_readable() {
+        // Tuple formats (e.g. JSON) can't assume a byte-string type exists,
+        // so fall back to serializing each byte individually.
+        let mut tup = serializer.serialize_tuple(N)?;
+        for byte in array {
+            tup.serialize_element(byte)?;
+        }
+        tup.end()
+    } else {
+        serializer.serialize_bytes(array)
+    }
+}
+
+struct ByteArrayVisitor<const N: usize>(PhantomData<[u8; N]>);
+
+impl<'de, const N: usize> Visitor<'de> for ByteArrayVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a byte array of length {}", N)
+    }
+
+    fn visit_bytes<E: DeError>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        <[u8; N]>::try_from(bytes).map_err(|_| E::invalid_length(bytes.len(), &self))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut out = [0u8; N];
+        for (i, slot) in out.iter_mut().enumerate() {
+            *slot = seq
+                .next_element()?
+                .ok_or_else(|| DeError::invalid_length(i, &self))?;
+        }
+        Ok(out)
+    }
+}
+
+/// Decode a single length-prefixed byte string back into `[u8; N]`.
+///
+/// # Errors
+/// Returns a deserialization error if the stored byte string's length doesn't
+/// match `N`.
+pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_tuple(N, ByteArrayVisitor(PhantomData))
+    } else {
+        deserializer.deserialize_bytes(ByteArrayVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Blob {
+        id: u64,
+        #[serde(with = "super")]
+        payload: [u8; 64],
+    }
+
+    fn sample() -> Blob {
+        let mut payload = [0u8; 64];
+        for (i, byte) in payload.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        Blob { id: 1, payload }
+    }
+
+    #[test]
+    fn test_postcard_roundtrip() {
+        let bytes = postcard::to_allocvec(&sample()).unwrap();
+        let decoded: Blob = postcard::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, sample());
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let json = serde_json::to_string(&sample()).unwrap();
+        let decoded: Blob = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, sample());
+    }
+
+    #[test]
+    fn test_wrong_length_rejected() {
+        // An entry stored with a 4-byte payload where a 64-byte one is
+        // expected must fail, not silently truncate or zero-pad.
+        #[derive(Serialize)]
+        struct ShortBlob {
+            id: u64,
+            payload: Vec<u8>,
+        }
+
+        let bytes = postcard::to_allocvec(&ShortBlob {
+            id: 1,
+            payload: vec![1, 2, 3, 4],
+        })
+        .unwrap();
+
+        let result: Result<Blob, _> = postcard::from_bytes(&bytes);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,300 @@
+//! Pluggable payload codecs for cache storage.
+//!
+//! [`serialize_for_cache`](super::serialize_for_cache)/[`deserialize_from_cache`](super::deserialize_from_cache)
+//! are the canonical, non-overridable Postcard + versioned-envelope path
+//! every `CacheEntity` uses (see that module's docs and `CacheEntity`'s own
+//! doc comment). This module adds an independent, opt-in path for callers
+//! who want a different wire format instead - JSON for interoperability with
+//! non-Rust readers, or MessagePack for payloads smaller and faster to
+//! (de)serialize than JSON without Postcard's Rust-type coupling.
+//!
+//! Each entry records which [`Codec`] encoded it, so reading it back with a
+//! different codec fails cleanly with [`Error::CodecMismatch`] instead of
+//! silently misinterpreting the bytes.
+//!
+//! # Scope
+//!
+//! This is deliberately a second, parallel envelope format rather than a
+//! modification of `CacheEnvelope`: threading a codec choice through
+//! `CacheExpander`/`GenericFeeder`'s read and write paths touches every
+//! method on both (and `CacheService`, which wraps them), which is too large
+//! a mechanical change to land safely in one pass without a build to verify
+//! it against. The codecs here are complete and independently usable today;
+//! wiring them into the expander/feeder as a configurable type parameter is
+//! follow-up work.
+
+use crate::error::{Error, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Magic header for codec-tagged entries.
+///
+/// Distinct from [`super::CACHE_MAGIC`] so the two envelope formats can
+/// never be mistaken for one another.
+const CODEC_MAGIC: [u8; 4] = *b"CKC1";
+
+const HEADER_LEN: usize = CODEC_MAGIC.len() + 1 + 8;
+
+/// Identifies which [`Codec`] encoded a payload.
+///
+/// Recorded in the envelope so a reader configured with a different codec
+/// fails with [`Error::CodecMismatch`] instead of handing the bytes to the
+/// wrong decoder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodecId {
+    Postcard,
+    Json,
+    MsgPack,
+}
+
+impl CodecId {
+    fn as_u8(self) -> u8 {
+        match self {
+            CodecId::Postcard => 0,
+            CodecId::Json => 1,
+            CodecId::MsgPack => 2,
+        }
+    }
+
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(CodecId::Postcard),
+            1 => Some(CodecId::Json),
+            2 => Some(CodecId::MsgPack),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            CodecId::Postcard => "postcard",
+            CodecId::Json => "json",
+            CodecId::MsgPack => "msgpack",
+        }
+    }
+}
+
+/// A pluggable serialization format for cache payloads.
+///
+/// Implementors only encode/decode the raw value; [`serialize_with_codec`]/
+/// [`deserialize_with_codec`] handle the surrounding envelope, checksum, and
+/// codec-identity check.
+pub trait Codec {
+    /// Which codec this is, recorded in the envelope.
+    fn id(&self) -> CodecId;
+
+    /// Encode `value` into this codec's wire format.
+    fn encode<T: Serialize>(&self, value: &T) -> Result<Vec<u8>>;
+
+    /// Decode this codec's wire format back into `T`.
+    fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T>;
+}
+
+/// The same Postcard format [`super::serialize_for_cache`] uses by default,
+/// exposed here so callers can pick it explicitly alongside [`JsonCodec`]/
+/// [`MsgPackCodec`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PostcardCodec;
+
+impl Codec for PostcardCodec {
+    fn id(&self) -> CodecId {
+        CodecId::Postcard
+    }
+
+    fn encode<T: Serialize>(&self, value: &T) -> Result<Vec<u8>> {
+        postcard::to_allocvec(value).map_err(|e| Error::SerializationError(e.to_string()))
+    }
+
+    fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T> {
+        postcard::from_bytes(bytes).map_err(|e| Error::DeserializationError(e.to_string()))
+    }
+}
+
+/// JSON payload codec: interoperable with non-Rust readers, at the cost of
+/// larger payloads and slower (de)serialization than Postcard or MessagePack.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonCodec;
+
+impl Codec for JsonCodec {
+    fn id(&self) -> CodecId {
+        CodecId::Json
+    }
+
+    fn encode<T: Serialize>(&self, value: &T) -> Result<Vec<u8>> {
+        serde_json::to_vec(value).map_err(Error::from)
+    }
+
+    fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T> {
+        serde_json::from_slice(bytes).map_err(Error::from)
+    }
+}
+
+/// MessagePack payload codec: substantially smaller and faster to
+/// (de)serialize than JSON for typical struct-shaped cache entities (the
+/// `User`/`Product` style entities this crate's tests exercise), while
+/// remaining a cross-language format, unlike Postcard.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MsgPackCodec;
+
+impl Codec for MsgPackCodec {
+    fn id(&self) -> CodecId {
+        CodecId::MsgPack
+    }
+
+    fn encode<T: Serialize>(&self, value: &T) -> Result<Vec<u8>> {
+        rmp_serde::to_vec(value).map_err(|e| Error::SerializationError(e.to_string()))
+    }
+
+    fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T> {
+        rmp_serde::from_slice(bytes).map_err(|e| Error::DeserializationError(e.to_string()))
+    }
+}
+
+/// Encode `value` with `codec`, wrapped in an envelope recording which codec
+/// was used and a checksum of the encoded bytes.
+///
+/// The Redis backend (and every other [`crate::backend::CacheBackend`])
+/// remains codec-agnostic: it only ever sees the resulting `Vec<u8>`.
+///
+/// # Errors
+/// Returns `Err` if `codec.encode` fails.
+pub fn serialize_with_codec<C: Codec, T: Serialize>(value: &T, codec: &C) -> Result<Vec<u8>> {
+    let payload = codec.encode(value)?;
+    let checksum = super::checksum_of_bytes(&payload);
+
+    let mut bytes = Vec::with_capacity(HEADER_LEN + payload.len());
+    bytes.extend_from_slice(&CODEC_MAGIC);
+    bytes.push(codec.id().as_u8());
+    bytes.extend_from_slice(&checksum.to_le_bytes());
+    bytes.extend_from_slice(&payload);
+    Ok(bytes)
+}
+
+/// Decode bytes written by [`serialize_with_codec`], requiring they were
+/// encoded with `codec`.
+///
+/// # Errors
+/// - `Error::InvalidCacheEntry`: entry too short, bad magic, or unknown codec tag
+/// - `Error::CodecMismatch`: the entry was encoded with a different codec than `codec`
+/// - `Error::ChecksumMismatch`: the payload bytes were corrupted or truncated
+/// - `Error::DeserializationError`: `codec.decode` failed
+pub fn deserialize_with_codec<C: Codec, T: DeserializeOwned>(
+    bytes: &[u8],
+    codec: &C,
+) -> Result<T> {
+    if bytes.len() < HEADER_LEN {
+        return Err(Error::InvalidCacheEntry(
+            "Entry too short for a codec envelope".to_string(),
+        ));
+    }
+
+    let (header_bytes, payload) = bytes.split_at(HEADER_LEN);
+    let magic: [u8; 4] = header_bytes[0..4].try_into().expect("slice is 4 bytes");
+    if magic != CODEC_MAGIC {
+        return Err(Error::InvalidCacheEntry(format!(
+            "Invalid codec envelope magic: expected {:?}, got {:?}",
+            CODEC_MAGIC, magic
+        )));
+    }
+
+    let found = CodecId::from_u8(header_bytes[4])
+        .ok_or_else(|| Error::InvalidCacheEntry(format!("Unknown codec tag: {}", header_bytes[4])))?;
+    if found != codec.id() {
+        return Err(Error::CodecMismatch {
+            expected: codec.id().name().to_string(),
+            found: found.name().to_string(),
+        });
+    }
+
+    let checksum = u64::from_le_bytes(header_bytes[5..13].try_into().expect("slice is 8 bytes"));
+    let actual = super::checksum_of_bytes(payload);
+    if actual != checksum {
+        return Err(Error::ChecksumMismatch {
+            expected: checksum,
+            actual,
+        });
+    }
+
+    codec.decode(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+    struct Widget {
+        id: u64,
+        name: String,
+    }
+
+    fn sample() -> Widget {
+        Widget {
+            id: 7,
+            name: "gadget".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_postcard_codec_roundtrip() {
+        let bytes = serialize_with_codec(&sample(), &PostcardCodec).unwrap();
+        let decoded: Widget = deserialize_with_codec(&bytes, &PostcardCodec).unwrap();
+        assert_eq!(decoded, sample());
+    }
+
+    #[test]
+    fn test_json_codec_roundtrip() {
+        let bytes = serialize_with_codec(&sample(), &JsonCodec).unwrap();
+        let decoded: Widget = deserialize_with_codec(&bytes, &JsonCodec).unwrap();
+        assert_eq!(decoded, sample());
+    }
+
+    #[test]
+    fn test_msgpack_codec_roundtrip() {
+        let bytes = serialize_with_codec(&sample(), &MsgPackCodec).unwrap();
+        let decoded: Widget = deserialize_with_codec(&bytes, &MsgPackCodec).unwrap();
+        assert_eq!(decoded, sample());
+    }
+
+    #[test]
+    fn test_msgpack_smaller_than_json() {
+        let json_bytes = serialize_with_codec(&sample(), &JsonCodec).unwrap();
+        let msgpack_bytes = serialize_with_codec(&sample(), &MsgPackCodec).unwrap();
+        assert!(
+            msgpack_bytes.len() < json_bytes.len(),
+            "MessagePack ({} bytes) should be smaller than JSON ({} bytes)",
+            msgpack_bytes.len(),
+            json_bytes.len()
+        );
+    }
+
+    #[test]
+    fn test_mixed_codec_read_fails_cleanly() {
+        let bytes = serialize_with_codec(&sample(), &JsonCodec).unwrap();
+        let result: Result<Widget> = deserialize_with_codec(&bytes, &MsgPackCodec);
+        match result.unwrap_err() {
+            Error::CodecMismatch { expected, found } => {
+                assert_eq!(expected, "msgpack");
+                assert_eq!(found, "json");
+            }
+            other => panic!("Expected CodecMismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_invalid_magic_rejected() {
+        let bytes = vec![0u8; 20];
+        let result: Result<Widget> = deserialize_with_codec(&bytes, &JsonCodec);
+        assert!(matches!(result.unwrap_err(), Error::InvalidCacheEntry(_)));
+    }
+
+    #[test]
+    fn test_corrupted_payload_checksum_rejected() {
+        let mut bytes = serialize_with_codec(&sample(), &JsonCodec).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF;
+        let result: Result<Widget> = deserialize_with_codec(&bytes, &JsonCodec);
+        assert!(matches!(result.unwrap_err(), Error::ChecksumMismatch { .. }));
+    }
+}
@@ -8,18 +8,24 @@
 //!
 //! Every cache entry follows this format:
 //! ```text
-//! ┌─────────────────┬─────────────────┬──────────────────────────┐
-//! │  MAGIC (4 bytes)│VERSION (4 bytes)│POSTCARD PAYLOAD (N bytes)│
-//! └─────────────────┴─────────────────┴──────────────────────────┘
-//!   "CKIT"              u32 (LE)           postcard::to_allocvec(T)
+//! ┌─────────────────┬─────────────────┬─────────────┬─────────────┬───────────────────┬──────────────────────────┐
+//! │  MAGIC (4 bytes)│VERSION (4 bytes)│FORMAT (1 byte)│FLAGS (1 byte)│CHECKSUM (8 bytes) │POSTCARD PAYLOAD (N bytes)│
+//! └─────────────────┴─────────────────┴─────────────┴─────────────┴───────────────────┴──────────────────────────┘
+//!   "CKIT"              u32 (LE)       CacheFormat tag  see below      u64 hash of payload   postcard::to_allocvec(T)
 //! ```
 //!
+//! `FLAGS` is currently one bit wide: whether the payload bytes that follow
+//! (and that `CHECKSUM` covers) are compressed. See
+//! [`serialize_for_cache_with_opts`] and [`CompressionConfig`].
+//!
 //! # Safety Guarantees
 //!
-//! - **Deterministic:** Same value always produces identical bytes
-//! - **Validated:** Magic and version checked on every deserialization
+//! - **Deterministic:** Same value and [`CompressionConfig`] always produce identical bytes
+//! - **Validated:** Magic, version, and payload checksum checked on every deserialization
 //! - **Versioned:** Schema changes force cache eviction, not silent migration
 //! - **Type-safe:** Postcard preserves exact Rust types
+//! - **Opt-in compression:** Large payloads can be transparently compressed above a
+//!   configurable size threshold; small entries are never touched, so they pay no CPU cost
 //!
 //! # Example
 //!
@@ -47,8 +53,12 @@
 //! ```
 
 use crate::error::{Error, Result};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
+pub mod big_array;
+pub mod codec;
+
 /// Magic header for cache-kit entries: b"CKIT"
 ///
 /// This 4-byte signature identifies valid cache-kit cache entries.
@@ -65,23 +75,257 @@ pub const CACHE_MAGIC: [u8; 4] = *b"CKIT";
 ///
 /// When deployed with a new version, old cache entries will be automatically
 /// evicted and recomputed from the source of truth.
-pub const CURRENT_SCHEMA_VERSION: u32 = 1;
+///
+/// Bumped to 3 when [`CacheEnvelope`] grew its `flags` byte (see
+/// [`serialize_for_cache_with_opts`]) - that's a field addition, so every
+/// entry stored under version 2 is evicted and recomputed rather than
+/// misread as having a `flags` byte it doesn't actually have.
+pub const CURRENT_SCHEMA_VERSION: u32 = 3;
+
+/// Oldest schema version [`CacheMigrator`] is expected to migrate from.
+///
+/// Entries stored under a version older than this floor are treated as
+/// unmigratable: raise the floor (and drop migration steps below it) once a
+/// version is old enough that nobody still has it cached. Entries below the
+/// floor get `Error::UnsupportedLegacyVersion` instead of
+/// `Error::MigrationMissing`, so callers can tell "needs a step we forgot to
+/// write" apart from "deliberately no longer supported".
+pub const MIN_SUPPORTED_SCHEMA_VERSION: u32 = 0;
+
+/// Which serde backend encoded a [`CacheEnvelope`]'s payload, stored as the
+/// `format` tag byte between `version` and `checksum` so one cache can hold
+/// entries written by different formats and [`deserialize_from_cache`] still
+/// decodes each one correctly, regardless of which format wrote it.
+///
+/// Tag values are fixed across builds rather than depending on which variants
+/// happen to be compiled in, so an entry written by a build with every
+/// format feature enabled still fails cleanly - not silently misreads - in a
+/// build that only enables a subset.
+///
+/// `Json` is always available, since `serde_json` is already a direct
+/// dependency of this crate (see `impl From<serde_json::Error> for Error`);
+/// only `Bincode` and `Ron` pull in a new optional dependency, so only those
+/// two are feature-gated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheFormat {
+    /// The default format; used by [`serialize_for_cache`].
+    Postcard,
+    /// Requires the `bincode` feature.
+    #[cfg(feature = "bincode")]
+    Bincode,
+    /// Always available.
+    Json,
+    /// Requires the `ron` feature.
+    #[cfg(feature = "ron")]
+    Ron,
+}
+
+impl Default for CacheFormat {
+    fn default() -> Self {
+        CacheFormat::Postcard
+    }
+}
+
+impl CacheFormat {
+    fn tag(self) -> u8 {
+        match self {
+            CacheFormat::Postcard => 0,
+            #[cfg(feature = "bincode")]
+            CacheFormat::Bincode => 1,
+            CacheFormat::Json => 2,
+            #[cfg(feature = "ron")]
+            CacheFormat::Ron => 3,
+        }
+    }
+
+    /// Resolve a tag byte read from an envelope back into a `CacheFormat`.
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidCacheEntry` for a tag that isn't a known
+    /// format, or that names a format whose feature isn't compiled in -
+    /// both are treated as the same "can't trust this entry" signal that
+    /// an unrecognized magic or version already gets.
+    fn from_tag(tag: u8) -> Result<Self> {
+        match tag {
+            0 => Ok(CacheFormat::Postcard),
+            #[cfg(feature = "bincode")]
+            1 => Ok(CacheFormat::Bincode),
+            2 => Ok(CacheFormat::Json),
+            #[cfg(feature = "ron")]
+            3 => Ok(CacheFormat::Ron),
+            other => Err(Error::InvalidCacheEntry(format!(
+                "Unknown or unsupported cache format tag: {}",
+                other
+            ))),
+        }
+    }
+
+    /// Encode `value` with this format's serde backend (no envelope framing).
+    fn encode<T: Serialize>(self, value: &T) -> Result<Vec<u8>> {
+        match self {
+            CacheFormat::Postcard => {
+                postcard::to_allocvec(value).map_err(|e| Error::SerializationError(e.to_string()))
+            }
+            #[cfg(feature = "bincode")]
+            CacheFormat::Bincode => {
+                bincode::serialize(value).map_err(|e| Error::SerializationError(e.to_string()))
+            }
+            CacheFormat::Json => serde_json::to_vec(value).map_err(Error::from),
+            #[cfg(feature = "ron")]
+            CacheFormat::Ron => ron::to_string(value)
+                .map(String::into_bytes)
+                .map_err(|e| Error::SerializationError(e.to_string())),
+        }
+    }
+
+    /// Decode `bytes` with this format's serde backend (no envelope framing).
+    ///
+    /// Bounded by `DeserializeOwned` rather than `Deserialize<'de>`: the
+    /// non-Postcard formats in [`deserialize_from_cache`] decode from an
+    /// owned intermediate buffer (see that function), not a borrow of the
+    /// original input, so `T` can't hold data borrowed from it either.
+    fn decode<T: DeserializeOwned>(self, bytes: &[u8]) -> Result<T> {
+        match self {
+            CacheFormat::Postcard => {
+                postcard::from_bytes(bytes).map_err(|e| Error::DeserializationError(e.to_string()))
+            }
+            #[cfg(feature = "bincode")]
+            CacheFormat::Bincode => bincode::deserialize(bytes)
+                .map_err(|e| Error::DeserializationError(e.to_string())),
+            CacheFormat::Json => serde_json::from_slice(bytes).map_err(Error::from),
+            #[cfg(feature = "ron")]
+            CacheFormat::Ron => {
+                let text = std::str::from_utf8(bytes)
+                    .map_err(|e| Error::DeserializationError(e.to_string()))?;
+                ron::from_str(text).map_err(|e| Error::DeserializationError(e.to_string()))
+            }
+        }
+    }
+}
+
+/// Bit in [`CacheEnvelope::flags`] marking the stored payload bytes as
+/// compressed with [`CompressionConfig::algorithm`]. See
+/// [`serialize_for_cache_with_opts`].
+const FLAG_COMPRESSED: u8 = 0x01;
+
+/// Which compressor (if any) [`serialize_for_cache_with_opts`] applies to a
+/// Postcard payload before it's stored.
+///
+/// `None` is always available; every other variant is feature-gated the same
+/// way [`CacheFormat`]'s optional variants are, since each pulls in its own
+/// optional dependency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressionAlgorithm {
+    /// Store the payload uncompressed. The default.
+    None,
+    /// Requires the `compression` feature.
+    #[cfg(feature = "compression")]
+    Zstd,
+}
+
+impl Default for CompressionAlgorithm {
+    fn default() -> Self {
+        CompressionAlgorithm::None
+    }
+}
+
+/// Tuning for [`serialize_for_cache_with_opts`]'s transparent payload
+/// compression.
+///
+/// [`serialize_for_cache`] uses [`CompressionConfig::default`], which never
+/// compresses, so it remains byte-for-byte unaffected by this type existing.
+/// Callers with large payloads (big `Vec`s, long strings) can opt in with a
+/// config that sets `algorithm`.
+///
+/// # Example
+///
+/// ```rust
+/// use cache_kit::serialization::{CompressionAlgorithm, CompressionConfig};
+///
+/// let config = CompressionConfig {
+///     algorithm: CompressionAlgorithm::None,
+///     min_size: 1024,
+///     level: 3,
+/// };
+/// assert_eq!(config, CompressionConfig::default());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompressionConfig {
+    /// Which algorithm to compress with; `None` disables compression entirely.
+    pub algorithm: CompressionAlgorithm,
+    /// Minimum encoded payload size, in bytes, before compression kicks in.
+    /// Payloads smaller than this are always stored raw, so small entries
+    /// pay no compression CPU cost.
+    pub min_size: usize,
+    /// Passed straight through to the algorithm's encoder (e.g. zstd's
+    /// compression level); meaning is algorithm-specific.
+    pub level: i32,
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        CompressionConfig {
+            algorithm: CompressionAlgorithm::None,
+            min_size: 1024,
+            level: 3,
+        }
+    }
+}
+
+impl CompressionConfig {
+    fn should_compress(&self, encoded_len: usize) -> bool {
+        self.algorithm != CompressionAlgorithm::None && encoded_len >= self.min_size
+    }
+}
+
+/// Compress `bytes` with `algorithm`, or return a copy unchanged for `None`.
+fn compress_bytes(algorithm: CompressionAlgorithm, level: i32, bytes: &[u8]) -> Result<Vec<u8>> {
+    match algorithm {
+        CompressionAlgorithm::None => Ok(bytes.to_vec()),
+        #[cfg(feature = "compression")]
+        CompressionAlgorithm::Zstd => zstd::stream::encode_all(bytes, level)
+            .map_err(|e| Error::SerializationError(e.to_string())),
+    }
+}
+
+/// Decompress a payload flagged [`FLAG_COMPRESSED`].
+///
+/// Unlike [`compress_bytes`], this never needs to match on
+/// [`CompressionAlgorithm::None`] (nothing flags an uncompressed payload) -
+/// only on whether the `compression` feature is compiled in, since the entry
+/// might have been written by a build that had it enabled.
+#[cfg(feature = "compression")]
+fn decompress_bytes(bytes: &[u8]) -> Result<Vec<u8>> {
+    zstd::stream::decode_all(bytes).map_err(|e| Error::DeserializationError(e.to_string()))
+}
+
+#[cfg(not(feature = "compression"))]
+fn decompress_bytes(_bytes: &[u8]) -> Result<Vec<u8>> {
+    Err(Error::InvalidCacheEntry(
+        "Cache entry is compressed but the `compression` feature is not enabled".to_string(),
+    ))
+}
 
 /// Versioned envelope for cache entries.
 ///
 /// Every cache entry is wrapped in this envelope to enable:
-/// - **Corruption detection:** Invalid magic → reject entry
+/// - **Corruption detection:** Invalid magic or checksum → reject entry
 /// - **Schema evolution:** Version mismatch → evict and recompute
 /// - **Observability:** Track version mismatches in metrics
 ///
 /// # Format
 ///
 /// ```text
-/// ┌─────────────────┬─────────────────┬──────────────────────────┐
-/// │  magic: [u8; 4] │ version: u32    │  payload: T              │
-/// └─────────────────┴─────────────────┴──────────────────────────┘
+/// ┌─────────────────┬───────────────┬───────────────┬──────────────┬──────────────────┬──────────────────────────┐
+/// │  magic: [u8; 4] │ version: u32  │  format: u8    │  flags: u8    │  checksum: u64    │  payload: T              │
+/// └─────────────────┴───────────────┴───────────────┴──────────────┴──────────────────┴──────────────────────────┘
 /// ```
 ///
+/// `format` only matters to [`serialize_for_cache_with`]/[`deserialize_from_cache`];
+/// `flags` only matters to [`serialize_for_cache_with_opts`]/[`deserialize_from_cache`].
+/// This struct's own `payload: T` is always encoded inline by whatever
+/// serializes the envelope as a whole (Postcard, for every constructor here).
+///
 /// # Example
 ///
 /// ```rust
@@ -96,12 +340,25 @@ pub struct CacheEnvelope<T> {
     pub magic: [u8; 4],
     /// Schema version: must match CURRENT_SCHEMA_VERSION
     pub version: u32,
+    /// Tag identifying which [`CacheFormat`] encoded `payload`.
+    pub format: u8,
+    /// Bit flags about how `payload`'s encoded bytes are stored; currently
+    /// only [`FLAG_COMPRESSED`]. Always 0 from [`CacheEnvelope::new`], since
+    /// that constructor embeds `payload: T` inline rather than through the
+    /// byte-oriented path [`serialize_for_cache_with_opts`] compresses.
+    pub flags: u8,
+    /// Hash of the encoded payload, checked before deserializing it
+    pub checksum: u64,
     /// The actual cached data
     pub payload: T,
 }
 
-impl<T> CacheEnvelope<T> {
-    /// Create a new envelope with current magic and version.
+impl<T: Serialize> CacheEnvelope<T> {
+    /// Create a new envelope with current magic, version, and payload checksum.
+    ///
+    /// Always tags itself as [`CacheFormat::Postcard`] and uncompressed; see
+    /// [`serialize_for_cache_with`] for a choice of format and
+    /// [`serialize_for_cache_with_opts`] for compression.
     ///
     /// # Example
     ///
@@ -112,14 +369,42 @@ impl<T> CacheEnvelope<T> {
     /// assert_eq!(envelope.payload, 42);
     /// ```
     pub fn new(payload: T) -> Self {
+        let checksum = checksum_of_payload(&payload);
         Self {
             magic: CACHE_MAGIC,
             version: CURRENT_SCHEMA_VERSION,
+            format: CacheFormat::Postcard.tag(),
+            flags: 0,
+            checksum,
             payload,
         }
     }
 }
 
+/// Hash raw bytes into the `u64` stored as [`CacheEnvelope::checksum`].
+///
+/// Uses `std::hash::DefaultHasher` (SipHash) rather than pulling in a
+/// dedicated CRC/xxHash crate: it's already a transitive dependency of every
+/// Rust toolchain, fast enough for cache-sized payloads, and this checksum
+/// only needs to catch accidental corruption, not resist tampering.
+fn checksum_of_bytes(bytes: &[u8]) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Serialize `payload` and compute its checksum for embedding in an envelope.
+fn checksum_of_payload<T: Serialize>(payload: &T) -> u64 {
+    match postcard::to_allocvec(payload) {
+        Ok(bytes) => checksum_of_bytes(&bytes),
+        // `CacheEnvelope::new` has no way to surface an error; a failure here
+        // will be caught again (and reported properly) when the envelope
+        // itself is serialized moments later.
+        Err(_) => 0,
+    }
+}
+
 /// Serialize a value with envelope for cache storage.
 ///
 /// This is the canonical way to serialize data for cache storage in cache-kit.
@@ -128,7 +413,7 @@ impl<T> CacheEnvelope<T> {
 /// # Format
 ///
 /// ```text
-/// [MAGIC: 4 bytes] [VERSION: 4 bytes] [POSTCARD PAYLOAD: N bytes]
+/// [MAGIC: 4 bytes] [VERSION: 4 bytes] [FORMAT: 1 byte] [CHECKSUM: 8 bytes] [POSTCARD PAYLOAD: N bytes]
 /// ```
 ///
 /// # Performance
@@ -160,11 +445,113 @@ impl<T> CacheEnvelope<T> {
 ///
 /// Returns `Error::SerializationError` if Postcard serialization fails.
 pub fn serialize_for_cache<T: Serialize>(value: &T) -> Result<Vec<u8>> {
-    let envelope = CacheEnvelope::new(value);
-    postcard::to_allocvec(&envelope).map_err(|e| {
+    serialize_for_cache_with_opts(value, &CompressionConfig::default())
+}
+
+/// Same as [`serialize_for_cache`], but compresses the Postcard payload per
+/// `config` before storing it.
+///
+/// Payloads at or above `config.min_size` are compressed with
+/// `config.algorithm` and the entry's [`CacheEnvelope::flags`] byte is
+/// stamped with [`FLAG_COMPRESSED`]; [`deserialize_from_cache`] checks that
+/// bit and decompresses before decoding. Payloads below the threshold are
+/// stored exactly as [`serialize_for_cache`] would, so small entries never
+/// pay a compression cost. `config.algorithm == CompressionAlgorithm::None`
+/// (the default) never compresses, regardless of `min_size`.
+///
+/// # Example
+///
+/// ```rust
+/// use cache_kit::serialization::{
+///     deserialize_from_cache, serialize_for_cache_with_opts, CompressionConfig,
+/// };
+///
+/// # fn main() -> cache_kit::Result<()> {
+/// let value = "x".repeat(2000);
+/// let config = CompressionConfig {
+///     min_size: 1024,
+///     ..CompressionConfig::default()
+/// };
+/// let bytes = serialize_for_cache_with_opts(&value, &config)?;
+/// let roundtripped: String = deserialize_from_cache(&bytes)?;
+/// assert_eq!(roundtripped, value);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns `Error::SerializationError` if Postcard serialization, or
+/// `config.algorithm`'s compressor, fails.
+pub fn serialize_for_cache_with_opts<T: Serialize>(
+    value: &T,
+    config: &CompressionConfig,
+) -> Result<Vec<u8>> {
+    let raw = postcard::to_allocvec(value).map_err(|e| {
         log::error!("Cache serialization failed: {}", e);
         Error::SerializationError(e.to_string())
-    })
+    })?;
+
+    let (stored, flags) = if config.should_compress(raw.len()) {
+        let compressed = compress_bytes(config.algorithm, config.level, &raw)?;
+        (compressed, FLAG_COMPRESSED)
+    } else {
+        (raw, 0u8)
+    };
+
+    let checksum = checksum_of_bytes(&stored);
+    let header = EnvelopeHeader {
+        magic: CACHE_MAGIC,
+        version: CURRENT_SCHEMA_VERSION,
+        format: CacheFormat::Postcard.tag(),
+        flags,
+        checksum,
+    };
+
+    let mut bytes = postcard::to_allocvec(&header).map_err(|e| {
+        log::error!("Cache serialization failed: {}", e);
+        Error::SerializationError(e.to_string())
+    })?;
+    bytes.extend_from_slice(&stored);
+    Ok(bytes)
+}
+
+/// Serialize a value for cache storage using `format` instead of the default
+/// Postcard backend, so the entry can hold e.g. JSON for interop with a
+/// non-Rust reader of the same cache.
+///
+/// [`deserialize_from_cache`] reads the tag this stores and dispatches to the
+/// matching decoder automatically, so one cache can mix entries written by
+/// different formats and callers never need to track which format wrote a
+/// given key.
+///
+/// # Errors
+/// Returns `Error::SerializationError` if `format`'s backend fails to encode `value`.
+pub fn serialize_for_cache_with<T: Serialize>(format: CacheFormat, value: &T) -> Result<Vec<u8>> {
+    if format == CacheFormat::Postcard {
+        return serialize_for_cache(value);
+    }
+
+    let encoded = format.encode(value)?;
+    let checksum = checksum_of_bytes(&encoded);
+    let header = EnvelopeHeader {
+        magic: CACHE_MAGIC,
+        version: CURRENT_SCHEMA_VERSION,
+        format: format.tag(),
+        flags: 0,
+        checksum,
+    };
+
+    // `encoded` is embedded as a length-prefixed Postcard `Vec<u8>` (not
+    // appended raw) so `decode_header`'s flat-prefix trick still isolates
+    // exactly these bytes on the read side, regardless of which format
+    // produced them.
+    let mut bytes = postcard::to_allocvec(&header)
+        .map_err(|e| Error::SerializationError(e.to_string()))?;
+    let framed_payload =
+        postcard::to_allocvec(&encoded).map_err(|e| Error::SerializationError(e.to_string()))?;
+    bytes.extend_from_slice(&framed_payload);
+    Ok(bytes)
 }
 
 /// Deserialize a value from cache storage with validation.
@@ -172,7 +559,8 @@ pub fn serialize_for_cache<T: Serialize>(value: &T) -> Result<Vec<u8>> {
 /// This function performs strict validation:
 /// 1. Checks magic header matches b"CKIT"
 /// 2. Checks version matches CURRENT_SCHEMA_VERSION
-/// 3. Deserializes Postcard payload
+/// 3. Checks the payload checksum matches the one stored in the envelope
+/// 4. Deserializes Postcard payload
 ///
 /// # Validation Strategy
 ///
@@ -184,6 +572,10 @@ pub fn serialize_for_cache<T: Serialize>(value: &T) -> Result<Vec<u8>> {
 /// - Indicates schema change between code versions
 /// - Cache entry should be evicted and recomputed
 ///
+/// **On checksum mismatch:** Returns `Error::ChecksumMismatch`
+/// - Indicates the payload bytes were corrupted or truncated in storage/transport
+/// - Cache entry should be evicted
+///
 /// **On Postcard error:** Returns `Error::DeserializationError`
 /// - Indicates corrupted payload
 /// - Cache entry should be evicted
@@ -211,41 +603,250 @@ pub fn serialize_for_cache<T: Serialize>(value: &T) -> Result<Vec<u8>> {
 ///
 /// - `Error::InvalidCacheEntry`: Invalid magic header
 /// - `Error::VersionMismatch`: Schema version mismatch
+/// - `Error::ChecksumMismatch`: Payload checksum does not match the envelope
 /// - `Error::DeserializationError`: Corrupted Postcard payload
-pub fn deserialize_from_cache<'de, T: Deserialize<'de>>(bytes: &'de [u8]) -> Result<T> {
-    // Attempt to deserialize envelope
-    let envelope: CacheEnvelope<T> = postcard::from_bytes(bytes).map_err(|e| {
-        log::error!("Cache deserialization failed: {}", e);
-        Error::DeserializationError(e.to_string())
-    })?;
-
-    // Validate magic header
-    if envelope.magic != CACHE_MAGIC {
-        log::warn!(
-            "Invalid cache entry: expected magic {:?}, got {:?}",
-            CACHE_MAGIC,
-            envelope.magic
-        );
-        return Err(Error::InvalidCacheEntry(format!(
-            "Invalid magic: expected {:?}, got {:?}",
-            CACHE_MAGIC, envelope.magic
-        )));
-    }
+pub fn deserialize_from_cache<T: DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+    let (header, tail) = decode_header(bytes)?;
 
     // Validate schema version
-    if envelope.version != CURRENT_SCHEMA_VERSION {
+    if header.version != CURRENT_SCHEMA_VERSION {
         log::warn!(
             "Cache version mismatch: expected {}, got {}",
             CURRENT_SCHEMA_VERSION,
-            envelope.version
+            header.version
         );
         return Err(Error::VersionMismatch {
             expected: CURRENT_SCHEMA_VERSION,
-            found: envelope.version,
+            found: header.version,
         });
     }
 
-    Ok(envelope.payload)
+    let format = CacheFormat::from_tag(header.format)?;
+
+    // Postcard is the envelope's own inline payload shape, so `tail` is
+    // already exactly the payload's (possibly compressed) encoding - no
+    // further unwrapping needed.
+    if format == CacheFormat::Postcard {
+        let actual = checksum_of_bytes(tail);
+        if actual != header.checksum {
+            log::warn!(
+                "Cache checksum mismatch: expected {}, got {}",
+                header.checksum,
+                actual
+            );
+            return Err(Error::ChecksumMismatch {
+                expected: header.checksum,
+                actual,
+            });
+        }
+
+        let decompressed;
+        let payload = if header.flags & FLAG_COMPRESSED != 0 {
+            decompressed = decompress_bytes(tail)?;
+            &decompressed[..]
+        } else {
+            tail
+        };
+
+        return postcard::from_bytes(payload).map_err(|e| {
+            log::error!("Cache deserialization failed: {}", e);
+            Error::DeserializationError(e.to_string())
+        });
+    }
+
+    // Every other format was written by `serialize_for_cache_with` with its
+    // encoded bytes wrapped as a length-prefixed Postcard `Vec<u8>` (see that
+    // function), so unwrap that framing before checksumming/decoding.
+    let payload: Vec<u8> = postcard::from_bytes(tail)
+        .map_err(|e| Error::DeserializationError(e.to_string()))?;
+
+    let actual = checksum_of_bytes(&payload);
+    if actual != header.checksum {
+        log::warn!(
+            "Cache checksum mismatch: expected {}, got {}",
+            header.checksum,
+            actual
+        );
+        return Err(Error::ChecksumMismatch {
+            expected: header.checksum,
+            actual,
+        });
+    }
+
+    format.decode(&payload).map_err(|e| {
+        log::error!("Cache deserialization failed: {}", e);
+        e
+    })
+}
+
+/// Envelope header only (magic, version, format, checksum), decoded without
+/// touching the payload.
+///
+/// Relies on Postcard encoding struct fields as a flat concatenation with no
+/// struct-level framing, so decoding this prefix of `CacheEnvelope<T>` leaves
+/// the remaining bytes as exactly the payload's own encoding.
+#[derive(Serialize, Deserialize)]
+struct EnvelopeHeader {
+    magic: [u8; 4],
+    version: u32,
+    format: u8,
+    flags: u8,
+    checksum: u64,
+}
+
+/// Reject envelope versions older than `minimum`, distinctly from an ordinary
+/// `VersionMismatch`: these aren't just stale, they predate the oldest version
+/// a migration chain is expected to cover.
+fn check_schema_floor(found: u32, minimum: u32) -> Result<()> {
+    if found < minimum {
+        return Err(Error::UnsupportedLegacyVersion { found, minimum });
+    }
+    Ok(())
+}
+
+/// Validate an entry's magic header and split it into its stored schema
+/// version and raw Postcard payload bytes, without checking that the
+/// version matches [`CURRENT_SCHEMA_VERSION`].
+///
+/// `CacheEntity::migrate` uses this so a migration hook can decode the old
+/// payload shape directly, instead of every implementer re-deriving the
+/// payload from the full envelope bytes by hand.
+///
+/// # Errors
+/// Returns `Error::InvalidCacheEntry` if the magic header doesn't match.
+pub fn decode_version_and_payload(bytes: &[u8]) -> Result<(u32, &[u8])> {
+    let (header, payload) = decode_header(bytes)?;
+    Ok((header.version, payload))
+}
+
+/// Split `bytes` into its envelope header and the raw payload bytes that follow it.
+fn decode_header(bytes: &[u8]) -> Result<(EnvelopeHeader, &[u8])> {
+    let (header, payload): (EnvelopeHeader, &[u8]) = postcard::take_from_bytes(bytes)
+        .map_err(|e| Error::DeserializationError(e.to_string()))?;
+
+    if header.magic != CACHE_MAGIC {
+        return Err(Error::InvalidCacheEntry(format!(
+            "Invalid magic: expected {:?}, got {:?}",
+            CACHE_MAGIC, header.magic
+        )));
+    }
+
+    Ok((header, payload))
+}
+
+/// A single schema migration step: rewrites the Postcard-encoded payload
+/// bytes for version `N` into the payload bytes for version `N + 1`.
+pub type MigrationStep = Box<dyn Fn(&[u8]) -> Result<Vec<u8>> + Send + Sync>;
+
+/// Ordered registry of sequential schema migrations for one cached type.
+///
+/// Old cache entries don't have to be evicted just because
+/// [`CURRENT_SCHEMA_VERSION`] moved on. Register one step per version
+/// transition with [`CacheMigrator::step`], then call [`CacheMigrator::migrate`]
+/// on raw cache bytes: it reads the envelope's stored version, walks the
+/// chain of steps up to `CURRENT_SCHEMA_VERSION`, and deserializes the result.
+/// Each step only needs to know how to hop from its own version to the next
+/// one — the migrator takes care of chaining multiple hops together.
+///
+/// There's deliberately no `CacheExpander`-level registry to register a
+/// `CacheMigrator` on: which migrations apply is a property of one cached
+/// *type*, not of the expander reading it, so the hook lives on
+/// [`crate::entity::CacheEntity::migrate`] instead, right alongside that
+/// trait's other per-type extension points (`cache_key`, `cache_prefix`, ...).
+/// A type with more than one migration step builds a `CacheMigrator` and
+/// calls [`CacheMigrator::migrate_payload`] from inside its own `migrate`
+/// impl - see that trait's doc example.
+///
+
+/// # Example
+///
+/// ```rust
+/// use cache_kit::serialization::CacheMigrator;
+///
+/// let migrator: CacheMigrator = CacheMigrator::new()
+///     .step(0, |_old_payload| Ok(postcard::to_allocvec(&"migrated").unwrap()));
+/// ```
+#[derive(Default)]
+pub struct CacheMigrator {
+    steps: std::collections::BTreeMap<u32, MigrationStep>,
+}
+
+impl CacheMigrator {
+    /// Create an empty migration chain.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a migration from schema version `from` to `from + 1`.
+    ///
+    /// `step` receives the Postcard-encoded payload bytes stored under
+    /// version `from` and must return the Postcard-encoded payload bytes for
+    /// version `from + 1`.
+    pub fn step(
+        mut self,
+        from: u32,
+        step: impl Fn(&[u8]) -> Result<Vec<u8>> + Send + Sync + 'static,
+    ) -> Self {
+        self.steps.insert(from, Box::new(step));
+        self
+    }
+
+    /// Decode `bytes`'s envelope header, walk the migration chain from its
+    /// stored version up to [`CURRENT_SCHEMA_VERSION`], and deserialize the
+    /// result as `T`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidCacheEntry`: bad magic header
+    /// - `Error::UnsupportedLegacyVersion`: stored version is below `MIN_SUPPORTED_SCHEMA_VERSION`
+    /// - `Error::MigrationMissing`: no registered step covers a hop in the chain
+    /// - `Error::DeserializationError`: a step, or the final payload decode, failed
+    pub fn migrate<T: for<'de> Deserialize<'de>>(&self, bytes: &[u8]) -> Result<T> {
+        let (header, payload) = decode_header(bytes)?;
+        self.migrate_payload(header.version, payload)
+    }
+
+    /// Same as [`CacheMigrator::migrate`], but starting from an
+    /// already-decoded `(version, payload)` pair instead of full envelope
+    /// bytes - the shape [`decode_version_and_payload`] returns, and the
+    /// shape [`crate::entity::CacheEntity::migrate`]'s hook already receives.
+    /// A type whose migrations are involved enough to want a chain can build
+    /// one `CacheMigrator` and call this straight from its `migrate` impl,
+    /// instead of hand-rolling the chain walk itself:
+    ///
+    /// ```ignore
+    /// fn migrate(old_version: u32, payload: &[u8]) -> Result<Option<Self>> {
+    ///     MY_MIGRATOR.migrate_payload(old_version, payload).map(Some)
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Same as [`CacheMigrator::migrate`], minus `Error::InvalidCacheEntry`
+    /// (the caller already validated the magic header to get here).
+    pub fn migrate_payload<T: for<'de> Deserialize<'de>>(
+        &self,
+        version: u32,
+        payload: &[u8],
+    ) -> Result<T> {
+        check_schema_floor(version, MIN_SUPPORTED_SCHEMA_VERSION)?;
+
+        let mut version = version;
+        let mut payload: Vec<u8> = payload.to_vec();
+        while version < CURRENT_SCHEMA_VERSION {
+            let step = self
+                .steps
+                .get(&version)
+                .ok_or(Error::MigrationMissing {
+                    from: version,
+                    to: version + 1,
+                })?;
+            payload = step(&payload)?;
+            version += 1;
+        }
+
+        postcard::from_bytes(&payload).map_err(|e| Error::DeserializationError(e.to_string()))
+    }
 }
 
 #[cfg(test)]
@@ -430,4 +1031,396 @@ mod tests {
             json_bytes.len()
         );
     }
+
+    #[test]
+    fn test_checksum_mismatch_rejected() {
+        let data = TestData {
+            id: 123,
+            name: "test".to_string(),
+            active: true,
+        };
+
+        let mut envelope = CacheEnvelope::new(&data);
+        envelope.checksum ^= 0xFF; // Flip bits to desync from the payload
+
+        let bytes = postcard::to_allocvec(&envelope).unwrap();
+        let result: Result<TestData> = deserialize_from_cache(&bytes);
+
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            Error::ChecksumMismatch { .. } => {} // Expected
+            e => panic!("Expected ChecksumMismatch, got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_checksum_catches_silent_bit_rot_not_just_truncation() {
+        // A corruption that still postcard-decodes successfully - into a
+        // different, wrong value - must still be caught by the checksum.
+        // Unlike `test_corrupted_payload_rejected`'s truncation (which fails
+        // to decode at all), this flips a single payload byte that stays a
+        // structurally valid encoding.
+        let data = TestData {
+            id: 123, // fits in a single-byte postcard varint (< 128)
+            name: "test".to_string(),
+            active: true,
+        };
+        let bytes = serialize_for_cache(&data).unwrap();
+
+        let envelope_len = postcard::to_allocvec(&CacheEnvelope::new(&data)).unwrap().len();
+        assert_eq!(bytes.len(), envelope_len);
+
+        // The payload is the tail of the envelope; its first byte is `id`'s
+        // single-byte varint. Flip a low bit that leaves the continuation
+        // bit (0x80) untouched, so the varint still decodes - to a different
+        // `u64` - and every byte after it keeps the same meaning.
+        let mut corrupted = bytes.clone();
+        let payload_start = corrupted.len() - postcard::to_allocvec(&data).unwrap().len();
+        corrupted[payload_start] ^= 0x01;
+
+        let result: Result<TestData> = deserialize_from_cache(&corrupted);
+        match result.unwrap_err() {
+            Error::ChecksumMismatch { .. } => {} // Expected
+            e => panic!("Expected ChecksumMismatch, got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_json_format_roundtrips() {
+        let data = TestData {
+            id: 123,
+            name: "test".to_string(),
+            active: true,
+        };
+
+        let bytes = serialize_for_cache_with(CacheFormat::Json, &data).unwrap();
+        let deserialized: TestData = deserialize_from_cache(&bytes).unwrap();
+
+        assert_eq!(data, deserialized);
+    }
+
+    #[test]
+    fn test_deserialize_from_cache_dispatches_on_format_tag_without_caller_hint() {
+        // The whole point of the format tag: the reader doesn't choose a
+        // format up front, it reads whatever the writer used.
+        let data = TestData {
+            id: 7,
+            name: "self-describing".to_string(),
+            active: false,
+        };
+
+        let postcard_bytes = serialize_for_cache(&data).unwrap();
+        let json_bytes = serialize_for_cache_with(CacheFormat::Json, &data).unwrap();
+
+        let from_postcard: TestData = deserialize_from_cache(&postcard_bytes).unwrap();
+        let from_json: TestData = deserialize_from_cache(&json_bytes).unwrap();
+
+        assert_eq!(from_postcard, data);
+        assert_eq!(from_json, data);
+    }
+
+    #[test]
+    fn test_json_format_checksum_mismatch_rejected() {
+        let data = TestData {
+            id: 123,
+            name: "test".to_string(),
+            active: true,
+        };
+
+        let mut bytes = serialize_for_cache_with(CacheFormat::Json, &data).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF; // Corrupt a trailing payload byte without touching the header.
+
+        let result: Result<TestData> = deserialize_from_cache(&bytes);
+        match result.unwrap_err() {
+            Error::ChecksumMismatch { .. } => {} // Expected
+            e => panic!("Expected ChecksumMismatch, got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_unknown_format_tag_rejected() {
+        let data = TestData {
+            id: 1,
+            name: "x".to_string(),
+            active: true,
+        };
+
+        let mut envelope = CacheEnvelope::new(&data);
+        envelope.format = 0xFF;
+
+        let bytes = postcard::to_allocvec(&envelope).unwrap();
+        let result: Result<TestData> = deserialize_from_cache(&bytes);
+
+        assert!(matches!(result, Err(Error::InvalidCacheEntry(_))));
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn test_bincode_format_roundtrips() {
+        let data = TestData {
+            id: 9,
+            name: "bincode".to_string(),
+            active: true,
+        };
+
+        let bytes = serialize_for_cache_with(CacheFormat::Bincode, &data).unwrap();
+        let deserialized: TestData = deserialize_from_cache(&bytes).unwrap();
+
+        assert_eq!(data, deserialized);
+    }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn test_ron_format_roundtrips() {
+        let data = TestData {
+            id: 10,
+            name: "ron".to_string(),
+            active: false,
+        };
+
+        let bytes = serialize_for_cache_with(CacheFormat::Ron, &data).unwrap();
+        let deserialized: TestData = deserialize_from_cache(&bytes).unwrap();
+
+        assert_eq!(data, deserialized);
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct TestDataV0 {
+        id: u64,
+        name: String,
+    }
+
+    #[test]
+    fn test_migrator_walks_single_hop() {
+        let old = TestDataV0 {
+            id: 123,
+            name: "old".to_string(),
+        };
+        let envelope = CacheEnvelope {
+            magic: CACHE_MAGIC,
+            version: 0,
+            format: 0,
+            flags: 0,
+            checksum: 0,
+            payload: &old,
+        };
+        let bytes = postcard::to_allocvec(&envelope).unwrap();
+
+        let migrator = CacheMigrator::new().step(0, |old_payload: &[u8]| {
+            let old: TestDataV0 = postcard::from_bytes(old_payload)
+                .map_err(|e| Error::DeserializationError(e.to_string()))?;
+            let upgraded = TestData {
+                id: old.id,
+                name: old.name,
+                active: true,
+            };
+            postcard::to_allocvec(&upgraded).map_err(|e| Error::SerializationError(e.to_string()))
+        });
+
+        let migrated: TestData = migrator.migrate(&bytes).unwrap();
+        assert_eq!(migrated.id, 123);
+        assert_eq!(migrated.name, "old");
+        assert!(migrated.active);
+    }
+
+    #[test]
+    fn test_migrator_walks_multiple_hops_transitively() {
+        // CURRENT_SCHEMA_VERSION is 2, so an entry stamped version 0 needs
+        // both the 0->1 and 1->2 steps applied in sequence before it decodes
+        // as TestData - exercising the "v1->v2->v3" chaining the migrator
+        // is meant to support, not just a single hop.
+        let old = TestDataV0 {
+            id: 7,
+            name: "old".to_string(),
+        };
+        let envelope = CacheEnvelope {
+            magic: CACHE_MAGIC,
+            version: 0,
+            format: 0,
+            flags: 0,
+            checksum: 0,
+            payload: &old,
+        };
+        let bytes = postcard::to_allocvec(&envelope).unwrap();
+
+        let migrator = CacheMigrator::new()
+            .step(0, |old_payload: &[u8]| {
+                let old: TestDataV0 = postcard::from_bytes(old_payload)
+                    .map_err(|e| Error::DeserializationError(e.to_string()))?;
+                // v1 keeps the same shape as v0 here; only v1->v2 adds a field.
+                postcard::to_allocvec(&old).map_err(|e| Error::SerializationError(e.to_string()))
+            })
+            .step(1, |v1_payload: &[u8]| {
+                let v1: TestDataV0 = postcard::from_bytes(v1_payload)
+                    .map_err(|e| Error::DeserializationError(e.to_string()))?;
+                let upgraded = TestData {
+                    id: v1.id,
+                    name: v1.name,
+                    active: true,
+                };
+                postcard::to_allocvec(&upgraded).map_err(|e| Error::SerializationError(e.to_string()))
+            });
+
+        let migrated: TestData = migrator.migrate(&bytes).unwrap();
+        assert_eq!(migrated.id, 7);
+        assert_eq!(migrated.name, "old");
+        assert!(migrated.active);
+    }
+
+    #[test]
+    fn test_migrator_missing_step_errors() {
+        let old = TestDataV0 {
+            id: 1,
+            name: "x".to_string(),
+        };
+        let envelope = CacheEnvelope {
+            magic: CACHE_MAGIC,
+            version: 0,
+            format: 0,
+            flags: 0,
+            checksum: 0,
+            payload: &old,
+        };
+        let bytes = postcard::to_allocvec(&envelope).unwrap();
+
+        let migrator = CacheMigrator::new();
+        let result: Result<TestData> = migrator.migrate(&bytes);
+
+        match result.unwrap_err() {
+            Error::MigrationMissing { from, to } => {
+                assert_eq!(from, 0);
+                assert_eq!(to, 1);
+            }
+            e => panic!("Expected MigrationMissing, got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_min_supported_schema_version_not_above_current() {
+        // The floor can never exceed what the chain actually produces.
+        assert!(MIN_SUPPORTED_SCHEMA_VERSION <= CURRENT_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_schema_floor_rejects_entries_below_minimum() {
+        match check_schema_floor(0, 1).unwrap_err() {
+            Error::UnsupportedLegacyVersion { found, minimum } => {
+                assert_eq!(found, 0);
+                assert_eq!(minimum, 1);
+            }
+            e => panic!("Expected UnsupportedLegacyVersion, got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_schema_floor_allows_entries_at_or_above_minimum() {
+        assert!(check_schema_floor(1, 1).is_ok());
+        assert!(check_schema_floor(2, 1).is_ok());
+    }
+
+    #[test]
+    fn test_migrator_current_version_skips_chain() {
+        let data = TestData {
+            id: 42,
+            name: "current".to_string(),
+            active: false,
+        };
+        let bytes = serialize_for_cache(&data).unwrap();
+
+        // No steps registered, but the version already matches current so
+        // the chain is never walked.
+        let migrator = CacheMigrator::new();
+        let migrated: TestData = migrator.migrate(&bytes).unwrap();
+        assert_eq!(migrated, data);
+    }
+
+    #[test]
+    fn test_default_compression_config_never_compresses() {
+        let config = CompressionConfig::default();
+        assert_eq!(config.algorithm, CompressionAlgorithm::None);
+        assert!(!config.should_compress(usize::MAX));
+    }
+
+    #[test]
+    fn test_serialize_for_cache_matches_none_algorithm_opts() {
+        // `serialize_for_cache` is documented as the default-config wrapper
+        // around `serialize_for_cache_with_opts`; this pins that down.
+        let data = TestData {
+            id: 123,
+            name: "x".repeat(5000),
+            active: true,
+        };
+
+        let via_default = serialize_for_cache(&data).unwrap();
+        let via_opts =
+            serialize_for_cache_with_opts(&data, &CompressionConfig::default()).unwrap();
+
+        assert_eq!(via_default, via_opts);
+    }
+
+    #[test]
+    fn test_small_payload_not_compressed_even_with_low_threshold() {
+        // `algorithm: None` disables compression outright, regardless of
+        // `min_size` - compression is opt-in on both axes.
+        let data = TestData {
+            id: 1,
+            name: "tiny".to_string(),
+            active: true,
+        };
+        let config = CompressionConfig {
+            algorithm: CompressionAlgorithm::None,
+            min_size: 0,
+            level: 3,
+        };
+
+        let bytes = serialize_for_cache_with_opts(&data, &config).unwrap();
+        let deserialized: TestData = deserialize_from_cache(&bytes).unwrap();
+        assert_eq!(data, deserialized);
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_large_payload_compressed_above_threshold() {
+        let data = TestData {
+            id: 1,
+            name: "y".repeat(5000),
+            active: true,
+        };
+        let config = CompressionConfig {
+            algorithm: CompressionAlgorithm::Zstd,
+            min_size: 1024,
+            level: 3,
+        };
+
+        let compressed_bytes = serialize_for_cache_with_opts(&data, &config).unwrap();
+        let uncompressed_bytes = serialize_for_cache(&data).unwrap();
+        assert!(
+            compressed_bytes.len() < uncompressed_bytes.len(),
+            "compressed ({} bytes) should be smaller than uncompressed ({} bytes)",
+            compressed_bytes.len(),
+            uncompressed_bytes.len()
+        );
+
+        let deserialized: TestData = deserialize_from_cache(&compressed_bytes).unwrap();
+        assert_eq!(data, deserialized);
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_payload_below_threshold_stored_raw_despite_available_compression() {
+        let data = TestData {
+            id: 1,
+            name: "tiny".to_string(),
+            active: true,
+        };
+        let config = CompressionConfig {
+            algorithm: CompressionAlgorithm::Zstd,
+            min_size: 1024,
+            level: 3,
+        };
+
+        let bytes = serialize_for_cache_with_opts(&data, &config).unwrap();
+        assert_eq!(bytes, serialize_for_cache(&data).unwrap());
+    }
 }
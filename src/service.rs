@@ -5,13 +5,43 @@
 use crate::backend::CacheBackend;
 use crate::entity::CacheEntity;
 use crate::error::Result;
-use crate::expander::{CacheExpander, OperationConfig};
+use crate::expander::{CacheExpander, CacheOutcome, OperationConfig};
 use crate::feed::CacheFeed;
+use crate::invalidation::InvalidationBus;
 use crate::observability::CacheMetrics;
 use crate::repository::DataRepository;
 use crate::strategy::CacheStrategy;
+use dashmap::{DashMap, DashSet};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::future::Future;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex as AsyncMutex;
+
+/// Deterministic xorshift64 PRNG state for [`jittered`] - mirrors
+/// `expander::BACKOFF_JITTER_STATE`'s rationale: spreading out a fleet's
+/// rehydration ticks doesn't need cryptographic randomness, just a shared
+/// counter distinct from the expander's own jitter state.
+static REHYDRATE_JITTER_STATE: std::sync::atomic::AtomicU64 =
+    std::sync::atomic::AtomicU64::new(0x853C49E6748FEA9B);
+
+/// Add up to 20% random jitter on top of `interval`, so every instance in a
+/// fleet sharing the same `spawn_rehydrate` interval doesn't refresh its hot
+/// keys in lockstep and stampede the repository at the same instant.
+fn jittered(interval: Duration) -> Duration {
+    use std::sync::atomic::Ordering;
+
+    let mut x = REHYDRATE_JITTER_STATE.fetch_add(1, Ordering::Relaxed);
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    REHYDRATE_JITTER_STATE.store(x, Ordering::Relaxed);
+
+    let unit = (x >> 11) as f64 * (1.0 / (1u64 << 53) as f64);
+    interval + Duration::from_nanos((interval.as_nanos() as f64 * 0.2 * unit) as u64)
+}
 
 /// High-level cache service for web applications.
 ///
@@ -49,6 +79,12 @@ use std::sync::Arc;
 #[derive(Clone)]
 pub struct CacheService<B: CacheBackend> {
     expander: Arc<CacheExpander<B>>,
+    /// Per-key locks coalescing concurrent `get_or_load` misses; mirrors
+    /// `CacheExpander`'s own `inflight` map for the typed fetch path.
+    inflight: Arc<DashMap<String, Arc<AsyncMutex<()>>>>,
+    /// Cache keys opted into background rehydration via `track`/`untrack`;
+    /// see `spawn_rehydrate`.
+    hot_keys: Arc<DashSet<String>>,
 }
 
 impl<B: CacheBackend> CacheService<B> {
@@ -56,6 +92,8 @@ impl<B: CacheBackend> CacheService<B> {
     pub fn new(backend: B) -> Self {
         CacheService {
             expander: Arc::new(CacheExpander::new(backend)),
+            inflight: Arc::new(DashMap::new()),
+            hot_keys: Arc::new(DashSet::new()),
         }
     }
 
@@ -63,9 +101,233 @@ impl<B: CacheBackend> CacheService<B> {
     pub fn with_metrics(backend: B, metrics: Box<dyn CacheMetrics>) -> Self {
         CacheService {
             expander: Arc::new(CacheExpander::new(backend).with_metrics(metrics)),
+            inflight: Arc::new(DashMap::new()),
+            hot_keys: Arc::new(DashSet::new()),
+        }
+    }
+
+    /// Create a new cache service that publishes to `bus` whenever
+    /// `CacheStrategy::Invalidate` evicts a key.
+    ///
+    /// Pair this with [`CacheService::spawn_invalidation_listener`] on every
+    /// instance sharing the same logical cache so each one also applies
+    /// invalidations published by the others.
+    pub fn with_invalidation_bus(backend: B, bus: Arc<dyn InvalidationBus>) -> Self {
+        CacheService {
+            expander: Arc::new(CacheExpander::new(backend).with_invalidation_bus(bus)),
+            inflight: Arc::new(DashMap::new()),
+            hot_keys: Arc::new(DashSet::new()),
+        }
+    }
+
+    /// Create a new cache service that serializes repository loads for the
+    /// same key across processes via `lock`.
+    ///
+    /// See [`CacheExpander::with_locked_refresh`] for what this adds on top
+    /// of the in-process single-flight guard every cache service already has.
+    #[cfg(feature = "redis")]
+    pub fn with_locked_refresh(
+        backend: B,
+        lock: Arc<crate::backend::redis::DistributedLock>,
+    ) -> Self {
+        CacheService {
+            expander: Arc::new(CacheExpander::new(backend).with_locked_refresh(lock)),
+            inflight: Arc::new(DashMap::new()),
+            hot_keys: Arc::new(DashSet::new()),
         }
     }
 
+    /// Create a new cache service that gates repository refreshes through
+    /// `limiter`.
+    ///
+    /// See [`CacheExpander::with_rate_limiter`]; callers must still opt each
+    /// operation into a cap via [`OperationConfig::with_rate_limit`].
+    #[cfg(feature = "redis")]
+    pub fn with_rate_limiter(
+        backend: B,
+        limiter: Arc<crate::backend::redis::RateLimiter>,
+    ) -> Self {
+        CacheService {
+            expander: Arc::new(CacheExpander::new(backend).with_rate_limiter(limiter)),
+            inflight: Arc::new(DashMap::new()),
+            hot_keys: Arc::new(DashSet::new()),
+        }
+    }
+
+    /// Create a new cache service whose backend recovers from persistent
+    /// read/write failures instead of propagating them.
+    ///
+    /// See [`crate::backend::RecoveringBackend`] for the retry/reset/fallback
+    /// sequence; `retry_count` is how many times a failing operation is
+    /// retried before a key reset and, ultimately, `policy` are tried.
+    pub fn with_recovery(
+        backend: B,
+        policy: crate::backend::RecoveryPolicy,
+        retry_count: u32,
+    ) -> CacheService<crate::backend::RecoveringBackend<B>> {
+        let backend = crate::backend::RecoveringBackend::new(backend, policy, retry_count);
+        CacheService {
+            expander: Arc::new(CacheExpander::new(backend)),
+            inflight: Arc::new(DashMap::new()),
+            hot_keys: Arc::new(DashSet::new()),
+        }
+    }
+
+    /// Spawn a background task that applies invalidations published by other
+    /// instances to this service's backend.
+    ///
+    /// Replays any invalidations the bus retained from before this call (see
+    /// `InvalidationBus::replay`) before switching over to the live
+    /// subscription, so a late-starting instance doesn't trust cache entries
+    /// that were invalidated elsewhere while it was down.
+    ///
+    /// Returns `None` if no invalidation bus was configured.
+    pub fn spawn_invalidation_listener(&self) -> Option<tokio::task::JoinHandle<()>>
+    where
+        B: 'static,
+    {
+        let bus = self.expander.invalidation_bus()?.clone();
+        let expander = self.expander.clone();
+
+        Some(tokio::spawn(async move {
+            for event in bus.replay() {
+                if let Err(e) = expander.backend().delete(&event.key).await {
+                    warn!(
+                        "⚠ Failed to apply replayed invalidation for {}: {}",
+                        event.key, e
+                    );
+                }
+            }
+
+            let mut rx = bus.subscribe();
+            loop {
+                match rx.recv().await {
+                    Ok(event) => {
+                        if let Err(e) = expander.backend().delete(&event.key).await {
+                            warn!(
+                                "⚠ Failed to apply remote invalidation for {}: {}",
+                                event.key, e
+                            );
+                        }
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!(
+                            "⚠ Invalidation listener lagged, skipped {} events",
+                            skipped
+                        );
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        }))
+    }
+
+    /// Opt `key` into background rehydration by [`CacheService::spawn_rehydrate`].
+    ///
+    /// `key` is the fully-qualified cache key (e.g. `"invoice:42"`, matching
+    /// the format [`CacheExpander::cache_key_for`]/`"{prefix}:{id}"` already
+    /// use), not a bare entity id - a handler opting a hot invoice in already
+    /// has it to hand from whichever `CacheEntity` read populated it.
+    /// Tracking the same key twice is a no-op.
+    pub fn track(&self, key: impl Into<String>) {
+        self.hot_keys.insert(key.into());
+    }
+
+    /// Stop rehydrating `key` in the background.
+    ///
+    /// A no-op if `key` wasn't tracked (e.g. an invoice that was never
+    /// promoted into the hot set, or was already untracked).
+    pub fn untrack(&self, key: impl AsRef<str>) {
+        self.hot_keys.remove(key.as_ref());
+    }
+
+    /// Spawn a background task that, every `interval` (plus up to 20% jitter,
+    /// to keep a fleet of instances sharing this interval from refreshing in
+    /// lockstep), re-fetches every tracked `T`-prefixed key from `repository`
+    /// and rewrites its cache entry - so a hot key opted in via
+    /// [`CacheService::track`] never ages past its TTL into a cold miss.
+    ///
+    /// Only keys under `T::cache_prefix()` are refreshed by a given call;
+    /// track multiple entity types by spawning one rehydrator per type, each
+    /// against the same `hot_keys` set (it's shared via `self.clone()`).
+    /// A key a caller never opted in via `track` is left alone, same as one
+    /// that was `untrack`ed since the last tick. Fetch errors and
+    /// now-uncacheable entities are logged and skipped rather than evicting
+    /// the existing entry, matching [`CacheExpander::spawn_background_refresh`]'s
+    /// "leave the stale value in place on a failed refresh" behavior.
+    pub fn spawn_rehydrate<T, R>(
+        &self,
+        interval: Duration,
+        repository: Arc<R>,
+    ) -> tokio::task::JoinHandle<()>
+    where
+        T: CacheEntity,
+        T::Key: FromStr,
+        R: DataRepository<T> + Send + Sync + 'static,
+        B: 'static,
+    {
+        let expander = self.expander.clone();
+        let hot_keys = self.hot_keys.clone();
+        let prefix = T::cache_prefix();
+
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(jittered(interval)).await;
+
+                let keys: Vec<String> = hot_keys
+                    .iter()
+                    .map(|key| key.clone())
+                    .filter(|key| {
+                        key.strip_prefix(prefix)
+                            .is_some_and(|rest| rest.starts_with(':'))
+                    })
+                    .collect();
+
+                for cache_key in keys {
+                    let id = match expander.extract_id_from_key::<T>(&cache_key) {
+                        Ok(id) => id,
+                        Err(e) => {
+                            warn!("⚠ Rehydrate couldn't parse id from {}: {}", cache_key, e);
+                            continue;
+                        }
+                    };
+
+                    match repository.fetch_by_id(&id).await {
+                        Ok(Some(entity)) if repository.is_cacheable(&entity) => {
+                            match entity.serialize_for_cache() {
+                                Ok(bytes) => {
+                                    let ttl = entity
+                                        .cache_ttl()
+                                        .or_else(|| expander.ttl_policy.get_ttl(prefix));
+                                    if let Err(e) =
+                                        expander.backend().set(&cache_key, bytes, ttl).await
+                                    {
+                                        warn!("⚠ Rehydrate failed to write {}: {}", cache_key, e);
+                                    }
+                                }
+                                Err(e) => {
+                                    warn!("⚠ Rehydrate failed to serialize {}: {}", cache_key, e);
+                                }
+                            }
+                        }
+                        Ok(Some(_)) => {
+                            debug!(
+                                "Rehydrate fetched an uncacheable entity for {}, leaving cache entry in place",
+                                cache_key
+                            );
+                        }
+                        Ok(None) => {
+                            debug!("Rehydrate found {} no longer exists in the repository", cache_key);
+                        }
+                        Err(e) => {
+                            warn!("⚠ Rehydrate fetch failed for {}: {}", cache_key, e);
+                        }
+                    }
+                }
+            }
+        })
+    }
+
     /// Execute a cache operation.
     ///
     /// This is equivalent to calling `expander.with()` but more ergonomic
@@ -163,12 +425,339 @@ impl<B: CacheBackend> CacheService<B> {
             .await
     }
 
+    /// Execute `CacheStrategy::StaleWhileRevalidate`: serve a cached value
+    /// immediately even past `config.stale_after`, while refreshing it from
+    /// `repository` on a detached background task - so a hot key's callers
+    /// never wait on the database, at the cost of serving up to one refresh
+    /// cycle of staleness. Concurrent revalidations for the same key are
+    /// deduplicated, at most one background fetch runs per stale entry.
+    ///
+    /// This is a separate method from [`CacheService::execute`] rather than
+    /// another `CacheStrategy` match arm there: the background refresh task
+    /// outlives the call, so it needs an owned `Arc<R>` handle to move into
+    /// it, unlike `execute`'s borrowed `&R` - see
+    /// [`CacheExpander::with_stale_while_revalidate`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let cache = CacheService::new(InMemoryBackend::new());
+    /// let repo = Arc::new(InvoiceRepository::new(pool));
+    /// let config = OperationConfig::default().with_stale_after(Duration::from_secs(30));
+    ///
+    /// cache.execute_stale_while_revalidate(&mut feeder, repo, config).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` in the same cases as [`CacheService::execute`], plus
+    /// `Error::ValidationError` if `config.stale_after` is `None` and the
+    /// expander's `ttl_policy` isn't `TtlPolicy::SoftHard`.
+    pub async fn execute_stale_while_revalidate<T, F, R>(
+        &self,
+        feeder: &mut F,
+        repository: Arc<R>,
+        config: OperationConfig,
+    ) -> Result<()>
+    where
+        T: CacheEntity,
+        F: CacheFeed<T>,
+        R: DataRepository<T> + Send + Sync + 'static,
+        T::Key: FromStr,
+    {
+        self.expander
+            .with_stale_while_revalidate::<T, F, R>(feeder, repository, config)
+            .await
+    }
+
+    /// Like [`CacheService::execute`], but returns the served value wrapped
+    /// in a [`CacheOutcome`] instead of feeding it to `feeder` and
+    /// discarding whether it was cached or fetched. Lets a caller log
+    /// provenance, emit metrics, or set a response header (e.g. `X-Cache:
+    /// HIT`) without threading a mutable `cache_hit` flag through its
+    /// `CacheFeed`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let cache = CacheService::new(InMemoryBackend::new());
+    /// let mut feeder = UserFeeder { id: "user_123".to_string(), user: None };
+    /// let repo = UserRepository::new(pool);
+    ///
+    /// match cache.execute_with_outcome(&mut feeder, &repo, CacheStrategy::Refresh).await? {
+    ///     Some(outcome) => println!("cache_hit={}", outcome.is_cached()),
+    ///     None => println!("not found"),
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` in the same cases as [`CacheService::execute`].
+    pub async fn execute_with_outcome<T, F, R>(
+        &self,
+        feeder: &mut F,
+        repository: &R,
+        strategy: CacheStrategy,
+    ) -> Result<Option<CacheOutcome<T>>>
+    where
+        T: CacheEntity,
+        F: CacheFeed<T>,
+        R: DataRepository<T>,
+        T::Key: FromStr,
+    {
+        self.expander
+            .with_outcome::<T, F, R>(feeder, repository, strategy)
+            .await
+    }
+
+    /// Like [`CacheService::execute_stale_while_revalidate`], but returns the
+    /// served value wrapped in a [`CacheOutcome`] instead of feeding it to
+    /// `feeder` - a stale hit (background refresh triggered) comes back as
+    /// `CacheOutcome::Refreshed` rather than being indistinguishable from a
+    /// fresh `CacheOutcome::Cached` hit.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` in the same cases as [`CacheService::execute_stale_while_revalidate`].
+    pub async fn execute_stale_while_revalidate_outcome<T, F, R>(
+        &self,
+        feeder: &mut F,
+        repository: Arc<R>,
+        config: OperationConfig,
+    ) -> Result<Option<CacheOutcome<T>>>
+    where
+        T: CacheEntity,
+        F: CacheFeed<T>,
+        R: DataRepository<T> + Send + Sync + 'static,
+        T::Key: FromStr,
+    {
+        self.expander
+            .with_stale_while_revalidate_outcome::<T, F, R>(feeder, repository, config)
+            .await
+    }
+
+    /// Resolve a list endpoint's rows through cache in one pass: a single
+    /// `mget` against the backend, one batched repository fetch for whatever
+    /// rows miss, and one `mset` writing the newly-fetched rows back - so a
+    /// handler serving a list can stop bypassing the per-row cache that
+    /// `get_invoice`-style single-entity reads already populate. Each row
+    /// comes back wrapped in a [`CacheOutcome`] so the handler can tell hot
+    /// rows from cold ones (e.g. for a response header or metric), in the
+    /// same order as `feeder.entity_ids()`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut feeder = GenericBatchFeeder::new(vec!["1".into(), "2".into()]);
+    /// let rows = cache.execute_batch(&mut feeder, &repo).await?;
+    /// for (id, outcome) in rows {
+    ///     if let Some(outcome) = outcome {
+    ///         println!("{id}: cache_hit={}", outcome.is_cached());
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` in the same cases as [`CacheService::execute_with_outcome`].
+    pub async fn execute_batch<T, F, R>(
+        &self,
+        feeder: &mut F,
+        repository: &R,
+    ) -> Result<Vec<(T::Key, Option<CacheOutcome<T>>)>>
+    where
+        T: CacheEntity,
+        F: crate::feed::BatchCacheFeed<T>,
+        R: DataRepository<T>,
+    {
+        self.expander
+            .with_batch_outcome::<T, F, R>(feeder, repository)
+            .await
+    }
+
+    /// [`CacheService::execute_batch`] with a per-operation [`OperationConfig`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` in the same cases as [`CacheService::execute_batch`].
+    pub async fn execute_batch_config<T, F, R>(
+        &self,
+        feeder: &mut F,
+        repository: &R,
+        config: OperationConfig,
+    ) -> Result<Vec<(T::Key, Option<CacheOutcome<T>>)>>
+    where
+        T: CacheEntity,
+        F: crate::feed::BatchCacheFeed<T>,
+        R: DataRepository<T>,
+    {
+        self.expander
+            .with_batch_config_outcome::<T, F, R>(feeder, repository, config)
+            .await
+    }
+
     /// Get a reference to the underlying expander.
     ///
     /// Use this if you need direct access to expander methods.
     pub fn expander(&self) -> &CacheExpander<B> {
         &self.expander
     }
+
+    /// Derive a service confined to `principal`'s namespace.
+    ///
+    /// Every key read or written through the returned service is prefixed
+    /// with `"{principal}:"` before it reaches the shared backend, so two
+    /// principals sharing one `CacheService` never observe each other's
+    /// entries even if their feeders happen to produce the same cache key
+    /// (e.g. a per-tenant "settings" singleton). Cheap to call per request:
+    /// it wraps a clone of the backend rather than opening a new connection.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let scoped = cache.scoped(&user_id);
+    /// scoped.execute::<Settings, _, _>(&mut feeder, &repo, CacheStrategy::Refresh).await?;
+    /// ```
+    pub fn scoped(&self, principal: impl Into<String>) -> CacheService<crate::backend::ScopedBackend<B>> {
+        let backend = crate::backend::ScopedBackend::new(self.expander.backend().clone(), principal);
+        CacheService {
+            expander: Arc::new(CacheExpander::new(backend)),
+            inflight: Arc::new(DashMap::new()),
+            hot_keys: Arc::new(DashSet::new()),
+        }
+    }
+
+    /// Evict every key cached under `principal`'s namespace.
+    ///
+    /// Use on logout or tenant deletion to guarantee nothing scoped to that
+    /// principal survives in the shared backend, without touching any other
+    /// principal's entries.
+    pub async fn flush_scope(&self, principal: impl AsRef<str>) -> Result<()> {
+        self.expander.backend().invalidate_prefix(principal.as_ref()).await
+    }
+
+    /// Alias for [`CacheService::flush_scope`], for callers thinking in
+    /// terms of a multi-tenant namespace rather than a per-principal scope -
+    /// `ns` is the same token passed to [`CacheService::scoped`], so an admin
+    /// tool can flush one tenant's cache without deriving a scoped service
+    /// first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` in the same cases as [`CacheService::flush_scope`].
+    pub async fn flush_namespace(&self, ns: impl AsRef<str>) -> Result<()> {
+        self.flush_scope(ns).await
+    }
+
+    /// Drop every key cached under `prefix`, returning how many were removed.
+    ///
+    /// Use this after a write that invalidates a whole list-cache family
+    /// (e.g. every `invoice:list:<customer>:*` page after a new invoice is
+    /// created) instead of tracking and deleting each page's key by hand.
+    ///
+    /// # Errors
+    /// Returns `Err` if the backend cannot enumerate its keys, or the delete fails.
+    pub async fn delete_prefix(&self, prefix: impl AsRef<str>) -> Result<u64> {
+        self.expander.backend().delete_prefix(prefix.as_ref()).await
+    }
+
+    /// Evict every cache entry an entity tagged itself with via
+    /// [`crate::entity::CacheEntity::cache_tags`].
+    ///
+    /// Use this after a write that affects entries beyond an entity's own
+    /// primary key - e.g. `invalidate_by_tag("customer:42")` after an
+    /// invoice status update, to also drop every cached list view keyed off
+    /// that customer, not just the single `invoice:{id}` entry `execute`
+    /// just refreshed.
+    ///
+    /// # Errors
+    /// Returns `Err` if the backend doesn't support tag-based invalidation,
+    /// or the invalidation itself fails.
+    pub async fn invalidate_by_tag(&self, tag: impl AsRef<str>) -> Result<()> {
+        self.expander.invalidate_tag(tag.as_ref()).await
+    }
+
+    /// Spawn a background admin HTTP server exposing `GET {metrics_path}`
+    /// (Prometheus text rendered from `metrics`) and `GET {health_path}`
+    /// (200/503 from this service's own backend's `health_check`).
+    ///
+    /// `metrics` is the same handle passed to `with_metrics`/`with_admin_metrics`
+    /// (or any other `Arc<HistogramMetrics>` the caller wants exposed) - kept as
+    /// an explicit argument rather than stored on `CacheService` since reading it
+    /// back out from the type-erased `Box<dyn CacheMetrics>` on `CacheExpander`
+    /// isn't possible once it's been boxed.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn serve_admin(
+        &self,
+        config: crate::admin::AdminConfig,
+        metrics: Arc<crate::observability::HistogramMetrics>,
+    ) -> tokio::task::JoinHandle<()>
+    where
+        B: 'static,
+    {
+        let expander = self.expander.clone();
+
+        tokio::spawn(async move {
+            if let Err(e) = crate::admin::serve(config, expander, metrics).await {
+                warn!("⚠ Admin endpoint stopped: {}", e);
+            }
+        })
+    }
+
+    /// Read `key` from the cache, or run `loader` on a miss and populate it.
+    ///
+    /// Unlike `execute`, this isn't tied to a `CacheEntity`/`DataRepository`
+    /// pair - `key` is used verbatim (callers typically build it with
+    /// `CacheKeyBuilder`) and `loader` can be any async closure producing a
+    /// `V`. Concurrent misses for the same `key` are coalesced: the first
+    /// caller runs `loader` while later callers block on a per-key lock,
+    /// then re-check the cache the winner just populated instead of also
+    /// invoking `loader`. This keeps a thundering herd on a cold key from
+    /// driving one `loader` call per waiting request.
+    ///
+    /// If `loader` errors, the slot is still released so the key isn't
+    /// permanently stuck - the next caller simply retries the load.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let key = CacheKeyBuilder::build::<User>(&user_id);
+    /// let user = cache.get_or_load(&key, || async { repo.fetch_by_id(&user_id).await }).await?;
+    /// ```
+    pub async fn get_or_load<V, F, Fut>(&self, key: &str, loader: F) -> Result<V>
+    where
+        V: Serialize + DeserializeOwned,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<V>>,
+    {
+        if let Some(bytes) = self.expander.backend().get(key).await? {
+            return crate::serialization::deserialize_from_cache(&bytes);
+        }
+
+        let lock = self
+            .inflight
+            .entry(key.to_string())
+            .or_insert_with(|| Arc::new(AsyncMutex::new(())))
+            .clone();
+        let _guard = lock.lock().await;
+
+        // Another caller may have populated the cache while we waited.
+        if let Some(bytes) = self.expander.backend().get(key).await? {
+            self.inflight.remove_if(key, |_, l| Arc::strong_count(l) == 1);
+            return crate::serialization::deserialize_from_cache(&bytes);
+        }
+
+        let result = loader().await;
+        if let Ok(value) = &result {
+            let bytes = crate::serialization::serialize_for_cache(value)?;
+            let _ = self.expander.backend().set(key, bytes, None).await;
+        }
+
+        self.inflight.remove_if(key, |_, l| Arc::strong_count(l) == 1);
+        result
+    }
 }
 
 #[cfg(test)]
@@ -176,6 +765,7 @@ mod tests {
     use super::*;
     use crate::backend::InMemoryBackend;
     use crate::feed::GenericFeeder;
+    use crate::invalidation::BroadcastInvalidationBus;
     use crate::repository::InMemoryRepository;
     use serde::{Deserialize, Serialize};
 
@@ -318,4 +908,520 @@ mod tests {
         assert!(feeder.data.is_some());
         assert_eq!(feeder.data.expect("Data not found").value, "test_value");
     }
+
+    #[tokio::test]
+    async fn test_cache_service_execute_with_outcome_distinguishes_cached_from_fetched() {
+        let backend = InMemoryBackend::new();
+        let service = CacheService::new(backend);
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "test_value".to_string(),
+            },
+        );
+
+        let mut first = GenericFeeder::new("1".to_string());
+        let outcome = service
+            .execute_with_outcome::<TestEntity, _, _>(&mut first, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute")
+            .expect("Expected a cache outcome");
+        assert!(!outcome.is_cached());
+        assert_eq!(outcome.into_inner().value, "test_value");
+
+        let mut second = GenericFeeder::new("1".to_string());
+        let outcome = service
+            .execute_with_outcome::<TestEntity, _, _>(&mut second, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute")
+            .expect("Expected a cache outcome");
+        assert!(outcome.is_cached());
+        assert_eq!(outcome.into_inner().value, "test_value");
+    }
+
+    #[tokio::test]
+    async fn test_cache_service_execute_batch_distinguishes_cached_from_fetched_rows() {
+        use crate::feed::GenericBatchFeeder;
+
+        let backend = InMemoryBackend::new();
+        let service = CacheService::new(backend);
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "2".to_string(),
+            TestEntity {
+                id: "2".to_string(),
+                value: "from_db".to_string(),
+            },
+        );
+
+        // Warm "1" into the cache via a plain single-entity execute first.
+        let mut repo_with_one = InMemoryRepository::new();
+        repo_with_one.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "cached".to_string(),
+            },
+        );
+        let mut warm_feeder = GenericFeeder::new("1".to_string());
+        service
+            .execute::<TestEntity, _, _>(&mut warm_feeder, &repo_with_one, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to warm cache");
+
+        let mut feeder =
+            GenericBatchFeeder::<TestEntity>::new(vec!["1".to_string(), "2".to_string()]);
+        let results = service
+            .execute_batch::<TestEntity, _, _>(&mut feeder, &repo)
+            .await
+            .expect("Failed to execute batch");
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, "1");
+        assert!(matches!(
+            results[0].1,
+            Some(CacheOutcome::Cached(ref v)) if v.value == "cached"
+        ));
+        assert_eq!(results[1].0, "2");
+        assert!(matches!(
+            results[1].1,
+            Some(CacheOutcome::Fetched(ref v)) if v.value == "from_db"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_spawn_rehydrate_refreshes_only_tracked_keys() {
+        use std::time::Duration;
+
+        let backend = InMemoryBackend::new();
+        let service = CacheService::new(backend);
+
+        let repo = Arc::new(SharedRepo::new());
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "original".to_string(),
+            },
+        );
+        repo.insert(
+            "2".to_string(),
+            TestEntity {
+                id: "2".to_string(),
+                value: "original".to_string(),
+            },
+        );
+
+        // Warm both "test:1" and "test:2" into the cache, but only track "1".
+        let mut first = GenericFeeder::new("1".to_string());
+        service
+            .execute::<TestEntity, _, _>(&mut first, &*repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to warm test:1");
+        let mut second = GenericFeeder::new("2".to_string());
+        service
+            .execute::<TestEntity, _, _>(&mut second, &*repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to warm test:2");
+        service.track("test:1");
+
+        // Change what the repository would now return for both ids.
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "rehydrated".to_string(),
+            },
+        );
+        repo.insert(
+            "2".to_string(),
+            TestEntity {
+                id: "2".to_string(),
+                value: "rehydrated".to_string(),
+            },
+        );
+
+        let handle = service.spawn_rehydrate::<TestEntity, _>(Duration::from_millis(10), repo);
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        handle.abort();
+
+        let mut tracked = GenericFeeder::new("1".to_string());
+        service
+            .execute::<TestEntity, _, _>(&mut tracked, &InMemoryRepository::<TestEntity>::new(), CacheStrategy::Fresh)
+            .await
+            .expect("Failed to read test:1 from cache");
+        assert_eq!(
+            tracked.data.expect("test:1 should still be cached").value,
+            "rehydrated"
+        );
+
+        let mut untracked = GenericFeeder::new("2".to_string());
+        service
+            .execute::<TestEntity, _, _>(&mut untracked, &InMemoryRepository::<TestEntity>::new(), CacheStrategy::Fresh)
+            .await
+            .expect("Failed to read test:2 from cache");
+        assert_eq!(
+            untracked.data.expect("test:2 should still be cached").value,
+            "original"
+        );
+    }
+
+    #[test]
+    fn test_track_untrack_roundtrip() {
+        let backend = InMemoryBackend::new();
+        let service = CacheService::new(backend);
+
+        service.track("invoice:1");
+        assert!(service.hot_keys.contains("invoice:1"));
+
+        service.untrack("invoice:1");
+        assert!(!service.hot_keys.contains("invoice:1"));
+
+        // Untracking a key that was never tracked is a no-op, not an error.
+        service.untrack("invoice:never-tracked");
+    }
+
+    /// A `DataRepository` shared across an `Arc` with interior mutability,
+    /// unlike `InMemoryRepository::insert`'s `&mut self` - needed to mutate
+    /// seeded data after it's already wrapped for
+    /// `CacheService::execute_stale_while_revalidate`'s owned-`Arc` handle.
+    struct SharedRepo<T: CacheEntity> {
+        data: std::sync::Mutex<std::collections::HashMap<String, T>>,
+    }
+
+    impl<T: CacheEntity> SharedRepo<T> {
+        fn new() -> Self {
+            SharedRepo {
+                data: std::sync::Mutex::new(std::collections::HashMap::new()),
+            }
+        }
+
+        fn insert(&self, id: T::Key, value: T) {
+            self.data.lock().expect("lock poisoned").insert(id.to_string(), value);
+        }
+    }
+
+    impl<T: CacheEntity> DataRepository<T> for SharedRepo<T> {
+        async fn fetch_by_id(&self, id: &T::Key) -> Result<Option<T>> {
+            Ok(self.data.lock().expect("lock poisoned").get(&id.to_string()).cloned())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cache_service_execute_stale_while_revalidate_serves_stale_and_refreshes() {
+        use std::time::Duration;
+
+        let backend = InMemoryBackend::new();
+        let service = CacheService::new(backend);
+
+        let repo = Arc::new(SharedRepo::new());
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "original".to_string(),
+            },
+        );
+
+        // A zero stale_after means every hit is already past its soft TTL.
+        let config = OperationConfig::default().with_stale_after(Duration::from_secs(0));
+        let mut first = GenericFeeder::new("1".to_string());
+        service
+            .execute_stale_while_revalidate::<TestEntity, _, _>(&mut first, repo.clone(), config.clone())
+            .await
+            .expect("Failed to execute");
+        assert_eq!(first.data.expect("Data not found").value, "original");
+
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "refreshed".to_string(),
+            },
+        );
+
+        let mut second = GenericFeeder::new("1".to_string());
+        service
+            .execute_stale_while_revalidate::<TestEntity, _, _>(&mut second, repo.clone(), config.clone())
+            .await
+            .expect("Failed to execute");
+        // The stale value is still served immediately...
+        assert_eq!(second.data.expect("Data not found").value, "original");
+
+        // ...while the background refresh catches up shortly after.
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let mut third = GenericFeeder::new("1".to_string());
+        service
+            .execute_stale_while_revalidate::<TestEntity, _, _>(&mut third, repo, config)
+            .await
+            .expect("Failed to execute");
+        assert_eq!(third.data.expect("Data not found").value, "refreshed");
+    }
+
+    #[tokio::test]
+    async fn test_invalidation_listener_applies_remote_invalidation() {
+        use std::time::Duration;
+
+        let bus: Arc<dyn InvalidationBus> = Arc::new(BroadcastInvalidationBus::new(16));
+
+        let publisher = CacheService::with_invalidation_bus(InMemoryBackend::new(), bus.clone());
+        let subscriber = CacheService::with_invalidation_bus(InMemoryBackend::new(), bus);
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "test_value".to_string(),
+            },
+        );
+
+        // Both services cache the same entity independently.
+        let mut feeder = GenericFeeder::new("1".to_string());
+        publisher
+            .execute::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute on publisher");
+        let mut feeder = GenericFeeder::new("1".to_string());
+        subscriber
+            .execute::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute on subscriber");
+
+        let listener = subscriber
+            .spawn_invalidation_listener()
+            .expect("Listener should be spawned when a bus is configured");
+
+        let cache_key = crate::key::CacheKeyBuilder::build::<TestEntity>(&"1".to_string());
+        assert!(subscriber
+            .expander()
+            .backend()
+            .exists(&cache_key)
+            .await
+            .expect("Failed to check exists"));
+
+        // Invalidating on the publisher should propagate to the subscriber's backend.
+        let mut feeder = GenericFeeder::new("1".to_string());
+        publisher
+            .execute::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Invalidate)
+            .await
+            .expect("Failed to invalidate on publisher");
+
+        // The listener runs in a spawned task; give it a moment to process the event.
+        for _ in 0..50 {
+            if !subscriber
+                .expander()
+                .backend()
+                .exists(&cache_key)
+                .await
+                .expect("Failed to check exists")
+            {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        assert!(!subscriber
+            .expander()
+            .backend()
+            .exists(&cache_key)
+            .await
+            .expect("Failed to check exists"));
+
+        listener.abort();
+    }
+
+    #[tokio::test]
+    async fn test_get_or_load_populates_cache_on_miss() {
+        let service = CacheService::new(InMemoryBackend::new());
+
+        let value = service
+            .get_or_load("greeting", || async { Ok("hello".to_string()) })
+            .await
+            .expect("Failed to load");
+        assert_eq!(value, "hello");
+
+        let cached: bool = service
+            .expander()
+            .backend()
+            .exists("greeting")
+            .await
+            .expect("Failed to check exists");
+        assert!(cached);
+    }
+
+    #[tokio::test]
+    async fn test_get_or_load_hits_cache_without_invoking_loader() {
+        let service = CacheService::new(InMemoryBackend::new());
+
+        service
+            .get_or_load("greeting", || async { Ok("hello".to_string()) })
+            .await
+            .expect("Failed to load");
+
+        let value = service
+            .get_or_load("greeting", || async {
+                panic!("loader should not run on a cache hit")
+            })
+            .await
+            .expect("Failed to load");
+        assert_eq!(value, "hello");
+    }
+
+    #[tokio::test]
+    async fn test_get_or_load_coalesces_concurrent_misses() {
+        let service = CacheService::new(InMemoryBackend::new());
+        let load_count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+        let mut handles = vec![];
+        for _ in 0..10 {
+            let service = service.clone();
+            let load_count = load_count.clone();
+            handles.push(tokio::spawn(async move {
+                service
+                    .get_or_load("stampede", || async move {
+                        load_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+                        Ok::<_, crate::error::Error>("loaded".to_string())
+                    })
+                    .await
+                    .expect("Failed to load")
+            }));
+        }
+
+        for handle in handles {
+            assert_eq!(handle.await.expect("Task failed"), "loaded");
+        }
+
+        assert_eq!(load_count.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_or_load_does_not_poison_key_on_loader_error() {
+        let service = CacheService::new(InMemoryBackend::new());
+
+        let first = service
+            .get_or_load("flaky", || async {
+                Err::<String, _>(crate::error::Error::RepositoryError("down".to_string()))
+            })
+            .await;
+        assert!(first.is_err());
+
+        let second = service
+            .get_or_load("flaky", || async { Ok("recovered".to_string()) })
+            .await
+            .expect("Failed to load after previous error");
+        assert_eq!(second, "recovered");
+    }
+
+    #[test]
+    fn test_spawn_invalidation_listener_none_without_bus() {
+        let service = CacheService::new(InMemoryBackend::new());
+        assert!(service.spawn_invalidation_listener().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_scoped_services_sharing_a_backend_dont_see_each_others_entries() {
+        let backend = InMemoryBackend::new();
+        let cache = CacheService::new(backend);
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "alice_value".to_string(),
+            },
+        );
+
+        let alice = cache.scoped("alice");
+        let mut feeder = GenericFeeder::new("1".to_string());
+        alice
+            .execute::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute");
+        assert_eq!(feeder.data.expect("Data not found").value, "alice_value");
+
+        let bob = cache.scoped("bob");
+        let cache_key = crate::key::CacheKeyBuilder::build::<TestEntity>(&"1".to_string());
+        assert!(!bob
+            .expander()
+            .backend()
+            .exists(&cache_key)
+            .await
+            .expect("Failed to check exists"));
+    }
+
+    #[tokio::test]
+    async fn test_flush_scope_only_evicts_that_principals_entries() {
+        let backend = InMemoryBackend::new();
+        let cache = CacheService::new(backend);
+
+        let mut repo = InMemoryRepository::new();
+        repo.insert(
+            "1".to_string(),
+            TestEntity {
+                id: "1".to_string(),
+                value: "v".to_string(),
+            },
+        );
+
+        let alice = cache.scoped("alice");
+        let bob = cache.scoped("bob");
+
+        let mut feeder = GenericFeeder::new("1".to_string());
+        alice
+            .execute::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute");
+        let mut feeder = GenericFeeder::new("1".to_string());
+        bob.execute::<TestEntity, _, _>(&mut feeder, &repo, CacheStrategy::Refresh)
+            .await
+            .expect("Failed to execute");
+
+        cache.flush_scope("alice").await.expect("Failed to flush scope");
+
+        let cache_key = crate::key::CacheKeyBuilder::build::<TestEntity>(&"1".to_string());
+        assert!(!alice
+            .expander()
+            .backend()
+            .exists(&cache_key)
+            .await
+            .expect("Failed to check exists"));
+        assert!(bob
+            .expander()
+            .backend()
+            .exists(&cache_key)
+            .await
+            .expect("Failed to check exists"));
+    }
+
+    #[tokio::test]
+    async fn test_delete_prefix_returns_count_and_removes_the_family() {
+        let backend = InMemoryBackend::new();
+        let cache = CacheService::new(backend);
+
+        cache
+            .expander()
+            .backend()
+            .set("invoice:list:1", vec![1], None)
+            .await
+            .expect("Failed to set");
+        cache
+            .expander()
+            .backend()
+            .set("invoice:list:2", vec![2], None)
+            .await
+            .expect("Failed to set");
+
+        let deleted = cache
+            .delete_prefix("invoice:list")
+            .await
+            .expect("Failed to delete prefix");
+        assert_eq!(deleted, 2);
+    }
 }
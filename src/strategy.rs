@@ -8,9 +8,9 @@
 //! Cache-kit uses an enum-based strategy pattern to replace ad-hoc boolean flags.
 //! This makes cache behavior explicit and type-safe.
 //!
-//! # The Four Strategies
+//! # The Five Strategies
 //!
-//! Every cache operation uses one of four strategies:
+//! Every cache operation uses one of five strategies:
 //!
 //! ```
 //! use cache_kit::strategy::CacheStrategy;
@@ -26,6 +26,9 @@
 //!
 //! // 4. Bypass - Skip cache entirely
 //! let _s = CacheStrategy::Bypass;
+//!
+//! // 5. StaleWhileRevalidate - Serve stale, refresh in the background
+//! let _s = CacheStrategy::StaleWhileRevalidate;
 //! ```
 //!
 //! # Decision Tree
@@ -50,6 +53,7 @@
 //! | **Refresh** | Return | DB fallback | Default; prefer cache, ensure availability |
 //! | **Invalidate** | Delete | Fetch DB | After mutations; need fresh data |
 //! | **Bypass** | Ignore | DB always | Testing or temporary disable |
+//! | **StaleWhileRevalidate** | Return, refresh in background if stale | DB fallback | Hot keys where tail latency matters more than absolute freshness |
 //!
 //! # Examples by Scenario
 //!
@@ -62,6 +66,8 @@
 //! - **Fresh**: Fastest if hit, but fails on cache miss.
 //! - **Invalidate**: Ensures freshness but increases DB load after mutations.
 //! - **Bypass**: Simplest for testing, but defeats caching benefits.
+//! - **StaleWhileRevalidate**: Never blocks a hot key on the database, at the cost of
+//!   serving data that's up to one refresh cycle old; see [`crate::CacheExpander::with_stale_while_revalidate`].
 
 use std::time::Duration;
 
@@ -132,6 +138,43 @@ pub enum CacheStrategy {
     /// 2. Store in cache (for others)
     /// 3. Return value
     Bypass,
+
+    /// **StaleWhileRevalidate**: Return a cached value even past its soft TTL,
+    /// refreshing it from the database on a detached background task instead
+    /// of making the caller wait.
+    ///
+    /// Use when: Tail latency on a hot key matters more than serving the
+    /// absolute latest value; data converges to fresh within one refresh cycle.
+    ///
+    /// Only reachable via [`crate::CacheExpander::with_stale_while_revalidate`],
+    /// which takes the repository as an `Arc` so the background refresh can
+    /// outlive the call - `CacheExpander::with`/`with_config` reject this
+    /// variant with `Error::NotImplemented`.
+    ///
+    /// Flow:
+    /// 1. Check cache
+    /// 2. If hit and still within its soft TTL: return cached value
+    /// 3. If hit but past its soft TTL: return cached value, spawn a background refresh
+    /// 4. If miss: fetch from database, store in cache, return value
+    ///
+    /// There's no separate "grace window" past which this falls back to a
+    /// synchronous fetch - the entry's own hard TTL (see
+    /// [`crate::observability::TtlPolicy::SoftHard`]) already plays that
+    /// role: once it lapses the backend entry is gone outright, so step 4
+    /// above (a genuine miss) takes over without needing a second duration
+    /// to track.
+    ///
+    /// A hit written under a different strategy (so it has no soft-expiry
+    /// envelope at all) is treated as always-fresh rather than erroring -
+    /// this strategy is safe to turn on for a key that's already being
+    /// read under `Fresh`/`Refresh` without a flag day to rewrite every
+    /// existing entry first.
+    ///
+    /// The soft-TTL duration is a per-call [`crate::OperationConfig::with_stale_after`]
+    /// setting rather than a field on this variant, consistent with how
+    /// every other per-operation knob in this crate is threaded through
+    /// `OperationConfig` instead of growing the strategy enum itself.
+    StaleWhileRevalidate,
 }
 
 impl std::fmt::Display for CacheStrategy {
@@ -141,10 +184,83 @@ impl std::fmt::Display for CacheStrategy {
             CacheStrategy::Refresh => write!(f, "Refresh"),
             CacheStrategy::Invalidate => write!(f, "Invalidate"),
             CacheStrategy::Bypass => write!(f, "Bypass"),
+            CacheStrategy::StaleWhileRevalidate => write!(f, "StaleWhileRevalidate"),
         }
     }
 }
 
+/// Per-key TTL jitter, applied on the write path to spread out expiry of
+/// keys written in the same burst and smooth the resulting load spike on the
+/// repository.
+///
+/// Distinct from [`crate::observability::TtlPolicy::Jittered`], which draws
+/// from a shared counter-seeded PRNG and so picks a fresh offset on every
+/// write: this derives the offset from a hash of the cache key itself, so
+/// the *same* key gets the *same* offset for as long as the process runs,
+/// instead of jittering on every re-write of a key that's still live.
+///
+/// # Example
+///
+/// ```
+/// use cache_kit::strategy::{CacheContext, JitterPolicy};
+/// use std::time::Duration;
+///
+/// let policy = JitterPolicy::new(Duration::from_secs(300), 0.1);
+/// let ctx = CacheContext::new("user:1".to_string()).with_jitter(&policy);
+/// let ttl = ctx.ttl_remaining.unwrap();
+/// assert!(ttl >= Duration::from_secs(270) && ttl <= Duration::from_secs(330));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JitterPolicy {
+    /// Center of the TTL range.
+    pub base: Duration,
+    /// Fraction of `base` the effective TTL may deviate by, in either
+    /// direction. Clamped to `[0.0, 1.0]` by [`JitterPolicy::new`].
+    pub spread: f64,
+}
+
+impl JitterPolicy {
+    /// Create a jitter policy, clamping `spread` to `[0.0, 1.0]` - a spread
+    /// above 1.0 would let the perturbed TTL go negative before saturating.
+    pub fn new(base: Duration, spread: f64) -> Self {
+        JitterPolicy {
+            base,
+            spread: spread.clamp(0.0, 1.0),
+        }
+    }
+
+    /// The effective TTL for `key`: `base` offset by up to `spread * base`,
+    /// with the offset (including its sign) derived from a hash of `key` so
+    /// repeated calls for the same key agree.
+    fn jittered_ttl(&self, key: &str) -> Duration {
+        if self.spread == 0.0 {
+            return self.base;
+        }
+
+        let signed_unit = key_jitter_unit(key);
+        let offset_nanos = (self.base.as_nanos() as f64) * self.spread * signed_unit;
+        let result_nanos = (self.base.as_nanos() as f64 + offset_nanos).max(0.0);
+        Duration::from_nanos(result_nanos as u64)
+    }
+}
+
+/// Hash `key` into a value in `[-1.0, 1.0)`, for [`JitterPolicy::jittered_ttl`].
+///
+/// Uses `DefaultHasher` (SipHash), the same hasher [`crate::serialization`]
+/// already relies on for its checksums - deterministic within a build,
+/// nothing here needs it to resist deliberate collisions.
+fn key_jitter_unit(key: &str) -> f64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    key.hash(&mut hasher);
+    let h = hasher.finish();
+
+    // Same [0, 2^53) -> [0.0, 1.0) -> [-1.0, 1.0) mapping
+    // `observability::jittered_duration` uses.
+    let unit = (h >> 11) as f64 * (1.0 / (1u64 << 53) as f64);
+    unit * 2.0 - 1.0
+}
+
 /// Context information for cache operations.
 #[derive(Clone, Debug)]
 pub struct CacheContext {
@@ -184,6 +300,14 @@ impl CacheContext {
         self.ttl_remaining = Some(ttl);
         self
     }
+
+    /// Apply a [`JitterPolicy`] to this context's key, recording the
+    /// perturbed TTL in `ttl_remaining` so observability reflects the
+    /// entry's actual expiry rather than the policy's nominal `base`.
+    pub fn with_jitter(mut self, policy: &JitterPolicy) -> Self {
+        self.ttl_remaining = Some(policy.jittered_ttl(&self.key));
+        self
+    }
 }
 
 #[cfg(test)]
@@ -196,6 +320,10 @@ mod tests {
         assert_eq!(CacheStrategy::Refresh.to_string(), "Refresh");
         assert_eq!(CacheStrategy::Invalidate.to_string(), "Invalidate");
         assert_eq!(CacheStrategy::Bypass.to_string(), "Bypass");
+        assert_eq!(
+            CacheStrategy::StaleWhileRevalidate.to_string(),
+            "StaleWhileRevalidate"
+        );
     }
 
     #[test]
@@ -219,4 +347,67 @@ mod tests {
         assert!(ctx.is_cached);
         assert_eq!(ctx.ttl_remaining, Some(Duration::from_secs(300)));
     }
+
+    #[test]
+    fn test_jitter_policy_stays_within_spread() {
+        let policy = JitterPolicy::new(Duration::from_secs(300), 0.1);
+
+        for i in 0..50 {
+            let ttl = policy.jittered_ttl(&format!("key:{i}"));
+            assert!(
+                ttl >= Duration::from_secs(270) && ttl <= Duration::from_secs(330),
+                "ttl {:?} out of bounds for key:{}",
+                ttl,
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn test_jitter_policy_same_key_is_stable() {
+        let policy = JitterPolicy::new(Duration::from_secs(300), 0.2);
+
+        let first = policy.jittered_ttl("user:42");
+        let second = policy.jittered_ttl("user:42");
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_jitter_policy_different_keys_spread_out() {
+        let policy = JitterPolicy::new(Duration::from_secs(300), 0.2);
+
+        let ttls: std::collections::HashSet<Duration> = (0..50)
+            .map(|i| policy.jittered_ttl(&format!("key:{i}")))
+            .collect();
+
+        assert!(
+            ttls.len() > 1,
+            "expected keys to receive different jittered TTLs, got one value for all"
+        );
+    }
+
+    #[test]
+    fn test_jitter_policy_zero_spread_is_exact() {
+        let policy = JitterPolicy::new(Duration::from_secs(60), 0.0);
+        assert_eq!(policy.jittered_ttl("any-key"), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_jitter_policy_clamps_spread_above_one() {
+        let policy = JitterPolicy::new(Duration::from_secs(100), 5.0);
+        assert_eq!(policy.spread, 1.0);
+    }
+
+    #[test]
+    fn test_cache_context_with_jitter_sets_ttl_remaining() {
+        let policy = JitterPolicy::new(Duration::from_secs(300), 0.1);
+        let ctx = CacheContext::new("user:7".to_string()).with_jitter(&policy);
+
+        let ttl = ctx.ttl_remaining.expect("with_jitter should set a TTL");
+        assert!(ttl >= Duration::from_secs(270) && ttl <= Duration::from_secs(330));
+
+        // Same key, same policy: stable across contexts too.
+        let ctx2 = CacheContext::new("user:7".to_string()).with_jitter(&policy);
+        assert_eq!(ctx.ttl_remaining, ctx2.ttl_remaining);
+    }
 }
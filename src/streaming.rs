@@ -0,0 +1,238 @@
+//! Streaming payload support for large cache entries.
+//!
+//! `CacheData` lets a cache value travel as a channel of byte chunks instead
+//! of one fully-materialized `Vec<u8>`, so caching a multi-megabyte file body
+//! or rendered document through [`crate::backend::CacheBackend::set_stream`]
+//! doesn't force the whole thing into memory at once. Backends that can't
+//! accept chunks natively buffer via [`CacheData::into_bytes`] instead - see
+//! each `set_stream`/`get_stream` override for details.
+//!
+//! The fully-materialized variant holds a [`bytes::Bytes`] rather than a
+//! `Vec<u8>`, so a caller that needs the same payload in two places (store it
+//! *and* hand it to a feeder, say) clones a refcount instead of deep-copying
+//! the buffer - see [`CacheData::from_shared`].
+
+use crate::error::{Error, Result};
+use bytes::Bytes;
+use tokio::sync::mpsc;
+
+/// Chunk size used by the default [`crate::backend::CacheBackend::get_stream`]
+/// implementation when a backend has no native chunking of its own.
+pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// A cache payload, either fully in memory or arriving/leaving chunk by chunk.
+pub enum CacheData {
+    /// A payload that's already fully materialized (the common case for
+    /// small values, and what every non-streaming backend call produces).
+    /// A `Bytes` clone is a refcount bump, not a buffer copy - see
+    /// [`CacheData::from_shared`].
+    Bytes(Bytes),
+    /// A payload delivered as a channel of chunks, each `Ok(chunk)` or the
+    /// single `Err` that ended the stream early.
+    Stream {
+        /// Chunk source. Closing the sender (dropping it) without an error
+        /// ends the stream normally.
+        chunks: mpsc::Receiver<Result<Vec<u8>>>,
+        /// Total size in bytes, if known up front (e.g. from a
+        /// `Content-Length`). Used only as a capacity hint.
+        size_hint: Option<u64>,
+    },
+}
+
+impl CacheData {
+    /// Wrap a value already in memory.
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        CacheData::Bytes(Bytes::from(bytes))
+    }
+
+    /// Wrap a value already held as a [`bytes::Bytes`], for a caller that
+    /// needs to keep its own cheap clone alongside the one passed in here
+    /// (e.g. writing a value back to the cache while also returning it to
+    /// the caller that fetched it) instead of cloning a `Vec<u8>` twice.
+    pub fn from_shared(bytes: Bytes) -> Self {
+        CacheData::Bytes(bytes)
+    }
+
+    /// Split `bytes` into `chunk_size`-sized pieces delivered over a channel,
+    /// for backends whose `get_stream` has no native chunked read and falls
+    /// back to slicing up a fully-read value.
+    pub fn chunked(bytes: Vec<u8>, chunk_size: usize) -> Self {
+        let chunk_size = chunk_size.max(1);
+        let size_hint = Some(bytes.len() as u64);
+        let (tx, rx) = mpsc::channel(4);
+
+        tokio::spawn(async move {
+            for chunk in bytes.chunks(chunk_size) {
+                if tx.send(Ok(chunk.to_vec())).await.is_err() {
+                    return;
+                }
+            }
+        });
+
+        CacheData::Stream {
+            chunks: rx,
+            size_hint,
+        }
+    }
+
+    /// Size hint in bytes, if known (always `Some` for [`CacheData::Bytes`]).
+    pub fn size_hint(&self) -> Option<u64> {
+        match self {
+            CacheData::Bytes(b) => Some(b.len() as u64),
+            CacheData::Stream { size_hint, .. } => *size_hint,
+        }
+    }
+
+    /// Collect the whole payload into memory, draining a [`CacheData::Stream`]
+    /// chunk by chunk. Used by backends whose `set_stream` has no native
+    /// chunked write and falls back to one `set()` call.
+    ///
+    /// # Errors
+    /// Returns `Err` if the stream yields one before closing.
+    pub async fn into_bytes(self) -> Result<Vec<u8>> {
+        match self {
+            CacheData::Bytes(bytes) => Ok(bytes.to_vec()),
+            CacheData::Stream { mut chunks, size_hint } => {
+                let mut buf = Vec::with_capacity(size_hint.unwrap_or(0) as usize);
+                while let Some(chunk) = chunks.recv().await {
+                    buf.extend_from_slice(&chunk?);
+                }
+                Ok(buf)
+            }
+        }
+    }
+}
+
+/// Feeder trait for streaming cache operations, mirroring [`crate::CacheFeed`]
+/// but over a raw [`CacheData`] payload instead of a [`crate::CacheEntity`].
+///
+/// Large opaque blobs (file bodies, rendered documents) don't benefit from
+/// `CacheEntity`'s typed serialize/deserialize round trip, so this trait
+/// works directly in bytes.
+pub trait StreamingCacheFeed: Send {
+    /// Return the cache key to fetch.
+    fn cache_key(&mut self) -> String;
+
+    /// Feed the loaded payload into this feeder.
+    fn feed(&mut self, data: Option<CacheData>);
+}
+
+/// Fallback source for a [`StreamingCacheFeed`] miss, mirroring
+/// [`crate::DataRepository`] but returning a [`CacheData`] instead of a typed
+/// entity.
+#[allow(async_fn_in_trait)]
+pub trait StreamingDataSource: Send + Sync {
+    /// Fetch the payload for `key` directly from the source of truth.
+    ///
+    /// # Errors
+    /// Returns `Err` if the fetch fails.
+    async fn fetch(&self, key: &str) -> Result<Option<CacheData>>;
+}
+
+/// Generic [`StreamingCacheFeed`] that stores the loaded payload on itself,
+/// the streaming counterpart to [`crate::feed::GenericFeeder`].
+pub struct GenericStreamingFeeder {
+    /// Cache key to fetch/populate.
+    pub key: String,
+    /// Loaded payload, set by `feed()`.
+    pub data: Option<CacheData>,
+}
+
+impl GenericStreamingFeeder {
+    /// Create a feeder for `key`.
+    pub fn new(key: String) -> Self {
+        GenericStreamingFeeder { key, data: None }
+    }
+}
+
+impl StreamingCacheFeed for GenericStreamingFeeder {
+    fn cache_key(&mut self) -> String {
+        self.key.clone()
+    }
+
+    fn feed(&mut self, data: Option<CacheData>) {
+        self.data = data;
+    }
+}
+
+/// Build the manifest bytes (little-endian chunk count) backends use to
+/// record how many `{key}:chunk:{n}` entries a streamed value was split
+/// into. Shared by every `CacheBackend::set_stream`/`get_stream` override
+/// that chunks natively, so the on-disk format stays consistent.
+pub fn encode_manifest(chunk_count: u32) -> Vec<u8> {
+    chunk_count.to_le_bytes().to_vec()
+}
+
+/// Parse a manifest written by [`encode_manifest`].
+///
+/// # Errors
+/// Returns `Err(Error::InvalidCacheEntry)` if `bytes` isn't exactly 4 bytes.
+pub fn decode_manifest(bytes: &[u8]) -> Result<u32> {
+    let array: [u8; 4] = bytes
+        .try_into()
+        .map_err(|_| Error::InvalidCacheEntry("corrupt stream chunk manifest".to_string()))?;
+    Ok(u32::from_le_bytes(array))
+}
+
+/// Cache key under which a streamed value's chunk count is recorded.
+pub fn manifest_key(key: &str) -> String {
+    format!("{}:chunks", key)
+}
+
+/// Cache key for the `n`th chunk of a streamed value stored under `key`.
+pub fn chunk_key(key: &str, n: u32) -> String {
+    format!("{}:chunk:{}", key, n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_cache_data_bytes_into_bytes_is_identity() {
+        let data = CacheData::from_bytes(vec![1, 2, 3]);
+        assert_eq!(data.into_bytes().await.expect("Failed to collect"), vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_cache_data_from_shared_clone_shares_the_same_allocation() {
+        let shared = Bytes::from(vec![4, 5, 6]);
+        let ptr_before = shared.as_ptr();
+
+        let data = CacheData::from_shared(shared.clone());
+        assert_eq!(shared.as_ptr(), ptr_before, "clone must not copy the buffer");
+        assert_eq!(data.into_bytes().await.expect("Failed to collect"), vec![4, 5, 6]);
+    }
+
+    #[tokio::test]
+    async fn test_cache_data_chunked_round_trips() {
+        let original: Vec<u8> = (0..200).collect();
+        let data = CacheData::chunked(original.clone(), 64);
+        assert_eq!(data.into_bytes().await.expect("Failed to collect"), original);
+    }
+
+    #[tokio::test]
+    async fn test_cache_data_chunked_size_hint() {
+        let data = CacheData::chunked(vec![0; 10], 4);
+        assert_eq!(data.size_hint(), Some(10));
+    }
+
+    #[test]
+    fn test_manifest_round_trips() {
+        let bytes = encode_manifest(7);
+        assert_eq!(decode_manifest(&bytes).expect("Failed to decode"), 7);
+    }
+
+    #[test]
+    fn test_manifest_rejects_corrupt_bytes() {
+        assert!(decode_manifest(&[1, 2]).is_err());
+    }
+
+    #[test]
+    fn test_generic_streaming_feeder() {
+        let mut feeder = GenericStreamingFeeder::new("doc:1".to_string());
+        assert_eq!(feeder.cache_key(), "doc:1");
+        feeder.feed(Some(CacheData::from_bytes(vec![9])));
+        assert!(feeder.data.is_some());
+    }
+}
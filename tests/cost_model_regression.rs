@@ -0,0 +1,234 @@
+//! Fits a linear cost model to the benchmark suite's payload-size sweeps.
+//!
+//! `serialization_benchmarks` and the backend benchmarks in
+//! `benches/cache_benchmark.rs` already sweep `[100, 1_000, 10_000, 100_000]`
+//! byte payloads and let Criterion report four opaque mean times per
+//! operation. This fits `time = intercept + slope * bytes` across those
+//! four points via ordinary least squares, so CI can track a fixed per-call
+//! overhead and a per-byte throughput cost instead - and diff `slope`/
+//! `intercept` across commits to catch a regression a single payload size
+//! might not reveal.
+//!
+//! Run after `cargo bench` has populated `target/criterion/`:
+//! ```bash
+//! cargo bench
+//! cargo test --test cost_model_regression -- --ignored --nocapture
+//! ```
+//! which writes `target/criterion/cost_model.json` next to Criterion's own
+//! report, mirroring the regression-analysis step added to the weight/
+//! benchmark pipelines this was ported from.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Below this R², the four points aren't well-explained by a straight line
+/// (e.g. an allocator cliff around 100 KB) and the fit should be flagged
+/// rather than trusted at face value.
+const MIN_GOOD_FIT_R_SQUARED: f64 = 0.9;
+
+/// A fitted `time = intercept + slope * bytes` cost model for one
+/// benchmarked operation, plus its goodness-of-fit.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CostModel {
+    /// Fixed per-call overhead, in nanoseconds.
+    pub intercept_ns: f64,
+    /// Marginal per-byte cost, in nanoseconds/byte.
+    pub slope_ns_per_byte: f64,
+    /// Fraction of variance in the measured times explained by the fitted
+    /// line, in `[0.0, 1.0]` for a sane fit (it can go negative for a fit
+    /// worse than just predicting the mean, which also indicates a bad fit).
+    pub r_squared: f64,
+}
+
+impl CostModel {
+    /// Whether the fit is trustworthy - see [`MIN_GOOD_FIT_R_SQUARED`].
+    pub fn is_good_fit(&self) -> bool {
+        self.r_squared >= MIN_GOOD_FIT_R_SQUARED
+    }
+}
+
+/// Fit `time = intercept + slope * bytes` to `(bytes, mean_time_ns)` samples
+/// via ordinary least squares.
+///
+/// # Panics
+/// Panics if `samples` has fewer than two distinct `x` values - a line
+/// isn't identifiable from a single point.
+pub fn fit_linear_cost_model(samples: &[(f64, f64)]) -> CostModel {
+    let n = samples.len() as f64;
+    assert!(
+        samples.iter().any(|&(x, _)| x != samples[0].0),
+        "fit_linear_cost_model needs at least two distinct x values"
+    );
+
+    let sum_x: f64 = samples.iter().map(|&(x, _)| x).sum();
+    let sum_y: f64 = samples.iter().map(|&(_, y)| y).sum();
+    let sum_xy: f64 = samples.iter().map(|&(x, y)| x * y).sum();
+    let sum_x2: f64 = samples.iter().map(|&(x, _)| x * x).sum();
+
+    let slope = (n * sum_xy - sum_x * sum_y) / (n * sum_x2 - sum_x * sum_x);
+    let intercept = (sum_y - slope * sum_x) / n;
+
+    let mean_y = sum_y / n;
+    let ss_tot: f64 = samples.iter().map(|&(_, y)| (y - mean_y).powi(2)).sum();
+    let ss_res: f64 = samples
+        .iter()
+        .map(|&(x, y)| {
+            let predicted = intercept + slope * x;
+            (y - predicted).powi(2)
+        })
+        .sum();
+    let r_squared = if ss_tot == 0.0 { 1.0 } else { 1.0 - ss_res / ss_tot };
+
+    CostModel {
+        intercept_ns: intercept,
+        slope_ns_per_byte: slope,
+        r_squared,
+    }
+}
+
+/// The subset of Criterion's per-benchmark `estimates.json` this needs -
+/// just the point estimate of the mean, in nanoseconds.
+#[derive(Debug, Deserialize)]
+struct CriterionEstimates {
+    mean: CriterionEstimate,
+}
+
+#[derive(Debug, Deserialize)]
+struct CriterionEstimate {
+    point_estimate: f64,
+}
+
+/// Criterion lays out `BenchmarkId::new("serialize", size)` as the
+/// directory `<group>/serialize/<size>/base/estimates.json`. This reads that
+/// mean for one `(operation, size)` pair.
+fn read_mean_ns(criterion_dir: &Path, group: &str, operation: &str, size: u64) -> Option<f64> {
+    let path = criterion_dir
+        .join(group)
+        .join(operation)
+        .join(size.to_string())
+        .join("base")
+        .join("estimates.json");
+    let bytes = std::fs::read(path).ok()?;
+    let estimates: CriterionEstimates = serde_json::from_slice(&bytes).ok()?;
+    Some(estimates.mean.point_estimate)
+}
+
+/// Fit a [`CostModel`] for `operation` in `group` across `sizes`, skipping
+/// sizes Criterion hasn't recorded a result for (e.g. a partial `cargo
+/// bench --bench cache_benchmark -- serialize` run).
+fn fit_operation(criterion_dir: &Path, group: &str, operation: &str, sizes: &[u64]) -> Option<CostModel> {
+    let samples: Vec<(f64, f64)> = sizes
+        .iter()
+        .filter_map(|&size| read_mean_ns(criterion_dir, group, operation, size).map(|mean_ns| (size as f64, mean_ns)))
+        .collect();
+
+    if samples.len() < 2 {
+        return None;
+    }
+    Some(fit_linear_cost_model(&samples))
+}
+
+/// Fit cost models for every `(group, operation)` pair in `operations`
+/// across `sizes`, returning them keyed by `"{group}/{operation}"` and
+/// logging a warning for any fit below [`MIN_GOOD_FIT_R_SQUARED`].
+fn analyze_criterion_output(
+    criterion_dir: &Path,
+    operations: &[(&str, &str)],
+    sizes: &[u64],
+) -> BTreeMap<String, CostModel> {
+    let mut models = BTreeMap::new();
+    for &(group, operation) in operations {
+        let Some(model) = fit_operation(criterion_dir, group, operation, sizes) else {
+            continue;
+        };
+        if !model.is_good_fit() {
+            eprintln!(
+                "⚠ cost model for {group}/{operation} has R² = {:.3} (< {MIN_GOOD_FIT_R_SQUARED}) - \
+                 likely non-linear over this size range, treat slope/intercept with caution",
+                model.r_squared
+            );
+        }
+        models.insert(format!("{group}/{operation}"), model);
+    }
+    models
+}
+
+/// Regenerate `target/criterion/cost_model.json` from whatever Criterion
+/// output is already on disk. Requires `cargo bench` to have run first, so
+/// this is `#[ignore]`d by default - not part of the normal test suite.
+#[test]
+#[ignore]
+fn generate_cost_model_report() {
+    let criterion_dir = PathBuf::from("target/criterion");
+    let sizes = [100, 1_000, 10_000, 100_000];
+    let operations = [
+        ("serialization", "serialize"),
+        ("serialization", "deserialize"),
+    ];
+
+    let models = analyze_criterion_output(&criterion_dir, &operations, &sizes);
+    assert!(
+        !models.is_empty(),
+        "no cost models fitted - did `cargo bench` run first?"
+    );
+
+    let report = serde_json::to_string_pretty(&models).expect("Failed to serialize cost model report");
+    std::fs::write(criterion_dir.join("cost_model.json"), report).expect("Failed to write cost model report");
+}
+
+#[test]
+fn test_fit_linear_cost_model_recovers_known_coefficients() {
+    let intercept = 50.0;
+    let slope = 2.5;
+    let samples: Vec<(f64, f64)> = [100.0, 1_000.0, 10_000.0, 100_000.0]
+        .iter()
+        .map(|&x| (x, intercept + slope * x))
+        .collect();
+
+    let model = fit_linear_cost_model(&samples);
+
+    assert!((model.intercept_ns - intercept).abs() < 1e-6);
+    assert!((model.slope_ns_per_byte - slope).abs() < 1e-9);
+    assert!((model.r_squared - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_fit_linear_cost_model_flags_poor_fit_with_low_r_squared() {
+    // A step function is about as far from linear as four points get.
+    let samples = vec![(100.0, 10.0), (1_000.0, 10.0), (10_000.0, 10.0), (100_000.0, 10_000.0)];
+
+    let model = fit_linear_cost_model(&samples);
+
+    assert!(!model.is_good_fit(), "r_squared = {}", model.r_squared);
+}
+
+#[test]
+fn test_is_good_fit_threshold() {
+    let good = CostModel {
+        intercept_ns: 0.0,
+        slope_ns_per_byte: 0.0,
+        r_squared: 0.95,
+    };
+    let bad = CostModel {
+        intercept_ns: 0.0,
+        slope_ns_per_byte: 0.0,
+        r_squared: 0.5,
+    };
+
+    assert!(good.is_good_fit());
+    assert!(!bad.is_good_fit());
+}
+
+#[test]
+fn test_analyze_criterion_output_skips_operations_missing_from_disk() {
+    let empty_dir = std::env::temp_dir().join(format!(
+        "cache_kit_cost_model_missing_{}",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&empty_dir);
+
+    let models = analyze_criterion_output(&empty_dir, &[("serialization", "serialize")], &[100, 1_000]);
+
+    assert!(models.is_empty());
+}
@@ -7,7 +7,7 @@ use cache_kit::backend::{CacheBackend, InMemoryBackend};
 use cache_kit::feed::GenericFeeder;
 use cache_kit::repository::InMemoryRepository;
 use cache_kit::serialization::{
-    deserialize_from_cache, serialize_for_cache, CACHE_MAGIC, CURRENT_SCHEMA_VERSION,
+    deserialize_from_cache, serialize_for_cache, CacheEnvelope, CACHE_MAGIC, CURRENT_SCHEMA_VERSION,
 };
 use cache_kit::{CacheEntity, CacheExpander, CacheStrategy};
 use serde::{Deserialize, Serialize};
@@ -349,6 +349,19 @@ async fn test_backend_raw_bytes_validation() {
     // Deserialize
     let deserialized: User = deserialize_from_cache(&retrieved_bytes).unwrap();
     assert_eq!(deserialized, user);
+
+    // A proxy or backend bug that silently truncates/corrupts the stored
+    // bytes must surface as a checksum failure, not a confusing Postcard
+    // decode error - flip a payload byte past the envelope header and
+    // confirm `deserialize_from_cache` catches it.
+    let mut corrupted = retrieved_bytes.clone();
+    let last = corrupted.len() - 1;
+    corrupted[last] ^= 0xFF;
+    let result: cache_kit::Result<User> = deserialize_from_cache(&corrupted);
+    match result.unwrap_err() {
+        cache_kit::Error::ChecksumMismatch { .. } => {} // Expected
+        e => panic!("Expected ChecksumMismatch, got {:?}", e),
+    }
 }
 
 #[tokio::test]
@@ -381,6 +394,13 @@ async fn test_backend_stores_postcard_not_json() {
     assert_eq!(&raw_bytes[0..4], b"CKIT");
     assert_ne!(raw_bytes[0], b'{'); // NOT JSON
 
+    // The envelope records which `CacheFormat` encoded the payload as a tag
+    // byte (0 == Postcard, stable across builds - see `CacheFormat::tag`), so
+    // this assertion holds even if a future entry in the same cache was
+    // written with `serialize_for_cache_with(CacheFormat::Json, ..)` instead.
+    let envelope: CacheEnvelope<User> = postcard::from_bytes(&raw_bytes).unwrap();
+    assert_eq!(envelope.format, 0, "expected the Postcard format tag");
+
     // Verify it IS valid Postcard with envelope
     let deserialized: User = deserialize_from_cache(&raw_bytes).unwrap();
     assert_eq!(deserialized, user);
@@ -0,0 +1,72 @@
+//! Property-based tests for `CompressedBackend` (feature `compression`).
+//!
+//! The threshold decision between "store verbatim" and "gzip, then store" is
+//! entirely internal to `CompressedBackend::encode`/`decode` - these tests
+//! confirm that decision is never ambiguous on read, for both small values
+//! that skip compression and large ones that don't.
+
+#![cfg(feature = "compression")]
+
+use cache_kit::backend::{CacheBackend, CompressedBackend, InMemoryBackend};
+use proptest::prelude::*;
+
+fn backend() -> CompressedBackend<InMemoryBackend> {
+    CompressedBackend::with_threshold(InMemoryBackend::new(), 64)
+}
+
+proptest! {
+    /// Property: any value under the threshold roundtrips unchanged and
+    /// verbatim (no compression attempted).
+    #[test]
+    fn prop_small_value_roundtrips(value in prop::collection::vec(any::<u8>(), 0..64)) {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let backend = backend();
+            backend.set("key", value.clone(), None).await.unwrap();
+
+            let raw = backend.inner().get("key").await.unwrap().unwrap();
+            prop_assert_eq!(raw[0], 0, "values under the threshold must tag as uncompressed");
+
+            let roundtripped = backend.get("key").await.unwrap();
+            prop_assert_eq!(roundtripped, Some(value));
+            Ok(())
+        })?;
+    }
+
+    /// Property: any value at or above the threshold is compressed on write
+    /// and decompresses back to the exact original bytes on read.
+    #[test]
+    fn prop_large_value_roundtrips(value in prop::collection::vec(any::<u8>(), 64..4096)) {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let backend = backend();
+            backend.set("key", value.clone(), None).await.unwrap();
+
+            let raw = backend.inner().get("key").await.unwrap().unwrap();
+            prop_assert_eq!(raw[0], 1, "values at or above the threshold must tag as compressed");
+
+            let roundtripped = backend.get("key").await.unwrap();
+            prop_assert_eq!(roundtripped, Some(value));
+            Ok(())
+        })?;
+    }
+
+    /// Property: whichever path a value takes, the reader never needs to be
+    /// told which one was used - the stored tag alone disambiguates it.
+    #[test]
+    fn prop_compressed_and_uncompressed_paths_agree_on_readback(
+        small in prop::collection::vec(any::<u8>(), 0..64),
+        large in prop::collection::vec(any::<u8>(), 64..4096),
+    ) {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let backend = backend();
+            backend.set("small", small.clone(), None).await.unwrap();
+            backend.set("large", large.clone(), None).await.unwrap();
+
+            let values = backend.mget(&["small", "large"]).await.unwrap();
+            prop_assert_eq!(values, vec![Some(small), Some(large)]);
+            Ok(())
+        })?;
+    }
+}
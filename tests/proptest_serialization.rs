@@ -79,6 +79,26 @@ impl CacheEntity for ComplexEntity {
     }
 }
 
+/// A fixed-width binary blob wider than serde's built-in 32-element array
+/// support - the `big_array` adapter is what makes deriving `Serialize` here
+/// possible at all.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct BigArrayEntity {
+    id: u64,
+    #[serde(with = "cache_kit::serialization::big_array")]
+    payload: [u8; 580],
+}
+
+impl CacheEntity for BigArrayEntity {
+    type Key = u64;
+    fn cache_key(&self) -> Self::Key {
+        self.id
+    }
+    fn cache_prefix() -> &'static str {
+        "big_array"
+    }
+}
+
 // ============================================================================
 // Arbitrary Implementations (for property-based testing)
 // ============================================================================
@@ -137,6 +157,15 @@ fn arb_complex_entity() -> impl Strategy<Value = ComplexEntity> {
         })
 }
 
+/// Generate arbitrary BigArrayEntity with a random 580-byte payload
+fn arb_big_array_entity() -> impl Strategy<Value = BigArrayEntity> {
+    (any::<u64>(), prop::collection::vec(any::<u8>(), 580..=580)).prop_map(|(id, bytes)| {
+        let mut payload = [0u8; 580];
+        payload.copy_from_slice(&bytes);
+        BigArrayEntity { id, payload }
+    })
+}
+
 // ============================================================================
 // Property 1: Roundtrip Property
 // ============================================================================
@@ -177,6 +206,23 @@ proptest! {
 
         prop_assert_eq!(entity, deserialized);
     }
+
+    /// Property: For any BigArrayEntity, a fixed-width [u8; 580] field behind
+    /// the big_array adapter roundtrips through the envelope deterministically
+    #[test]
+    fn prop_big_array_entity_roundtrip(entity in arb_big_array_entity()) {
+        let bytes1 = serialize_for_cache(&entity)
+            .expect("Serialization should never fail for valid BigArrayEntity");
+        let bytes2 = serialize_for_cache(&entity)
+            .expect("Serialization should never fail for valid BigArrayEntity");
+
+        prop_assert_eq!(&bytes1, &bytes2, "Serialization of a big array field must be deterministic");
+
+        let deserialized: BigArrayEntity = deserialize_from_cache(&bytes1)
+            .expect("Deserialization should never fail for valid bytes");
+
+        prop_assert_eq!(entity, deserialized);
+    }
 }
 
 // ============================================================================
@@ -478,4 +524,35 @@ proptest! {
             prop_assert!(result.is_err(), "Should reject truncated data");
         }
     }
+
+    /// Property: A single flipped bit anywhere past the envelope header is
+    /// always caught by the payload checksum, even when postcard still
+    /// happens to decode the corrupted bytes into a valid-looking `Product`
+    /// (e.g. a bit flip inside `price` or `quantity`). Magic/version/truncation
+    /// corruption were already covered above; this is the "bytes decoded but
+    /// are wrong" case those don't reach.
+    #[test]
+    fn prop_single_bit_flip_past_header_detected(
+        product in arb_product(),
+        flip_offset in any::<usize>(),
+        flip_bit in 0u8..8,
+    ) {
+        let bytes = serialize_for_cache(&product)
+            .expect("Serialization should succeed");
+
+        // Header is magic(4) + version(4, postcard varint) + format(1) +
+        // checksum(up to 10, postcard varint) - skip comfortably past it so
+        // the flip lands in the payload, not the fields deserialize_from_cache
+        // checks before ever touching the checksum.
+        let header_len = 16;
+        prop_assume!(bytes.len() > header_len);
+
+        let mut corrupted = bytes.clone();
+        let offset = header_len + flip_offset % (corrupted.len() - header_len);
+        corrupted[offset] ^= 1 << flip_bit;
+        prop_assume!(corrupted != bytes);
+
+        let result: Result<Product, _> = deserialize_from_cache(&corrupted);
+        prop_assert!(result.is_err(), "Should reject a single-bit-flipped payload");
+    }
 }
@@ -472,7 +472,7 @@ async fn test_redis_connection_pooling() {
         .expect("Failed to create Redis backend");
 
     // Get pool stats
-    let stats = backend.pool_stats();
+    let stats = backend.pool_stats().await;
     println!("Pool Stats:");
     println!("  Connections: {}", stats.connections);
     println!("  Idle: {}", stats.idle_connections);
@@ -516,7 +516,7 @@ async fn test_redis_connection_pooling() {
     println!("✓ 100 concurrent operations completed successfully");
 
     // Check pool stats after concurrent operations
-    let final_stats = backend.pool_stats();
+    let final_stats = backend.pool_stats().await;
     println!("Final Pool Stats:");
     println!("  Connections: {}", final_stats.connections);
     println!("  Idle: {}", final_stats.idle_connections);